@@ -81,7 +81,7 @@ impl From<Method> for http::Method {
 /// ```
 #[derive(Debug, Parser)]
 pub struct ApiCommand {
-    /// Service name
+    /// Service type (official type or any of its aliases, i.e. `volumev3`)
     #[arg()]
     service: String,
 
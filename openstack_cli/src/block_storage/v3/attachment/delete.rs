@@ -33,10 +33,10 @@ use crate::OpenStackCliError;
 use crate::OutputConfig;
 use crate::StructTable;
 
-use bytes::Bytes;
-use http::Response;
+use crate::common::batch_query;
+use crate::common::check_batch_result;
 use openstack_sdk::api::block_storage::v3::attachment::delete;
-use openstack_sdk::api::RawQueryAsync;
+use openstack_sdk::api::ignore;
 use structable_derive::StructTable;
 
 /// Delete an attachment.
@@ -69,9 +69,11 @@ struct PathParameters {
     #[arg(
         help_heading = "Path parameters",
         id = "path_param_id",
-        value_name = "ID"
+        value_name = "ID",
+        num_args = 1..,
+        required = true,
     )]
-    id: String,
+    id: Vec<String>,
 }
 /// Attachment response representation
 #[derive(Deserialize, Serialize, Clone, StructTable)]
@@ -89,18 +91,30 @@ impl AttachmentCommand {
         let op = OutputProcessor::from_args(parsed_args);
         op.validate_args(parsed_args)?;
 
-        let mut ep_builder = delete::Request::builder();
-
-        // Set path parameters
-        ep_builder.id(&self.path.id);
-        // Set query parameters
-        // Set body parameters
-
-        let ep = ep_builder
-            .build()
-            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
-
-        let _rsp: Response<Bytes> = ep.raw_query_async(client).await?;
-        Ok(())
+        let ids: Vec<String> = self.path.id.clone();
+
+        let delete_eps = ids
+            .iter()
+            .map(|id| {
+                let mut ep_builder = delete::Request::builder();
+
+                // Set path parameters
+                ep_builder.id(id);
+                // Set query parameters
+                // Set body parameters
+
+                ep_builder
+                    .build()
+                    .map(ignore)
+                    .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let deleted: Vec<Option<()>> = batch_query(client, delete_eps, &ids, "Deleting").await?;
+        let ids: Vec<String> = ids
+            .into_iter()
+            .zip(deleted)
+            .filter_map(|(id, res)| res.map(|_| id))
+            .collect();
+        check_batch_result(self.path.id.len(), ids.len())
     }
 }
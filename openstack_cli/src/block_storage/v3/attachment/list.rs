@@ -34,7 +34,6 @@ use crate::OutputConfig;
 use crate::StructTable;
 
 use openstack_sdk::api::block_storage::v3::attachment::list_detailed;
-use openstack_sdk::api::QueryAsync;
 use openstack_sdk::api::{paged, Pagination};
 use serde_json::Value;
 use structable_derive::StructTable;
@@ -207,11 +206,10 @@ impl AttachmentsCommand {
             .build()
             .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
 
-        let data: Vec<serde_json::Value> = paged(ep, Pagination::Limit(self.max_items))
-            .query_async(client)
-            .await?;
+        let ep = paged(ep, Pagination::Limit(self.max_items)).prefetch(true);
 
-        op.output_list::<ResponseData>(data)?;
+        op.output_list_stream::<ResponseData, _, _>(ep.iter_async(client))
+            .await?;
         Ok(())
     }
 }
@@ -17,8 +17,10 @@
 
 use clap::{Parser, Subcommand};
 
+use crate::output::impl_struct_table;
 use crate::{Cli, OpenStackCliError};
 
+use openstack_sdk::types::block_storage::v3::Snapshot;
 use openstack_sdk::AsyncOpenStack;
 
 mod create;
@@ -31,6 +33,22 @@ mod os_update_snapshot_status;
 mod set;
 mod show;
 
+impl_struct_table!(
+    Snapshot,
+    ["created_at", "id", "name", "status", "updated_at"],
+    [
+        "consumes_quota",
+        "count",
+        "description",
+        "group_snapshot_id",
+        "metadata",
+        "os-extended-snapshot-attributes:progress",
+        "os-extended-snapshot-attributes:project_id",
+        "size",
+        "volume_id"
+    ]
+);
+
 /// Volume snapshots (snapshots)
 ///
 /// A snapshot is a point-in-time copy of the data that a volume contains.
@@ -35,6 +35,8 @@ use crate::StructTable;
 
 use crate::common::parse_key_val;
 use openstack_sdk::api::block_storage::v3::snapshot::create;
+use openstack_sdk::api::block_storage::v3::snapshot::get;
+use openstack_sdk::api::wait_for_status;
 use openstack_sdk::api::QueryAsync;
 use serde_json::Value;
 use structable_derive::StructTable;
@@ -51,6 +53,10 @@ pub struct SnapshotCommand {
     #[command(flatten)]
     path: PathParameters,
 
+    /// Wait for the operation to complete
+    #[arg(long)]
+    wait: bool,
+
     /// A `snapshot` object.
     ///
     #[command(flatten)]
@@ -270,7 +276,22 @@ impl SnapshotCommand {
             .build()
             .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
 
-        let data = ep.query_async(client).await?;
+        let data: serde_json::Value = ep.query_async(client).await?;
+        let data = if self.wait {
+            let id = data["id"]
+                .as_str()
+                .ok_or_else(|| anyhow::anyhow!("Response does not contain resource `id`"))?;
+            let get_ep = get::Request::builder()
+                .id(id)
+                .build()
+                .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+            wait_for_status(get_ep, "status", ["available"])
+                .failures(["error"])
+                .query_async(client)
+                .await?
+        } else {
+            data
+        };
         op.output_single::<ResponseData>(data)?;
         Ok(())
     }
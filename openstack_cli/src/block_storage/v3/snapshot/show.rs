@@ -20,7 +20,6 @@
 //! Wraps invoking of the `v3/snapshots/{id}` with `GET` method
 
 use clap::Args;
-use serde::{Deserialize, Serialize};
 use tracing::info;
 
 use anyhow::Result;
@@ -30,14 +29,11 @@ use openstack_sdk::AsyncOpenStack;
 use crate::output::OutputProcessor;
 use crate::Cli;
 use crate::OpenStackCliError;
-use crate::OutputConfig;
-use crate::StructTable;
 
 use openstack_sdk::api::block_storage::v3::snapshot::find;
 use openstack_sdk::api::find;
 use openstack_sdk::api::QueryAsync;
-use serde_json::Value;
-use structable_derive::StructTable;
+use openstack_sdk::types::block_storage::v3::Snapshot;
 
 /// Return data about the given snapshot.
 ///
@@ -68,128 +64,6 @@ struct PathParameters {
     )]
     id: String,
 }
-/// Snapshot response representation
-#[derive(Deserialize, Serialize, Clone, StructTable)]
-struct ResponseData {
-    /// Whether this resource consumes quota or not. Resources that not counted
-    /// for quota usage are usually temporary internal resources created to
-    /// perform an operation.
-    ///
-    /// **New in version 3.65**
-    ///
-    #[serde()]
-    #[structable(optional)]
-    consumes_quota: Option<bool>,
-
-    /// The total count of requested resource before pagination is applied.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    count: Option<i32>,
-
-    /// The date and time when the resource was created.
-    ///
-    /// The date and time stamp format is
-    /// [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601):
-    ///
-    /// ```text
-    /// CCYY-MM-DDThh:mm:ss±hh:mm
-    ///
-    /// ```
-    ///
-    /// For example, `2015-08-27T09:49:58-05:00`.
-    ///
-    /// The `±hh:mm` value, if included, is the time zone as an offset from
-    /// UTC.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    created_at: Option<String>,
-
-    /// A description for the snapshot.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    description: Option<String>,
-
-    /// The ID of the group snapshot.
-    ///
-    /// **New in version 3.14**
-    ///
-    #[serde()]
-    #[structable(optional)]
-    group_snapshot_id: Option<String>,
-
-    /// The snapshot UUID.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    id: Option<String>,
-
-    /// One or more metadata key and value pairs for the snapshot, if any.
-    ///
-    #[serde()]
-    #[structable(optional, pretty)]
-    metadata: Option<Value>,
-
-    /// The name of the snapshot.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    name: Option<String>,
-
-    /// A percentage value for the build progress.
-    ///
-    #[serde(rename = "os-extended-snapshot-attributes:progress")]
-    #[structable(optional, title = "os-extended-snapshot-attributes:progress")]
-    os_extended_snapshot_attributes_progress: Option<String>,
-
-    /// The UUID of the owning project.
-    ///
-    #[serde(rename = "os-extended-snapshot-attributes:project_id")]
-    #[structable(optional, title = "os-extended-snapshot-attributes:project_id")]
-    os_extended_snapshot_attributes_project_id: Option<String>,
-
-    /// The size of the volume, in gibibytes (GiB).
-    ///
-    #[serde()]
-    #[structable(optional)]
-    size: Option<i64>,
-
-    /// The status for the snapshot.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    status: Option<String>,
-
-    /// The date and time when the resource was updated.
-    ///
-    /// The date and time stamp format is
-    /// [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601):
-    ///
-    /// ```text
-    /// CCYY-MM-DDThh:mm:ss±hh:mm
-    ///
-    /// ```
-    ///
-    /// For example, `2015-08-27T09:49:58-05:00`.
-    ///
-    /// The `±hh:mm` value, if included, is the time zone as an offset from
-    /// UTC. In the previous example, the offset value is `-05:00`.
-    ///
-    /// If the `updated_at` date and time stamp is not set, its value is
-    /// `null`.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    updated_at: Option<String>,
-
-    /// If the snapshot was created from a volume, the volume ID.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    volume_id: Option<String>,
-}
 
 impl SnapshotCommand {
     /// Perform command action
@@ -211,7 +85,7 @@ impl SnapshotCommand {
             .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
         let find_data: serde_json::Value = find(find_ep).query_async(client).await?;
 
-        op.output_single::<ResponseData>(find_data)?;
+        op.output_single::<Snapshot>(find_data)?;
         Ok(())
     }
 }
@@ -17,8 +17,10 @@
 
 use clap::{Parser, Subcommand};
 
+use crate::output::impl_struct_table;
 use crate::{Cli, OpenStackCliError};
 
+use openstack_sdk::types::block_storage::v3::Volume;
 use openstack_sdk::AsyncOpenStack;
 
 mod create_30;
@@ -33,6 +35,34 @@ mod set_30;
 mod set_353;
 mod show;
 
+impl_struct_table!(
+    Volume,
+    ["created_at", "id", "name", "status", "updated_at"],
+    [
+        "attachments",
+        "availability_zone",
+        "cluster_name",
+        "consistencygroup_id",
+        "consumes_quota",
+        "description",
+        "encrypted",
+        "group_id",
+        "metadata",
+        "migration_status",
+        "multiattach",
+        "provider_id",
+        "replication_status",
+        "service_uuid",
+        "shared_targets",
+        "size",
+        "snapshot_id",
+        "source_volid",
+        "user_id",
+        "volume_type",
+        "volume_type_id"
+    ]
+);
+
 /// Block Storage Volume commands
 #[derive(Parser)]
 pub struct VolumeCommand {
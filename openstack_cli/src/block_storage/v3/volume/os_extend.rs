@@ -35,7 +35,10 @@ use crate::StructTable;
 
 use bytes::Bytes;
 use http::Response;
+use openstack_sdk::api::block_storage::v3::volume::get;
 use openstack_sdk::api::block_storage::v3::volume::os_extend;
+use openstack_sdk::api::wait_for_status;
+use openstack_sdk::api::QueryAsync;
 use openstack_sdk::api::RawQueryAsync;
 use structable_derive::StructTable;
 
@@ -51,6 +54,10 @@ pub struct VolumeCommand {
     #[command(flatten)]
     path: PathParameters,
 
+    /// Wait for the operation to complete
+    #[arg(long)]
+    wait: bool,
+
     #[command(flatten)]
     os_extend: OsExtend,
 }
@@ -113,6 +120,17 @@ impl VolumeCommand {
             .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
 
         let _rsp: Response<Bytes> = ep.raw_query_async(client).await?;
+        if self.wait {
+            let get_ep = get::Request::builder()
+                .id(&self.path.id)
+                .build()
+                .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+            let _data: serde_json::Value =
+                wait_for_status(get_ep, "status", ["available", "in-use"])
+                    .failures(["error", "error_extending"])
+                    .query_async(client)
+                    .await?;
+        }
         let data = ResponseData {};
         // Maybe output some headers metadata
         op.output_human::<ResponseData>(&data)?;
@@ -23,14 +23,22 @@ use std::io::IsTerminal;
 
 use serde_json::Value;
 
+use futures::StreamExt;
 use indicatif::{ProgressBar, ProgressStyle};
 use std::path::Path;
+use std::pin::pin;
 use tokio::fs;
 use tokio::io::{self};
 use tokio_util::compat::{FuturesAsyncReadCompatExt, TokioAsyncReadCompatExt};
 use tokio_util::io::InspectReader;
 
+use openstack_sdk::api::identity::v3::project::find as find_project;
+use openstack_sdk::api::{batch, find, QueryAsync};
 use openstack_sdk::types::BoxedAsyncRead;
+use openstack_sdk::AsyncOpenStack;
+
+/// Number of concurrent requests of the bulk operations
+const BATCH_CONCURRENCY: usize = 10;
 
 /// Newtype for the `Vec<String>`
 #[derive(Deserialize, Default, Debug, Clone, Serialize)]
@@ -388,6 +396,108 @@ pub(crate) async fn download_file(
     Ok(())
 }
 
+/// Execute queries concurrently displaying the progress bar.
+///
+/// Failure of a query is reported together with the corresponding label and
+/// does not interrupt remaining queries. Results are returned in the order of
+/// the queries with `None` for the failed ones.
+pub(crate) async fn batch_query<E, T>(
+    client: &AsyncOpenStack,
+    endpoints: Vec<E>,
+    labels: &[String],
+    message: &'static str,
+) -> Result<Vec<Option<T>>, OpenStackCliError>
+where
+    E: QueryAsync<T, AsyncOpenStack> + Send + Sync,
+    T: Send + 'static,
+{
+    let batch = batch(endpoints).concurrency(BATCH_CONCURRENCY);
+    let progress_bar = ProgressBar::new(batch.len() as u64);
+    progress_bar.set_message(message);
+    progress_bar.set_style(
+        ProgressStyle::default_bar()
+            .progress_chars("#>-")
+            .template("[{bar:40.cyan/blue}] {pos}/{len} - {msg}")?,
+    );
+
+    let mut results: Vec<Option<T>> = (0..batch.len()).map(|_| None).collect();
+    let mut stream = pin!(batch.iter_async(client));
+    while let Some((idx, res)) = stream.next().await {
+        match res {
+            Ok(data) => results[idx] = Some(data),
+            Err(err) => progress_bar.println(format!("{}: {}", labels[idx], err)),
+        }
+        progress_bar.inc(1);
+    }
+    progress_bar.finish_and_clear();
+    Ok(results)
+}
+
+/// Return error when not every item of the bulk operation succeeded
+pub(crate) fn check_batch_result(total: usize, succeeded: usize) -> Result<(), OpenStackCliError> {
+    if succeeded < total {
+        return Err(OpenStackCliError::BatchFailed {
+            failed: total - succeeded,
+            total,
+        });
+    }
+    Ok(())
+}
+
+/// Resolve project name or ID into the project ID.
+///
+/// When no project is given the project of the current authorization scope is
+/// used.
+pub(crate) async fn find_project_id(
+    client: &mut AsyncOpenStack,
+    project: Option<&String>,
+) -> Result<String, OpenStackCliError> {
+    if let Some(project) = project {
+        let mut find_builder = find_project::Request::builder();
+        find_builder.id(project);
+        let find_ep = find_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+        let find_data: serde_json::Value = find(find_ep).query_async(client).await?;
+        return Ok(find_data["id"]
+            .as_str()
+            .expect("Resource ID is a string")
+            .to_string());
+    }
+    client
+        .get_auth_info()
+        .and_then(|x| x.token.project)
+        .and_then(|x| x.id)
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "Project is not given and the current authorization is not project scoped"
+            )
+            .into()
+        })
+}
+
+/// Flatten the quota usage details into the list with a row per resource.
+///
+/// `{"<resource>": {"limit": .., "<used_key>": .., "reserved": ..}}` is
+/// converted into `[{"resource": .., "limit": .., "<used_key>": .., "reserved": ..}]`.
+/// Attributes that are not resources (i.e. the `id` of the project) are
+/// skipped.
+pub(crate) fn flatten_quota_details(data: &Value, used_key: &str) -> Vec<Value> {
+    data.as_object()
+        .into_iter()
+        .flatten()
+        .filter(|(_, details)| details.is_object())
+        .map(|(resource, details)| {
+            let mut row = serde_json::Map::new();
+            row.insert("resource".into(), Value::String(resource.clone()));
+            for key in ["limit", used_key, "reserved"] {
+                row.insert(key.into(), details[key].clone());
+            }
+            Value::Object(row)
+        })
+        .collect()
+}
+
 /// Construct BoxedAsyncRead with progress bar from stdin
 async fn build_upload_asyncread_from_stdin() -> Result<BoxedAsyncRead, OpenStackCliError> {
     let progress_bar = ProgressBar::new(0);
@@ -475,4 +585,21 @@ mod tests {
             parse_key_val_opt::<String, String>("foo=").unwrap()
         );
     }
+
+    #[test]
+    fn test_flatten_quota_details() {
+        let data = serde_json::json!({
+            "id": "project",
+            "cores": {"limit": 20, "in_use": 2, "reserved": 0}
+        });
+        assert_eq!(
+            vec![serde_json::json!({
+                "resource": "cores",
+                "limit": 20,
+                "in_use": 2,
+                "reserved": 0
+            })],
+            flatten_quota_details(&data, "in_use")
+        );
+    }
 }
@@ -26,7 +26,12 @@ mod extension;
 mod flavor;
 mod hypervisor;
 mod keypair;
+mod limit;
+mod quota;
 mod server;
+mod server_group;
+mod service;
+mod usage;
 
 /// Compute service (Nova) operations
 #[derive(Parser)]
@@ -54,7 +59,17 @@ pub enum ComputeCommands {
     Flavor(Box<flavor::FlavorCommand>),
     Hypervisor(Box<hypervisor::HypervisorCommand>),
     Keypair(Box<keypair::KeypairCommand>),
+    #[command(about = "Limits")]
+    Limits(Box<limit::LimitCommand>),
+    #[command(about = "Quota sets")]
+    Quota(Box<quota::QuotaCommand>),
     Server(Box<server::ServerCommand>),
+    #[command(about = "Server groups")]
+    ServerGroup(Box<server_group::ServerGroupCommand>),
+    #[command(about = "Compute services")]
+    Service(Box<service::ServiceCommand>),
+    #[command(about = "Usage reports")]
+    Usage(Box<usage::UsageCommand>),
 }
 
 impl ComputeCommand {
@@ -75,7 +90,12 @@ impl ComputeCommand {
             ComputeCommands::Hypervisor(cmd) => cmd.take_action(parsed_args, session).await,
             ComputeCommands::Flavor(cmd) => cmd.take_action(parsed_args, session).await,
             ComputeCommands::Keypair(cmd) => cmd.take_action(parsed_args, session).await,
+            ComputeCommands::Limits(cmd) => cmd.take_action(parsed_args, session).await,
+            ComputeCommands::Quota(cmd) => cmd.take_action(parsed_args, session).await,
             ComputeCommands::Server(cmd) => cmd.take_action(parsed_args, session).await,
+            ComputeCommands::ServerGroup(cmd) => cmd.take_action(parsed_args, session).await,
+            ComputeCommands::Service(cmd) => cmd.take_action(parsed_args, session).await,
+            ComputeCommands::Usage(cmd) => cmd.take_action(parsed_args, session).await,
         }
     }
 }
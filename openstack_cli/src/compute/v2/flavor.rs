@@ -16,8 +16,10 @@
 
 use clap::{Parser, Subcommand};
 
+use openstack_sdk::types::compute::v2::Flavor;
 use openstack_sdk::AsyncOpenStack;
 
+use crate::output::impl_struct_table;
 use crate::{Cli, OpenStackCliError};
 
 mod add_tenant_access;
@@ -32,6 +34,21 @@ mod remove_tenant_access;
 mod set;
 mod show;
 
+impl_struct_table!(
+    Flavor,
+    ["id", "name"],
+    [
+        "disk",
+        "extra_specs",
+        "os-flavor-access:is_public",
+        "OS-FLV-EXT-DATA:ephemeral",
+        "ram",
+        "rxtx_factor",
+        "swap",
+        "vcpus"
+    ]
+);
+
 /// Flavor commands
 ///
 /// Flavors are a way to describe the basic dimensions of a server
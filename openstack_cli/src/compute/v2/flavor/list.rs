@@ -20,7 +20,6 @@
 //! Wraps invoking of the `v2.1/flavors/detail` with `GET` method
 
 use clap::Args;
-use serde::{Deserialize, Serialize};
 use tracing::info;
 
 use anyhow::Result;
@@ -30,16 +29,10 @@ use openstack_sdk::AsyncOpenStack;
 use crate::output::OutputProcessor;
 use crate::Cli;
 use crate::OpenStackCliError;
-use crate::OutputConfig;
-use crate::StructTable;
 
-use crate::common::IntString;
-use crate::common::NumString;
 use openstack_sdk::api::compute::v2::flavor::list_detailed;
-use openstack_sdk::api::QueryAsync;
 use openstack_sdk::api::{paged, Pagination};
-use serde_json::Value;
-use structable_derive::StructTable;
+use openstack_sdk::types::compute::v2::Flavor;
 
 /// Lists flavors with details.
 ///
@@ -91,89 +84,6 @@ struct QueryParameters {
 /// Path parameters
 #[derive(Args)]
 struct PathParameters {}
-/// Flavors response representation
-#[derive(Deserialize, Serialize, Clone, StructTable)]
-struct ResponseData {
-    /// The size of the root disk that will be created in GiB. If 0 the root
-    /// disk will be set to exactly the size of the image used to deploy the
-    /// instance. However, in this case the scheduler cannot select the compute
-    /// host based on the virtual image size. Therefore, 0 should only be used
-    /// for volume booted instances or for testing purposes. Volume-backed
-    /// instances can be enforced for flavors with zero root disk via the
-    /// `os_compute_api:servers:create:zero_disk_flavor` policy rule.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    disk: Option<IntString>,
-
-    /// A dictionary of the flavor’s extra-specs key-and-value pairs. This will
-    /// only be included if the user is allowed by policy to index flavor
-    /// extra_specs.
-    ///
-    /// **New in version 2.61**
-    ///
-    #[serde()]
-    #[structable(optional, pretty, wide)]
-    extra_specs: Option<Value>,
-
-    /// The ID of the flavor. While people often make this look like an int,
-    /// this is really a string.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    id: Option<String>,
-
-    /// The display name of a flavor.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    name: Option<String>,
-
-    /// Whether the flavor is public (available to all projects) or scoped to a
-    /// set of projects. Default is True if not specified.
-    ///
-    #[serde(rename = "os-flavor-access:is_public")]
-    #[structable(optional, title = "os-flavor-access:is_public", wide)]
-    os_flavor_access_is_public: Option<bool>,
-
-    /// The size of the ephemeral disk that will be created, in GiB. Ephemeral
-    /// disks may be written over on server state changes. So should only be
-    /// used as a scratch space for applications that are aware of its
-    /// limitations. Defaults to 0.
-    ///
-    #[serde(rename = "OS-FLV-EXT-DATA:ephemeral")]
-    #[structable(optional, title = "OS-FLV-EXT-DATA:ephemeral", wide)]
-    os_flv_ext_data_ephemeral: Option<IntString>,
-
-    /// The amount of RAM a flavor has, in MiB.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    ram: Option<IntString>,
-
-    /// The receive / transmit factor (as a float) that will be set on ports if
-    /// the network backend supports the QOS extension. Otherwise it will be
-    /// ignored. It defaults to 1.0.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    rxtx_factor: Option<NumString>,
-
-    /// The size of a dedicated swap disk that will be allocated, in MiB. If 0
-    /// (the default), no dedicated swap disk will be created. Currently, the
-    /// empty string (‘’) is used to represent 0. As of microversion 2.75
-    /// default return value of swap is 0 instead of empty string.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    swap: Option<IntString>,
-
-    /// The number of virtual CPUs that will be allocated to the server.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    vcpus: Option<IntString>,
-}
 
 impl FlavorsCommand {
     /// Perform command action
@@ -218,11 +128,10 @@ impl FlavorsCommand {
             .build()
             .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
 
-        let data: Vec<serde_json::Value> = paged(ep, Pagination::Limit(self.max_items))
-            .query_async(client)
-            .await?;
+        let ep = paged(ep, Pagination::Limit(self.max_items)).prefetch(true);
 
-        op.output_list::<ResponseData>(data)?;
+        op.output_list_stream::<Flavor, _, _>(ep.iter_async(client))
+            .await?;
         Ok(())
     }
 }
@@ -21,7 +21,9 @@ use openstack_sdk::AsyncOpenStack;
 use crate::{Cli, OpenStackCliError};
 
 mod list;
+mod server;
 mod show;
+mod uptime;
 
 /// Lists all hypervisors, shows summary statistics for all hypervisors over
 /// all compute nodes, shows details for a hypervisor, shows the uptime for a
@@ -41,7 +43,9 @@ pub struct HypervisorCommand {
 #[derive(Subcommand)]
 pub enum HypervisorCommands {
     List(list::HypervisorsCommand),
+    Server(Box<server::ServerCommand>),
     Show(show::HypervisorCommand),
+    Uptime(Box<uptime::UptimeCommand>),
 }
 
 impl HypervisorCommand {
@@ -53,7 +57,9 @@ impl HypervisorCommand {
     ) -> Result<(), OpenStackCliError> {
         match &self.command {
             HypervisorCommands::List(cmd) => cmd.take_action(parsed_args, session).await,
+            HypervisorCommands::Server(cmd) => cmd.take_action(parsed_args, session).await,
             HypervisorCommands::Show(cmd) => cmd.take_action(parsed_args, session).await,
+            HypervisorCommands::Uptime(cmd) => cmd.take_action(parsed_args, session).await,
         }
     }
 }
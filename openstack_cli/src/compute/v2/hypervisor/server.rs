@@ -0,0 +1,56 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+//! Hypervisor servers commands
+
+use clap::{Parser, Subcommand};
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::{Cli, OpenStackCliError};
+
+mod list_21;
+mod list_253;
+
+/// Hypervisor servers
+///
+/// Lists all servers hosted on a hypervisor.
+#[derive(Parser)]
+pub struct ServerCommand {
+    /// subcommand
+    #[command(subcommand)]
+    command: ServerCommands,
+}
+
+/// Supported subcommands
+#[allow(missing_docs)]
+#[derive(Subcommand)]
+pub enum ServerCommands {
+    #[command(visible_alias = "list")]
+    List253(list_253::ServersCommand),
+    List21(list_21::ServersCommand),
+}
+
+impl ServerCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        session: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        match &self.command {
+            ServerCommands::List253(cmd) => cmd.take_action(parsed_args, session).await,
+            ServerCommands::List21(cmd) => cmd.take_action(parsed_args, session).await,
+        }
+    }
+}
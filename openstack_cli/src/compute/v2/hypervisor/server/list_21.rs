@@ -11,11 +11,7 @@
 // limitations under the License.
 //
 // SPDX-License-Identifier: Apache-2.0
-//
-// WARNING: This file is automatically generated from OpenAPI schema using
-// `openstack-codegenerator`.
-
-//! Get Server command
+//! List Hypervisor Servers command
 //!
 //! Wraps invoking of the `v2.1/os-hypervisors/{id}/servers` with `GET` method
 
@@ -33,15 +29,17 @@ use crate::OpenStackCliError;
 use crate::OutputConfig;
 use crate::StructTable;
 
-use bytes::Bytes;
-use http::Response;
 use openstack_sdk::api::compute::v2::hypervisor::server::get;
-use openstack_sdk::api::RawQueryAsync;
+use openstack_sdk::api::QueryAsync;
+use serde_json::Value;
 use structable_derive::StructTable;
 
 /// List all servers belong to each hypervisor whose host name is matching a
 /// given hypervisor host name or portion of it.
 ///
+/// This API is deprecated starting with microversion 2.53. Use the `list`
+/// command instead.
+///
 /// Policy defaults enable only users with the administrative role to perform
 /// this operation. Cloud providers can change these permissions through the
 /// `policy.json` file.
@@ -52,7 +50,7 @@ use structable_derive::StructTable;
 ///
 #[derive(Args)]
 #[command(about = "List Hypervisor Servers (DEPRECATED)")]
-pub struct ServerCommand {
+pub struct ServersCommand {
     /// Request Query parameters
     #[command(flatten)]
     query: QueryParameters,
@@ -69,27 +67,46 @@ struct QueryParameters {}
 /// Path parameters
 #[derive(Args)]
 struct PathParameters {
-    /// id parameter for /v2.1/os-hypervisors/{id}/servers API
+    /// The hypervisor host name or a portion of it.
     ///
     #[arg(
         help_heading = "Path parameters",
         id = "path_param_id",
-        value_name = "ID"
+        value_name = "HYPERVISOR_HOSTNAME_PATTERN"
     )]
     id: String,
 }
-/// Server response representation
+
+/// Hypervisor server response representation
 #[derive(Deserialize, Serialize, Clone, StructTable)]
-struct ResponseData {}
+struct ResponseData {
+    /// The hypervisor host name provided by the Nova virt driver.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    hypervisor_hostname: Option<String>,
+
+    /// The server name.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    name: Option<String>,
+
+    /// The server ID.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    uuid: Option<String>,
+}
 
-impl ServerCommand {
+impl ServersCommand {
     /// Perform command action
     pub async fn take_action(
         &self,
         parsed_args: &Cli,
         client: &mut AsyncOpenStack,
     ) -> Result<(), OpenStackCliError> {
-        info!("Get Server");
+        info!("List Hypervisor Servers");
 
         let op = OutputProcessor::from_args(parsed_args);
         op.validate_args(parsed_args)?;
@@ -105,10 +122,26 @@ impl ServerCommand {
             .build()
             .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
 
-        let _rsp: Response<Bytes> = ep.raw_query_async(client).await?;
-        let data = ResponseData {};
-        // Maybe output some headers metadata
-        op.output_human::<ResponseData>(&data)?;
+        let data: Value = ep.query_async(client).await?;
+        // Flatten `{"hypervisors": [{"servers": [..]}]}` into a table with a
+        // row per server.
+        let servers: Vec<Value> = data["hypervisors"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .flat_map(|hypervisor| {
+                hypervisor["servers"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .map(|server| {
+                        let mut server = server.clone();
+                        server["hypervisor_hostname"] = hypervisor["hypervisor_hostname"].clone();
+                        server
+                    })
+            })
+            .collect();
+        op.output_list::<ResponseData>(servers)?;
         Ok(())
     }
 }
@@ -0,0 +1,56 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+//! Hypervisor uptime commands
+
+use clap::{Parser, Subcommand};
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::{Cli, OpenStackCliError};
+
+mod show_21;
+mod show_288;
+
+/// Hypervisor uptime
+///
+/// Shows the uptime of a hypervisor.
+#[derive(Parser)]
+pub struct UptimeCommand {
+    /// subcommand
+    #[command(subcommand)]
+    command: UptimeCommands,
+}
+
+/// Supported subcommands
+#[allow(missing_docs)]
+#[derive(Subcommand)]
+pub enum UptimeCommands {
+    #[command(visible_alias = "show")]
+    Show288(show_288::UptimeCommand),
+    Show21(show_21::UptimeCommand),
+}
+
+impl UptimeCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        session: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        match &self.command {
+            UptimeCommands::Show288(cmd) => cmd.take_action(parsed_args, session).await,
+            UptimeCommands::Show21(cmd) => cmd.take_action(parsed_args, session).await,
+        }
+    }
+}
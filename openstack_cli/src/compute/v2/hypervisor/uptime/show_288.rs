@@ -0,0 +1,138 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+//! Show Hypervisor Uptime command [microversion = 2.88]
+//!
+//! Wraps invoking of the `v2.1/os-hypervisors/{id}` with `GET` method
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use anyhow::Result;
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::output::OutputProcessor;
+use crate::Cli;
+use crate::OpenStackCliError;
+use crate::OutputConfig;
+use crate::StructTable;
+
+use openstack_sdk::api::compute::v2::hypervisor::find;
+use openstack_sdk::api::find;
+use openstack_sdk::api::QueryAsync;
+use serde_json::Value;
+use structable_derive::StructTable;
+
+/// Shows the uptime for a given hypervisor.
+///
+/// Starting with microversion 2.88 the uptime is part of the hypervisor
+/// details. The hypervisor is looked up by its host name or UUID.
+///
+/// Policy defaults enable only users with the administrative role to perform
+/// this operation. Cloud providers can change these permissions through the
+/// `policy.json` file.
+///
+/// Normal response codes: 200
+///
+/// Error response codes: badRequest(400), unauthorized(401), forbidden(403),
+/// itemNotFound(404)
+///
+#[derive(Args)]
+#[command(about = "Show Hypervisor Uptime (microversion = 2.88)")]
+pub struct UptimeCommand {
+    /// Request Query parameters
+    #[command(flatten)]
+    query: QueryParameters,
+
+    /// Path parameters
+    #[command(flatten)]
+    path: PathParameters,
+}
+
+/// Query parameters
+#[derive(Args)]
+struct QueryParameters {}
+
+/// Path parameters
+#[derive(Args)]
+struct PathParameters {
+    /// Hypervisor host name or ID.
+    ///
+    #[arg(
+        help_heading = "Path parameters",
+        id = "path_param_id",
+        value_name = "HYPERVISOR"
+    )]
+    id: String,
+}
+
+/// Hypervisor uptime response representation
+#[derive(Deserialize, Serialize, Clone, StructTable)]
+struct ResponseData {
+    /// The id of the hypervisor.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    id: Option<Value>,
+
+    /// The hypervisor host name provided by the Nova virt driver.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    hypervisor_hostname: Option<String>,
+
+    /// The state of the hypervisor. One of `up` or `down`.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    state: Option<String>,
+
+    /// The status of the hypervisor. One of `enabled` or `disabled`.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    status: Option<String>,
+
+    /// The total uptime of the hypervisor and information about average load.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    uptime: Option<String>,
+}
+
+impl UptimeCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        client: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        info!("Show Hypervisor Uptime");
+
+        let op = OutputProcessor::from_args(parsed_args);
+        op.validate_args(parsed_args)?;
+
+        let mut find_builder = find::Request::builder();
+        find_builder.id(&self.path.id);
+        find_builder.header("OpenStack-API-Version", "compute 2.88");
+        let find_ep = find_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+        let find_data: Value = find(find_ep).query_async(client).await?;
+
+        op.output_single::<ResponseData>(find_data)?;
+        Ok(())
+    }
+}
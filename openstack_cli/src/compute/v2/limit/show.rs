@@ -0,0 +1,127 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Show Limit command
+//!
+//! Wraps invoking of the `v2.1/limits` with `GET` method
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use anyhow::Result;
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::output::OutputProcessor;
+use crate::Cli;
+use crate::OpenStackCliError;
+use crate::OutputConfig;
+use crate::StructTable;
+
+use crate::common::find_project_id;
+use openstack_sdk::api::compute::v2::limit::get;
+use openstack_sdk::api::QueryAsync;
+use serde_json::{json, Value};
+use structable_derive::StructTable;
+
+/// Shows absolute limits for the project.
+///
+/// Every absolute limit is reported as a row with its name and value. The
+/// `total*Used` rows report the current usage of the project.
+///
+/// Normal response codes: 200
+///
+/// Error response codes: unauthorized(401), forbidden(403)
+///
+#[derive(Args)]
+#[command(about = "Show Rate And Absolute Limits")]
+pub struct LimitCommand {
+    /// Request Query parameters
+    #[command(flatten)]
+    query: QueryParameters,
+
+    /// Path parameters
+    #[command(flatten)]
+    path: PathParameters,
+}
+
+/// Query parameters
+#[derive(Args)]
+struct QueryParameters {
+    /// Show the limits of another project (name or ID). Requires
+    /// administrative privileges.
+    ///
+    #[arg(help_heading = "Query parameters", long, value_name = "PROJECT")]
+    project: Option<String>,
+}
+
+/// Path parameters
+#[derive(Args)]
+struct PathParameters {}
+
+/// Limit response representation
+#[derive(Deserialize, Serialize, Clone, StructTable)]
+struct ResponseData {
+    /// The name of the limit.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    name: Option<String>,
+
+    /// The value of the limit. A value of `-1` means no limit.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    value: Option<i64>,
+}
+
+impl LimitCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        client: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        info!("Show Limit");
+
+        let op = OutputProcessor::from_args(parsed_args);
+        op.validate_args(parsed_args)?;
+
+        let mut ep_builder = get::Request::builder();
+
+        // Set path parameters
+        // Set query parameters
+        if self.query.project.is_some() {
+            ep_builder.tenant_id(find_project_id(client, self.query.project.as_ref()).await?);
+        }
+        // Set body parameters
+
+        let ep = ep_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+
+        let data: Value = ep.query_async(client).await?;
+        // Flatten `{"absolute": {"<name>": <value>}}` into a table with a row
+        // per limit.
+        let limits: Vec<Value> = data["absolute"]
+            .as_object()
+            .into_iter()
+            .flatten()
+            .map(|(name, value)| json!({"name": name, "value": value}))
+            .collect();
+        op.output_list::<ResponseData>(limits)?;
+        Ok(())
+    }
+}
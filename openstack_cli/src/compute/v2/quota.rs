@@ -0,0 +1,70 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Quota sets (os-quota-sets)
+//!
+//! Shows the quotas and their usage, lists the default quotas and updates the
+//! quotas for a project or a project and user.
+
+use clap::{Parser, Subcommand};
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::{Cli, OpenStackCliError};
+
+mod defaults;
+mod set_21;
+mod set_236;
+mod set_257;
+mod show;
+
+/// Quota sets
+///
+/// Shows the quotas and their usage, lists the default quotas and updates the
+/// quotas for a project or a project and user.
+#[derive(Parser)]
+pub struct QuotaCommand {
+    /// subcommand
+    #[command(subcommand)]
+    command: QuotaCommands,
+}
+
+/// Supported subcommands
+#[allow(missing_docs)]
+#[derive(Subcommand)]
+pub enum QuotaCommands {
+    Defaults(Box<defaults::QuotaSetCommand>),
+    #[command(visible_alias = "set")]
+    Set257(Box<set_257::QuotaSetCommand>),
+    Set236(Box<set_236::QuotaSetCommand>),
+    Set21(Box<set_21::QuotaSetCommand>),
+    Show(Box<show::QuotaSetCommand>),
+}
+
+impl QuotaCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        session: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        match &self.command {
+            QuotaCommands::Defaults(cmd) => cmd.take_action(parsed_args, session).await,
+            QuotaCommands::Set257(cmd) => cmd.take_action(parsed_args, session).await,
+            QuotaCommands::Set236(cmd) => cmd.take_action(parsed_args, session).await,
+            QuotaCommands::Set21(cmd) => cmd.take_action(parsed_args, session).await,
+            QuotaCommands::Show(cmd) => cmd.take_action(parsed_args, session).await,
+        }
+    }
+}
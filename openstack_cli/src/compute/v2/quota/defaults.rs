@@ -0,0 +1,203 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// WARNING: This file is automatically generated from OpenAPI schema using
+// `openstack-codegenerator`.
+
+//! Show QuotaSet defaults command
+//!
+//! Wraps invoking of the `v2.1/os-quota-sets/{id}/defaults` with `GET` method
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use anyhow::Result;
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::output::OutputProcessor;
+use crate::Cli;
+use crate::OpenStackCliError;
+use crate::OutputConfig;
+use crate::StructTable;
+
+use crate::common::find_project_id;
+use openstack_sdk::api::compute::v2::quota_set::defaults;
+use openstack_sdk::api::QueryAsync;
+use structable_derive::StructTable;
+
+/// Lists the default quotas for a project.
+///
+/// Normal response codes: 200
+///
+/// Error response codes: badRequest(400), unauthorized(401), forbidden(403)
+///
+#[derive(Args)]
+#[command(about = "List Default Quotas For Tenant")]
+pub struct QuotaSetCommand {
+    /// Request Query parameters
+    #[command(flatten)]
+    query: QueryParameters,
+
+    /// Path parameters
+    #[command(flatten)]
+    path: PathParameters,
+}
+
+/// Query parameters
+#[derive(Args)]
+struct QueryParameters {}
+
+/// Path parameters
+#[derive(Args)]
+struct PathParameters {
+    /// Project (name or ID). Defaults to the currently authorized project.
+    ///
+    #[arg(
+        help_heading = "Path parameters",
+        id = "path_param_id",
+        value_name = "PROJECT"
+    )]
+    id: Option<String>,
+}
+/// QuotaSet response representation
+#[derive(Deserialize, Serialize, Clone, StructTable)]
+struct ResponseData {
+    /// The number of allowed server cores for each tenant.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    cores: Option<i32>,
+
+    /// The number of allowed fixed IP addresses for each tenant. Must be equal
+    /// to or greater than the number of allowed servers.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    fixed_ips: Option<i32>,
+
+    /// The number of allowed floating IP addresses for each tenant.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    floating_ips: Option<i32>,
+
+    /// The number of allowed bytes of content for each injected file.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    injected_file_content_bytes: Option<i32>,
+
+    /// The number of allowed bytes for each injected file path.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    injected_file_path_bytes: Option<i32>,
+
+    /// The number of allowed injected files for each tenant.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    injected_files: Option<i32>,
+
+    /// The number of allowed servers for each tenant.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    instances: Option<i32>,
+
+    /// The number of allowed key pairs for each user.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    key_pairs: Option<i32>,
+
+    /// The number of allowed metadata items for each server.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    metadata_items: Option<i32>,
+
+    /// The number of allowed networks for each tenant.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    networks: Option<i32>,
+
+    /// The amount of allowed server RAM, in MiB, for each tenant.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    ram: Option<i32>,
+
+    /// The number of allowed rules for each security group.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    security_group_rules: Option<i32>,
+
+    /// The number of allowed security groups for each tenant.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    security_groups: Option<i32>,
+
+    /// The number of allowed members for each server group.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    server_group_members: Option<i32>,
+
+    /// The number of allowed server groups for each tenant.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    server_groups: Option<i32>,
+
+    /// The UUID of the project the quotas are listed for.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    id: Option<String>,
+}
+
+impl QuotaSetCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        client: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        info!("Show QuotaSet defaults");
+
+        let op = OutputProcessor::from_args(parsed_args);
+        op.validate_args(parsed_args)?;
+
+        let mut ep_builder = defaults::Request::builder();
+
+        // Set path parameters
+        ep_builder.id(find_project_id(client, self.path.id.as_ref()).await?);
+        // Set query parameters
+        // Set body parameters
+
+        let ep = ep_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+
+        let data = ep.query_async(client).await?;
+        op.output_single::<ResponseData>(data)?;
+        Ok(())
+    }
+}
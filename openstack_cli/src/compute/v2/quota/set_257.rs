@@ -0,0 +1,311 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// WARNING: This file is automatically generated from OpenAPI schema using
+// `openstack-codegenerator`.
+
+//! Set QuotaSet command [microversion = 2.57]
+//!
+//! Wraps invoking of the `v2.1/os-quota-sets/{id}` with `PUT` method
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use anyhow::Result;
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::output::OutputProcessor;
+use crate::Cli;
+use crate::OpenStackCliError;
+use crate::OutputConfig;
+use crate::StructTable;
+
+use crate::common::find_project_id;
+use openstack_sdk::api::compute::v2::quota_set::set_257;
+use openstack_sdk::api::QueryAsync;
+use structable_derive::StructTable;
+
+/// Update the quotas for a project or a project and a user.
+///
+/// Users can force the update even if the quota has already been used and the
+/// reserved quota exceeds the new quota. To force the update, specify the
+/// `--force true`. Default is `false`.
+///
+/// Normal response codes: 200
+///
+/// Error response codes: badRequest(400), unauthorized(401), forbidden(403)
+///
+#[derive(Args)]
+#[command(about = "Update Quotas (microversion = 2.57)")]
+pub struct QuotaSetCommand {
+    /// Request Query parameters
+    #[command(flatten)]
+    query: QueryParameters,
+
+    /// Path parameters
+    #[command(flatten)]
+    path: PathParameters,
+
+    /// A `quota_set` object.
+    ///
+    #[command(flatten)]
+    quota_set: QuotaSet,
+}
+
+/// Query parameters
+#[derive(Args)]
+struct QueryParameters {
+    /// ID of the user to operate on the quotas of. Without it the project
+    /// quotas are used.
+    ///
+    #[arg(help_heading = "Query parameters", long, value_name = "USER_ID")]
+    user_id: Option<String>,
+}
+
+/// Path parameters
+#[derive(Args)]
+struct PathParameters {
+    /// Project (name or ID). Defaults to the currently authorized project.
+    ///
+    #[arg(
+        help_heading = "Path parameters",
+        id = "path_param_id",
+        value_name = "PROJECT"
+    )]
+    id: Option<String>,
+}
+/// QuotaSet Body data
+#[derive(Args, Clone)]
+struct QuotaSet {
+    /// The number of allowed server cores for each tenant. A value of `-1`
+    /// means no limit.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    cores: Option<i32>,
+
+    /// You can force the update even if the quota has already been used and
+    /// the reserved quota exceeds the new quota. To force the update, specify
+    /// the `"force": "True"`. Default is `False`.
+    ///
+    #[arg(action=clap::ArgAction::Set, help_heading = "Body parameters", long)]
+    force: Option<bool>,
+
+    /// The number of allowed servers for each tenant. A value of `-1` means no
+    /// limit.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    instances: Option<i32>,
+
+    /// The number of allowed key pairs for each user. A value of `-1` means no
+    /// limit.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    key_pairs: Option<i32>,
+
+    /// The number of allowed metadata items for each server. A value of `-1`
+    /// means no limit.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    metadata_items: Option<i32>,
+
+    /// The amount of allowed server RAM, in MiB, for each tenant. A value of
+    /// `-1` means no limit.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    ram: Option<i32>,
+
+    /// The number of allowed members for each server group. A value of `-1`
+    /// means no limit.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    server_group_members: Option<i32>,
+
+    /// The number of allowed server groups for each tenant. A value of `-1`
+    /// means no limit.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    server_groups: Option<i32>,
+}
+
+/// QuotaSet response representation
+#[derive(Deserialize, Serialize, Clone, StructTable)]
+struct ResponseData {
+    /// The number of allowed server cores for each tenant.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    cores: Option<i32>,
+
+    /// The number of allowed fixed IP addresses for each tenant. Must be equal
+    /// to or greater than the number of allowed servers.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    fixed_ips: Option<i32>,
+
+    /// The number of allowed floating IP addresses for each tenant.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    floating_ips: Option<i32>,
+
+    /// The number of allowed bytes of content for each injected file.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    injected_file_content_bytes: Option<i32>,
+
+    /// The number of allowed bytes for each injected file path.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    injected_file_path_bytes: Option<i32>,
+
+    /// The number of allowed injected files for each tenant.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    injected_files: Option<i32>,
+
+    /// The number of allowed servers for each tenant.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    instances: Option<i32>,
+
+    /// The number of allowed key pairs for each user.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    key_pairs: Option<i32>,
+
+    /// The number of allowed metadata items for each server.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    metadata_items: Option<i32>,
+
+    /// The number of allowed networks for each tenant.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    networks: Option<i32>,
+
+    /// The amount of allowed server RAM, in MiB, for each tenant.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    ram: Option<i32>,
+
+    /// The number of allowed rules for each security group.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    security_group_rules: Option<i32>,
+
+    /// The number of allowed security groups for each tenant.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    security_groups: Option<i32>,
+
+    /// The number of allowed members for each server group.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    server_group_members: Option<i32>,
+
+    /// The number of allowed server groups for each tenant.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    server_groups: Option<i32>,
+
+    /// The UUID of the project the quotas are listed for.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    id: Option<String>,
+}
+
+impl QuotaSetCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        client: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        info!("Set QuotaSet");
+
+        let op = OutputProcessor::from_args(parsed_args);
+        op.validate_args(parsed_args)?;
+
+        let mut ep_builder = set_257::Request::builder();
+        ep_builder.header("OpenStack-API-Version", "compute 2.57");
+
+        // Set path parameters
+        ep_builder.id(find_project_id(client, self.path.id.as_ref()).await?);
+        // Set query parameters
+        if let Some(val) = &self.query.user_id {
+            ep_builder.user_id(val);
+        }
+        // Set body parameters
+        // Set Request.quota_set data
+        let args = &self.quota_set;
+        let mut quota_set_builder = set_257::QuotaSetBuilder::default();
+        if let Some(val) = &args.cores {
+            quota_set_builder.cores(*val);
+        }
+
+        if let Some(val) = &args.force {
+            quota_set_builder.force(*val);
+        }
+
+        if let Some(val) = &args.instances {
+            quota_set_builder.instances(*val);
+        }
+
+        if let Some(val) = &args.key_pairs {
+            quota_set_builder.key_pairs(*val);
+        }
+
+        if let Some(val) = &args.metadata_items {
+            quota_set_builder.metadata_items(*val);
+        }
+
+        if let Some(val) = &args.ram {
+            quota_set_builder.ram(*val);
+        }
+
+        if let Some(val) = &args.server_group_members {
+            quota_set_builder.server_group_members(*val);
+        }
+
+        if let Some(val) = &args.server_groups {
+            quota_set_builder.server_groups(*val);
+        }
+
+        ep_builder.quota_set(quota_set_builder.build().unwrap());
+
+        let ep = ep_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+
+        let data = ep.query_async(client).await?;
+        op.output_single::<ResponseData>(data)?;
+        Ok(())
+    }
+}
@@ -16,8 +16,10 @@
 
 use clap::{Parser, Subcommand};
 
+use openstack_sdk::types::compute::v2::Server;
 use openstack_sdk::AsyncOpenStack;
 
+use crate::output::impl_struct_table;
 use crate::{Cli, OpenStackCliError};
 
 mod add_fixed_ip_21;
@@ -119,6 +121,53 @@ mod unshelve_277;
 mod unshelve_291;
 mod volume_attachment;
 
+impl_struct_table!(
+    Server,
+    ["id", "name", "status"],
+    [
+        "accessIPv4",
+        "accessIPv6",
+        "addresses",
+        "config_drive",
+        "created",
+        "description",
+        "fault",
+        "flavor",
+        "host_status",
+        "hostId",
+        "image",
+        "key_name",
+        "locked",
+        "metadata",
+        "OS-DCF:diskConfig",
+        "OS-EXT-AZ:availability_zone",
+        "OS-EXT-SRV-ATTR:host",
+        "OS-EXT-SRV-ATTR:hostname",
+        "OS-EXT-SRV-ATTR:hypervisor_hostname",
+        "OS-EXT-SRV-ATTR:instance_name",
+        "OS-EXT-SRV-ATTR:kernel_id",
+        "OS-EXT-SRV-ATTR:launch_index",
+        "OS-EXT-SRV-ATTR:ramdisk_id",
+        "OS-EXT-SRV-ATTR:reservation_id",
+        "OS-EXT-SRV-ATTR:root_device_name",
+        "OS-EXT-SRV-ATTR:user_data",
+        "OS-EXT-STS:power_state",
+        "OS-EXT-STS:task_state",
+        "OS-EXT-STS:vm_state",
+        "os-extended-volumes:volumes_attached",
+        "OS-SRV-USG:launched_at",
+        "OS-SRV-USG:terminated_at",
+        "progress",
+        "security_groups",
+        "server_groups",
+        "tags",
+        "tenant_id",
+        "trusted_image_certificates",
+        "updated",
+        "user_id"
+    ]
+);
+
 /// **Servers (servers)**
 ///
 /// Lists, creates, shows details for, updates, and deletes
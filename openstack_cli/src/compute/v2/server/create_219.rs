@@ -37,6 +37,10 @@ use crate::common::parse_json;
 use crate::common::parse_key_val;
 use clap::ValueEnum;
 use openstack_sdk::api::compute::v2::server::create_219;
+use openstack_sdk::api::compute::v2::server::get;
+use openstack_sdk::api::compute::v2::server_group::find as find_server_group;
+use openstack_sdk::api::find;
+use openstack_sdk::api::wait_for_status;
 use openstack_sdk::api::QueryAsync;
 use serde_json::Value;
 use structable_derive::StructTable;
@@ -87,6 +91,15 @@ pub struct ServerCommand {
     #[command(flatten)]
     path: PathParameters,
 
+    /// Wait for the operation to complete
+    #[arg(long)]
+    wait: bool,
+
+    /// Server group name or ID. The server is scheduled according to the
+    /// policy of the group. This is a shortcut for the `group` scheduler hint.
+    #[arg(long, value_name = "SERVER_GROUP", conflicts_with = "group")]
+    server_group: Option<String>,
+
     /// The dictionary of data to send to the scheduler. Alternatively, you can
     /// specify `OS-SCH-HNT:scheduler_hints` as the key in the request body.
     ///
@@ -484,10 +497,23 @@ impl ServerCommand {
         // Set path parameters
         // Set query parameters
         // Set body parameters
+        // Resolve the server group name into the ID
+        let server_group_id = match &self.server_group {
+            Some(val) => {
+                let find_ep = find_server_group::Request::builder()
+                    .id(val)
+                    .build()
+                    .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+                let find_data: Value = find(find_ep).query_async(client).await?;
+                find_data["id"].as_str().map(String::from)
+            }
+            None => None,
+        };
+
         // Set Request.os_scheduler_hints data
         if let Some(args) = &self.os_scheduler_hints {
             let mut os_scheduler_hints_builder = create_219::OsSchedulerHintsBuilder::default();
-            if let Some(val) = &args.group {
+            if let Some(val) = server_group_id.as_ref().or(args.group.as_ref()) {
                 os_scheduler_hints_builder.group(val);
             }
 
@@ -522,6 +548,10 @@ impl ServerCommand {
                 os_scheduler_hints_builder.cidr(val);
             }
 
+            ep_builder.os_scheduler_hints(os_scheduler_hints_builder.build().unwrap());
+        } else if let Some(val) = &server_group_id {
+            let mut os_scheduler_hints_builder = create_219::OsSchedulerHintsBuilder::default();
+            os_scheduler_hints_builder.group(val);
             ep_builder.os_scheduler_hints(os_scheduler_hints_builder.build().unwrap());
         }
 
@@ -643,7 +673,22 @@ impl ServerCommand {
             .build()
             .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
 
-        let data = ep.query_async(client).await?;
+        let data: serde_json::Value = ep.query_async(client).await?;
+        let data = if self.wait {
+            let id = data["id"]
+                .as_str()
+                .ok_or_else(|| anyhow::anyhow!("Response does not contain resource `id`"))?;
+            let get_ep = get::Request::builder()
+                .id(id)
+                .build()
+                .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+            wait_for_status(get_ep, "status", ["ACTIVE"])
+                .failures(["ERROR"])
+                .query_async(client)
+                .await?
+        } else {
+            data
+        };
         op.output_single::<ResponseData>(data)?;
         Ok(())
     }
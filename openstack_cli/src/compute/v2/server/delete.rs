@@ -33,10 +33,15 @@ use crate::OpenStackCliError;
 use crate::OutputConfig;
 use crate::StructTable;
 
-use bytes::Bytes;
-use http::Response;
+use crate::common::batch_query;
+use crate::common::check_batch_result;
 use openstack_sdk::api::compute::v2::server::delete;
-use openstack_sdk::api::RawQueryAsync;
+use openstack_sdk::api::compute::v2::server::find;
+use openstack_sdk::api::compute::v2::server::get;
+use openstack_sdk::api::find;
+use openstack_sdk::api::ignore;
+use openstack_sdk::api::wait_for_delete;
+use serde_json::Value;
 use structable_derive::StructTable;
 
 /// Deletes a server.
@@ -69,6 +74,10 @@ pub struct ServerCommand {
     /// Path parameters
     #[command(flatten)]
     path: PathParameters,
+
+    /// Wait for the operation to complete
+    #[arg(long)]
+    wait: bool,
 }
 
 /// Query parameters
@@ -83,9 +92,11 @@ struct PathParameters {
     #[arg(
         help_heading = "Path parameters",
         id = "path_param_id",
-        value_name = "ID"
+        value_name = "ID",
+        num_args = 1..,
+        required = true,
     )]
-    id: String,
+    id: Vec<String>,
 }
 /// Server response representation
 #[derive(Deserialize, Serialize, Clone, StructTable)]
@@ -103,18 +114,68 @@ impl ServerCommand {
         let op = OutputProcessor::from_args(parsed_args);
         op.validate_args(parsed_args)?;
 
-        let mut ep_builder = delete::Request::builder();
-
-        // Set path parameters
-        ep_builder.id(&self.path.id);
-        // Set query parameters
-        // Set body parameters
-
-        let ep = ep_builder
-            .build()
-            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
-
-        let _rsp: Response<Bytes> = ep.raw_query_async(client).await?;
-        Ok(())
+        // Resolve names into IDs
+        let find_eps = self
+            .path
+            .id
+            .iter()
+            .map(|id| {
+                find::Request::builder()
+                    .id(id)
+                    .build()
+                    .map(find)
+                    .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let found: Vec<Option<Value>> =
+            batch_query(client, find_eps, &self.path.id, "Resolving").await?;
+        let ids: Vec<String> = found
+            .iter()
+            .flatten()
+            .filter_map(|x| x["id"].as_str().map(String::from))
+            .collect();
+
+        let delete_eps = ids
+            .iter()
+            .map(|id| {
+                let mut ep_builder = delete::Request::builder();
+
+                // Set path parameters
+                ep_builder.id(id);
+                // Set query parameters
+                // Set body parameters
+
+                ep_builder
+                    .build()
+                    .map(ignore)
+                    .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let deleted: Vec<Option<()>> = batch_query(client, delete_eps, &ids, "Deleting").await?;
+        let mut ids: Vec<String> = ids
+            .into_iter()
+            .zip(deleted)
+            .filter_map(|(id, res)| res.map(|_| id))
+            .collect();
+
+        if self.wait {
+            let wait_eps = ids
+                .iter()
+                .map(|id| {
+                    get::Request::builder()
+                        .id(id)
+                        .build()
+                        .map(wait_for_delete)
+                        .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let waited: Vec<Option<()>> = batch_query(client, wait_eps, &ids, "Waiting").await?;
+            ids = ids
+                .into_iter()
+                .zip(waited)
+                .filter_map(|(id, res)| res.map(|_| id))
+                .collect();
+        }
+        check_batch_result(self.path.id.len(), ids.len())
     }
 }
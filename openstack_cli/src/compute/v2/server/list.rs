@@ -20,7 +20,6 @@
 //! Wraps invoking of the `v2.1/servers/detail` with `GET` method
 
 use clap::Args;
-use serde::{Deserialize, Serialize};
 use tracing::info;
 
 use anyhow::Result;
@@ -30,14 +29,10 @@ use openstack_sdk::AsyncOpenStack;
 use crate::output::OutputProcessor;
 use crate::Cli;
 use crate::OpenStackCliError;
-use crate::OutputConfig;
-use crate::StructTable;
 
 use openstack_sdk::api::compute::v2::server::list_detailed;
-use openstack_sdk::api::QueryAsync;
 use openstack_sdk::api::{paged, Pagination};
-use serde_json::Value;
-use structable_derive::StructTable;
+use openstack_sdk::types::compute::v2::Server;
 
 /// For each server, shows server details including config drive, extended
 /// status, and server usage information.
@@ -248,413 +243,6 @@ struct QueryParameters {
 /// Path parameters
 #[derive(Args)]
 struct PathParameters {}
-/// Servers response representation
-#[derive(Deserialize, Serialize, Clone, StructTable)]
-struct ResponseData {
-    /// IPv4 address that should be used to access this server. May be
-    /// automatically set by the provider.
-    ///
-    #[serde(rename = "accessIPv4")]
-    #[structable(optional, title = "accessIPv4", wide)]
-    access_ipv4: Option<String>,
-
-    /// IPv6 address that should be used to access this server. May be
-    /// automatically set by the provider.
-    ///
-    #[serde(rename = "accessIPv6")]
-    #[structable(optional, title = "accessIPv6", wide)]
-    access_ipv6: Option<String>,
-
-    /// The addresses for the server. Servers with status `BUILD` hide their
-    /// addresses information.
-    ///
-    #[serde()]
-    #[structable(optional, pretty, wide)]
-    addresses: Option<Value>,
-
-    /// Indicates whether or not a config drive was used for this server. The
-    /// value is `True` or an empty string. An empty string stands for `False`.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    config_drive: Option<String>,
-
-    /// The date and time when the resource was created. The date and time
-    /// stamp format is [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601)
-    ///
-    /// ```text
-    /// CCYY-MM-DDThh:mm:ss±hh:mm
-    ///
-    /// ```
-    ///
-    /// For example, `2015-08-27T09:49:58-05:00`. The `±hh:mm` value, if
-    /// included, is the time zone as an offset from UTC. In the previous
-    /// example, the offset value is `-05:00`.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    created: Option<String>,
-
-    /// The description of the server. Before microversion 2.19 this was set to
-    /// the server name.
-    ///
-    /// **New in version 2.19**
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    description: Option<String>,
-
-    /// A fault object. Only displayed when the server status is `ERROR` or
-    /// `DELETED` and a fault occurred.
-    ///
-    #[serde()]
-    #[structable(optional, pretty, wide)]
-    fault: Option<Value>,
-
-    /// Before microversion 2.47 this contains the ID and links for the flavor
-    /// used to boot the server instance. This can be an empty object in case
-    /// flavor information is no longer present in the system.
-    ///
-    /// As of microversion 2.47 this contains a subset of the actual flavor
-    /// information used to create the server instance, represented as a nested
-    /// dictionary.
-    ///
-    #[serde()]
-    #[structable(optional, pretty, wide)]
-    flavor: Option<Value>,
-
-    /// The host status. Values where next value in list can override the
-    /// previous:
-    ///
-    /// - `UP` if nova-compute up.
-    /// - `UNKNOWN` if nova-compute not reported by servicegroup driver.
-    /// - `DOWN` if nova-compute forced down.
-    /// - `MAINTENANCE` if nova-compute is disabled.
-    /// - Empty string indicates there is no host for server.
-    ///
-    /// This attribute appears in the response only if the policy permits. By
-    /// default, only administrators can get this parameter.
-    ///
-    /// **New in version 2.16**
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    host_status: Option<String>,
-
-    /// An ID string representing the host. This is a hashed value so will not
-    /// actually look like a hostname, and is hashed with data from the
-    /// project_id, so the same physical host as seen by two different
-    /// project_ids, will be different. It is useful when within the same
-    /// project you need to determine if two instances are on the same or
-    /// different physical hosts for the purposes of availability or
-    /// performance.
-    ///
-    #[serde(rename = "hostId")]
-    #[structable(optional, title = "hostId", wide)]
-    host_id: Option<String>,
-
-    /// Id of the server
-    ///
-    #[serde()]
-    #[structable(optional)]
-    id: Option<String>,
-
-    /// The UUID and links for the image for your server instance. The `image`
-    /// object will be an empty string when you boot the server from a volume.
-    ///
-    #[serde()]
-    #[structable(optional, pretty, wide)]
-    image: Option<Value>,
-
-    /// The name of associated key pair, if any.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    key_name: Option<String>,
-
-    /// True if the instance is locked otherwise False.
-    ///
-    /// **New in version 2.9**
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    locked: Option<bool>,
-
-    /// A dictionary of metadata key-and-value pairs, which is maintained for
-    /// backward compatibility.
-    ///
-    #[serde()]
-    #[structable(optional, pretty, wide)]
-    metadata: Option<Value>,
-
-    /// The server name.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    name: Option<String>,
-
-    /// Disk configuration. The value is either:
-    ///
-    /// - `AUTO`. The API builds the server with a single partition the size of
-    ///   the target flavor disk. The API automatically adjusts the file system
-    ///   to fit the entire partition.
-    /// - `MANUAL`. The API builds the server by using the partition scheme and
-    ///   file system that is in the source image. If the target flavor disk is
-    ///   larger, The API does not partition the remaining disk space.
-    ///
-    #[serde(rename = "OS-DCF:diskConfig")]
-    #[structable(optional, title = "OS-DCF:diskConfig", wide)]
-    os_dcf_disk_config: Option<String>,
-
-    /// The availability zone name.
-    ///
-    #[serde(rename = "OS-EXT-AZ:availability_zone")]
-    #[structable(optional, title = "OS-EXT-AZ:availability_zone", wide)]
-    os_ext_az_availability_zone: Option<String>,
-
-    /// The name of the compute host on which this instance is running. Appears
-    /// in the response for administrative users only.
-    ///
-    #[serde(rename = "OS-EXT-SRV-ATTR:host")]
-    #[structable(optional, title = "OS-EXT-SRV-ATTR:host", wide)]
-    os_ext_srv_attr_host: Option<String>,
-
-    /// The hostname of the instance reported in the metadata service. This
-    /// parameter only appears in responses for administrators until
-    /// microversion 2.90, after which it is shown for all users.
-    ///
-    /// Note
-    ///
-    /// This information is published via the metadata service and requires
-    /// application such as `cloud-init` to propagate it through to the
-    /// instance.
-    ///
-    /// **New in version 2.3**
-    ///
-    #[serde(rename = "OS-EXT-SRV-ATTR:hostname")]
-    #[structable(optional, title = "OS-EXT-SRV-ATTR:hostname", wide)]
-    os_ext_srv_attr_hostname: Option<String>,
-
-    /// The hypervisor host name provided by the Nova virt driver. For the
-    /// Ironic driver, it is the Ironic node uuid. Appears in the response for
-    /// administrative users only.
-    ///
-    #[serde(rename = "OS-EXT-SRV-ATTR:hypervisor_hostname")]
-    #[structable(optional, title = "OS-EXT-SRV-ATTR:hypervisor_hostname", wide)]
-    os_ext_srv_attr_hypervisor_hostname: Option<String>,
-
-    /// The instance name. The Compute API generates the instance name from the
-    /// instance name template. Appears in the response for administrative
-    /// users only.
-    ///
-    #[serde(rename = "OS-EXT-SRV-ATTR:instance_name")]
-    #[structable(optional, title = "OS-EXT-SRV-ATTR:instance_name", wide)]
-    os_ext_srv_attr_instance_name: Option<String>,
-
-    /// The UUID of the kernel image when using an AMI. Will be null if not. By
-    /// default, it appears in the response for administrative users only.
-    ///
-    /// **New in version 2.3**
-    ///
-    #[serde(rename = "OS-EXT-SRV-ATTR:kernel_id")]
-    #[structable(optional, title = "OS-EXT-SRV-ATTR:kernel_id", wide)]
-    os_ext_srv_attr_kernel_id: Option<String>,
-
-    /// When servers are launched via multiple create, this is the sequence in
-    /// which the servers were launched. By default, it appears in the response
-    /// for administrative users only.
-    ///
-    /// **New in version 2.3**
-    ///
-    #[serde(rename = "OS-EXT-SRV-ATTR:launch_index")]
-    #[structable(optional, title = "OS-EXT-SRV-ATTR:launch_index", wide)]
-    os_ext_srv_attr_launch_index: Option<i32>,
-
-    /// The UUID of the ramdisk image when using an AMI. Will be null if not.
-    /// By default, it appears in the response for administrative users only.
-    ///
-    /// **New in version 2.3**
-    ///
-    #[serde(rename = "OS-EXT-SRV-ATTR:ramdisk_id")]
-    #[structable(optional, title = "OS-EXT-SRV-ATTR:ramdisk_id", wide)]
-    os_ext_srv_attr_ramdisk_id: Option<String>,
-
-    /// The reservation id for the server. This is an id that can be useful in
-    /// tracking groups of servers created with multiple create, that will all
-    /// have the same reservation_id. By default, it appears in the response
-    /// for administrative users only.
-    ///
-    /// **New in version 2.3**
-    ///
-    #[serde(rename = "OS-EXT-SRV-ATTR:reservation_id")]
-    #[structable(optional, title = "OS-EXT-SRV-ATTR:reservation_id", wide)]
-    os_ext_srv_attr_reservation_id: Option<String>,
-
-    /// The root device name for the instance By default, it appears in the
-    /// response for administrative users only.
-    ///
-    /// **New in version 2.3**
-    ///
-    #[serde(rename = "OS-EXT-SRV-ATTR:root_device_name")]
-    #[structable(optional, title = "OS-EXT-SRV-ATTR:root_device_name", wide)]
-    os_ext_srv_attr_root_device_name: Option<String>,
-
-    /// The user_data the instance was created with. By default, it appears in
-    /// the response for administrative users only.
-    ///
-    /// **New in version 2.3**
-    ///
-    #[serde(rename = "OS-EXT-SRV-ATTR:user_data")]
-    #[structable(optional, title = "OS-EXT-SRV-ATTR:user_data", wide)]
-    os_ext_srv_attr_user_data: Option<String>,
-
-    /// The power state of the instance. This is an enum value that is mapped
-    /// as:
-    ///
-    /// ```text
-    /// 0: NOSTATE
-    /// 1: RUNNING
-    /// 3: PAUSED
-    /// 4: SHUTDOWN
-    /// 6: CRASHED
-    /// 7: SUSPENDED
-    ///
-    /// ```
-    ///
-    #[serde(rename = "OS-EXT-STS:power_state")]
-    #[structable(optional, title = "OS-EXT-STS:power_state", wide)]
-    os_ext_sts_power_state: Option<i32>,
-
-    /// The task state of the instance.
-    ///
-    #[serde(rename = "OS-EXT-STS:task_state")]
-    #[structable(optional, title = "OS-EXT-STS:task_state", wide)]
-    os_ext_sts_task_state: Option<String>,
-
-    /// The VM state.
-    ///
-    #[serde(rename = "OS-EXT-STS:vm_state")]
-    #[structable(optional, title = "OS-EXT-STS:vm_state", wide)]
-    os_ext_sts_vm_state: Option<String>,
-
-    /// The attached volumes, if any.
-    ///
-    #[serde(rename = "os-extended-volumes:volumes_attached")]
-    #[structable(optional, pretty, title = "os-extended-volumes:volumes_attached", wide)]
-    os_extended_volumes_volumes_attached: Option<Value>,
-
-    /// The date and time when the server was launched.
-    ///
-    /// The date and time stamp format is
-    /// [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601):
-    ///
-    /// ```text
-    /// CCYY-MM-DDThh:mm:ss±hh:mm
-    ///
-    /// ```
-    ///
-    /// For example, `2015-08-27T09:49:58-05:00`.
-    ///
-    /// The `hh±:mm` value, if included, is the time zone as an offset from
-    /// UTC. If the `deleted_at` date and time stamp is not set, its value is
-    /// `null`.
-    ///
-    #[serde(rename = "OS-SRV-USG:launched_at")]
-    #[structable(optional, title = "OS-SRV-USG:launched_at", wide)]
-    os_srv_usg_launched_at: Option<String>,
-
-    /// The date and time when the server was deleted.
-    ///
-    /// The date and time stamp format is
-    /// [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601):
-    ///
-    /// ```text
-    /// CCYY-MM-DDThh:mm:ss±hh:mm
-    ///
-    /// ```
-    ///
-    /// For example, `2015-08-27T09:49:58-05:00`. The `±hh:mm` value, if
-    /// included, is the time zone as an offset from UTC. If the `deleted_at`
-    /// date and time stamp is not set, its value is `null`.
-    ///
-    #[serde(rename = "OS-SRV-USG:terminated_at")]
-    #[structable(optional, title = "OS-SRV-USG:terminated_at", wide)]
-    os_srv_usg_terminated_at: Option<String>,
-
-    /// A percentage value of the operation progress. This parameter only
-    /// appears when the server status is `ACTIVE`, `BUILD`, `REBUILD`,
-    /// `RESIZE`, `VERIFY_RESIZE` or `MIGRATING`.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    progress: Option<i32>,
-
-    /// One or more security groups objects.
-    ///
-    #[serde()]
-    #[structable(optional, pretty, wide)]
-    security_groups: Option<Value>,
-
-    /// The UUIDs of the server groups to which the server belongs. Currently
-    /// this can contain at most one entry.
-    ///
-    #[serde()]
-    #[structable(optional, pretty, wide)]
-    server_groups: Option<Value>,
-
-    /// The server status.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    status: Option<String>,
-
-    /// A list of tags. The maximum count of tags in this list is 50.
-    ///
-    /// **New in version 2.26**
-    ///
-    #[serde()]
-    #[structable(optional, pretty, wide)]
-    tags: Option<Value>,
-
-    /// The UUID of the tenant in a multi-tenancy cloud.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    tenant_id: Option<String>,
-
-    /// A list of trusted certificate IDs, that were used during image
-    /// signature verification to verify the signing certificate. The list is
-    /// restricted to a maximum of 50 IDs. The value is `null` if trusted
-    /// certificate IDs are not set.
-    ///
-    /// **New in version 2.63**
-    ///
-    #[serde()]
-    #[structable(optional, pretty, wide)]
-    trusted_image_certificates: Option<Value>,
-
-    /// The date and time when the resource was updated. The date and time
-    /// stamp format is [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601)
-    ///
-    /// ```text
-    /// CCYY-MM-DDThh:mm:ss±hh:mm
-    ///
-    /// ```
-    ///
-    /// For example, `2015-08-27T09:49:58-05:00`. The `±hh:mm` value, if
-    /// included, is the time zone as an offset from UTC. In the previous
-    /// example, the offset value is `-05:00`.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    updated: Option<String>,
-
-    /// The user ID of the user who owns the server.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    user_id: Option<String>,
-}
 
 impl ServersCommand {
     /// Perform command action
@@ -849,11 +437,10 @@ impl ServersCommand {
             .build()
             .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
 
-        let data: Vec<serde_json::Value> = paged(ep, Pagination::Limit(self.max_items))
-            .query_async(client)
-            .await?;
+        let ep = paged(ep, Pagination::Limit(self.max_items)).prefetch(true);
 
-        op.output_list::<ResponseData>(data)?;
+        op.output_list_stream::<Server, _, _>(ep.iter_async(client))
+            .await?;
         Ok(())
     }
 }
@@ -35,7 +35,10 @@ use crate::StructTable;
 
 use bytes::Bytes;
 use http::Response;
+use openstack_sdk::api::compute::v2::server::get;
 use openstack_sdk::api::compute::v2::server::os_start;
+use openstack_sdk::api::wait_for_status;
+use openstack_sdk::api::QueryAsync;
 use openstack_sdk::api::RawQueryAsync;
 use structable_derive::StructTable;
 
@@ -76,6 +79,10 @@ pub struct ServerCommand {
     /// Path parameters
     #[command(flatten)]
     path: PathParameters,
+
+    /// Wait for the operation to complete
+    #[arg(long)]
+    wait: bool,
 }
 
 /// Query parameters
@@ -122,6 +129,16 @@ impl ServerCommand {
             .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
 
         let _rsp: Response<Bytes> = ep.raw_query_async(client).await?;
+        if self.wait {
+            let get_ep = get::Request::builder()
+                .id(&self.path.id)
+                .build()
+                .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+            let _data: serde_json::Value = wait_for_status(get_ep, "status", ["ACTIVE"])
+                .failures(["ERROR"])
+                .query_async(client)
+                .await?;
+        }
         let data = ResponseData {};
         // Maybe output some headers metadata
         op.output_human::<ResponseData>(&data)?;
@@ -33,7 +33,9 @@ use crate::OpenStackCliError;
 use crate::OutputConfig;
 use crate::StructTable;
 
+use openstack_sdk::api::compute::v2::server::get;
 use openstack_sdk::api::compute::v2::server::rescue;
+use openstack_sdk::api::wait_for_status;
 use openstack_sdk::api::QueryAsync;
 use structable_derive::StructTable;
 
@@ -66,6 +68,10 @@ pub struct ServerCommand {
     #[command(flatten)]
     path: PathParameters,
 
+    /// Wait for the operation to complete
+    #[arg(long)]
+    wait: bool,
+
     /// The action to rescue a server.
     ///
     #[command(flatten)]
@@ -147,6 +153,16 @@ impl ServerCommand {
             .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
 
         let data = ep.query_async(client).await?;
+        if self.wait {
+            let get_ep = get::Request::builder()
+                .id(&self.path.id)
+                .build()
+                .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+            let _data: serde_json::Value = wait_for_status(get_ep, "status", ["RESCUE"])
+                .failures(["ERROR"])
+                .query_async(client)
+                .await?;
+        }
         op.output_single::<ResponseData>(data)?;
         Ok(())
     }
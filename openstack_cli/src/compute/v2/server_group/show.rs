@@ -0,0 +1,171 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// WARNING: This file is automatically generated from OpenAPI schema using
+// `openstack-codegenerator`.
+
+//! Show Server Group command
+//!
+//! Wraps invoking of the `v2.1/os-server_groups/{id}` with `GET` method
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use anyhow::Result;
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::output::OutputProcessor;
+use crate::Cli;
+use crate::OpenStackCliError;
+use crate::OutputConfig;
+use crate::StructTable;
+
+use openstack_sdk::api::compute::v2::server_group::find;
+use openstack_sdk::api::find;
+use openstack_sdk::api::QueryAsync;
+use serde_json::Value;
+use structable_derive::StructTable;
+
+/// Shows details for a server group.
+///
+/// Normal response codes: 200
+///
+/// Error response codes: unauthorized(401), forbidden(403), itemNotFound(404)
+///
+#[derive(Args)]
+#[command(about = "Show Server Group Details")]
+pub struct ServerGroupCommand {
+    /// Request Query parameters
+    #[command(flatten)]
+    query: QueryParameters,
+
+    /// Path parameters
+    #[command(flatten)]
+    path: PathParameters,
+}
+
+/// Query parameters
+#[derive(Args)]
+struct QueryParameters {}
+
+/// Path parameters
+#[derive(Args)]
+struct PathParameters {
+    /// id parameter for /v2.1/os-server_groups/{id} API
+    ///
+    #[arg(
+        help_heading = "Path parameters",
+        id = "path_param_id",
+        value_name = "ID"
+    )]
+    id: String,
+}
+/// ServerGroup response representation
+#[derive(Deserialize, Serialize, Clone, StructTable)]
+struct ResponseData {
+    /// The UUID of the server group.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    id: Option<String>,
+
+    /// The name of the server group.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    name: Option<String>,
+
+    /// The `policy` field represents the name of the policy.
+    ///
+    /// **New in version 2.64**
+    ///
+    #[serde()]
+    #[structable(optional)]
+    policy: Option<String>,
+
+    /// A list of exactly one policy name to associate with the server group.
+    ///
+    /// **Available until version 2.63**
+    ///
+    #[serde()]
+    #[structable(optional, pretty)]
+    policies: Option<Value>,
+
+    /// The `rules` field, which is a dict, can be applied to the policy.
+    /// Currently, only the `max_server_per_host` rule is supported for the
+    /// `anti-affinity` policy.
+    ///
+    /// **New in version 2.64**
+    ///
+    #[serde()]
+    #[structable(optional, pretty)]
+    rules: Option<Value>,
+
+    /// A list of members in the server group.
+    ///
+    #[serde()]
+    #[structable(optional, pretty)]
+    members: Option<Value>,
+
+    /// Metadata key and value pairs. The metadata field always remains empty.
+    ///
+    /// **Available until version 2.63**
+    ///
+    #[serde()]
+    #[structable(optional, pretty)]
+    metadata: Option<Value>,
+
+    /// The project ID who owns the server group.
+    ///
+    /// **New in version 2.13**
+    ///
+    #[serde()]
+    #[structable(optional)]
+    project_id: Option<String>,
+
+    /// The user ID who owns the server group.
+    ///
+    /// **New in version 2.13**
+    ///
+    #[serde()]
+    #[structable(optional)]
+    user_id: Option<String>,
+}
+
+impl ServerGroupCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        client: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        info!("Show Server Group");
+
+        let op = OutputProcessor::from_args(parsed_args);
+        op.validate_args(parsed_args)?;
+
+        let mut find_builder = find::Request::builder();
+
+        find_builder.id(&self.path.id);
+        let find_ep = find_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+        let find_data: serde_json::Value = find(find_ep).query_async(client).await?;
+
+        op.output_single::<ResponseData>(find_data)?;
+        Ok(())
+    }
+}
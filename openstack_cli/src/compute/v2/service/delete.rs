@@ -0,0 +1,119 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+//! Delete Service command
+//!
+//! Wraps invoking of the `v2.1/os-services/{id}` with `DELETE` method
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use anyhow::Result;
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::output::OutputProcessor;
+use crate::Cli;
+use crate::OpenStackCliError;
+use crate::OutputConfig;
+use crate::StructTable;
+
+use crate::common::batch_query;
+use crate::common::check_batch_result;
+use openstack_sdk::api::compute::v2::service::delete;
+use openstack_sdk::api::ignore;
+use structable_derive::StructTable;
+
+/// Deletes a service. If it’s a `nova-compute` service, then the
+/// corresponding host will be removed from all the host aggregates as well.
+///
+/// Attempts to delete a `nova-compute` service which is still hosting
+/// instances will result in a 409 HTTPConflict response. The instances will
+/// need to be migrated or deleted before a compute service can be deleted.
+///
+/// Normal response codes: 204
+///
+/// Error response codes: badRequest(400), unauthorized(401), forbidden(403),
+/// itemNotFound(404), conflict(409)
+///
+#[derive(Args)]
+#[command(about = "Delete Compute Service")]
+pub struct ServiceCommand {
+    /// Request Query parameters
+    #[command(flatten)]
+    query: QueryParameters,
+
+    /// Path parameters
+    #[command(flatten)]
+    path: PathParameters,
+}
+
+/// Query parameters
+#[derive(Args)]
+struct QueryParameters {}
+
+/// Path parameters
+#[derive(Args)]
+struct PathParameters {
+    /// The id of the service as a uuid.
+    ///
+    #[arg(
+        help_heading = "Path parameters",
+        id = "path_param_id",
+        value_name = "ID",
+        num_args = 1..,
+        required = true,
+    )]
+    id: Vec<String>,
+}
+/// Service response representation
+#[derive(Deserialize, Serialize, Clone, StructTable)]
+struct ResponseData {}
+
+impl ServiceCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        client: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        info!("Delete Service");
+
+        let op = OutputProcessor::from_args(parsed_args);
+        op.validate_args(parsed_args)?;
+
+        let delete_eps = self
+            .path
+            .id
+            .iter()
+            .map(|id| {
+                let mut ep_builder = delete::Request::builder();
+                ep_builder.header("OpenStack-API-Version", "compute 2.53");
+
+                // Set path parameters
+                ep_builder.id(id);
+                // Set query parameters
+                // Set body parameters
+
+                ep_builder
+                    .build()
+                    .map(ignore)
+                    .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let deleted: Vec<Option<()>> =
+            batch_query(client, delete_eps, &self.path.id, "Deleting").await?;
+        check_batch_result(self.path.id.len(), deleted.iter().flatten().count())
+    }
+}
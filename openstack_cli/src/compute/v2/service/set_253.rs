@@ -0,0 +1,211 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+//! Set Service command [microversion = 2.53]
+//!
+//! Wraps invoking of the `v2.1/os-services/{id}` with `PUT` method
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use anyhow::Result;
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::output::OutputProcessor;
+use crate::Cli;
+use crate::OpenStackCliError;
+use crate::OutputConfig;
+use crate::StructTable;
+
+use clap::ValueEnum;
+use openstack_sdk::api::compute::v2::service::set_253;
+use openstack_sdk::api::QueryAsync;
+use structable_derive::StructTable;
+
+/// Update a compute service to enable or disable scheduling, including
+/// recording a reason why a compute service was disabled from scheduling. Set
+/// or unset the `forced_down` flag for the service. This operation is only
+/// allowed on services whose `binary` is `nova-compute`.
+///
+/// Normal response codes: 200
+///
+/// Error response codes: badRequest(400), unauthorized(401), forbidden(403),
+/// itemNotFound(404)
+///
+#[derive(Args)]
+#[command(about = "Update Compute Service (microversion = 2.53)")]
+pub struct ServiceCommand {
+    /// Request Query parameters
+    #[command(flatten)]
+    query: QueryParameters,
+
+    /// Path parameters
+    #[command(flatten)]
+    path: PathParameters,
+
+    /// The reason for disabling a service. The minimum length is 1 and the
+    /// maximum length is 255. This may only be requested with
+    /// `status=disabled`.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    disabled_reason: Option<String>,
+
+    /// `forced_down` is a manual override to tell nova that the service in
+    /// question has been fenced manually by the operations team (either hard
+    /// powered off, or network unplugged). That signals that it is safe to
+    /// proceed with `evacuate` or other operations that nova has safety checks
+    /// to prevent for hosts that are up.
+    ///
+    /// Warning
+    ///
+    /// Setting a service forced down without completing fencing steps is not
+    /// safe.
+    ///
+    #[arg(action=clap::ArgAction::Set, help_heading = "Body parameters", long)]
+    forced_down: Option<bool>,
+
+    /// The status of the service. One of `enabled` or `disabled`.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    status: Option<Status>,
+}
+
+/// Query parameters
+#[derive(Args)]
+struct QueryParameters {}
+
+/// Path parameters
+#[derive(Args)]
+struct PathParameters {
+    /// The id of the service as a uuid.
+    ///
+    #[arg(
+        help_heading = "Path parameters",
+        id = "path_param_id",
+        value_name = "ID"
+    )]
+    id: String,
+}
+
+#[derive(Clone, Eq, Ord, PartialEq, PartialOrd, ValueEnum)]
+enum Status {
+    Disabled,
+    Enabled,
+}
+
+/// Service response representation
+#[derive(Deserialize, Serialize, Clone, StructTable)]
+struct ResponseData {
+    /// The id of the service as a uuid.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    id: Option<String>,
+
+    /// The binary name of the service.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    binary: Option<String>,
+
+    /// The name of the host.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    host: Option<String>,
+
+    /// The availability zone name.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    zone: Option<String>,
+
+    /// The status of the service. One of `enabled` or `disabled`.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    status: Option<String>,
+
+    /// The state of the service. One of `up` or `down`.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    state: Option<String>,
+
+    /// The date and time when the resource was updated.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    updated_at: Option<String>,
+
+    /// The reason for disabling a service.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    disabled_reason: Option<String>,
+
+    /// Whether or not this service was forced down manually by an administrator after the service was fenced.
+    ///
+    /// **New in version 2.11**
+    ///
+    #[serde()]
+    #[structable(optional)]
+    forced_down: Option<bool>,
+}
+
+impl ServiceCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        client: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        info!("Set Service");
+
+        let op = OutputProcessor::from_args(parsed_args);
+        op.validate_args(parsed_args)?;
+
+        let mut ep_builder = set_253::Request::builder();
+        ep_builder.header("OpenStack-API-Version", "compute 2.53");
+
+        // Set path parameters
+        ep_builder.id(&self.path.id);
+        // Set query parameters
+        // Set body parameters
+        if let Some(val) = &self.disabled_reason {
+            ep_builder.disabled_reason(val);
+        }
+
+        if let Some(val) = &self.forced_down {
+            ep_builder.forced_down(*val);
+        }
+
+        if let Some(val) = &self.status {
+            let tmp = match val {
+                Status::Disabled => set_253::Status::Disabled,
+                Status::Enabled => set_253::Status::Enabled,
+            };
+            ep_builder.status(tmp);
+        }
+
+        let ep = ep_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+
+        let data = ep.query_async(client).await?;
+        op.output_single::<ResponseData>(data)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,129 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Usage reports (os-simple-tenant-usage)
+//!
+//! Reports usage statistics of compute and storage resources periodically for
+//! an individual tenant or all tenants.
+
+use clap::{Parser, Subcommand};
+use serde_json::{json, Value};
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::{Cli, OpenStackCliError};
+
+mod list;
+mod show;
+
+/// Usage reports
+///
+/// Reports usage statistics of compute and storage resources periodically for
+/// an individual tenant or all tenants. The usage statistics will include all
+/// instances' CPU, memory and local disk during a specific period.
+#[derive(Parser)]
+pub struct UsageCommand {
+    /// subcommand
+    #[command(subcommand)]
+    command: UsageCommands,
+}
+
+/// Supported subcommands
+#[allow(missing_docs)]
+#[derive(Subcommand)]
+pub enum UsageCommands {
+    List(Box<list::UsagesCommand>),
+    Show(Box<show::UsageCommand>),
+}
+
+impl UsageCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        session: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        match &self.command {
+            UsageCommands::List(cmd) => cmd.take_action(parsed_args, session).await,
+            UsageCommands::Show(cmd) => cmd.take_action(parsed_args, session).await,
+        }
+    }
+}
+
+/// Parse the boundary of the usage period.
+///
+/// Nova only accepts `YYYY-MM-DDTHH:MM:SS[.ffffff]` timestamps without time
+/// zone, so a bare date is extended to the midnight (UTC) of that day.
+fn parse_usage_time(s: &str) -> Result<String, String> {
+    let date = s.split('T').next().unwrap_or_default();
+    let date_parts: Vec<&str> = date.split('-').collect();
+    if date_parts.len() != 3 || date_parts.iter().any(|x| x.parse::<u16>().is_err()) {
+        return Err(format!(
+            "`{}` is not a date (YYYY-MM-DD) or timestamp (YYYY-MM-DDTHH:MM:SS)",
+            s
+        ));
+    }
+    if s.len() == date.len() {
+        return Ok(format!("{}T00:00:00", s));
+    }
+    Ok(s.to_string())
+}
+
+/// Condense a `tenant_usage` into the totals of the usage period.
+fn usage_summary(usage: &Value) -> Value {
+    let round = |x: &Value| x.as_f64().map(|v| (v * 100.0).round() / 100.0);
+    json!({
+        "project_id": usage["tenant_id"],
+        "servers": usage["server_usages"].as_array().map(|x| x.len()),
+        "ram_mb_hours": round(&usage["total_memory_mb_usage"]),
+        "cpu_hours": round(&usage["total_vcpus_usage"]),
+        "disk_gb_hours": round(&usage["total_local_gb_usage"]),
+        "start": usage["start"],
+        "stop": usage["stop"],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_usage_time() {
+        assert_eq!(
+            parse_usage_time("2024-01-31").unwrap(),
+            "2024-01-31T00:00:00"
+        );
+        assert_eq!(
+            parse_usage_time("2024-01-31T12:30:00").unwrap(),
+            "2024-01-31T12:30:00"
+        );
+        assert!(parse_usage_time("yesterday").is_err());
+        assert!(parse_usage_time("2024-01").is_err());
+    }
+
+    #[test]
+    fn test_usage_summary() {
+        let summary = usage_summary(&json!({
+            "tenant_id": "p1",
+            "server_usages": [{}, {}],
+            "total_memory_mb_usage": 1024.123456,
+            "total_vcpus_usage": 2.005,
+            "total_local_gb_usage": 10.0,
+        }));
+        assert_eq!(summary["project_id"], "p1");
+        assert_eq!(summary["servers"], 2);
+        assert_eq!(summary["ram_mb_hours"], 1024.12);
+        assert_eq!(summary["disk_gb_hours"], 10.0);
+    }
+}
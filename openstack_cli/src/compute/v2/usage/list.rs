@@ -0,0 +1,173 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! List Usages command
+//!
+//! Wraps invoking of the `v2.1/os-simple-tenant-usage` with `GET` method
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use anyhow::Result;
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::output::OutputProcessor;
+use crate::Cli;
+use crate::OpenStackCliError;
+use crate::OutputConfig;
+use crate::StructTable;
+
+use openstack_sdk::api::compute::v2::simple_tenant_usage::list;
+use openstack_sdk::api::QueryAsync;
+use serde_json::Value;
+use structable_derive::StructTable;
+
+use super::{parse_usage_time, usage_summary};
+
+/// Lists usage statistics for all tenants.
+///
+/// Every project with servers in the usage period is reported with the number
+/// of its servers and the RAM, CPU and disk hours consumed by them.
+///
+/// Normal response codes: 200
+///
+/// Error response codes: badRequest(400), unauthorized(401), forbidden(403)
+///
+#[derive(Args)]
+#[command(about = "List Tenant Usage Statistics For All Tenants")]
+pub struct UsagesCommand {
+    /// Request Query parameters
+    #[command(flatten)]
+    query: QueryParameters,
+
+    /// Path parameters
+    #[command(flatten)]
+    path: PathParameters,
+}
+
+/// Query parameters
+#[derive(Args)]
+struct QueryParameters {
+    /// The beginning time of the usage period as date (`YYYY-MM-DD`) or
+    /// timestamp (`YYYY-MM-DDTHH:MM:SS`) in UTC.
+    ///
+    #[arg(
+        help_heading = "Query parameters",
+        long,
+        value_name = "START",
+        value_parser = parse_usage_time
+    )]
+    start: String,
+
+    /// The ending time of the usage period as date (`YYYY-MM-DD`) or
+    /// timestamp (`YYYY-MM-DDTHH:MM:SS`) in UTC.
+    ///
+    #[arg(
+        help_heading = "Query parameters",
+        long,
+        value_name = "END",
+        value_parser = parse_usage_time
+    )]
+    end: String,
+}
+
+/// Path parameters
+#[derive(Args)]
+struct PathParameters {}
+
+/// Usage response representation
+#[derive(Deserialize, Serialize, Clone, StructTable)]
+struct ResponseData {
+    /// The UUID of the project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    project_id: Option<String>,
+
+    /// The number of servers which were active in the usage period.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    servers: Option<u64>,
+
+    /// Multiplying the server memory size (in MiB) by hours the server exists,
+    /// and then adding that all together for each server.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    ram_mb_hours: Option<f64>,
+
+    /// Multiplying the number of virtual CPUs of the server by hours the
+    /// server exists, and then adding that all together for each server.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    cpu_hours: Option<f64>,
+
+    /// Multiplying the server disk size (in GiB) by hours the server exists,
+    /// and then adding that all together for each server.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    disk_gb_hours: Option<f64>,
+
+    /// The beginning time to calculate usage statistics on compute and
+    /// storage resources.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    start: Option<String>,
+
+    /// The ending time to calculate usage statistics on compute and storage
+    /// resources.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    stop: Option<String>,
+}
+
+impl UsagesCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        client: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        info!("List Usages");
+
+        let op = OutputProcessor::from_args(parsed_args);
+        op.validate_args(parsed_args)?;
+
+        let mut ep_builder = list::Request::builder();
+
+        // Set path parameters
+        // Set query parameters
+        // Server usages are only returned in the detailed mode and are needed
+        // to count the servers
+        ep_builder.detailed("1");
+        ep_builder.start(&self.query.start);
+        ep_builder.end(&self.query.end);
+        // Set body parameters
+
+        let ep = ep_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+
+        let data: Vec<Value> = ep.query_async(client).await?;
+        op.output_list::<ResponseData>(data.iter().map(usage_summary).collect())?;
+        Ok(())
+    }
+}
@@ -0,0 +1,186 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Show Usage command
+//!
+//! Wraps invoking of the `v2.1/os-simple-tenant-usage/{id}` with `GET` method
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use anyhow::Result;
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::output::OutputProcessor;
+use crate::Cli;
+use crate::OpenStackCliError;
+use crate::OutputConfig;
+use crate::StructTable;
+
+use crate::common::find_project_id;
+use openstack_sdk::api::compute::v2::simple_tenant_usage::get;
+use openstack_sdk::api::QueryAsync;
+use serde_json::Value;
+use structable_derive::StructTable;
+
+use super::{parse_usage_time, usage_summary};
+
+/// Shows usage statistics for a tenant.
+///
+/// The project is reported with the number of its servers and the RAM, CPU
+/// and disk hours consumed by them in the usage period.
+///
+/// Normal response codes: 200
+///
+/// Error response codes: badRequest(400), unauthorized(401), forbidden(403)
+///
+#[derive(Args)]
+#[command(about = "Show Usage Statistics For Tenant")]
+pub struct UsageCommand {
+    /// Request Query parameters
+    #[command(flatten)]
+    query: QueryParameters,
+
+    /// Path parameters
+    #[command(flatten)]
+    path: PathParameters,
+}
+
+/// Query parameters
+#[derive(Args)]
+struct QueryParameters {
+    /// The beginning time of the usage period as date (`YYYY-MM-DD`) or
+    /// timestamp (`YYYY-MM-DDTHH:MM:SS`) in UTC.
+    ///
+    #[arg(
+        help_heading = "Query parameters",
+        long,
+        value_name = "START",
+        value_parser = parse_usage_time
+    )]
+    start: String,
+
+    /// The ending time of the usage period as date (`YYYY-MM-DD`) or
+    /// timestamp (`YYYY-MM-DDTHH:MM:SS`) in UTC.
+    ///
+    #[arg(
+        help_heading = "Query parameters",
+        long,
+        value_name = "END",
+        value_parser = parse_usage_time
+    )]
+    end: String,
+}
+
+/// Path parameters
+#[derive(Args)]
+struct PathParameters {
+    /// Project (name or ID). Defaults to the currently authorized project.
+    ///
+    #[arg(
+        help_heading = "Path parameters",
+        id = "path_param_id",
+        value_name = "PROJECT"
+    )]
+    id: Option<String>,
+}
+
+/// Usage response representation
+#[derive(Deserialize, Serialize, Clone, StructTable)]
+struct ResponseData {
+    /// The UUID of the project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    project_id: Option<String>,
+
+    /// The number of servers which were active in the usage period.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    servers: Option<u64>,
+
+    /// Multiplying the server memory size (in MiB) by hours the server exists,
+    /// and then adding that all together for each server.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    ram_mb_hours: Option<f64>,
+
+    /// Multiplying the number of virtual CPUs of the server by hours the
+    /// server exists, and then adding that all together for each server.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    cpu_hours: Option<f64>,
+
+    /// Multiplying the server disk size (in GiB) by hours the server exists,
+    /// and then adding that all together for each server.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    disk_gb_hours: Option<f64>,
+
+    /// The beginning time to calculate usage statistics on compute and
+    /// storage resources.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    start: Option<String>,
+
+    /// The ending time to calculate usage statistics on compute and storage
+    /// resources.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    stop: Option<String>,
+}
+
+impl UsageCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        client: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        info!("Show Usage");
+
+        let op = OutputProcessor::from_args(parsed_args);
+        op.validate_args(parsed_args)?;
+
+        let project_id = find_project_id(client, self.path.id.as_ref()).await?;
+
+        let mut ep_builder = get::Request::builder();
+
+        // Set path parameters
+        ep_builder.id(&project_id);
+        // Set query parameters
+        ep_builder.start(&self.query.start);
+        ep_builder.end(&self.query.end);
+        // Set body parameters
+
+        let ep = ep_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+
+        let data: Value = ep.query_async(client).await?;
+        let mut usage = usage_summary(&data);
+        // Projects without servers in the period get an empty usage
+        usage["project_id"] = project_id.into();
+        op.output_single::<ResponseData>(usage)?;
+        Ok(())
+    }
+}
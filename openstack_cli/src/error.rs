@@ -63,6 +63,15 @@ pub enum OpenStackCliError {
     #[error("cannot uniqly findresource by identifier")]
     IdNotUnique,
 
+    /// Some items of the bulk operation failed
+    #[error("{failed} of {total} items failed")]
+    BatchFailed {
+        /// Number of failed items.
+        failed: usize,
+        /// Total number of items.
+        total: usize,
+    },
+
     /// IO error
     #[error("IO error: {}", source)]
     IO {
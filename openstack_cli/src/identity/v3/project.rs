@@ -16,8 +16,10 @@
 
 use clap::{Parser, Subcommand};
 
+use openstack_sdk::types::identity::v3::project::Project;
 use openstack_sdk::AsyncOpenStack;
 
+use crate::output::impl_struct_table;
 use crate::{Cli, OpenStackCliError};
 
 mod create;
@@ -28,6 +30,20 @@ mod set;
 mod show;
 mod user;
 
+impl_struct_table!(
+    Project,
+    ["id", "name"],
+    [
+        "description",
+        "domain_id",
+        "enabled",
+        "is_domain",
+        "options",
+        "parent_id",
+        "tags"
+    ]
+);
+
 /// Identity Project commands
 #[derive(Parser)]
 pub struct ProjectCommand {
@@ -20,7 +20,6 @@
 //! Wraps invoking of the `v3/projects` with `GET` method
 
 use clap::Args;
-use serde::{Deserialize, Serialize};
 use tracing::info;
 
 use anyhow::Result;
@@ -30,13 +29,10 @@ use openstack_sdk::AsyncOpenStack;
 use crate::output::OutputProcessor;
 use crate::Cli;
 use crate::OpenStackCliError;
-use crate::OutputConfig;
-use crate::StructTable;
 
 use openstack_sdk::api::identity::v3::project::list;
 use openstack_sdk::api::QueryAsync;
-use serde_json::Value;
-use structable_derive::StructTable;
+use openstack_sdk::types::identity::v3::project::Project;
 
 /// Lists projects.
 ///
@@ -90,66 +86,6 @@ struct QueryParameters {
 /// Path parameters
 #[derive(Args)]
 struct PathParameters {}
-/// Projects response representation
-#[derive(Deserialize, Serialize, Clone, StructTable)]
-struct ResponseData {
-    /// The description of the project.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    description: Option<String>,
-
-    /// The ID of the domain for the project.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    domain_id: Option<String>,
-
-    /// Whether the Service Provider is enabled or not
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    enabled: Option<bool>,
-
-    /// The ID for the project.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    id: Option<String>,
-
-    /// Whether the Service Provider is enabled or not
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    is_domain: Option<bool>,
-
-    /// The name of the project.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    name: Option<String>,
-
-    /// The resource options for the project. Available resource options are
-    /// `immutable`.
-    ///
-    #[serde()]
-    #[structable(optional, pretty, wide)]
-    options: Option<Value>,
-
-    /// The ID of the parent for the project.
-    ///
-    /// **New in version 3.4**
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    parent_id: Option<String>,
-
-    /// A list of simple strings assigned to a project.
-    ///
-    #[serde()]
-    #[structable(optional, pretty, wide)]
-    tags: Option<Value>,
-}
 
 impl ProjectsCommand {
     /// Perform command action
@@ -190,7 +126,7 @@ impl ProjectsCommand {
 
         let data: Vec<serde_json::Value> = ep.query_async(client).await?;
 
-        op.output_list::<ResponseData>(data)?;
+        op.output_list::<Project>(data)?;
         Ok(())
     }
 }
@@ -16,8 +16,10 @@
 
 use clap::{Parser, Subcommand};
 
+use openstack_sdk::types::identity::v3::user::User;
 use openstack_sdk::AsyncOpenStack;
 
+use crate::output::impl_struct_table;
 use crate::{Cli, OpenStackCliError};
 
 pub(super) mod access_rule;
@@ -37,6 +39,20 @@ mod group {
     pub(super) mod list;
 }
 
+impl_struct_table!(
+    User,
+    ["id", "name"],
+    [
+        "default_project_id",
+        "description",
+        "domain_id",
+        "enabled",
+        "federated",
+        "options",
+        "password"
+    ]
+);
+
 /// User commands
 ///
 /// A user is an individual API consumer that is owned by a domain. A role
@@ -16,8 +16,10 @@
 
 use clap::{Parser, Subcommand};
 
+use openstack_sdk::types::image::v2::Image;
 use openstack_sdk::AsyncOpenStack;
 
+use crate::output::impl_struct_table;
 use crate::{Cli, OpenStackCliError};
 
 mod create;
@@ -33,6 +35,33 @@ mod patch;
 mod reactivate;
 mod show;
 
+impl_struct_table!(
+    Image,
+    ["created_at", "id", "name", "status", "updated_at"],
+    [
+        "checksum",
+        "container_format",
+        "direct_url",
+        "disk_format",
+        "file",
+        "locations",
+        "min_disk",
+        "min_ram",
+        "os_hash_algo",
+        "os_hash_value",
+        "os_hidden",
+        "owner",
+        "protected",
+        "schema",
+        "self",
+        "size",
+        "stores",
+        "tags",
+        "virtual_size",
+        "visibility"
+    ]
+);
+
 /// Image (Glance) commands
 #[derive(Parser)]
 pub struct ImageCommand {
@@ -37,6 +37,9 @@ use crate::common::build_upload_asyncread;
 use bytes::Bytes;
 use http::Response;
 use openstack_sdk::api::image::v2::image::file::upload;
+use openstack_sdk::api::image::v2::image::get;
+use openstack_sdk::api::wait_for_status;
+use openstack_sdk::api::QueryAsync;
 use openstack_sdk::api::RawQueryAsync;
 use structable_derive::StructTable;
 
@@ -80,6 +83,10 @@ pub struct FileCommand {
     #[command(flatten)]
     path: PathParameters,
 
+    /// Wait for the operation to complete
+    #[arg(long)]
+    wait: bool,
+
     /// Source filename (using "-" will read object from stdout)
     #[arg(long)]
     file: Option<String>,
@@ -134,6 +141,16 @@ impl FileCommand {
         let data = build_upload_asyncread(dst).await?;
 
         let _rsp: Response<Bytes> = ep.raw_query_read_body_async(client, data).await?;
+        if self.wait {
+            let get_ep = get::Request::builder()
+                .id(&self.path.image_id)
+                .build()
+                .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+            let _data: serde_json::Value = wait_for_status(get_ep, "status", ["active"])
+                .failures(["killed"])
+                .query_async(client)
+                .await?;
+        }
         // TODO: what if there is an interesting response
         Ok(())
     }
@@ -36,6 +36,9 @@ use crate::StructTable;
 use bytes::Bytes;
 use http::Response;
 use openstack_sdk::api::image::v2::image::import::create;
+use openstack_sdk::api::image::v2::image::get;
+use openstack_sdk::api::wait_for_status;
+use openstack_sdk::api::QueryAsync;
 use openstack_sdk::api::RawQueryAsync;
 use structable_derive::StructTable;
 
@@ -119,6 +122,10 @@ pub struct ImportCommand {
     #[command(flatten)]
     path: PathParameters,
 
+    /// Wait for the operation to complete
+    #[arg(long)]
+    wait: bool,
+
     /// When set to True the data will be imported to the set of stores you may
     /// consume from this particular deployment of Glance (ie: the same set of
     /// stores returned to a call to /v2/info/stores on the glance-api the
@@ -248,6 +255,16 @@ impl ImportCommand {
             .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
 
         let _rsp: Response<Bytes> = ep.raw_query_async(client).await?;
+        if self.wait {
+            let get_ep = get::Request::builder()
+                .id(&self.path.image_id)
+                .build()
+                .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+            let _data: serde_json::Value = wait_for_status(get_ep, "status", ["active"])
+                .failures(["killed"])
+                .query_async(client)
+                .await?;
+        }
         let data = ResponseData {};
         // Maybe output some headers metadata
         op.output_human::<ResponseData>(&data)?;
@@ -20,7 +20,6 @@
 //! Wraps invoking of the `v2/images` with `GET` method
 
 use clap::Args;
-use serde::{Deserialize, Serialize};
 use tracing::info;
 
 use anyhow::Result;
@@ -30,14 +29,10 @@ use openstack_sdk::AsyncOpenStack;
 use crate::output::OutputProcessor;
 use crate::Cli;
 use crate::OpenStackCliError;
-use crate::OutputConfig;
-use crate::StructTable;
 
 use openstack_sdk::api::image::v2::image::list;
-use openstack_sdk::api::QueryAsync;
 use openstack_sdk::api::{paged, Pagination};
-use serde_json::Value;
-use structable_derive::StructTable;
+use openstack_sdk::types::image::v2::Image;
 
 /// Lists public virtual machine (VM) images. *(Since Image API v2.0)*
 ///
@@ -279,162 +274,6 @@ struct QueryParameters {
 /// Path parameters
 #[derive(Args)]
 struct PathParameters {}
-/// Images response representation
-#[derive(Deserialize, Serialize, Clone, StructTable)]
-struct ResponseData {
-    /// md5 hash of image contents.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    checksum: Option<String>,
-
-    /// Format of the container
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    container_format: Option<String>,
-
-    /// Date and time of image registration
-    ///
-    #[serde()]
-    #[structable(optional)]
-    created_at: Option<String>,
-
-    /// URL to access the image file kept in external store
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    direct_url: Option<String>,
-
-    /// Format of the disk
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    disk_format: Option<String>,
-
-    /// An image file url
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    file: Option<String>,
-
-    /// An identifier for the image
-    ///
-    #[serde()]
-    #[structable(optional)]
-    id: Option<String>,
-
-    /// A set of URLs to access the image file kept in external store
-    ///
-    #[serde()]
-    #[structable(optional, pretty, wide)]
-    locations: Option<Value>,
-
-    /// Amount of disk space (in GB) required to boot image.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    min_disk: Option<i32>,
-
-    /// Amount of ram (in MB) required to boot image.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    min_ram: Option<i32>,
-
-    /// Descriptive name for the image
-    ///
-    #[serde()]
-    #[structable(optional)]
-    name: Option<String>,
-
-    /// Algorithm to calculate the os_hash_value
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    os_hash_algo: Option<String>,
-
-    /// Hexdigest of the image contents using the algorithm specified by the
-    /// os_hash_algo
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    os_hash_value: Option<String>,
-
-    /// If true, image will not appear in default image list response.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    os_hidden: Option<bool>,
-
-    /// Owner of the image
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    owner: Option<String>,
-
-    /// If true, image will not be deletable.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    protected: Option<bool>,
-
-    /// An image schema url
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    schema: Option<String>,
-
-    /// An image self url
-    ///
-    #[serde(rename = "self")]
-    #[structable(optional, title = "self", wide)]
-    _self: Option<String>,
-
-    /// Size of image file in bytes
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    size: Option<i64>,
-
-    /// Status of the image
-    ///
-    #[serde()]
-    #[structable(optional)]
-    status: Option<String>,
-
-    /// Store in which image data resides. Only present when the operator has
-    /// enabled multiple stores. May be a comma-separated list of store
-    /// identifiers.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    stores: Option<String>,
-
-    /// List of strings related to the image
-    ///
-    #[serde()]
-    #[structable(optional, pretty, wide)]
-    tags: Option<Value>,
-
-    /// Date and time of the last image modification
-    ///
-    #[serde()]
-    #[structable(optional)]
-    updated_at: Option<String>,
-
-    /// Virtual size of image in bytes
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    virtual_size: Option<i64>,
-
-    /// Scope of image accessibility
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    visibility: Option<String>,
-}
 
 impl ImagesCommand {
     /// Perform command action
@@ -512,11 +351,10 @@ impl ImagesCommand {
             .build()
             .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
 
-        let data: Vec<serde_json::Value> = paged(ep, Pagination::Limit(self.max_items))
-            .query_async(client)
-            .await?;
+        let ep = paged(ep, Pagination::Limit(self.max_items)).prefetch(true);
 
-        op.output_list::<ResponseData>(data)?;
+        op.output_list_stream::<Image, _, _>(ep.iter_async(client))
+            .await?;
         Ok(())
     }
 }
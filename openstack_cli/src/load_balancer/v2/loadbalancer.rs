@@ -16,8 +16,10 @@
 
 use clap::{Parser, Subcommand};
 
+use openstack_sdk::types::load_balancer::v2::LoadBalancer;
 use openstack_sdk::AsyncOpenStack;
 
+use crate::output::impl_struct_table;
 use crate::{Cli, OpenStackCliError};
 
 mod create;
@@ -29,6 +31,32 @@ mod show;
 mod stats;
 mod status;
 
+impl_struct_table!(
+    LoadBalancer,
+    ["created_at", "id", "name", "updated_at"],
+    [
+        "additional_vips",
+        "admin_state_up",
+        "availability_zone",
+        "description",
+        "flavor_id",
+        "listeners",
+        "operating_status",
+        "pools",
+        "project_id",
+        "provider",
+        "provisioning_status",
+        "tags",
+        "tenant_id",
+        "vip_address",
+        "vip_network_id",
+        "vip_port_id",
+        "vip_qos_policy_id",
+        "vip_subnet_id",
+        "vip_vnic_type"
+    ]
+);
+
 /// Loadbalancer (Octavia) commands
 #[derive(Parser)]
 pub struct LoadbalancerCommand {
@@ -41,14 +69,14 @@ pub struct LoadbalancerCommand {
 #[allow(missing_docs)]
 #[derive(Subcommand)]
 pub enum LoadbalancerCommands {
-    Create(create::LoadbalancerCommand),
-    Delete(delete::LoadbalancerCommand),
-    Failover(failover::LoadbalancerCommand),
-    List(list::LoadbalancersCommand),
-    Set(set::LoadbalancerCommand),
-    Show(show::LoadbalancerCommand),
-    Stats(stats::LoadbalancerCommand),
-    Status(status::LoadbalancerCommand),
+    Create(Box<create::LoadbalancerCommand>),
+    Delete(Box<delete::LoadbalancerCommand>),
+    Failover(Box<failover::LoadbalancerCommand>),
+    List(Box<list::LoadbalancersCommand>),
+    Set(Box<set::LoadbalancerCommand>),
+    Show(Box<show::LoadbalancerCommand>),
+    Stats(Box<stats::LoadbalancerCommand>),
+    Status(Box<status::LoadbalancerCommand>),
 }
 
 impl LoadbalancerCommand {
@@ -20,7 +20,6 @@
 //! Wraps invoking of the `v2/lbaas/loadbalancers/{loadbalancer_id}` with `GET` method
 
 use clap::Args;
-use serde::{Deserialize, Serialize};
 use tracing::info;
 
 use anyhow::Result;
@@ -30,14 +29,11 @@ use openstack_sdk::AsyncOpenStack;
 use crate::output::OutputProcessor;
 use crate::Cli;
 use crate::OpenStackCliError;
-use crate::OutputConfig;
-use crate::StructTable;
 
 use openstack_sdk::api::find;
 use openstack_sdk::api::load_balancer::v2::loadbalancer::find;
 use openstack_sdk::api::QueryAsync;
-use serde_json::Value;
-use structable_derive::StructTable;
+use openstack_sdk::types::load_balancer::v2::LoadBalancer;
 
 /// Shows the details of a load balancer.
 ///
@@ -76,159 +72,6 @@ struct PathParameters {
     )]
     id: String,
 }
-/// Loadbalancer response representation
-#[derive(Deserialize, Serialize, Clone, StructTable)]
-struct ResponseData {
-    /// A list of JSON objects defining “additional VIPs”. The format for these
-    /// is `{"subnet_id": <subnet_id>, "ip_address": <ip_address>}`, where the
-    /// `subnet_id` field is mandatory and the `ip_address` field is optional.
-    /// Additional VIP subnets must all belong to the same network as the
-    /// primary VIP.
-    ///
-    /// **New in version 2.26**
-    ///
-    #[serde()]
-    #[structable(optional, pretty)]
-    additional_vips: Option<Value>,
-
-    /// The administrative state of the resource, which is up (`true`) or down
-    /// (`false`).
-    ///
-    #[serde()]
-    #[structable(optional)]
-    admin_state_up: Option<bool>,
-
-    /// An availability zone name.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    availability_zone: Option<String>,
-
-    /// The UTC date and timestamp when the resource was created.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    created_at: Option<String>,
-
-    /// A human-readable description for the resource.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    description: Option<String>,
-
-    /// The ID of the flavor.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    flavor_id: Option<String>,
-
-    /// The ID of the load balancer.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    id: Option<String>,
-
-    /// The associated listener IDs, if any.
-    ///
-    #[serde()]
-    #[structable(optional, pretty)]
-    listeners: Option<Value>,
-
-    /// Human-readable name of the resource.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    name: Option<String>,
-
-    /// The operating status of the resource. See
-    /// [Operating Status Codes](#op-status).
-    ///
-    #[serde()]
-    #[structable(optional)]
-    operating_status: Option<String>,
-
-    /// The associated pool IDs, if any.
-    ///
-    #[serde()]
-    #[structable(optional, pretty)]
-    pools: Option<Value>,
-
-    /// The ID of the project owning this resource.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    project_id: Option<String>,
-
-    /// Provider name for the load balancer.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    provider: Option<String>,
-
-    /// The provisioning status of the resource. See
-    /// [Provisioning Status Codes](#prov-status).
-    ///
-    #[serde()]
-    #[structable(optional)]
-    provisioning_status: Option<String>,
-
-    /// A list of simple strings assigned to the resource.
-    ///
-    /// **New in version 2.5**
-    ///
-    #[serde()]
-    #[structable(optional, pretty)]
-    tags: Option<Value>,
-
-    #[serde()]
-    #[structable(optional)]
-    tenant_id: Option<String>,
-
-    /// The UTC date and timestamp when the resource was last updated.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    updated_at: Option<String>,
-
-    /// The IP address of the Virtual IP (VIP).
-    ///
-    #[serde()]
-    #[structable(optional)]
-    vip_address: Option<String>,
-
-    /// The ID of the network for the Virtual IP (VIP).
-    ///
-    #[serde()]
-    #[structable(optional)]
-    vip_network_id: Option<String>,
-
-    /// The ID of the Virtual IP (VIP) port.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    vip_port_id: Option<String>,
-
-    /// The ID of the QoS Policy which will apply to the Virtual IP (VIP).
-    ///
-    #[serde()]
-    #[structable(optional)]
-    vip_qos_policy_id: Option<String>,
-
-    /// The ID of the subnet for the Virtual IP (VIP).
-    ///
-    #[serde()]
-    #[structable(optional)]
-    vip_subnet_id: Option<String>,
-
-    /// The VIP vNIC type used for the load balancer. One of `normal` or
-    /// `direct`.
-    ///
-    /// **New in version 2.28**
-    ///
-    #[serde()]
-    #[structable(optional)]
-    vip_vnic_type: Option<String>,
-}
 
 impl LoadbalancerCommand {
     /// Perform command action
@@ -250,7 +93,7 @@ impl LoadbalancerCommand {
             .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
         let find_data: serde_json::Value = find(find_ep).query_async(client).await?;
 
-        op.output_single::<ResponseData>(find_data)?;
+        op.output_single::<LoadBalancer>(find_data)?;
         Ok(())
     }
 }
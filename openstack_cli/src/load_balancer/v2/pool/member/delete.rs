@@ -36,6 +36,9 @@ use crate::StructTable;
 use bytes::Bytes;
 use http::Response;
 use openstack_sdk::api::load_balancer::v2::pool::member::delete;
+use openstack_sdk::api::load_balancer::v2::pool::member::get;
+use openstack_sdk::api::wait_for_delete;
+use openstack_sdk::api::QueryAsync;
 use openstack_sdk::api::RawQueryAsync;
 use structable_derive::StructTable;
 
@@ -54,6 +57,10 @@ pub struct MemberCommand {
     /// Path parameters
     #[command(flatten)]
     path: PathParameters,
+
+    /// Wait for the operation to complete
+    #[arg(long)]
+    wait: bool,
 }
 
 /// Query parameters
@@ -111,6 +118,18 @@ impl MemberCommand {
             .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
 
         let _rsp: Response<Bytes> = ep.raw_query_async(client).await?;
+        if self.wait {
+            let get_ep = get::Request::builder()
+                .pool_id(&self.path.pool_id)
+                .id(&self.path.id)
+                .build()
+                .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+            wait_for_delete(get_ep)
+                .field("provisioning_status")
+                .failures(["ERROR"])
+                .query_async(client)
+                .await?;
+        }
         Ok(())
     }
 }
@@ -22,13 +22,28 @@ use crate::{Cli, OpenStackCliError};
 
 mod address_group;
 mod address_scope;
+mod agent;
 mod availability_zone;
+mod bgpvpn;
 mod extension;
 mod floatingip;
+mod fwaas;
 mod network;
+mod network_ip_availability;
 mod port;
+mod qos;
+mod quota;
+mod rbac_policy;
 mod router;
+mod security_group;
+mod security_group_rule;
+mod segment;
+mod service_provider;
 mod subnet;
+mod subnetpool;
+mod tag;
+mod trunk;
+mod vpn;
 
 /// Network (Neutron) commands
 #[derive(Parser)]
@@ -44,13 +59,27 @@ pub struct NetworkCommand {
 pub enum NetworkCommands {
     AddressGroup(Box<address_group::AddressGroupCommand>),
     AddressScope(Box<address_scope::AddressScopeCommand>),
+    Agent(Box<agent::AgentCommand>),
     AvailabilityZone(Box<availability_zone::AvailabilityZoneCommand>),
+    Bgpvpn(Box<bgpvpn::BgpvpnCommand>),
     Extension(Box<extension::ExtensionCommand>),
     FloatingIP(Box<floatingip::FloatingIPCommand>),
+    Fwaas(Box<fwaas::FwaasCommand>),
     Network(Box<network::NetworkCommand>),
+    NetworkIpAvailability(Box<network_ip_availability::NetworkIpAvailabilityCommand>),
     Port(Box<port::PortCommand>),
+    Qos(Box<qos::QosCommand>),
+    Quota(Box<quota::QuotaCommand>),
+    RbacPolicy(Box<rbac_policy::RbacPolicyCommand>),
     Router(Box<router::RouterCommand>),
+    SecurityGroup(Box<security_group::SecurityGroupCommand>),
+    SecurityGroupRule(Box<security_group_rule::SecurityGroupRuleCommand>),
+    Segment(Box<segment::SegmentCommand>),
+    ServiceProvider(Box<service_provider::ServiceProviderCommand>),
     Subnet(Box<subnet::SubnetCommand>),
+    Subnetpool(Box<subnetpool::SubnetpoolCommand>),
+    Trunk(Box<trunk::TrunkCommand>),
+    Vpn(Box<vpn::VpnCommand>),
 }
 
 impl NetworkCommand {
@@ -63,13 +92,29 @@ impl NetworkCommand {
         match &self.command {
             NetworkCommands::AddressGroup(cmd) => cmd.take_action(parsed_args, session).await,
             NetworkCommands::AddressScope(cmd) => cmd.take_action(parsed_args, session).await,
+            NetworkCommands::Agent(cmd) => cmd.take_action(parsed_args, session).await,
             NetworkCommands::AvailabilityZone(cmd) => cmd.take_action(parsed_args, session).await,
+            NetworkCommands::Bgpvpn(cmd) => cmd.take_action(parsed_args, session).await,
             NetworkCommands::Extension(cmd) => cmd.take_action(parsed_args, session).await,
             NetworkCommands::FloatingIP(cmd) => cmd.take_action(parsed_args, session).await,
+            NetworkCommands::Fwaas(cmd) => cmd.take_action(parsed_args, session).await,
             NetworkCommands::Network(cmd) => cmd.take_action(parsed_args, session).await,
+            NetworkCommands::NetworkIpAvailability(cmd) => {
+                cmd.take_action(parsed_args, session).await
+            }
             NetworkCommands::Port(cmd) => cmd.take_action(parsed_args, session).await,
+            NetworkCommands::Qos(cmd) => cmd.take_action(parsed_args, session).await,
+            NetworkCommands::Quota(cmd) => cmd.take_action(parsed_args, session).await,
+            NetworkCommands::RbacPolicy(cmd) => cmd.take_action(parsed_args, session).await,
             NetworkCommands::Router(cmd) => cmd.take_action(parsed_args, session).await,
+            NetworkCommands::SecurityGroup(cmd) => cmd.take_action(parsed_args, session).await,
+            NetworkCommands::SecurityGroupRule(cmd) => cmd.take_action(parsed_args, session).await,
+            NetworkCommands::Segment(cmd) => cmd.take_action(parsed_args, session).await,
+            NetworkCommands::ServiceProvider(cmd) => cmd.take_action(parsed_args, session).await,
             NetworkCommands::Subnet(cmd) => cmd.take_action(parsed_args, session).await,
+            NetworkCommands::Subnetpool(cmd) => cmd.take_action(parsed_args, session).await,
+            NetworkCommands::Trunk(cmd) => cmd.take_action(parsed_args, session).await,
+            NetworkCommands::Vpn(cmd) => cmd.take_action(parsed_args, session).await,
         }
     }
 }
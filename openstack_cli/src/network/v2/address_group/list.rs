@@ -34,7 +34,7 @@ use crate::OutputConfig;
 use crate::StructTable;
 
 use openstack_sdk::api::network::v2::address_group::list;
-use openstack_sdk::api::QueryAsync;
+use openstack_sdk::api::{paged, Pagination};
 use serde_json::Value;
 use structable_derive::StructTable;
 
@@ -63,6 +63,10 @@ pub struct AddressGroupsCommand {
     /// Path parameters
     #[command(flatten)]
     path: PathParameters,
+
+    /// Total limit of entities count to return. Use this when there are too many entries.
+    #[arg(long, default_value_t = 10000)]
+    max_items: usize,
 }
 
 /// Query parameters
@@ -78,6 +82,22 @@ struct QueryParameters {
     #[arg(help_heading = "Query parameters", long)]
     id: Option<String>,
 
+    /// Requests a page size of items. Returns a number of items up to a limit
+    /// value. Use the limit parameter to make an initial limited request and
+    /// use the ID of the last-seen item from the response as the marker
+    /// parameter value in a subsequent limited request.
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    limit: Option<i32>,
+
+    /// The ID of the last-seen item. Use the limit parameter to make an
+    /// initial limited request and use the ID of the last-seen item from the
+    /// response as the marker parameter value in a subsequent limited
+    /// request.
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    marker: Option<String>,
+
     /// name query parameter for /v2.0/address-groups API
     ///
     #[arg(help_heading = "Query parameters", long)]
@@ -140,6 +160,12 @@ impl AddressGroupsCommand {
 
         // Set path parameters
         // Set query parameters
+        if let Some(val) = &self.query.limit {
+            ep_builder.limit(*val);
+        }
+        if let Some(val) = &self.query.marker {
+            ep_builder.marker(val);
+        }
         if let Some(val) = &self.query.id {
             ep_builder.id(val);
         }
@@ -158,9 +184,10 @@ impl AddressGroupsCommand {
             .build()
             .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
 
-        let data: Vec<serde_json::Value> = ep.query_async(client).await?;
+        let ep = paged(ep, Pagination::Limit(self.max_items)).prefetch(true);
 
-        op.output_list::<ResponseData>(data)?;
+        op.output_list_stream::<ResponseData, _, _>(ep.iter_async(client))
+            .await?;
         Ok(())
     }
 }
@@ -33,9 +33,11 @@ use crate::OpenStackCliError;
 use crate::OutputConfig;
 use crate::StructTable;
 
+use openstack_sdk::api::conditional;
 use openstack_sdk::api::find;
 use openstack_sdk::api::network::v2::address_group::find;
 use openstack_sdk::api::network::v2::address_group::set;
+use openstack_sdk::api::Precondition;
 use openstack_sdk::api::QueryAsync;
 use serde_json::Value;
 use structable_derive::StructTable;
@@ -61,6 +63,12 @@ pub struct AddressGroupCommand {
     ///
     #[command(flatten)]
     address_group: AddressGroup,
+
+    /// Only update the resource when its current `revision_number` matches
+    /// the given value.
+    ///
+    #[arg(help_heading = "Header parameters", long, value_name = "REVISION")]
+    if_revision: Option<u64>,
 }
 
 /// Query parameters
@@ -174,7 +182,14 @@ impl AddressGroupCommand {
             .build()
             .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
 
-        let data = ep.query_async(client).await?;
+        let data = match self.if_revision {
+            Some(revision) => {
+                conditional(ep, Precondition::RevisionNumber(revision))
+                    .query_async(client)
+                    .await?
+            }
+            None => ep.query_async(client).await?,
+        };
         op.output_single::<ResponseData>(data)?;
         Ok(())
     }
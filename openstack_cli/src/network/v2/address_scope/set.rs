@@ -34,9 +34,11 @@ use crate::OutputConfig;
 use crate::StructTable;
 
 use crate::common::BoolString;
+use openstack_sdk::api::conditional;
 use openstack_sdk::api::find;
 use openstack_sdk::api::network::v2::address_scope::find;
 use openstack_sdk::api::network::v2::address_scope::set;
+use openstack_sdk::api::Precondition;
 use openstack_sdk::api::QueryAsync;
 use structable_derive::StructTable;
 
@@ -61,6 +63,12 @@ pub struct AddressScopeCommand {
     ///
     #[command(flatten)]
     address_scope: AddressScope,
+
+    /// Only update the resource when its current `revision_number` matches
+    /// the given value.
+    ///
+    #[arg(help_heading = "Header parameters", long, value_name = "REVISION")]
+    if_revision: Option<u64>,
 }
 
 /// Query parameters
@@ -175,7 +183,14 @@ impl AddressScopeCommand {
             .build()
             .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
 
-        let data = ep.query_async(client).await?;
+        let data = match self.if_revision {
+            Some(revision) => {
+                conditional(ep, Precondition::RevisionNumber(revision))
+                    .query_async(client)
+                    .await?
+            }
+            None => ep.query_async(client).await?,
+        };
         op.output_single::<ResponseData>(data)?;
         Ok(())
     }
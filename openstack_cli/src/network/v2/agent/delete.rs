@@ -15,9 +15,9 @@
 // WARNING: This file is automatically generated from OpenAPI schema using
 // `openstack-codegenerator`.
 
-//! Delete Tag command
+//! Delete Agent command
 //!
-//! Wraps invoking of the `v2.0/floatingips/{floatingip_id}/tags/{id}` with `DELETE` method
+//! Wraps invoking of the `v2.0/agents/{id}` with `DELETE` method
 
 use clap::Args;
 use serde::{Deserialize, Serialize};
@@ -33,16 +33,22 @@ use crate::OpenStackCliError;
 use crate::OutputConfig;
 use crate::StructTable;
 
-use bytes::Bytes;
-use http::Response;
-use openstack_sdk::api::network::v2::floatingip::tag::delete;
-use openstack_sdk::api::RawQueryAsync;
+use crate::common::batch_query;
+use crate::common::check_batch_result;
+use openstack_sdk::api::ignore;
+use openstack_sdk::api::network::v2::agent::delete;
 use structable_derive::StructTable;
 
-/// Command without description in OpenAPI
+/// Agents that won't be used anymore can be removed. Before deleting agents
+/// via API, the agent should be stopped/disabled.
+///
+/// Normal response codes: 204
+///
+/// Error response codes: 401, 404, 409
 ///
 #[derive(Args)]
-pub struct TagCommand {
+#[command(about = "Delete agent")]
+pub struct AgentCommand {
     /// Request Query parameters
     #[command(flatten)]
     query: QueryParameters,
@@ -59,54 +65,57 @@ struct QueryParameters {}
 /// Path parameters
 #[derive(Args)]
 struct PathParameters {
-    /// floatingip_id parameter for /v2.0/floatingips/{floatingip_id}/tags/{id}
-    /// API
-    ///
-    #[arg(
-        help_heading = "Path parameters",
-        id = "path_param_floatingip_id",
-        value_name = "FLOATINGIP_ID"
-    )]
-    floatingip_id: String,
-
-    /// id parameter for /v2.0/floatingips/{floatingip_id}/tags/{id} API
+    /// id parameter for /v2.0/agents/{id} API
     ///
     #[arg(
         help_heading = "Path parameters",
         id = "path_param_id",
-        value_name = "ID"
+        value_name = "ID",
+        num_args = 1..,
+        required = true,
     )]
-    id: String,
+    id: Vec<String>,
 }
-/// Tag response representation
+/// Agent response representation
 #[derive(Deserialize, Serialize, Clone, StructTable)]
 struct ResponseData {}
 
-impl TagCommand {
+impl AgentCommand {
     /// Perform command action
     pub async fn take_action(
         &self,
         parsed_args: &Cli,
         client: &mut AsyncOpenStack,
     ) -> Result<(), OpenStackCliError> {
-        info!("Delete Tag");
+        info!("Delete Agent");
 
         let op = OutputProcessor::from_args(parsed_args);
         op.validate_args(parsed_args)?;
 
-        let mut ep_builder = delete::Request::builder();
-
-        // Set path parameters
-        ep_builder.floatingip_id(&self.path.floatingip_id);
-        ep_builder.id(&self.path.id);
-        // Set query parameters
-        // Set body parameters
-
-        let ep = ep_builder
-            .build()
-            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
-
-        let _rsp: Response<Bytes> = ep.raw_query_async(client).await?;
-        Ok(())
+        let ids = self.path.id.clone();
+
+        let delete_eps = ids
+            .iter()
+            .map(|id| {
+                let mut ep_builder = delete::Request::builder();
+
+                // Set path parameters
+                ep_builder.id(id);
+                // Set query parameters
+                // Set body parameters
+
+                ep_builder
+                    .build()
+                    .map(ignore)
+                    .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let deleted: Vec<Option<()>> = batch_query(client, delete_eps, &ids, "Deleting").await?;
+        let ids: Vec<String> = ids
+            .into_iter()
+            .zip(deleted)
+            .filter_map(|(id, res)| res.map(|_| id))
+            .collect();
+        check_batch_result(self.path.id.len(), ids.len())
     }
 }
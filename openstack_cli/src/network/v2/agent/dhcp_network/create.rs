@@ -11,16 +11,12 @@
 // limitations under the License.
 //
 // SPDX-License-Identifier: Apache-2.0
-//
-// WARNING: This file is automatically generated from OpenAPI schema using
-// `openstack-codegenerator`.
 
-//! Set Tag command
+//! Create DhcpNetwork command
 //!
-//! Wraps invoking of the `v2.0/routers/{router_id}/tags/{id}` with `PUT` method
+//! Wraps invoking of the `v2.0/agents/{agent_id}/dhcp-networks` with `POST` method
 
 use clap::Args;
-use serde::{Deserialize, Serialize};
 use tracing::info;
 
 use anyhow::Result;
@@ -30,19 +26,22 @@ use openstack_sdk::AsyncOpenStack;
 use crate::output::OutputProcessor;
 use crate::Cli;
 use crate::OpenStackCliError;
-use crate::OutputConfig;
-use crate::StructTable;
 
-use bytes::Bytes;
-use http::Response;
-use openstack_sdk::api::network::v2::router::tag::set;
-use openstack_sdk::api::RawQueryAsync;
-use structable_derive::StructTable;
+use openstack_sdk::api::find;
+use openstack_sdk::api::ignore;
+use openstack_sdk::api::network::v2::agent::dhcp_network::create;
+use openstack_sdk::api::network::v2::network::find as find_network;
+use openstack_sdk::api::QueryAsync;
 
-/// Command without description in OpenAPI
+/// Add a network to a DHCP agent
+///
+/// Normal response codes: 201
+///
+/// Error response codes: 400, 401, 403, 409
 ///
 #[derive(Args)]
-pub struct TagCommand {
+#[command(about = "Add network to a DHCP agent")]
+pub struct DhcpNetworkCommand {
     /// Request Query parameters
     #[command(flatten)]
     query: QueryParameters,
@@ -50,6 +49,16 @@ pub struct TagCommand {
     /// Path parameters
     #[command(flatten)]
     path: PathParameters,
+
+    /// Network (name or ID) to add to the agent.
+    ///
+    #[arg(
+        help_heading = "Body parameters",
+        long,
+        value_name = "NETWORK",
+        visible_alias = "network-id"
+    )]
+    network: String,
 }
 
 /// Query parameters
@@ -59,56 +68,52 @@ struct QueryParameters {}
 /// Path parameters
 #[derive(Args)]
 struct PathParameters {
-    /// router_id parameter for /v2.0/routers/{router_id}/tags/{id} API
+    /// agent_id parameter for /v2.0/agents/{agent_id}/dhcp-networks API
     ///
     #[arg(
         help_heading = "Path parameters",
-        id = "path_param_router_id",
-        value_name = "ROUTER_ID"
+        id = "path_param_agent_id",
+        value_name = "AGENT_ID"
     )]
-    router_id: String,
-
-    /// id parameter for /v2.0/routers/{router_id}/tags/{id} API
-    ///
-    #[arg(
-        help_heading = "Path parameters",
-        id = "path_param_id",
-        value_name = "ID"
-    )]
-    id: String,
+    agent_id: String,
 }
-/// Tag response representation
-#[derive(Deserialize, Serialize, Clone, StructTable)]
-struct ResponseData {}
 
-impl TagCommand {
+impl DhcpNetworkCommand {
     /// Perform command action
     pub async fn take_action(
         &self,
         parsed_args: &Cli,
         client: &mut AsyncOpenStack,
     ) -> Result<(), OpenStackCliError> {
-        info!("Set Tag");
+        info!("Create DhcpNetwork");
 
         let op = OutputProcessor::from_args(parsed_args);
         op.validate_args(parsed_args)?;
 
-        let mut ep_builder = set::Request::builder();
+        let mut find_builder = find_network::Request::builder();
+        find_builder.id(&self.network);
+        let find_ep = find_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+        let find_data: serde_json::Value = find(find_ep).query_async(client).await?;
+        let network_id = find_data["id"]
+            .as_str()
+            .expect("Resource ID is a string")
+            .to_string();
+
+        let mut ep_builder = create::Request::builder();
 
         // Set path parameters
-        ep_builder.router_id(&self.path.router_id);
-        ep_builder.id(&self.path.id);
+        ep_builder.agent_id(&self.path.agent_id);
         // Set query parameters
         // Set body parameters
+        ep_builder.network_id(network_id);
 
         let ep = ep_builder
             .build()
             .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
 
-        let _rsp: Response<Bytes> = ep.raw_query_async(client).await?;
-        let data = ResponseData {};
-        // Maybe output some headers metadata
-        op.output_human::<ResponseData>(&data)?;
+        ignore(ep).query_async(client).await?;
         Ok(())
     }
 }
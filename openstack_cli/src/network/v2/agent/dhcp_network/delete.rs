@@ -0,0 +1,130 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// WARNING: This file is automatically generated from OpenAPI schema using
+// `openstack-codegenerator`.
+
+//! Delete DhcpNetwork command
+//!
+//! Wraps invoking of the `v2.0/agents/{agent_id}/dhcp-networks/{id}` with `DELETE` method
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use anyhow::Result;
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::output::OutputProcessor;
+use crate::Cli;
+use crate::OpenStackCliError;
+use crate::OutputConfig;
+use crate::StructTable;
+
+use crate::common::batch_query;
+use crate::common::check_batch_result;
+use openstack_sdk::api::ignore;
+use openstack_sdk::api::network::v2::agent::dhcp_network::delete;
+use structable_derive::StructTable;
+
+/// Removes a network from a dhcp agent.
+///
+/// Normal response codes: 204
+///
+/// Error response codes: 401, 403, 409
+///
+#[derive(Args)]
+#[command(about = "Remove network from a DHCP agent")]
+pub struct DhcpNetworkCommand {
+    /// Request Query parameters
+    #[command(flatten)]
+    query: QueryParameters,
+
+    /// Path parameters
+    #[command(flatten)]
+    path: PathParameters,
+}
+
+/// Query parameters
+#[derive(Args)]
+struct QueryParameters {}
+
+/// Path parameters
+#[derive(Args)]
+struct PathParameters {
+    /// agent_id parameter for /v2.0/agents/{agent_id}/dhcp-networks/{id} API
+    ///
+    #[arg(
+        help_heading = "Path parameters",
+        id = "path_param_agent_id",
+        value_name = "AGENT_ID"
+    )]
+    agent_id: String,
+
+    /// id parameter for /v2.0/agents/{agent_id}/dhcp-networks/{id} API
+    ///
+    #[arg(
+        help_heading = "Path parameters",
+        id = "path_param_id",
+        value_name = "ID",
+        num_args = 1..,
+        required = true,
+    )]
+    id: Vec<String>,
+}
+/// DhcpNetwork response representation
+#[derive(Deserialize, Serialize, Clone, StructTable)]
+struct ResponseData {}
+
+impl DhcpNetworkCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        client: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        info!("Delete DhcpNetwork");
+
+        let op = OutputProcessor::from_args(parsed_args);
+        op.validate_args(parsed_args)?;
+
+        let ids = self.path.id.clone();
+
+        let delete_eps = ids
+            .iter()
+            .map(|id| {
+                let mut ep_builder = delete::Request::builder();
+
+                // Set path parameters
+                ep_builder.agent_id(&self.path.agent_id);
+                ep_builder.id(id);
+                // Set query parameters
+                // Set body parameters
+
+                ep_builder
+                    .build()
+                    .map(ignore)
+                    .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let deleted: Vec<Option<()>> = batch_query(client, delete_eps, &ids, "Deleting").await?;
+        let ids: Vec<String> = ids
+            .into_iter()
+            .zip(deleted)
+            .filter_map(|(id, res)| res.map(|_| id))
+            .collect();
+        check_batch_result(self.path.id.len(), ids.len())
+    }
+}
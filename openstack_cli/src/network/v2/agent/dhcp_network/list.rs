@@ -15,9 +15,9 @@
 // WARNING: This file is automatically generated from OpenAPI schema using
 // `openstack-codegenerator`.
 
-//! List Tags command
+//! List DhcpNetworks command
 //!
-//! Wraps invoking of the `v2.0/ports/{port_id}/tags` with `GET` method
+//! Wraps invoking of the `v2.0/agents/{agent_id}/dhcp-networks` with `GET` method
 
 use clap::Args;
 use serde::{Deserialize, Serialize};
@@ -33,13 +33,21 @@ use crate::OpenStackCliError;
 use crate::OutputConfig;
 use crate::StructTable;
 
-use openstack_sdk::api::network::v2::port::tag::list;
+use crate::common::BoolString;
+use openstack_sdk::api::network::v2::agent::dhcp_network::list;
 use openstack_sdk::api::QueryAsync;
+use serde_json::Value;
+use structable_derive::StructTable;
 
-/// Command without description in OpenAPI
+/// Lists networks that a DHCP agent hosts.
+///
+/// Normal response codes: 200
+///
+/// Error response codes: 401, 403
 ///
 #[derive(Args)]
-pub struct TagsCommand {
+#[command(about = "List networks hosted by a DHCP agent")]
+pub struct DhcpNetworksCommand {
     /// Request Query parameters
     #[command(flatten)]
     query: QueryParameters,
@@ -56,47 +64,64 @@ struct QueryParameters {}
 /// Path parameters
 #[derive(Args)]
 struct PathParameters {
-    /// port_id parameter for /v2.0/ports/{port_id}/tags/{id} API
+    /// agent_id parameter for /v2.0/agents/{agent_id}/dhcp-networks API
     ///
     #[arg(
         help_heading = "Path parameters",
-        id = "path_param_port_id",
-        value_name = "PORT_ID"
+        id = "path_param_agent_id",
+        value_name = "AGENT_ID"
     )]
-    port_id: String,
-}
-/// Tags response representation
-#[derive(Deserialize, Serialize, Clone)]
-struct ResponseData(String);
-
-impl StructTable for ResponseData {
-    fn build(&self, _: &OutputConfig) -> (Vec<String>, Vec<Vec<String>>) {
-        let headers: Vec<String> = Vec::from(["Value".to_string()]);
-        let res: Vec<Vec<String>> = Vec::from([Vec::from([self.0.to_string()])]);
-        (headers, res)
-    }
+    agent_id: String,
 }
+/// DhcpNetworks response representation
+#[derive(Deserialize, Serialize, Clone, StructTable)]
+struct ResponseData {
+    /// The administrative state of the network, which is up (`true`) or down
+    /// (`false`).
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    admin_state_up: Option<BoolString>,
 
-impl StructTable for Vec<ResponseData> {
-    fn build(&self, _: &OutputConfig) -> (Vec<String>, Vec<Vec<String>>) {
-        let headers: Vec<String> = Vec::from(["Values".to_string()]);
-        let res: Vec<Vec<String>> = Vec::from([Vec::from([self
-            .iter()
-            .map(|v| v.0.to_string())
-            .collect::<Vec<_>>()
-            .join(", ")])]);
-        (headers, res)
-    }
+    /// The ID of the network.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    id: Option<String>,
+
+    /// Human-readable name of the network.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    name: Option<String>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    project_id: Option<String>,
+
+    /// The network status. Values are `ACTIVE`, `DOWN`, `BUILD` or `ERROR`.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    status: Option<String>,
+
+    /// The associated subnets.
+    ///
+    #[serde()]
+    #[structable(optional, pretty)]
+    subnets: Option<Value>,
 }
 
-impl TagsCommand {
+impl DhcpNetworksCommand {
     /// Perform command action
     pub async fn take_action(
         &self,
         parsed_args: &Cli,
         client: &mut AsyncOpenStack,
     ) -> Result<(), OpenStackCliError> {
-        info!("List Tags");
+        info!("List DhcpNetworks");
 
         let op = OutputProcessor::from_args(parsed_args);
         op.validate_args(parsed_args)?;
@@ -104,7 +129,7 @@ impl TagsCommand {
         let mut ep_builder = list::Request::builder();
 
         // Set path parameters
-        ep_builder.port_id(&self.path.port_id);
+        ep_builder.agent_id(&self.path.agent_id);
         // Set query parameters
         // Set body parameters
 
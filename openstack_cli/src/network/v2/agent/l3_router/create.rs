@@ -11,16 +11,12 @@
 // limitations under the License.
 //
 // SPDX-License-Identifier: Apache-2.0
-//
-// WARNING: This file is automatically generated from OpenAPI schema using
-// `openstack-codegenerator`.
 
-//! Delete Tag command
+//! Create L3Router command
 //!
-//! Wraps invoking of the `v2.0/routers/{router_id}/tags/{id}` with `DELETE` method
+//! Wraps invoking of the `v2.0/agents/{agent_id}/l3-routers` with `POST` method
 
 use clap::Args;
-use serde::{Deserialize, Serialize};
 use tracing::info;
 
 use anyhow::Result;
@@ -30,19 +26,22 @@ use openstack_sdk::AsyncOpenStack;
 use crate::output::OutputProcessor;
 use crate::Cli;
 use crate::OpenStackCliError;
-use crate::OutputConfig;
-use crate::StructTable;
 
-use bytes::Bytes;
-use http::Response;
-use openstack_sdk::api::network::v2::router::tag::delete;
-use openstack_sdk::api::RawQueryAsync;
-use structable_derive::StructTable;
+use openstack_sdk::api::find;
+use openstack_sdk::api::ignore;
+use openstack_sdk::api::network::v2::agent::l3_router::create;
+use openstack_sdk::api::network::v2::router::find as find_router;
+use openstack_sdk::api::QueryAsync;
 
-/// Command without description in OpenAPI
+/// Add a router to an L3 agent.
+///
+/// Normal response codes: 201
+///
+/// Error response codes: 400, 401, 404, 409
 ///
 #[derive(Args)]
-pub struct TagCommand {
+#[command(about = "Add router to an L3 agent")]
+pub struct L3RouterCommand {
     /// Request Query parameters
     #[command(flatten)]
     query: QueryParameters,
@@ -50,6 +49,16 @@ pub struct TagCommand {
     /// Path parameters
     #[command(flatten)]
     path: PathParameters,
+
+    /// Router (name or ID) to add to the agent.
+    ///
+    #[arg(
+        help_heading = "Body parameters",
+        long,
+        value_name = "ROUTER",
+        visible_alias = "router-id"
+    )]
+    router: String,
 }
 
 /// Query parameters
@@ -59,53 +68,52 @@ struct QueryParameters {}
 /// Path parameters
 #[derive(Args)]
 struct PathParameters {
-    /// router_id parameter for /v2.0/routers/{router_id}/tags/{id} API
+    /// agent_id parameter for /v2.0/agents/{agent_id}/l3-routers API
     ///
     #[arg(
         help_heading = "Path parameters",
-        id = "path_param_router_id",
-        value_name = "ROUTER_ID"
+        id = "path_param_agent_id",
+        value_name = "AGENT_ID"
     )]
-    router_id: String,
-
-    /// id parameter for /v2.0/routers/{router_id}/tags/{id} API
-    ///
-    #[arg(
-        help_heading = "Path parameters",
-        id = "path_param_id",
-        value_name = "ID"
-    )]
-    id: String,
+    agent_id: String,
 }
-/// Tag response representation
-#[derive(Deserialize, Serialize, Clone, StructTable)]
-struct ResponseData {}
 
-impl TagCommand {
+impl L3RouterCommand {
     /// Perform command action
     pub async fn take_action(
         &self,
         parsed_args: &Cli,
         client: &mut AsyncOpenStack,
     ) -> Result<(), OpenStackCliError> {
-        info!("Delete Tag");
+        info!("Create L3Router");
 
         let op = OutputProcessor::from_args(parsed_args);
         op.validate_args(parsed_args)?;
 
-        let mut ep_builder = delete::Request::builder();
+        let mut find_builder = find_router::Request::builder();
+        find_builder.id(&self.router);
+        let find_ep = find_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+        let find_data: serde_json::Value = find(find_ep).query_async(client).await?;
+        let router_id = find_data["id"]
+            .as_str()
+            .expect("Resource ID is a string")
+            .to_string();
+
+        let mut ep_builder = create::Request::builder();
 
         // Set path parameters
-        ep_builder.router_id(&self.path.router_id);
-        ep_builder.id(&self.path.id);
+        ep_builder.agent_id(&self.path.agent_id);
         // Set query parameters
         // Set body parameters
+        ep_builder.router_id(router_id);
 
         let ep = ep_builder
             .build()
             .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
 
-        let _rsp: Response<Bytes> = ep.raw_query_async(client).await?;
+        ignore(ep).query_async(client).await?;
         Ok(())
     }
 }
@@ -15,9 +15,9 @@
 // WARNING: This file is automatically generated from OpenAPI schema using
 // `openstack-codegenerator`.
 
-//! Set Tag command
+//! Delete L3Router command
 //!
-//! Wraps invoking of the `v2.0/networks/{network_id}/tags/{id}` with `PUT` method
+//! Wraps invoking of the `v2.0/agents/{agent_id}/l3-routers/{id}` with `DELETE` method
 
 use clap::Args;
 use serde::{Deserialize, Serialize};
@@ -33,16 +33,21 @@ use crate::OpenStackCliError;
 use crate::OutputConfig;
 use crate::StructTable;
 
-use bytes::Bytes;
-use http::Response;
-use openstack_sdk::api::network::v2::network::tag::set;
-use openstack_sdk::api::RawQueryAsync;
+use crate::common::batch_query;
+use crate::common::check_batch_result;
+use openstack_sdk::api::ignore;
+use openstack_sdk::api::network::v2::agent::l3_router::delete;
 use structable_derive::StructTable;
 
-/// Command without description in OpenAPI
+/// Removes a router from an l3 agent.
+///
+/// Normal response codes: 204
+///
+/// Error response codes: 401, 404
 ///
 #[derive(Args)]
-pub struct TagCommand {
+#[command(about = "Remove router from an L3 agent")]
+pub struct L3RouterCommand {
     /// Request Query parameters
     #[command(flatten)]
     query: QueryParameters,
@@ -59,56 +64,67 @@ struct QueryParameters {}
 /// Path parameters
 #[derive(Args)]
 struct PathParameters {
-    /// network_id parameter for /v2.0/networks/{network_id}/tags/{id} API
+    /// agent_id parameter for /v2.0/agents/{agent_id}/l3-routers/{id} API
     ///
     #[arg(
         help_heading = "Path parameters",
-        id = "path_param_network_id",
-        value_name = "NETWORK_ID"
+        id = "path_param_agent_id",
+        value_name = "AGENT_ID"
     )]
-    network_id: String,
+    agent_id: String,
 
-    /// id parameter for /v2.0/networks/{network_id}/tags/{id} API
+    /// id parameter for /v2.0/agents/{agent_id}/l3-routers/{id} API
     ///
     #[arg(
         help_heading = "Path parameters",
         id = "path_param_id",
-        value_name = "ID"
+        value_name = "ID",
+        num_args = 1..,
+        required = true,
     )]
-    id: String,
+    id: Vec<String>,
 }
-/// Tag response representation
+/// L3Router response representation
 #[derive(Deserialize, Serialize, Clone, StructTable)]
 struct ResponseData {}
 
-impl TagCommand {
+impl L3RouterCommand {
     /// Perform command action
     pub async fn take_action(
         &self,
         parsed_args: &Cli,
         client: &mut AsyncOpenStack,
     ) -> Result<(), OpenStackCliError> {
-        info!("Set Tag");
+        info!("Delete L3Router");
 
         let op = OutputProcessor::from_args(parsed_args);
         op.validate_args(parsed_args)?;
 
-        let mut ep_builder = set::Request::builder();
-
-        // Set path parameters
-        ep_builder.network_id(&self.path.network_id);
-        ep_builder.id(&self.path.id);
-        // Set query parameters
-        // Set body parameters
-
-        let ep = ep_builder
-            .build()
-            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
-
-        let _rsp: Response<Bytes> = ep.raw_query_async(client).await?;
-        let data = ResponseData {};
-        // Maybe output some headers metadata
-        op.output_human::<ResponseData>(&data)?;
-        Ok(())
+        let ids = self.path.id.clone();
+
+        let delete_eps = ids
+            .iter()
+            .map(|id| {
+                let mut ep_builder = delete::Request::builder();
+
+                // Set path parameters
+                ep_builder.agent_id(&self.path.agent_id);
+                ep_builder.id(id);
+                // Set query parameters
+                // Set body parameters
+
+                ep_builder
+                    .build()
+                    .map(ignore)
+                    .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let deleted: Vec<Option<()>> = batch_query(client, delete_eps, &ids, "Deleting").await?;
+        let ids: Vec<String> = ids
+            .into_iter()
+            .zip(deleted)
+            .filter_map(|(id, res)| res.map(|_| id))
+            .collect();
+        check_batch_result(self.path.id.len(), ids.len())
     }
 }
@@ -15,9 +15,9 @@
 // WARNING: This file is automatically generated from OpenAPI schema using
 // `openstack-codegenerator`.
 
-//! List Tags command
+//! List L3Routers command
 //!
-//! Wraps invoking of the `v2.0/networks/{network_id}/tags` with `GET` method
+//! Wraps invoking of the `v2.0/agents/{agent_id}/l3-routers` with `GET` method
 
 use clap::Args;
 use serde::{Deserialize, Serialize};
@@ -33,13 +33,20 @@ use crate::OpenStackCliError;
 use crate::OutputConfig;
 use crate::StructTable;
 
-use openstack_sdk::api::network::v2::network::tag::list;
+use crate::common::BoolString;
+use openstack_sdk::api::network::v2::agent::l3_router::list;
 use openstack_sdk::api::QueryAsync;
+use structable_derive::StructTable;
 
-/// Command without description in OpenAPI
+/// Lists routers that an l3 agent hosts.
+///
+/// Normal response codes: 200
+///
+/// Error response codes: 401, 403
 ///
 #[derive(Args)]
-pub struct TagsCommand {
+#[command(about = "List routers hosted by an L3 agent")]
+pub struct L3RoutersCommand {
     /// Request Query parameters
     #[command(flatten)]
     query: QueryParameters,
@@ -56,47 +63,70 @@ struct QueryParameters {}
 /// Path parameters
 #[derive(Args)]
 struct PathParameters {
-    /// network_id parameter for /v2.0/networks/{network_id}/tags/{id} API
+    /// agent_id parameter for /v2.0/agents/{agent_id}/l3-routers API
     ///
     #[arg(
         help_heading = "Path parameters",
-        id = "path_param_network_id",
-        value_name = "NETWORK_ID"
+        id = "path_param_agent_id",
+        value_name = "AGENT_ID"
     )]
-    network_id: String,
-}
-/// Tags response representation
-#[derive(Deserialize, Serialize, Clone)]
-struct ResponseData(String);
-
-impl StructTable for ResponseData {
-    fn build(&self, _: &OutputConfig) -> (Vec<String>, Vec<Vec<String>>) {
-        let headers: Vec<String> = Vec::from(["Value".to_string()]);
-        let res: Vec<Vec<String>> = Vec::from([Vec::from([self.0.to_string()])]);
-        (headers, res)
-    }
+    agent_id: String,
 }
+/// L3Routers response representation
+#[derive(Deserialize, Serialize, Clone, StructTable)]
+struct ResponseData {
+    /// The administrative state of the router, which is up (`true`) or down
+    /// (`false`).
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    admin_state_up: Option<BoolString>,
 
-impl StructTable for Vec<ResponseData> {
-    fn build(&self, _: &OutputConfig) -> (Vec<String>, Vec<Vec<String>>) {
-        let headers: Vec<String> = Vec::from(["Values".to_string()]);
-        let res: Vec<Vec<String>> = Vec::from([Vec::from([self
-            .iter()
-            .map(|v| v.0.to_string())
-            .collect::<Vec<_>>()
-            .join(", ")])]);
-        (headers, res)
-    }
+    /// `true` indicates a distributed router.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    distributed: Option<BoolString>,
+
+    /// `true` indicates a highly-available router.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    ha: Option<BoolString>,
+
+    /// The ID of the router.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    id: Option<String>,
+
+    /// Human-readable name of the router.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    name: Option<String>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    project_id: Option<String>,
+
+    /// The router status.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    status: Option<String>,
 }
 
-impl TagsCommand {
+impl L3RoutersCommand {
     /// Perform command action
     pub async fn take_action(
         &self,
         parsed_args: &Cli,
         client: &mut AsyncOpenStack,
     ) -> Result<(), OpenStackCliError> {
-        info!("List Tags");
+        info!("List L3Routers");
 
         let op = OutputProcessor::from_args(parsed_args);
         op.validate_args(parsed_args)?;
@@ -104,7 +134,7 @@ impl TagsCommand {
         let mut ep_builder = list::Request::builder();
 
         // Set path parameters
-        ep_builder.network_id(&self.path.network_id);
+        ep_builder.agent_id(&self.path.agent_id);
         // Set query parameters
         // Set body parameters
 
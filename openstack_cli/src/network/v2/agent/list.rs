@@ -0,0 +1,294 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// WARNING: This file is automatically generated from OpenAPI schema using
+// `openstack-codegenerator`.
+
+//! List Agents command
+//!
+//! Wraps invoking of the `v2.0/agents` with `GET` method
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use anyhow::Result;
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::output::OutputProcessor;
+use crate::Cli;
+use crate::OpenStackCliError;
+use crate::OutputConfig;
+use crate::StructTable;
+
+use crate::common::BoolString;
+use openstack_sdk::api::network::v2::agent::list;
+use openstack_sdk::api::{paged, Pagination};
+use serde_json::Value;
+use structable_derive::StructTable;
+
+/// Lists all agents.
+///
+/// Additionally, you can filter results by using query string parameters. For
+/// information, see
+/// [Filtering and Column Selection](https://wiki.openstack.org/wiki/Neutron/APIv2-specification#Filtering_and_Column_Selection).
+///
+/// Use the `fields` query parameter to control which fields are returned in
+/// the response body. For information, see
+/// [Filtering and Column Selection](https://wiki.openstack.org/wiki/Neutron/APIv2-specification#Filtering_and_Column_Selection).
+///
+/// Normal response codes: 200
+///
+/// Error response codes: 401
+///
+#[derive(Args)]
+#[command(about = "List all agents")]
+pub struct AgentsCommand {
+    /// Request Query parameters
+    #[command(flatten)]
+    query: QueryParameters,
+
+    /// Path parameters
+    #[command(flatten)]
+    path: PathParameters,
+
+    /// Total limit of entities count to return. Use this when there are too many entries.
+    #[arg(long, default_value_t = 10000)]
+    max_items: usize,
+}
+
+/// Query parameters
+#[derive(Args)]
+struct QueryParameters {
+    /// admin_state_up query parameter for /v2.0/agents API
+    ///
+    #[arg(action=clap::ArgAction::Set, help_heading = "Query parameters", long)]
+    admin_state_up: Option<bool>,
+
+    /// agent_type query parameter for /v2.0/agents API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    agent_type: Option<String>,
+
+    /// alive query parameter for /v2.0/agents API
+    ///
+    #[arg(action=clap::ArgAction::Set, help_heading = "Query parameters", long)]
+    alive: Option<bool>,
+
+    /// availability_zone query parameter for /v2.0/agents API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    availability_zone: Option<String>,
+
+    /// binary query parameter for /v2.0/agents API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    binary: Option<String>,
+
+    /// description query parameter for /v2.0/agents API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    description: Option<String>,
+
+    /// host query parameter for /v2.0/agents API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    host: Option<String>,
+
+    /// id query parameter for /v2.0/agents API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    id: Option<String>,
+
+    /// Requests a page size of items. Returns a number of items up to a limit
+    /// value. Use the limit parameter to make an initial limited request and
+    /// use the ID of the last-seen item from the response as the marker
+    /// parameter value in a subsequent limited request.
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    limit: Option<i32>,
+
+    /// The ID of the last-seen item. Use the limit parameter to make an
+    /// initial limited request and use the ID of the last-seen item from the
+    /// response as the marker parameter value in a subsequent limited
+    /// request.
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    marker: Option<String>,
+
+    /// topic query parameter for /v2.0/agents API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    topic: Option<String>,
+}
+
+/// Path parameters
+#[derive(Args)]
+struct PathParameters {}
+/// Agents response representation
+#[derive(Deserialize, Serialize, Clone, StructTable)]
+struct ResponseData {
+    /// The administrative state of the resource, which is up (`true`) or down
+    /// (`false`).
+    ///
+    #[serde()]
+    #[structable(optional)]
+    admin_state_up: Option<BoolString>,
+
+    /// The type of agent such as `Open vSwitch agent` or `DHCP agent`.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    agent_type: Option<String>,
+
+    /// Indicates the agent is alive and running.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    alive: Option<BoolString>,
+
+    /// The availability zone of the agent.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    availability_zone: Option<String>,
+
+    /// The executable command used to start the agent such as
+    /// `neutron-openvswitch-agent` or `neutron-dhcp-agent`.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    binary: Option<String>,
+
+    /// An object containing configuration specific key/value pairs; the
+    /// semantics of which are determined by the binary name and type.
+    ///
+    #[serde()]
+    #[structable(optional, pretty, wide)]
+    configurations: Option<Value>,
+
+    /// Time at which the resource has been created (in UTC ISO8601 format).
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    created_at: Option<String>,
+
+    /// The human readable description for the resource.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    description: Option<String>,
+
+    /// Time at which the last heartbeat was received.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    heartbeat_timestamp: Option<String>,
+
+    /// The hostname of the system the agent is running on.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    host: Option<String>,
+
+    /// The ID of the resource.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    id: Option<String>,
+
+    /// Whether the `resource_versions` of the agent are synchronized with the
+    /// placement service.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    resources_synced: Option<BoolString>,
+
+    /// Time at which the agent was started.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    started_at: Option<String>,
+
+    /// The name of AMQP topic the agent is listening on such as `dhcp_agent`.
+    /// A special topic `N/A` is used when the agent doesn't use AMQP.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    topic: Option<String>,
+}
+
+impl AgentsCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        client: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        info!("List Agents");
+
+        let op = OutputProcessor::from_args(parsed_args);
+        op.validate_args(parsed_args)?;
+
+        let mut ep_builder = list::Request::builder();
+
+        // Set path parameters
+        // Set query parameters
+        if let Some(val) = &self.query.limit {
+            ep_builder.limit(*val);
+        }
+        if let Some(val) = &self.query.marker {
+            ep_builder.marker(val);
+        }
+        if let Some(val) = &self.query.admin_state_up {
+            ep_builder.admin_state_up(*val);
+        }
+        if let Some(val) = &self.query.agent_type {
+            ep_builder.agent_type(val);
+        }
+        if let Some(val) = &self.query.alive {
+            ep_builder.alive(*val);
+        }
+        if let Some(val) = &self.query.availability_zone {
+            ep_builder.availability_zone(val);
+        }
+        if let Some(val) = &self.query.binary {
+            ep_builder.binary(val);
+        }
+        if let Some(val) = &self.query.description {
+            ep_builder.description(val);
+        }
+        if let Some(val) = &self.query.host {
+            ep_builder.host(val);
+        }
+        if let Some(val) = &self.query.id {
+            ep_builder.id(val);
+        }
+        if let Some(val) = &self.query.topic {
+            ep_builder.topic(val);
+        }
+        // Set body parameters
+
+        let ep = ep_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+
+        let ep = paged(ep, Pagination::Limit(self.max_items)).prefetch(true);
+
+        op.output_list_stream::<ResponseData, _, _>(ep.iter_async(client))
+            .await?;
+        Ok(())
+    }
+}
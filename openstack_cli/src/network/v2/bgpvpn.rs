@@ -0,0 +1,57 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Neutron BGP VPN commands
+
+use clap::{Parser, Subcommand};
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::{Cli, OpenStackCliError};
+
+#[allow(clippy::module_inception)]
+mod bgpvpn;
+
+/// BGP VPN
+///
+/// The BGP VPN extension (`bgpvpn`) interconnects Neutron resources with
+/// BGP-based VPNs operated outside of the cloud.
+#[derive(Parser)]
+pub struct BgpvpnCommand {
+    /// subcommand
+    #[command(subcommand)]
+    command: BgpvpnCommands,
+}
+
+/// Supported subcommands
+#[allow(missing_docs)]
+#[derive(Subcommand)]
+pub enum BgpvpnCommands {
+    Bgpvpn(Box<bgpvpn::BgpvpnCommand>),
+}
+
+impl BgpvpnCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        session: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        session.ensure_network_extension("bgpvpn").await?;
+
+        match &self.command {
+            BgpvpnCommands::Bgpvpn(cmd) => cmd.take_action(parsed_args, session).await,
+        }
+    }
+}
@@ -0,0 +1,81 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Bgpvpn resource commands
+
+use clap::{Parser, Subcommand};
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::{Cli, OpenStackCliError};
+
+mod create;
+mod delete;
+mod list;
+mod network_association;
+mod port_association;
+mod router_association;
+mod set;
+mod show;
+
+/// BGP VPNs
+///
+/// A BGP VPN interconnects Neutron networks, routers and ports with VPNs built
+/// on BGP (BGP/MPLS IP VPNs and E-VPN). The association subcommands attach the
+/// resources to a BGP VPN.
+#[derive(Parser)]
+pub struct BgpvpnCommand {
+    /// subcommand
+    #[command(subcommand)]
+    command: BgpvpnCommands,
+}
+
+/// Supported subcommands
+#[allow(missing_docs)]
+#[derive(Subcommand)]
+pub enum BgpvpnCommands {
+    Create(Box<create::BgpvpnCommand>),
+    Delete(Box<delete::BgpvpnCommand>),
+    List(Box<list::BgpvpnsCommand>),
+    NetworkAssociation(Box<network_association::NetworkAssociationCommand>),
+    PortAssociation(Box<port_association::PortAssociationCommand>),
+    RouterAssociation(Box<router_association::RouterAssociationCommand>),
+    Set(Box<set::BgpvpnCommand>),
+    Show(Box<show::BgpvpnCommand>),
+}
+
+impl BgpvpnCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        session: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        match &self.command {
+            BgpvpnCommands::Create(cmd) => cmd.take_action(parsed_args, session).await,
+            BgpvpnCommands::Delete(cmd) => cmd.take_action(parsed_args, session).await,
+            BgpvpnCommands::List(cmd) => cmd.take_action(parsed_args, session).await,
+            BgpvpnCommands::NetworkAssociation(cmd) => cmd.take_action(parsed_args, session).await,
+            BgpvpnCommands::PortAssociation(cmd) => {
+                session
+                    .ensure_network_extension("bgpvpn-routes-control")
+                    .await?;
+                cmd.take_action(parsed_args, session).await
+            }
+            BgpvpnCommands::RouterAssociation(cmd) => cmd.take_action(parsed_args, session).await,
+            BgpvpnCommands::Set(cmd) => cmd.take_action(parsed_args, session).await,
+            BgpvpnCommands::Show(cmd) => cmd.take_action(parsed_args, session).await,
+        }
+    }
+}
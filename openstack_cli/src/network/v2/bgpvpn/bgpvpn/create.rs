@@ -0,0 +1,296 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// WARNING: This file is automatically generated from OpenAPI schema using
+// `openstack-codegenerator`.
+
+//! Create Bgpvpn command
+//!
+//! Wraps invoking of the `v2.0/bgpvpn/bgpvpns` with `POST` method
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use anyhow::Result;
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::output::OutputProcessor;
+use crate::Cli;
+use crate::OpenStackCliError;
+use crate::OutputConfig;
+use crate::StructTable;
+
+use clap::ValueEnum;
+use openstack_sdk::api::network::v2::bgpvpn::bgpvpn::create;
+use openstack_sdk::api::QueryAsync;
+use serde_json::Value;
+use structable_derive::StructTable;
+
+/// Creates a BGP VPN.
+///
+/// Normal response codes: 201
+///
+/// Error response codes: 400, 401
+///
+#[derive(Args)]
+#[command(about = "Create BGP VPN")]
+pub struct BgpvpnCommand {
+    /// Request Query parameters
+    #[command(flatten)]
+    query: QueryParameters,
+
+    /// Path parameters
+    #[command(flatten)]
+    path: PathParameters,
+
+    /// A `bgpvpn` object.
+    ///
+    #[command(flatten)]
+    bgpvpn: Bgpvpn,
+}
+
+/// Query parameters
+#[derive(Args)]
+struct QueryParameters {}
+
+/// Path parameters
+#[derive(Args)]
+struct PathParameters {}
+#[derive(Clone, Eq, Ord, PartialEq, PartialOrd, ValueEnum)]
+enum Type {
+    L2,
+    L3,
+}
+
+/// Bgpvpn Body data
+#[derive(Args, Clone)]
+struct Bgpvpn {
+    /// Selection of the type of VPN and the technology behind it. Allowed
+    /// values are `l2` or `l3`. Default is `l3`.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    _type: Option<Type>,
+
+    /// Additional Route Targets that will be used for export.
+    ///
+    #[arg(action=clap::ArgAction::Append, help_heading = "Body parameters", long)]
+    export_targets: Option<Vec<String>>,
+
+    /// Additional Route Targets that will be imported.
+    ///
+    #[arg(action=clap::ArgAction::Append, help_heading = "Body parameters", long)]
+    import_targets: Option<Vec<String>>,
+
+    /// The default BGP LOCAL_PREF of routes that will be advertised to the
+    /// BGPVPN (unless overridden per-route).
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    local_pref: Option<i32>,
+
+    /// The user meaningful name of the BGP VPN.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    name: Option<String>,
+
+    /// List of route distinguisher strings. If this parameter is specified,
+    /// one of these RDs will be used to advertise VPN routes.
+    ///
+    #[arg(action=clap::ArgAction::Append, help_heading = "Body parameters", long)]
+    route_distinguishers: Option<Vec<String>>,
+
+    /// Route Targets that will be both imported and used for export.
+    ///
+    #[arg(action=clap::ArgAction::Append, help_heading = "Body parameters", long)]
+    route_targets: Option<Vec<String>>,
+
+    /// The ID of the project that owns the resource. Only administrative and
+    /// users with advsvc role can specify a project ID other than their own.
+    /// You cannot change this value through authorization policies.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    tenant_id: Option<String>,
+
+    /// The globally-assigned VXLAN `vni` for the BGP VPN.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    vni: Option<i32>,
+}
+
+/// Bgpvpn response representation
+#[derive(Deserialize, Serialize, Clone, StructTable)]
+struct ResponseData {
+    /// Selection of the type of VPN and the technology behind it. Allowed
+    /// values are `l2` or `l3`.
+    ///
+    #[serde(rename = "type")]
+    #[structable(optional, title = "type")]
+    _type: Option<String>,
+
+    /// Additional Route Targets that will be used for export.
+    ///
+    #[serde()]
+    #[structable(optional, pretty)]
+    export_targets: Option<Value>,
+
+    /// The ID of the BGP VPN.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    id: Option<String>,
+
+    /// Additional Route Targets that will be imported.
+    ///
+    #[serde()]
+    #[structable(optional, pretty)]
+    import_targets: Option<Value>,
+
+    /// The default BGP LOCAL_PREF of routes that will be advertised to the
+    /// BGPVPN (unless overridden per-route).
+    ///
+    #[serde()]
+    #[structable(optional)]
+    local_pref: Option<i32>,
+
+    /// The user meaningful name of the BGP VPN.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    name: Option<String>,
+
+    /// This read-only list of network IDs reflects the associations defined by
+    /// Network association API resources.
+    ///
+    #[serde()]
+    #[structable(optional, pretty)]
+    networks: Option<Value>,
+
+    /// This read-only list of port IDs reflects the associations defined by
+    /// Port association API resources.
+    ///
+    #[serde()]
+    #[structable(optional, pretty)]
+    ports: Option<Value>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    project_id: Option<String>,
+
+    /// List of route distinguisher strings. If this parameter is specified,
+    /// one of these RDs will be used to advertise VPN routes.
+    ///
+    #[serde()]
+    #[structable(optional, pretty)]
+    route_distinguishers: Option<Value>,
+
+    /// Route Targets that will be both imported and used for export.
+    ///
+    #[serde()]
+    #[structable(optional, pretty)]
+    route_targets: Option<Value>,
+
+    /// This read-only list of router IDs reflects the associations defined by
+    /// Router association API resources.
+    ///
+    #[serde()]
+    #[structable(optional, pretty)]
+    routers: Option<Value>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    tenant_id: Option<String>,
+
+    /// The globally-assigned VXLAN `vni` for the BGP VPN.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    vni: Option<i32>,
+}
+
+impl BgpvpnCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        client: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        info!("Create Bgpvpn");
+
+        let op = OutputProcessor::from_args(parsed_args);
+        op.validate_args(parsed_args)?;
+
+        let mut ep_builder = create::Request::builder();
+
+        // Set path parameters
+        // Set query parameters
+        // Set body parameters
+        // Set Request.bgpvpn data
+        let args = &self.bgpvpn;
+        let mut bgpvpn_builder = create::BgpvpnBuilder::default();
+        if let Some(val) = &args.export_targets {
+            bgpvpn_builder.export_targets(val.iter().map(|v| v.into()).collect::<Vec<_>>());
+        }
+
+        if let Some(val) = &args.import_targets {
+            bgpvpn_builder.import_targets(val.iter().map(|v| v.into()).collect::<Vec<_>>());
+        }
+
+        if let Some(val) = &args.local_pref {
+            bgpvpn_builder.local_pref(*val);
+        }
+
+        if let Some(val) = &args.name {
+            bgpvpn_builder.name(val);
+        }
+
+        if let Some(val) = &args.route_distinguishers {
+            bgpvpn_builder.route_distinguishers(val.iter().map(|v| v.into()).collect::<Vec<_>>());
+        }
+
+        if let Some(val) = &args.route_targets {
+            bgpvpn_builder.route_targets(val.iter().map(|v| v.into()).collect::<Vec<_>>());
+        }
+
+        if let Some(val) = &args.tenant_id {
+            bgpvpn_builder.tenant_id(val);
+        }
+
+        if let Some(val) = &args._type {
+            let tmp = match val {
+                Type::L2 => create::Type::L2,
+                Type::L3 => create::Type::L3,
+            };
+            bgpvpn_builder._type(tmp);
+        }
+
+        if let Some(val) = &args.vni {
+            bgpvpn_builder.vni(*val);
+        }
+
+        ep_builder.bgpvpn(bgpvpn_builder.build().unwrap());
+
+        let ep = ep_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+
+        let data = ep.query_async(client).await?;
+        op.output_single::<ResponseData>(data)?;
+        Ok(())
+    }
+}
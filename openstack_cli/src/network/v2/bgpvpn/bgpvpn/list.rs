@@ -0,0 +1,278 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// WARNING: This file is automatically generated from OpenAPI schema using
+// `openstack-codegenerator`.
+
+//! List Bgpvpns command
+//!
+//! Wraps invoking of the `v2.0/bgpvpn/bgpvpns` with `GET` method
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use anyhow::Result;
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::output::OutputProcessor;
+use crate::Cli;
+use crate::OpenStackCliError;
+use crate::OutputConfig;
+use crate::StructTable;
+
+use openstack_sdk::api::network::v2::bgpvpn::bgpvpn::list;
+use openstack_sdk::api::{paged, Pagination};
+use serde_json::Value;
+use structable_derive::StructTable;
+
+/// Lists BGP VPNs that belong to a project.
+///
+/// Additionally, you can filter results by using query string parameters. For
+/// information, see
+/// [Filtering and Column Selection](https://wiki.openstack.org/wiki/Neutron/APIv2-specification#Filtering_and_Column_Selection).
+///
+/// Use the `fields` query parameter to control which fields are returned in
+/// the response body. For information, see
+/// [Filtering and Column Selection](https://wiki.openstack.org/wiki/Neutron/APIv2-specification#Filtering_and_Column_Selection).
+///
+/// Normal response codes: 200
+///
+/// Error response codes: 401
+///
+#[derive(Args)]
+#[command(about = "List BGP VPNs")]
+pub struct BgpvpnsCommand {
+    /// Request Query parameters
+    #[command(flatten)]
+    query: QueryParameters,
+
+    /// Path parameters
+    #[command(flatten)]
+    path: PathParameters,
+
+    /// Total limit of entities count to return. Use this when there are too many entries.
+    #[arg(long, default_value_t = 10000)]
+    max_items: usize,
+}
+
+/// Query parameters
+#[derive(Args)]
+struct QueryParameters {
+    /// Requests a page size of items. Returns a number of items up to a limit
+    /// value. Use the limit parameter to make an initial limited request and
+    /// use the ID of the last-seen item from the response as the marker
+    /// parameter value in a subsequent limited request.
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    limit: Option<i32>,
+
+    /// The ID of the last-seen item. Use the limit parameter to make an
+    /// initial limited request and use the ID of the last-seen item from the
+    /// response as the marker parameter value in a subsequent limited
+    /// request.
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    marker: Option<String>,
+
+    /// type query parameter for /v2.0/bgpvpn/bgpvpns API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    _type: Option<String>,
+
+    /// id query parameter for /v2.0/bgpvpn/bgpvpns API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    id: Option<String>,
+
+    /// local_pref query parameter for /v2.0/bgpvpn/bgpvpns API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    local_pref: Option<i32>,
+
+    /// name query parameter for /v2.0/bgpvpn/bgpvpns API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    name: Option<String>,
+
+    /// project_id query parameter for /v2.0/bgpvpn/bgpvpns API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    project_id: Option<String>,
+
+    /// tenant_id query parameter for /v2.0/bgpvpn/bgpvpns API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    tenant_id: Option<String>,
+
+    /// vni query parameter for /v2.0/bgpvpn/bgpvpns API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    vni: Option<i32>,
+}
+
+/// Path parameters
+#[derive(Args)]
+struct PathParameters {}
+/// Bgpvpns response representation
+#[derive(Deserialize, Serialize, Clone, StructTable)]
+struct ResponseData {
+    /// Selection of the type of VPN and the technology behind it. Allowed
+    /// values are `l2` or `l3`.
+    ///
+    #[serde(rename = "type")]
+    #[structable(optional, title = "type")]
+    _type: Option<String>,
+
+    /// Additional Route Targets that will be used for export.
+    ///
+    #[serde()]
+    #[structable(optional, pretty, wide)]
+    export_targets: Option<Value>,
+
+    /// The ID of the BGP VPN.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    id: Option<String>,
+
+    /// Additional Route Targets that will be imported.
+    ///
+    #[serde()]
+    #[structable(optional, pretty, wide)]
+    import_targets: Option<Value>,
+
+    /// The default BGP LOCAL_PREF of routes that will be advertised to the
+    /// BGPVPN (unless overridden per-route).
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    local_pref: Option<i32>,
+
+    /// The user meaningful name of the BGP VPN.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    name: Option<String>,
+
+    /// This read-only list of network IDs reflects the associations defined by
+    /// Network association API resources.
+    ///
+    #[serde()]
+    #[structable(optional, pretty, wide)]
+    networks: Option<Value>,
+
+    /// This read-only list of port IDs reflects the associations defined by
+    /// Port association API resources.
+    ///
+    #[serde()]
+    #[structable(optional, pretty, wide)]
+    ports: Option<Value>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    project_id: Option<String>,
+
+    /// List of route distinguisher strings. If this parameter is specified,
+    /// one of these RDs will be used to advertise VPN routes.
+    ///
+    #[serde()]
+    #[structable(optional, pretty, wide)]
+    route_distinguishers: Option<Value>,
+
+    /// Route Targets that will be both imported and used for export.
+    ///
+    #[serde()]
+    #[structable(optional, pretty)]
+    route_targets: Option<Value>,
+
+    /// This read-only list of router IDs reflects the associations defined by
+    /// Router association API resources.
+    ///
+    #[serde()]
+    #[structable(optional, pretty, wide)]
+    routers: Option<Value>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    tenant_id: Option<String>,
+
+    /// The globally-assigned VXLAN `vni` for the BGP VPN.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    vni: Option<i32>,
+}
+
+impl BgpvpnsCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        client: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        info!("List Bgpvpns");
+
+        let op = OutputProcessor::from_args(parsed_args);
+        op.validate_args(parsed_args)?;
+
+        let mut ep_builder = list::Request::builder();
+
+        // Set path parameters
+        // Set query parameters
+        if let Some(val) = &self.query.limit {
+            ep_builder.limit(*val);
+        }
+        if let Some(val) = &self.query.marker {
+            ep_builder.marker(val);
+        }
+        if let Some(val) = &self.query.id {
+            ep_builder.id(val);
+        }
+        if let Some(val) = &self.query.local_pref {
+            ep_builder.local_pref(*val);
+        }
+        if let Some(val) = &self.query.name {
+            ep_builder.name(val);
+        }
+        if let Some(val) = &self.query.project_id {
+            ep_builder.project_id(val);
+        }
+        if let Some(val) = &self.query.tenant_id {
+            ep_builder.tenant_id(val);
+        }
+        if let Some(val) = &self.query._type {
+            ep_builder._type(val);
+        }
+        if let Some(val) = &self.query.vni {
+            ep_builder.vni(*val);
+        }
+        // Set body parameters
+
+        let ep = ep_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+
+        let ep = paged(ep, Pagination::Limit(self.max_items)).prefetch(true);
+
+        op.output_list_stream::<ResponseData, _, _>(ep.iter_async(client))
+            .await?;
+        Ok(())
+    }
+}
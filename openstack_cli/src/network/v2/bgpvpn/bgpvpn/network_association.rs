@@ -0,0 +1,63 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! NetworkAssociation resource commands
+
+use clap::{Parser, Subcommand};
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::{Cli, OpenStackCliError};
+
+mod create;
+mod delete;
+mod list;
+mod show;
+
+/// BGP VPN network associations
+///
+/// Associating a network with a BGP VPN connects all the subnets of the
+/// network to the VPN.
+#[derive(Parser)]
+pub struct NetworkAssociationCommand {
+    /// subcommand
+    #[command(subcommand)]
+    command: NetworkAssociationCommands,
+}
+
+/// Supported subcommands
+#[allow(missing_docs)]
+#[derive(Subcommand)]
+pub enum NetworkAssociationCommands {
+    Create(Box<create::NetworkAssociationCommand>),
+    Delete(Box<delete::NetworkAssociationCommand>),
+    List(Box<list::NetworkAssociationsCommand>),
+    Show(Box<show::NetworkAssociationCommand>),
+}
+
+impl NetworkAssociationCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        session: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        match &self.command {
+            NetworkAssociationCommands::Create(cmd) => cmd.take_action(parsed_args, session).await,
+            NetworkAssociationCommands::Delete(cmd) => cmd.take_action(parsed_args, session).await,
+            NetworkAssociationCommands::List(cmd) => cmd.take_action(parsed_args, session).await,
+            NetworkAssociationCommands::Show(cmd) => cmd.take_action(parsed_args, session).await,
+        }
+    }
+}
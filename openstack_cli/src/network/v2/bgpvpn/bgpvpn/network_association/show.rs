@@ -15,9 +15,9 @@
 // WARNING: This file is automatically generated from OpenAPI schema using
 // `openstack-codegenerator`.
 
-//! Show Tag command
+//! Show NetworkAssociation command
 //!
-//! Wraps invoking of the `v2.0/networks/{network_id}/tags/{id}` with `GET` method
+//! Wraps invoking of the `v2.0/bgpvpn/bgpvpns/{bgpvpn_id}/network_associations/{id}` with `GET` method
 
 use clap::Args;
 use serde::{Deserialize, Serialize};
@@ -33,16 +33,23 @@ use crate::OpenStackCliError;
 use crate::OutputConfig;
 use crate::StructTable;
 
-use bytes::Bytes;
-use http::Response;
-use openstack_sdk::api::network::v2::network::tag::get;
-use openstack_sdk::api::RawQueryAsync;
+use openstack_sdk::api::network::v2::bgpvpn::bgpvpn::network_association::get;
+use openstack_sdk::api::QueryAsync;
 use structable_derive::StructTable;
 
-/// Command without description in OpenAPI
+/// Shows details for a network association for the BGP VPN.
+///
+/// Use the `fields` query parameter to control which fields are returned in
+/// the response body. For information, see
+/// [Filtering and Column Selection](https://wiki.openstack.org/wiki/Neutron/APIv2-specification#Filtering_and_Column_Selection).
+///
+/// Normal response codes: 200
+///
+/// Error response codes: 401, 404
 ///
 #[derive(Args)]
-pub struct TagCommand {
+#[command(about = "Show network association details")]
+pub struct NetworkAssociationCommand {
     /// Request Query parameters
     #[command(flatten)]
     query: QueryParameters,
@@ -59,16 +66,16 @@ struct QueryParameters {}
 /// Path parameters
 #[derive(Args)]
 struct PathParameters {
-    /// network_id parameter for /v2.0/networks/{network_id}/tags/{id} API
+    /// bgpvpn_id parameter for /v2.0/bgpvpn/bgpvpns/{bgpvpn_id}/network_associations/{id} API
     ///
     #[arg(
         help_heading = "Path parameters",
-        id = "path_param_network_id",
-        value_name = "NETWORK_ID"
+        id = "path_param_bgpvpn_id",
+        value_name = "BGPVPN_ID"
     )]
-    network_id: String,
+    bgpvpn_id: String,
 
-    /// id parameter for /v2.0/networks/{network_id}/tags/{id} API
+    /// id parameter for /v2.0/bgpvpn/bgpvpns/{bgpvpn_id}/network_associations/{id} API
     ///
     #[arg(
         help_heading = "Path parameters",
@@ -77,18 +84,42 @@ struct PathParameters {
     )]
     id: String,
 }
-/// Tag response representation
+/// NetworkAssociation response representation
 #[derive(Deserialize, Serialize, Clone, StructTable)]
-struct ResponseData {}
+struct ResponseData {
+    /// The ID of the network association.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    id: Option<String>,
+
+    /// The ID of a Neutron network with which to associate the BGP VPN.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    network_id: Option<String>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    project_id: Option<String>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    tenant_id: Option<String>,
+}
 
-impl TagCommand {
+impl NetworkAssociationCommand {
     /// Perform command action
     pub async fn take_action(
         &self,
         parsed_args: &Cli,
         client: &mut AsyncOpenStack,
     ) -> Result<(), OpenStackCliError> {
-        info!("Show Tag");
+        info!("Show NetworkAssociation");
 
         let op = OutputProcessor::from_args(parsed_args);
         op.validate_args(parsed_args)?;
@@ -96,7 +127,7 @@ impl TagCommand {
         let mut ep_builder = get::Request::builder();
 
         // Set path parameters
-        ep_builder.network_id(&self.path.network_id);
+        ep_builder.bgpvpn_id(&self.path.bgpvpn_id);
         ep_builder.id(&self.path.id);
         // Set query parameters
         // Set body parameters
@@ -105,10 +136,8 @@ impl TagCommand {
             .build()
             .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
 
-        let _rsp: Response<Bytes> = ep.raw_query_async(client).await?;
-        let data = ResponseData {};
-        // Maybe output some headers metadata
-        op.output_human::<ResponseData>(&data)?;
+        let data = ep.query_async(client).await?;
+        op.output_single::<ResponseData>(data)?;
         Ok(())
     }
 }
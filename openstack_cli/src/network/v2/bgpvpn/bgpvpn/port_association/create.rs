@@ -0,0 +1,214 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Create PortAssociation command
+//!
+//! Wraps invoking of the `v2.0/bgpvpn/bgpvpns/{bgpvpn_id}/port_associations` with `POST` method
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use anyhow::Result;
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::output::OutputProcessor;
+use crate::Cli;
+use crate::OpenStackCliError;
+use crate::OutputConfig;
+use crate::StructTable;
+
+use crate::common::parse_json;
+use crate::common::BoolString;
+use openstack_sdk::api::find;
+use openstack_sdk::api::network::v2::bgpvpn::bgpvpn::port_association::create;
+use openstack_sdk::api::network::v2::port::find as find_port;
+use openstack_sdk::api::QueryAsync;
+use serde_json::Value;
+use structable_derive::StructTable;
+
+/// Creates a port association for the BGP VPN.
+///
+/// Normal response codes: 201
+///
+/// Error response codes: 400, 401, 404
+///
+#[derive(Args)]
+#[command(about = "Create port association")]
+pub struct PortAssociationCommand {
+    /// Request Query parameters
+    #[command(flatten)]
+    query: QueryParameters,
+
+    /// Path parameters
+    #[command(flatten)]
+    path: PathParameters,
+
+    /// A `port_association` object.
+    ///
+    #[command(flatten)]
+    port_association: PortAssociation,
+}
+
+/// Query parameters
+#[derive(Args)]
+struct QueryParameters {}
+
+/// Path parameters
+#[derive(Args)]
+struct PathParameters {
+    /// bgpvpn_id parameter for /v2.0/bgpvpn/bgpvpns/{bgpvpn_id}/port_associations API
+    ///
+    #[arg(
+        help_heading = "Path parameters",
+        id = "path_param_bgpvpn_id",
+        value_name = "BGPVPN_ID"
+    )]
+    bgpvpn_id: String,
+}
+/// PortAssociation Body data
+#[derive(Args, Clone)]
+struct PortAssociation {
+    /// Boolean flag controlling whether or not the fixed IPs of a port will be
+    /// advertised to the BGPVPN (default: true).
+    ///
+    #[arg(action=clap::ArgAction::Set, help_heading = "Body parameters", long)]
+    advertise_fixed_ips: Option<bool>,
+
+    /// The name or ID of the port to associate with the BGP VPN.
+    ///
+    #[arg(
+        help_heading = "Body parameters",
+        long,
+        value_name = "PORT",
+        visible_alias = "port"
+    )]
+    port_id: String,
+
+    /// List of routes, each route being a dict with at least a `type` key,
+    /// which can be `prefix` or `bgpvpn`. For the `prefix` type, the IP prefix
+    /// (v4 or v6) to advertise is specified in the `prefix` key. For the
+    /// `bgpvpn` type, the `bgpvpn_id` key specifies the BGP VPN from which
+    /// routes will be readvertised.
+    ///
+    #[arg(help_heading = "Body parameters", long, value_name="JSON", value_parser=parse_json)]
+    routes: Option<Value>,
+
+    /// The ID of the project that owns the resource. Only administrative and
+    /// users with advsvc role can specify a project ID other than their own.
+    /// You cannot change this value through authorization policies.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    tenant_id: Option<String>,
+}
+
+/// PortAssociation response representation
+#[derive(Deserialize, Serialize, Clone, StructTable)]
+struct ResponseData {
+    /// Boolean flag controlling whether or not the fixed IPs of a port will be
+    /// advertised to the BGPVPN.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    advertise_fixed_ips: Option<BoolString>,
+
+    /// The ID of the port association.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    id: Option<String>,
+
+    /// The ID of a Neutron port with which to associate the BGP VPN.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    port_id: Option<String>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    project_id: Option<String>,
+
+    /// List of routes, each route being a dict with at least a `type` key,
+    /// which can be `prefix` or `bgpvpn`.
+    ///
+    #[serde()]
+    #[structable(optional, pretty)]
+    routes: Option<Value>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    tenant_id: Option<String>,
+}
+
+impl PortAssociationCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        client: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        info!("Create PortAssociation");
+
+        let op = OutputProcessor::from_args(parsed_args);
+        op.validate_args(parsed_args)?;
+
+        let mut ep_builder = create::Request::builder();
+
+        // Set path parameters
+        ep_builder.bgpvpn_id(&self.path.bgpvpn_id);
+        // Set query parameters
+        // Set body parameters
+        // Set Request.port_association data
+        let args = &self.port_association;
+        let mut port_association_builder = create::PortAssociationBuilder::default();
+        if let Some(val) = &args.advertise_fixed_ips {
+            port_association_builder.advertise_fixed_ips(*val);
+        }
+
+        let mut find_builder = find_port::Request::builder();
+        find_builder.id(&args.port_id);
+        let find_ep = find_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+        let find_data: serde_json::Value = find(find_ep).query_async(client).await?;
+        let port_id = find_data["id"]
+            .as_str()
+            .expect("Resource ID is a string")
+            .to_string();
+        port_association_builder.port_id(port_id);
+
+        if let Some(val) = &args.routes {
+            port_association_builder.routes(val.clone());
+        }
+
+        if let Some(val) = &args.tenant_id {
+            port_association_builder.tenant_id(val);
+        }
+
+        ep_builder.port_association(port_association_builder.build().unwrap());
+
+        let ep = ep_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+
+        let data = ep.query_async(client).await?;
+        op.output_single::<ResponseData>(data)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,221 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// WARNING: This file is automatically generated from OpenAPI schema using
+// `openstack-codegenerator`.
+
+//! List PortAssociations command
+//!
+//! Wraps invoking of the `v2.0/bgpvpn/bgpvpns/{bgpvpn_id}/port_associations` with `GET` method
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use anyhow::Result;
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::output::OutputProcessor;
+use crate::Cli;
+use crate::OpenStackCliError;
+use crate::OutputConfig;
+use crate::StructTable;
+
+use crate::common::BoolString;
+use openstack_sdk::api::network::v2::bgpvpn::bgpvpn::port_association::list;
+use openstack_sdk::api::{paged, Pagination};
+use serde_json::Value;
+use structable_derive::StructTable;
+
+/// Lists port associations for the BGP VPN.
+///
+/// Additionally, you can filter results by using query string parameters. For
+/// information, see
+/// [Filtering and Column Selection](https://wiki.openstack.org/wiki/Neutron/APIv2-specification#Filtering_and_Column_Selection).
+///
+/// Use the `fields` query parameter to control which fields are returned in
+/// the response body. For information, see
+/// [Filtering and Column Selection](https://wiki.openstack.org/wiki/Neutron/APIv2-specification#Filtering_and_Column_Selection).
+///
+/// Normal response codes: 200
+///
+/// Error response codes: 401, 404
+///
+#[derive(Args)]
+#[command(about = "List port associations")]
+pub struct PortAssociationsCommand {
+    /// Request Query parameters
+    #[command(flatten)]
+    query: QueryParameters,
+
+    /// Path parameters
+    #[command(flatten)]
+    path: PathParameters,
+
+    /// Total limit of entities count to return. Use this when there are too many entries.
+    #[arg(long, default_value_t = 10000)]
+    max_items: usize,
+}
+
+/// Query parameters
+#[derive(Args)]
+struct QueryParameters {
+    /// advertise_fixed_ips query parameter for /v2.0/bgpvpn/bgpvpns/{bgpvpn_id}/port_associations API
+    ///
+    #[arg(action=clap::ArgAction::Set, help_heading = "Query parameters", long)]
+    advertise_fixed_ips: Option<bool>,
+
+    /// id query parameter for /v2.0/bgpvpn/bgpvpns/{bgpvpn_id}/port_associations API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    id: Option<String>,
+
+    /// Requests a page size of items. Returns a number of items up to a limit
+    /// value. Use the limit parameter to make an initial limited request and
+    /// use the ID of the last-seen item from the response as the marker
+    /// parameter value in a subsequent limited request.
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    limit: Option<i32>,
+
+    /// The ID of the last-seen item. Use the limit parameter to make an
+    /// initial limited request and use the ID of the last-seen item from the
+    /// response as the marker parameter value in a subsequent limited
+    /// request.
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    marker: Option<String>,
+
+    /// port_id query parameter for /v2.0/bgpvpn/bgpvpns/{bgpvpn_id}/port_associations API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    port_id: Option<String>,
+
+    /// project_id query parameter for /v2.0/bgpvpn/bgpvpns/{bgpvpn_id}/port_associations API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    project_id: Option<String>,
+
+    /// tenant_id query parameter for /v2.0/bgpvpn/bgpvpns/{bgpvpn_id}/port_associations API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    tenant_id: Option<String>,
+}
+
+/// Path parameters
+#[derive(Args)]
+struct PathParameters {
+    /// bgpvpn_id parameter for /v2.0/bgpvpn/bgpvpns/{bgpvpn_id}/port_associations API
+    ///
+    #[arg(
+        help_heading = "Path parameters",
+        id = "path_param_bgpvpn_id",
+        value_name = "BGPVPN_ID"
+    )]
+    bgpvpn_id: String,
+}
+/// PortAssociations response representation
+#[derive(Deserialize, Serialize, Clone, StructTable)]
+struct ResponseData {
+    /// Boolean flag controlling whether or not the fixed IPs of a port will be
+    /// advertised to the BGPVPN.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    advertise_fixed_ips: Option<BoolString>,
+
+    /// The ID of the port association.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    id: Option<String>,
+
+    /// The ID of a Neutron port with which to associate the BGP VPN.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    port_id: Option<String>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    project_id: Option<String>,
+
+    /// List of routes, each route being a dict with at least a `type` key,
+    /// which can be `prefix` or `bgpvpn`.
+    ///
+    #[serde()]
+    #[structable(optional, pretty)]
+    routes: Option<Value>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    tenant_id: Option<String>,
+}
+
+impl PortAssociationsCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        client: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        info!("List PortAssociations");
+
+        let op = OutputProcessor::from_args(parsed_args);
+        op.validate_args(parsed_args)?;
+
+        let mut ep_builder = list::Request::builder();
+
+        // Set path parameters
+        ep_builder.bgpvpn_id(&self.path.bgpvpn_id);
+        // Set query parameters
+        if let Some(val) = &self.query.limit {
+            ep_builder.limit(*val);
+        }
+        if let Some(val) = &self.query.marker {
+            ep_builder.marker(val);
+        }
+        if let Some(val) = &self.query.id {
+            ep_builder.id(val);
+        }
+        if let Some(val) = &self.query.advertise_fixed_ips {
+            ep_builder.advertise_fixed_ips(*val);
+        }
+        if let Some(val) = &self.query.port_id {
+            ep_builder.port_id(val);
+        }
+        if let Some(val) = &self.query.project_id {
+            ep_builder.project_id(val);
+        }
+        if let Some(val) = &self.query.tenant_id {
+            ep_builder.tenant_id(val);
+        }
+        // Set body parameters
+
+        let ep = ep_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+
+        let ep = paged(ep, Pagination::Limit(self.max_items)).prefetch(true);
+
+        op.output_list_stream::<ResponseData, _, _>(ep.iter_async(client))
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,192 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// WARNING: This file is automatically generated from OpenAPI schema using
+// `openstack-codegenerator`.
+
+//! Set PortAssociation command
+//!
+//! Wraps invoking of the `v2.0/bgpvpn/bgpvpns/{bgpvpn_id}/port_associations/{id}` with `PUT` method
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use anyhow::Result;
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::output::OutputProcessor;
+use crate::Cli;
+use crate::OpenStackCliError;
+use crate::OutputConfig;
+use crate::StructTable;
+
+use crate::common::parse_json;
+use crate::common::BoolString;
+use openstack_sdk::api::network::v2::bgpvpn::bgpvpn::port_association::set;
+use openstack_sdk::api::QueryAsync;
+use serde_json::Value;
+use structable_derive::StructTable;
+
+/// Updates a port association for the BGP VPN.
+///
+/// Normal response codes: 200
+///
+/// Error response codes: 400, 401, 404
+///
+#[derive(Args)]
+#[command(about = "Update port association")]
+pub struct PortAssociationCommand {
+    /// Request Query parameters
+    #[command(flatten)]
+    query: QueryParameters,
+
+    /// Path parameters
+    #[command(flatten)]
+    path: PathParameters,
+
+    /// A `port_association` object.
+    ///
+    #[command(flatten)]
+    port_association: PortAssociation,
+}
+
+/// Query parameters
+#[derive(Args)]
+struct QueryParameters {}
+
+/// Path parameters
+#[derive(Args)]
+struct PathParameters {
+    /// bgpvpn_id parameter for /v2.0/bgpvpn/bgpvpns/{bgpvpn_id}/port_associations/{id} API
+    ///
+    #[arg(
+        help_heading = "Path parameters",
+        id = "path_param_bgpvpn_id",
+        value_name = "BGPVPN_ID"
+    )]
+    bgpvpn_id: String,
+
+    /// id parameter for /v2.0/bgpvpn/bgpvpns/{bgpvpn_id}/port_associations/{id} API
+    ///
+    #[arg(
+        help_heading = "Path parameters",
+        id = "path_param_id",
+        value_name = "ID"
+    )]
+    id: String,
+}
+/// PortAssociation Body data
+#[derive(Args, Clone)]
+struct PortAssociation {
+    /// Boolean flag controlling whether or not the fixed IPs of a port will be
+    /// advertised to the BGPVPN (default: true).
+    ///
+    #[arg(action=clap::ArgAction::Set, help_heading = "Body parameters", long)]
+    advertise_fixed_ips: Option<bool>,
+
+    /// List of routes, each route being a dict with at least a `type` key,
+    /// which can be `prefix` or `bgpvpn`. For the `prefix` type, the IP prefix
+    /// (v4 or v6) to advertise is specified in the `prefix` key. For the
+    /// `bgpvpn` type, the `bgpvpn_id` key specifies the BGP VPN from which
+    /// routes will be readvertised.
+    ///
+    #[arg(help_heading = "Body parameters", long, value_name="JSON", value_parser=parse_json)]
+    routes: Option<Value>,
+}
+
+/// PortAssociation response representation
+#[derive(Deserialize, Serialize, Clone, StructTable)]
+struct ResponseData {
+    /// Boolean flag controlling whether or not the fixed IPs of a port will be
+    /// advertised to the BGPVPN.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    advertise_fixed_ips: Option<BoolString>,
+
+    /// The ID of the port association.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    id: Option<String>,
+
+    /// The ID of a Neutron port with which to associate the BGP VPN.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    port_id: Option<String>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    project_id: Option<String>,
+
+    /// List of routes, each route being a dict with at least a `type` key,
+    /// which can be `prefix` or `bgpvpn`.
+    ///
+    #[serde()]
+    #[structable(optional, pretty)]
+    routes: Option<Value>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    tenant_id: Option<String>,
+}
+
+impl PortAssociationCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        client: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        info!("Set PortAssociation");
+
+        let op = OutputProcessor::from_args(parsed_args);
+        op.validate_args(parsed_args)?;
+
+        let mut ep_builder = set::Request::builder();
+
+        // Set path parameters
+        ep_builder.bgpvpn_id(&self.path.bgpvpn_id);
+        ep_builder.id(&self.path.id);
+        // Set query parameters
+        // Set body parameters
+        // Set Request.port_association data
+        let args = &self.port_association;
+        let mut port_association_builder = set::PortAssociationBuilder::default();
+        if let Some(val) = &args.advertise_fixed_ips {
+            port_association_builder.advertise_fixed_ips(*val);
+        }
+
+        if let Some(val) = &args.routes {
+            port_association_builder.routes(val.clone());
+        }
+
+        ep_builder.port_association(port_association_builder.build().unwrap());
+
+        let ep = ep_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+
+        let data = ep.query_async(client).await?;
+        op.output_single::<ResponseData>(data)?;
+        Ok(())
+    }
+}
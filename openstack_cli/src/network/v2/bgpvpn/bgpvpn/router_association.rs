@@ -0,0 +1,66 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! RouterAssociation resource commands
+
+use clap::{Parser, Subcommand};
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::{Cli, OpenStackCliError};
+
+mod create;
+mod delete;
+mod list;
+mod set;
+mod show;
+
+/// BGP VPN router associations
+///
+/// Associating a router with a BGP VPN connects all the subnets attached to
+/// the router to the VPN.
+#[derive(Parser)]
+pub struct RouterAssociationCommand {
+    /// subcommand
+    #[command(subcommand)]
+    command: RouterAssociationCommands,
+}
+
+/// Supported subcommands
+#[allow(missing_docs)]
+#[derive(Subcommand)]
+pub enum RouterAssociationCommands {
+    Create(Box<create::RouterAssociationCommand>),
+    Delete(Box<delete::RouterAssociationCommand>),
+    List(Box<list::RouterAssociationsCommand>),
+    Set(Box<set::RouterAssociationCommand>),
+    Show(Box<show::RouterAssociationCommand>),
+}
+
+impl RouterAssociationCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        session: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        match &self.command {
+            RouterAssociationCommands::Create(cmd) => cmd.take_action(parsed_args, session).await,
+            RouterAssociationCommands::Delete(cmd) => cmd.take_action(parsed_args, session).await,
+            RouterAssociationCommands::List(cmd) => cmd.take_action(parsed_args, session).await,
+            RouterAssociationCommands::Set(cmd) => cmd.take_action(parsed_args, session).await,
+            RouterAssociationCommands::Show(cmd) => cmd.take_action(parsed_args, session).await,
+        }
+    }
+}
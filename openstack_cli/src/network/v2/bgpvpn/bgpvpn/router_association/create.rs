@@ -0,0 +1,193 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Create RouterAssociation command
+//!
+//! Wraps invoking of the `v2.0/bgpvpn/bgpvpns/{bgpvpn_id}/router_associations` with `POST` method
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use anyhow::Result;
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::output::OutputProcessor;
+use crate::Cli;
+use crate::OpenStackCliError;
+use crate::OutputConfig;
+use crate::StructTable;
+
+use crate::common::BoolString;
+use openstack_sdk::api::find;
+use openstack_sdk::api::network::v2::bgpvpn::bgpvpn::router_association::create;
+use openstack_sdk::api::network::v2::router::find as find_router;
+use openstack_sdk::api::QueryAsync;
+use structable_derive::StructTable;
+
+/// Creates a router association for the BGP VPN.
+///
+/// Normal response codes: 201
+///
+/// Error response codes: 400, 401, 404
+///
+#[derive(Args)]
+#[command(about = "Create router association")]
+pub struct RouterAssociationCommand {
+    /// Request Query parameters
+    #[command(flatten)]
+    query: QueryParameters,
+
+    /// Path parameters
+    #[command(flatten)]
+    path: PathParameters,
+
+    /// A `router_association` object.
+    ///
+    #[command(flatten)]
+    router_association: RouterAssociation,
+}
+
+/// Query parameters
+#[derive(Args)]
+struct QueryParameters {}
+
+/// Path parameters
+#[derive(Args)]
+struct PathParameters {
+    /// bgpvpn_id parameter for /v2.0/bgpvpn/bgpvpns/{bgpvpn_id}/router_associations API
+    ///
+    #[arg(
+        help_heading = "Path parameters",
+        id = "path_param_bgpvpn_id",
+        value_name = "BGPVPN_ID"
+    )]
+    bgpvpn_id: String,
+}
+/// RouterAssociation Body data
+#[derive(Args, Clone)]
+struct RouterAssociation {
+    /// Boolean flag controlling whether or not the routes specified in the
+    /// `routes` attribute of the router will be advertised to the BGPVPN
+    /// (default: true).
+    ///
+    #[arg(action=clap::ArgAction::Set, help_heading = "Body parameters", long)]
+    advertise_extra_routes: Option<bool>,
+
+    /// The name or ID of the router to associate with the BGP VPN.
+    ///
+    #[arg(
+        help_heading = "Body parameters",
+        long,
+        value_name = "ROUTER",
+        visible_alias = "router"
+    )]
+    router_id: String,
+
+    /// The ID of the project that owns the resource. Only administrative and
+    /// users with advsvc role can specify a project ID other than their own.
+    /// You cannot change this value through authorization policies.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    tenant_id: Option<String>,
+}
+
+/// RouterAssociation response representation
+#[derive(Deserialize, Serialize, Clone, StructTable)]
+struct ResponseData {
+    /// Boolean flag controlling whether or not the routes specified in the
+    /// `routes` attribute of the router will be advertised to the BGPVPN.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    advertise_extra_routes: Option<BoolString>,
+
+    /// The ID of the router association.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    id: Option<String>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    project_id: Option<String>,
+
+    /// The ID of a Neutron router with which to associate the BGP VPN.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    router_id: Option<String>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    tenant_id: Option<String>,
+}
+
+impl RouterAssociationCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        client: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        info!("Create RouterAssociation");
+
+        let op = OutputProcessor::from_args(parsed_args);
+        op.validate_args(parsed_args)?;
+
+        let mut ep_builder = create::Request::builder();
+
+        // Set path parameters
+        ep_builder.bgpvpn_id(&self.path.bgpvpn_id);
+        // Set query parameters
+        // Set body parameters
+        // Set Request.router_association data
+        let args = &self.router_association;
+        let mut router_association_builder = create::RouterAssociationBuilder::default();
+        if let Some(val) = &args.advertise_extra_routes {
+            router_association_builder.advertise_extra_routes(*val);
+        }
+
+        let mut find_builder = find_router::Request::builder();
+        find_builder.id(&args.router_id);
+        let find_ep = find_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+        let find_data: serde_json::Value = find(find_ep).query_async(client).await?;
+        let router_id = find_data["id"]
+            .as_str()
+            .expect("Resource ID is a string")
+            .to_string();
+        router_association_builder.router_id(router_id);
+
+        if let Some(val) = &args.tenant_id {
+            router_association_builder.tenant_id(val);
+        }
+
+        ep_builder.router_association(router_association_builder.build().unwrap());
+
+        let ep = ep_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+
+        let data = ep.query_async(client).await?;
+        op.output_single::<ResponseData>(data)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,279 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// WARNING: This file is automatically generated from OpenAPI schema using
+// `openstack-codegenerator`.
+
+//! Set Bgpvpn command
+//!
+//! Wraps invoking of the `v2.0/bgpvpn/bgpvpns/{id}` with `PUT` method
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use anyhow::Result;
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::output::OutputProcessor;
+use crate::Cli;
+use crate::OpenStackCliError;
+use crate::OutputConfig;
+use crate::StructTable;
+
+use openstack_sdk::api::find;
+use openstack_sdk::api::network::v2::bgpvpn::bgpvpn::find;
+use openstack_sdk::api::network::v2::bgpvpn::bgpvpn::set;
+use openstack_sdk::api::QueryAsync;
+use serde_json::Value;
+use structable_derive::StructTable;
+
+/// Updates a BGP VPN.
+///
+/// Normal response codes: 200
+///
+/// Error response codes: 400, 401, 404
+///
+#[derive(Args)]
+#[command(about = "Update BGP VPN")]
+pub struct BgpvpnCommand {
+    /// Request Query parameters
+    #[command(flatten)]
+    query: QueryParameters,
+
+    /// Path parameters
+    #[command(flatten)]
+    path: PathParameters,
+
+    /// A `bgpvpn` object.
+    ///
+    #[command(flatten)]
+    bgpvpn: Bgpvpn,
+}
+
+/// Query parameters
+#[derive(Args)]
+struct QueryParameters {}
+
+/// Path parameters
+#[derive(Args)]
+struct PathParameters {
+    /// id parameter for /v2.0/bgpvpn/bgpvpns/{id} API
+    ///
+    #[arg(
+        help_heading = "Path parameters",
+        id = "path_param_id",
+        value_name = "ID"
+    )]
+    id: String,
+}
+/// Bgpvpn Body data
+#[derive(Args, Clone)]
+struct Bgpvpn {
+    /// Additional Route Targets that will be used for export.
+    ///
+    #[arg(action=clap::ArgAction::Append, help_heading = "Body parameters", long)]
+    export_targets: Option<Vec<String>>,
+
+    /// Additional Route Targets that will be imported.
+    ///
+    #[arg(action=clap::ArgAction::Append, help_heading = "Body parameters", long)]
+    import_targets: Option<Vec<String>>,
+
+    /// The default BGP LOCAL_PREF of routes that will be advertised to the
+    /// BGPVPN (unless overridden per-route).
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    local_pref: Option<i32>,
+
+    /// The user meaningful name of the BGP VPN.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    name: Option<String>,
+
+    /// List of route distinguisher strings. If this parameter is specified,
+    /// one of these RDs will be used to advertise VPN routes.
+    ///
+    #[arg(action=clap::ArgAction::Append, help_heading = "Body parameters", long)]
+    route_distinguishers: Option<Vec<String>>,
+
+    /// Route Targets that will be both imported and used for export.
+    ///
+    #[arg(action=clap::ArgAction::Append, help_heading = "Body parameters", long)]
+    route_targets: Option<Vec<String>>,
+}
+
+/// Bgpvpn response representation
+#[derive(Deserialize, Serialize, Clone, StructTable)]
+struct ResponseData {
+    /// Selection of the type of VPN and the technology behind it. Allowed
+    /// values are `l2` or `l3`.
+    ///
+    #[serde(rename = "type")]
+    #[structable(optional, title = "type")]
+    _type: Option<String>,
+
+    /// Additional Route Targets that will be used for export.
+    ///
+    #[serde()]
+    #[structable(optional, pretty)]
+    export_targets: Option<Value>,
+
+    /// The ID of the BGP VPN.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    id: Option<String>,
+
+    /// Additional Route Targets that will be imported.
+    ///
+    #[serde()]
+    #[structable(optional, pretty)]
+    import_targets: Option<Value>,
+
+    /// The default BGP LOCAL_PREF of routes that will be advertised to the
+    /// BGPVPN (unless overridden per-route).
+    ///
+    #[serde()]
+    #[structable(optional)]
+    local_pref: Option<i32>,
+
+    /// The user meaningful name of the BGP VPN.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    name: Option<String>,
+
+    /// This read-only list of network IDs reflects the associations defined by
+    /// Network association API resources.
+    ///
+    #[serde()]
+    #[structable(optional, pretty)]
+    networks: Option<Value>,
+
+    /// This read-only list of port IDs reflects the associations defined by
+    /// Port association API resources.
+    ///
+    #[serde()]
+    #[structable(optional, pretty)]
+    ports: Option<Value>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    project_id: Option<String>,
+
+    /// List of route distinguisher strings. If this parameter is specified,
+    /// one of these RDs will be used to advertise VPN routes.
+    ///
+    #[serde()]
+    #[structable(optional, pretty)]
+    route_distinguishers: Option<Value>,
+
+    /// Route Targets that will be both imported and used for export.
+    ///
+    #[serde()]
+    #[structable(optional, pretty)]
+    route_targets: Option<Value>,
+
+    /// This read-only list of router IDs reflects the associations defined by
+    /// Router association API resources.
+    ///
+    #[serde()]
+    #[structable(optional, pretty)]
+    routers: Option<Value>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    tenant_id: Option<String>,
+
+    /// The globally-assigned VXLAN `vni` for the BGP VPN.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    vni: Option<i32>,
+}
+
+impl BgpvpnCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        client: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        info!("Set Bgpvpn");
+
+        let op = OutputProcessor::from_args(parsed_args);
+        op.validate_args(parsed_args)?;
+
+        let mut find_builder = find::Request::builder();
+
+        find_builder.id(&self.path.id);
+        let find_ep = find_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+        let find_data: serde_json::Value = find(find_ep).query_async(client).await?;
+
+        let mut ep_builder = set::Request::builder();
+
+        // Set path parameters
+        let resource_id = find_data["id"]
+            .as_str()
+            .expect("Resource ID is a string")
+            .to_string();
+        ep_builder.id(resource_id.clone());
+        // Set query parameters
+        // Set body parameters
+        // Set Request.bgpvpn data
+        let args = &self.bgpvpn;
+        let mut bgpvpn_builder = set::BgpvpnBuilder::default();
+        if let Some(val) = &args.export_targets {
+            bgpvpn_builder.export_targets(val.iter().map(|v| v.into()).collect::<Vec<_>>());
+        }
+
+        if let Some(val) = &args.import_targets {
+            bgpvpn_builder.import_targets(val.iter().map(|v| v.into()).collect::<Vec<_>>());
+        }
+
+        if let Some(val) = &args.local_pref {
+            bgpvpn_builder.local_pref(*val);
+        }
+
+        if let Some(val) = &args.name {
+            bgpvpn_builder.name(val);
+        }
+
+        if let Some(val) = &args.route_distinguishers {
+            bgpvpn_builder.route_distinguishers(val.iter().map(|v| v.into()).collect::<Vec<_>>());
+        }
+
+        if let Some(val) = &args.route_targets {
+            bgpvpn_builder.route_targets(val.iter().map(|v| v.into()).collect::<Vec<_>>());
+        }
+
+        ep_builder.bgpvpn(bgpvpn_builder.build().unwrap());
+
+        let ep = ep_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+
+        let data = ep.query_async(client).await?;
+        op.output_single::<ResponseData>(data)?;
+        Ok(())
+    }
+}
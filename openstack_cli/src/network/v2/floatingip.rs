@@ -16,8 +16,10 @@
 
 use clap::{Parser, Subcommand};
 
+use openstack_sdk::api::network::v2::floatingip::get;
 use openstack_sdk::AsyncOpenStack;
 
+use crate::network::v2::tag::{TagCommand, TaggedResource};
 use crate::{Cli, OpenStackCliError};
 
 mod create;
@@ -26,7 +28,25 @@ mod list;
 mod port_forwarding;
 mod set;
 mod show;
-mod tag;
+
+/// Tagged floating IP
+///
+/// Floating IPs have no name, so the resource is fetched by its ID.
+pub(crate) struct TaggedFloatingIP;
+
+impl TaggedResource for TaggedFloatingIP {
+    const COLLECTION: &'static str = "floatingips";
+    const VALUE_NAME: &'static str = "FLOATINGIP_ID";
+
+    type Find = get::Request<'static>;
+
+    fn find(id: &str) -> Result<Self::Find, OpenStackCliError> {
+        get::Request::builder()
+            .id(id.to_string())
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))
+    }
+}
 
 /// Floating IP commands
 #[derive(Parser)]
@@ -46,7 +66,7 @@ pub enum FloatingIPCommands {
     PortForwarding(port_forwarding::PortForwardingCommand),
     Set(set::FloatingipCommand),
     Show(show::FloatingipCommand),
-    Tag(tag::TagCommand),
+    Tag(Box<TagCommand<TaggedFloatingIP>>),
 }
 
 impl FloatingIPCommand {
@@ -162,6 +162,17 @@ struct Floatingip {
     ///
     #[arg(help_heading = "Body parameters", long)]
     tenant_id: Option<String>,
+
+    /// Tag to set on the resource. Repeat the option to set multiple tags.
+    ///
+    #[arg(
+        action=clap::ArgAction::Append,
+        help_heading = "Body parameters",
+        long = "tag",
+        value_name = "TAG",
+        visible_alias = "tags"
+    )]
+    tags: Option<Vec<String>>,
 }
 
 /// Floatingip response representation
@@ -346,6 +357,10 @@ impl FloatingipCommand {
             floatingip_builder.description(val);
         }
 
+        if let Some(val) = &args.tags {
+            floatingip_builder.tags(val.iter().map(|v| v.into()).collect::<Vec<_>>());
+        }
+
         ep_builder.floatingip(floatingip_builder.build().unwrap());
 
         let ep = ep_builder
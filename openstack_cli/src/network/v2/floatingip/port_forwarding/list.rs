@@ -34,7 +34,7 @@ use crate::OutputConfig;
 use crate::StructTable;
 
 use openstack_sdk::api::network::v2::floatingip::port_forwarding::list;
-use openstack_sdk::api::QueryAsync;
+use openstack_sdk::api::{paged, Pagination};
 use structable_derive::StructTable;
 
 /// Lists floating IP port forwardings that the project has access to.
@@ -62,6 +62,10 @@ pub struct PortForwardingsCommand {
     /// Path parameters
     #[command(flatten)]
     path: PathParameters,
+
+    /// Total limit of entities count to return. Use this when there are too many entries.
+    #[arg(long, default_value_t = 10000)]
+    max_items: usize,
 }
 
 /// Query parameters
@@ -97,6 +101,22 @@ struct QueryParameters {
     #[arg(help_heading = "Query parameters", long)]
     internal_port_id: Option<String>,
 
+    /// Requests a page size of items. Returns a number of items up to a limit
+    /// value. Use the limit parameter to make an initial limited request and
+    /// use the ID of the last-seen item from the response as the marker
+    /// parameter value in a subsequent limited request.
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    limit: Option<i32>,
+
+    /// The ID of the last-seen item. Use the limit parameter to make an
+    /// initial limited request and use the ID of the last-seen item from the
+    /// response as the marker parameter value in a subsequent limited
+    /// request.
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    marker: Option<String>,
+
     /// protocol query parameter for
     /// /v2.0/floatingips/{floatingip_id}/port_forwardings API
     ///
@@ -199,6 +219,12 @@ impl PortForwardingsCommand {
         // Set path parameters
         ep_builder.floatingip_id(&self.path.floatingip_id);
         // Set query parameters
+        if let Some(val) = &self.query.limit {
+            ep_builder.limit(*val);
+        }
+        if let Some(val) = &self.query.marker {
+            ep_builder.marker(val);
+        }
         if let Some(val) = &self.query.id {
             ep_builder.id(val);
         }
@@ -223,9 +249,10 @@ impl PortForwardingsCommand {
             .build()
             .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
 
-        let data: Vec<serde_json::Value> = ep.query_async(client).await?;
+        let ep = paged(ep, Pagination::Limit(self.max_items)).prefetch(true);
 
-        op.output_list::<ResponseData>(data)?;
+        op.output_list_stream::<ResponseData, _, _>(ep.iter_async(client))
+            .await?;
         Ok(())
     }
 }
@@ -33,7 +33,9 @@ use crate::OpenStackCliError;
 use crate::OutputConfig;
 use crate::StructTable;
 
+use openstack_sdk::api::conditional;
 use openstack_sdk::api::network::v2::floatingip::set;
+use openstack_sdk::api::Precondition;
 use openstack_sdk::api::QueryAsync;
 use serde_json::Value;
 use structable_derive::StructTable;
@@ -73,6 +75,12 @@ pub struct FloatingipCommand {
     ///
     #[command(flatten)]
     floatingip: Floatingip,
+
+    /// Only update the resource when its current `revision_number` matches
+    /// the given value.
+    ///
+    #[arg(help_heading = "Header parameters", long, value_name = "REVISION")]
+    if_revision: Option<u64>,
 }
 
 /// Query parameters
@@ -286,7 +294,14 @@ impl FloatingipCommand {
             .build()
             .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
 
-        let data = ep.query_async(client).await?;
+        let data = match self.if_revision {
+            Some(revision) => {
+                conditional(ep, Precondition::RevisionNumber(revision))
+                    .query_async(client)
+                    .await?
+            }
+            None => ep.query_async(client).await?,
+        };
         op.output_single::<ResponseData>(data)?;
         Ok(())
     }
@@ -0,0 +1,62 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Neutron FWaaS v2 commands
+
+use clap::{Parser, Subcommand};
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::{Cli, OpenStackCliError};
+
+mod firewall_group;
+mod firewall_policy;
+mod firewall_rule;
+
+/// Firewall as a Service
+///
+/// The FWaaS v2 extension (`fwaas_v2`) filters the traffic of ports with
+/// firewall groups built of ordered firewall policies and rules.
+#[derive(Parser)]
+pub struct FwaasCommand {
+    /// subcommand
+    #[command(subcommand)]
+    command: FwaasCommands,
+}
+
+/// Supported subcommands
+#[allow(missing_docs)]
+#[derive(Subcommand)]
+pub enum FwaasCommands {
+    FirewallGroup(Box<firewall_group::FirewallGroupCommand>),
+    FirewallPolicy(Box<firewall_policy::FirewallPolicyCommand>),
+    FirewallRule(Box<firewall_rule::FirewallRuleCommand>),
+}
+
+impl FwaasCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        session: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        session.ensure_network_extension("fwaas_v2").await?;
+
+        match &self.command {
+            FwaasCommands::FirewallGroup(cmd) => cmd.take_action(parsed_args, session).await,
+            FwaasCommands::FirewallPolicy(cmd) => cmd.take_action(parsed_args, session).await,
+            FwaasCommands::FirewallRule(cmd) => cmd.take_action(parsed_args, session).await,
+        }
+    }
+}
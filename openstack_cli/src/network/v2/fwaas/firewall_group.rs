@@ -12,11 +12,7 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 
-//! Resource Tag module
-//!
-//! This module is defined once and symlinked into individual resources. This way code is
-//! de-duplicated while also ensuring the same command interface is being maintained.
-#![allow(clippy::duplicate_mod)]
+//! FirewallGroup resource commands
 
 use clap::{Parser, Subcommand};
 
@@ -24,33 +20,35 @@ use openstack_sdk::AsyncOpenStack;
 
 use crate::{Cli, OpenStackCliError};
 
+mod create;
 mod delete;
-mod delete_all;
 mod list;
 mod set;
 mod show;
 
-/// Lists tags, creates, replaces or deletes one or more tags for a
-/// resource, checks the existence of a tag for a resource.
+/// Firewall groups
+///
+/// A firewall group applies an ingress and an egress firewall policy to the
+/// ports it is associated with.
 #[derive(Parser)]
-pub struct TagCommand {
+pub struct FirewallGroupCommand {
     /// subcommand
     #[command(subcommand)]
-    command: TagCommands,
+    command: FirewallGroupCommands,
 }
 
 /// Supported subcommands
 #[allow(missing_docs)]
 #[derive(Subcommand)]
-pub enum TagCommands {
-    Add(set::TagCommand),
-    Check(show::TagCommand),
-    Delete(delete::TagCommand),
-    List(list::TagsCommand),
-    Purge(delete_all::TagCommand),
+pub enum FirewallGroupCommands {
+    Create(Box<create::FirewallGroupCommand>),
+    Delete(Box<delete::FirewallGroupCommand>),
+    List(Box<list::FirewallGroupsCommand>),
+    Set(Box<set::FirewallGroupCommand>),
+    Show(Box<show::FirewallGroupCommand>),
 }
 
-impl TagCommand {
+impl FirewallGroupCommand {
     /// Perform command action
     pub async fn take_action(
         &self,
@@ -58,11 +56,11 @@ impl TagCommand {
         session: &mut AsyncOpenStack,
     ) -> Result<(), OpenStackCliError> {
         match &self.command {
-            TagCommands::Add(cmd) => cmd.take_action(parsed_args, session).await,
-            TagCommands::Check(cmd) => cmd.take_action(parsed_args, session).await,
-            TagCommands::Delete(cmd) => cmd.take_action(parsed_args, session).await,
-            TagCommands::List(cmd) => cmd.take_action(parsed_args, session).await,
-            TagCommands::Purge(cmd) => cmd.take_action(parsed_args, session).await,
+            FirewallGroupCommands::Create(cmd) => cmd.take_action(parsed_args, session).await,
+            FirewallGroupCommands::Delete(cmd) => cmd.take_action(parsed_args, session).await,
+            FirewallGroupCommands::List(cmd) => cmd.take_action(parsed_args, session).await,
+            FirewallGroupCommands::Set(cmd) => cmd.take_action(parsed_args, session).await,
+            FirewallGroupCommands::Show(cmd) => cmd.take_action(parsed_args, session).await,
         }
     }
 }
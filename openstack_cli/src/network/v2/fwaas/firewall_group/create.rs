@@ -0,0 +1,285 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Create FirewallGroup command
+//!
+//! Wraps invoking of the `v2.0/fwaas/firewall_groups` with `POST` method
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use anyhow::Result;
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::output::OutputProcessor;
+use crate::Cli;
+use crate::OpenStackCliError;
+use crate::OutputConfig;
+use crate::StructTable;
+
+use crate::common::BoolString;
+use openstack_sdk::api::find;
+use openstack_sdk::api::network::v2::fwaas::firewall_group::create;
+use openstack_sdk::api::network::v2::fwaas::firewall_policy::find as find_firewall_policy;
+use openstack_sdk::api::QueryAsync;
+use serde_json::Value;
+use structable_derive::StructTable;
+
+/// Creates a firewall group.
+///
+/// Normal response codes: 201
+///
+/// Error response codes: 400, 401
+///
+#[derive(Args)]
+#[command(about = "Create firewall group")]
+pub struct FirewallGroupCommand {
+    /// Request Query parameters
+    #[command(flatten)]
+    query: QueryParameters,
+
+    /// Path parameters
+    #[command(flatten)]
+    path: PathParameters,
+
+    /// A `firewall_group` object.
+    ///
+    #[command(flatten)]
+    firewall_group: FirewallGroup,
+}
+
+/// Query parameters
+#[derive(Args)]
+struct QueryParameters {}
+
+/// Path parameters
+#[derive(Args)]
+struct PathParameters {}
+/// FirewallGroup Body data
+#[derive(Args, Clone)]
+struct FirewallGroup {
+    /// The administrative state of the resource, which is up (`true`) or down
+    /// (`false`). Default is `true`.
+    ///
+    #[arg(action=clap::ArgAction::Set, help_heading = "Body parameters", long)]
+    admin_state_up: Option<bool>,
+
+    /// A human-readable description for the resource. Default is an empty
+    /// string.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    description: Option<String>,
+
+    /// The name or ID of the egress firewall policy for the firewall group.
+    ///
+    #[arg(
+        help_heading = "Body parameters",
+        long,
+        value_name = "FIREWALL_POLICY",
+        visible_alias = "egress-firewall-policy"
+    )]
+    egress_firewall_policy_id: Option<String>,
+
+    /// The name or ID of the ingress firewall policy for the firewall group.
+    ///
+    #[arg(
+        help_heading = "Body parameters",
+        long,
+        value_name = "FIREWALL_POLICY",
+        visible_alias = "ingress-firewall-policy"
+    )]
+    ingress_firewall_policy_id: Option<String>,
+
+    /// Human-readable name of the resource. Default is an empty string.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    name: Option<String>,
+
+    /// A list of the IDs of the ports associated with the firewall group. Set
+    /// to an empty list to disassociate all the ports.
+    ///
+    #[arg(action=clap::ArgAction::Append, help_heading = "Body parameters", long)]
+    ports: Option<Vec<String>>,
+
+    /// Set to `true` to make this firewall group visible to other projects.
+    /// Default is `false`.
+    ///
+    #[arg(action=clap::ArgAction::Set, help_heading = "Body parameters", long)]
+    shared: Option<bool>,
+
+    /// The ID of the project that owns the resource. Only administrative and
+    /// users with advsvc role can specify a project ID other than their own.
+    /// You cannot change this value through authorization policies.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    tenant_id: Option<String>,
+}
+
+/// FirewallGroup response representation
+#[derive(Deserialize, Serialize, Clone, StructTable)]
+struct ResponseData {
+    /// The administrative state of the resource, which is up (`true`) or down
+    /// (`false`).
+    ///
+    #[serde()]
+    #[structable(optional)]
+    admin_state_up: Option<BoolString>,
+
+    /// A human-readable description for the resource.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    description: Option<String>,
+
+    /// The ID of the egress firewall policy for the firewall group.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    egress_firewall_policy_id: Option<String>,
+
+    /// The ID of the firewall group.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    id: Option<String>,
+
+    /// The ID of the ingress firewall policy for the firewall group.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    ingress_firewall_policy_id: Option<String>,
+
+    /// Human-readable name of the resource.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    name: Option<String>,
+
+    /// A list of the IDs of the ports associated with the firewall group.
+    ///
+    #[serde()]
+    #[structable(optional, pretty)]
+    ports: Option<Value>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    project_id: Option<String>,
+
+    /// Indicates whether this firewall group is shared across all projects.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    shared: Option<BoolString>,
+
+    /// The status of the firewall group. Valid values are `ACTIVE`,
+    /// `INACTIVE`, `ERROR`, `PENDING_UPDATE`, or `PENDING_DELETE`.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    status: Option<String>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    tenant_id: Option<String>,
+}
+
+impl FirewallGroupCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        client: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        info!("Create FirewallGroup");
+
+        let op = OutputProcessor::from_args(parsed_args);
+        op.validate_args(parsed_args)?;
+
+        let mut ep_builder = create::Request::builder();
+
+        // Set path parameters
+        // Set query parameters
+        // Set body parameters
+        // Set Request.firewall_group data
+        let args = &self.firewall_group;
+        let mut firewall_group_builder = create::FirewallGroupBuilder::default();
+        if let Some(val) = &args.admin_state_up {
+            firewall_group_builder.admin_state_up(*val);
+        }
+
+        if let Some(val) = &args.description {
+            firewall_group_builder.description(val);
+        }
+
+        if let Some(val) = &args.egress_firewall_policy_id {
+            let mut find_builder = find_firewall_policy::Request::builder();
+            find_builder.id(val);
+            let find_ep = find_builder
+                .build()
+                .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+            let find_data: serde_json::Value = find(find_ep).query_async(client).await?;
+            let egress_firewall_policy_id = find_data["id"]
+                .as_str()
+                .expect("Resource ID is a string")
+                .to_string();
+            firewall_group_builder.egress_firewall_policy_id(egress_firewall_policy_id);
+        }
+
+        if let Some(val) = &args.ingress_firewall_policy_id {
+            let mut find_builder = find_firewall_policy::Request::builder();
+            find_builder.id(val);
+            let find_ep = find_builder
+                .build()
+                .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+            let find_data: serde_json::Value = find(find_ep).query_async(client).await?;
+            let ingress_firewall_policy_id = find_data["id"]
+                .as_str()
+                .expect("Resource ID is a string")
+                .to_string();
+            firewall_group_builder.ingress_firewall_policy_id(ingress_firewall_policy_id);
+        }
+
+        if let Some(val) = &args.name {
+            firewall_group_builder.name(val);
+        }
+
+        if let Some(val) = &args.ports {
+            firewall_group_builder.ports(val.iter().map(|v| v.into()).collect::<Vec<_>>());
+        }
+
+        if let Some(val) = &args.shared {
+            firewall_group_builder.shared(*val);
+        }
+
+        if let Some(val) = &args.tenant_id {
+            firewall_group_builder.tenant_id(val);
+        }
+
+        ep_builder.firewall_group(firewall_group_builder.build().unwrap());
+
+        let ep = ep_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+
+        let data = ep.query_async(client).await?;
+        op.output_single::<ResponseData>(data)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,281 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// WARNING: This file is automatically generated from OpenAPI schema using
+// `openstack-codegenerator`.
+
+//! List FirewallGroups command
+//!
+//! Wraps invoking of the `v2.0/fwaas/firewall_groups` with `GET` method
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use anyhow::Result;
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::output::OutputProcessor;
+use crate::Cli;
+use crate::OpenStackCliError;
+use crate::OutputConfig;
+use crate::StructTable;
+
+use crate::common::BoolString;
+use openstack_sdk::api::network::v2::fwaas::firewall_group::list;
+use openstack_sdk::api::{paged, Pagination};
+use serde_json::Value;
+use structable_derive::StructTable;
+
+/// Lists firewall groups that belong to a project.
+///
+/// Additionally, you can filter results by using query string parameters. For
+/// information, see
+/// [Filtering and Column Selection](https://wiki.openstack.org/wiki/Neutron/APIv2-specification#Filtering_and_Column_Selection).
+///
+/// Use the `fields` query parameter to control which fields are returned in
+/// the response body. For information, see
+/// [Filtering and Column Selection](https://wiki.openstack.org/wiki/Neutron/APIv2-specification#Filtering_and_Column_Selection).
+///
+/// Normal response codes: 200
+///
+/// Error response codes: 401
+///
+#[derive(Args)]
+#[command(about = "List firewall groups")]
+pub struct FirewallGroupsCommand {
+    /// Request Query parameters
+    #[command(flatten)]
+    query: QueryParameters,
+
+    /// Path parameters
+    #[command(flatten)]
+    path: PathParameters,
+
+    /// Total limit of entities count to return. Use this when there are too many entries.
+    #[arg(long, default_value_t = 10000)]
+    max_items: usize,
+}
+
+/// Query parameters
+#[derive(Args)]
+struct QueryParameters {
+    /// admin_state_up query parameter for /v2.0/fwaas/firewall_groups API
+    ///
+    #[arg(action=clap::ArgAction::Set, help_heading = "Query parameters", long)]
+    admin_state_up: Option<bool>,
+
+    /// description query parameter for /v2.0/fwaas/firewall_groups API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    description: Option<String>,
+
+    /// egress_firewall_policy_id query parameter for /v2.0/fwaas/firewall_groups API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    egress_firewall_policy_id: Option<String>,
+
+    /// id query parameter for /v2.0/fwaas/firewall_groups API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    id: Option<String>,
+
+    /// ingress_firewall_policy_id query parameter for /v2.0/fwaas/firewall_groups API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    ingress_firewall_policy_id: Option<String>,
+
+    /// Requests a page size of items. Returns a number of items up to a limit
+    /// value. Use the limit parameter to make an initial limited request and
+    /// use the ID of the last-seen item from the response as the marker
+    /// parameter value in a subsequent limited request.
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    limit: Option<i32>,
+
+    /// The ID of the last-seen item. Use the limit parameter to make an
+    /// initial limited request and use the ID of the last-seen item from the
+    /// response as the marker parameter value in a subsequent limited
+    /// request.
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    marker: Option<String>,
+
+    /// name query parameter for /v2.0/fwaas/firewall_groups API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    name: Option<String>,
+
+    /// project_id query parameter for /v2.0/fwaas/firewall_groups API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    project_id: Option<String>,
+
+    /// shared query parameter for /v2.0/fwaas/firewall_groups API
+    ///
+    #[arg(action=clap::ArgAction::Set, help_heading = "Query parameters", long)]
+    shared: Option<bool>,
+
+    /// status query parameter for /v2.0/fwaas/firewall_groups API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    status: Option<String>,
+
+    /// tenant_id query parameter for /v2.0/fwaas/firewall_groups API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    tenant_id: Option<String>,
+}
+
+/// Path parameters
+#[derive(Args)]
+struct PathParameters {}
+/// FirewallGroups response representation
+#[derive(Deserialize, Serialize, Clone, StructTable)]
+struct ResponseData {
+    /// The administrative state of the resource, which is up (`true`) or down
+    /// (`false`).
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    admin_state_up: Option<BoolString>,
+
+    /// A human-readable description for the resource.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    description: Option<String>,
+
+    /// The ID of the egress firewall policy for the firewall group.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    egress_firewall_policy_id: Option<String>,
+
+    /// The ID of the firewall group.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    id: Option<String>,
+
+    /// The ID of the ingress firewall policy for the firewall group.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    ingress_firewall_policy_id: Option<String>,
+
+    /// Human-readable name of the resource.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    name: Option<String>,
+
+    /// A list of the IDs of the ports associated with the firewall group.
+    ///
+    #[serde()]
+    #[structable(optional, pretty, wide)]
+    ports: Option<Value>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    project_id: Option<String>,
+
+    /// Indicates whether this firewall group is shared across all projects.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    shared: Option<BoolString>,
+
+    /// The status of the firewall group. Valid values are `ACTIVE`,
+    /// `INACTIVE`, `ERROR`, `PENDING_UPDATE`, or `PENDING_DELETE`.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    status: Option<String>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    tenant_id: Option<String>,
+}
+
+impl FirewallGroupsCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        client: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        info!("List FirewallGroups");
+
+        let op = OutputProcessor::from_args(parsed_args);
+        op.validate_args(parsed_args)?;
+
+        let mut ep_builder = list::Request::builder();
+
+        // Set path parameters
+        // Set query parameters
+        if let Some(val) = &self.query.limit {
+            ep_builder.limit(*val);
+        }
+        if let Some(val) = &self.query.marker {
+            ep_builder.marker(val);
+        }
+        if let Some(val) = &self.query.admin_state_up {
+            ep_builder.admin_state_up(*val);
+        }
+        if let Some(val) = &self.query.description {
+            ep_builder.description(val);
+        }
+        if let Some(val) = &self.query.egress_firewall_policy_id {
+            ep_builder.egress_firewall_policy_id(val);
+        }
+        if let Some(val) = &self.query.id {
+            ep_builder.id(val);
+        }
+        if let Some(val) = &self.query.ingress_firewall_policy_id {
+            ep_builder.ingress_firewall_policy_id(val);
+        }
+        if let Some(val) = &self.query.name {
+            ep_builder.name(val);
+        }
+        if let Some(val) = &self.query.project_id {
+            ep_builder.project_id(val);
+        }
+        if let Some(val) = &self.query.shared {
+            ep_builder.shared(*val);
+        }
+        if let Some(val) = &self.query.status {
+            ep_builder.status(val);
+        }
+        if let Some(val) = &self.query.tenant_id {
+            ep_builder.tenant_id(val);
+        }
+        // Set body parameters
+
+        let ep = ep_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+
+        let ep = paged(ep, Pagination::Limit(self.max_items)).prefetch(true);
+
+        op.output_list_stream::<ResponseData, _, _>(ep.iter_async(client))
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,73 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! FirewallPolicy resource commands
+
+use clap::{Parser, Subcommand};
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::{Cli, OpenStackCliError};
+
+mod create;
+mod delete;
+mod insert_rule;
+mod list;
+mod remove_rule;
+mod set;
+mod show;
+
+/// Firewall policies
+///
+/// A firewall policy is an ordered collection of firewall rules. The
+/// `insert-rule` and `remove-rule` subcommands manage the position of a single
+/// rule in the policy.
+#[derive(Parser)]
+pub struct FirewallPolicyCommand {
+    /// subcommand
+    #[command(subcommand)]
+    command: FirewallPolicyCommands,
+}
+
+/// Supported subcommands
+#[allow(missing_docs)]
+#[derive(Subcommand)]
+pub enum FirewallPolicyCommands {
+    Create(Box<create::FirewallPolicyCommand>),
+    Delete(Box<delete::FirewallPolicyCommand>),
+    InsertRule(Box<insert_rule::FirewallPolicyCommand>),
+    List(Box<list::FirewallPoliciesCommand>),
+    RemoveRule(Box<remove_rule::FirewallPolicyCommand>),
+    Set(Box<set::FirewallPolicyCommand>),
+    Show(Box<show::FirewallPolicyCommand>),
+}
+
+impl FirewallPolicyCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        session: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        match &self.command {
+            FirewallPolicyCommands::Create(cmd) => cmd.take_action(parsed_args, session).await,
+            FirewallPolicyCommands::Delete(cmd) => cmd.take_action(parsed_args, session).await,
+            FirewallPolicyCommands::InsertRule(cmd) => cmd.take_action(parsed_args, session).await,
+            FirewallPolicyCommands::List(cmd) => cmd.take_action(parsed_args, session).await,
+            FirewallPolicyCommands::RemoveRule(cmd) => cmd.take_action(parsed_args, session).await,
+            FirewallPolicyCommands::Set(cmd) => cmd.take_action(parsed_args, session).await,
+            FirewallPolicyCommands::Show(cmd) => cmd.take_action(parsed_args, session).await,
+        }
+    }
+}
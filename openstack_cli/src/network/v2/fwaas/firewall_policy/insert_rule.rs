@@ -0,0 +1,236 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Insert Rule FirewallPolicy command
+//!
+//! Wraps invoking of the `v2.0/fwaas/firewall_policies/{id}/insert_rule` with `PUT` method
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use anyhow::Result;
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::output::OutputProcessor;
+use crate::Cli;
+use crate::OpenStackCliError;
+use crate::OutputConfig;
+use crate::StructTable;
+
+use crate::common::BoolString;
+use openstack_sdk::api::find;
+use openstack_sdk::api::network::v2::fwaas::firewall_policy::find as find_firewall_policy;
+use openstack_sdk::api::network::v2::fwaas::firewall_policy::insert_rule;
+use openstack_sdk::api::network::v2::fwaas::firewall_rule::find as find_firewall_rule;
+use openstack_sdk::api::QueryAsync;
+use serde_json::Value;
+use structable_derive::StructTable;
+
+/// Inserts a firewall rule in a firewall policy relative to the position of
+/// other rules.
+///
+/// When neither `--insert-after` nor `--insert-before` is given the rule is
+/// inserted at the top of the policy.
+///
+/// Normal response codes: 200
+///
+/// Error response codes: 400, 401, 404, 409
+///
+#[derive(Args)]
+#[command(about = "Insert rule into firewall policy")]
+pub struct FirewallPolicyCommand {
+    /// Request Query parameters
+    #[command(flatten)]
+    query: QueryParameters,
+
+    /// Path parameters
+    #[command(flatten)]
+    path: PathParameters,
+
+    /// Firewall rule (name or ID) to insert into the policy.
+    ///
+    #[arg(
+        help_heading = "Body parameters",
+        long,
+        value_name = "FIREWALL_RULE",
+        visible_alias = "firewall-rule-id"
+    )]
+    firewall_rule: String,
+
+    /// Firewall rule (name or ID) after which the rule is inserted.
+    ///
+    #[arg(
+        conflicts_with = "insert_before",
+        help_heading = "Body parameters",
+        long,
+        value_name = "FIREWALL_RULE"
+    )]
+    insert_after: Option<String>,
+
+    /// Firewall rule (name or ID) before which the rule is inserted.
+    ///
+    #[arg(help_heading = "Body parameters", long, value_name = "FIREWALL_RULE")]
+    insert_before: Option<String>,
+}
+
+/// Query parameters
+#[derive(Args)]
+struct QueryParameters {}
+
+/// Path parameters
+#[derive(Args)]
+struct PathParameters {
+    /// Firewall policy resource name or ID
+    ///
+    #[arg(
+        help_heading = "Path parameters",
+        id = "path_param_id",
+        value_name = "FIREWALL_POLICY"
+    )]
+    id: String,
+}
+/// FirewallPolicy response representation
+#[derive(Deserialize, Serialize, Clone, StructTable)]
+struct ResponseData {
+    /// Each time that the firewall policy or its associated rules are changed,
+    /// the API sets this attribute to `false`. To audit the policy, explicitly
+    /// set this attribute to `true`.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    audited: Option<BoolString>,
+
+    /// A human-readable description for the resource.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    description: Option<String>,
+
+    /// A list of the IDs of the firewall rules associated with the firewall
+    /// policy.
+    ///
+    #[serde()]
+    #[structable(optional, pretty)]
+    firewall_rules: Option<Value>,
+
+    /// The ID of the firewall policy.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    id: Option<String>,
+
+    /// Human-readable name of the resource.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    name: Option<String>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    project_id: Option<String>,
+
+    /// Indicates whether this firewall policy is shared across all projects.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    shared: Option<BoolString>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    tenant_id: Option<String>,
+}
+
+impl FirewallPolicyCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        client: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        info!("Insert Rule FirewallPolicy");
+
+        let op = OutputProcessor::from_args(parsed_args);
+        op.validate_args(parsed_args)?;
+
+        let mut find_builder = find_firewall_policy::Request::builder();
+        find_builder.id(&self.path.id);
+        let find_ep = find_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+        let find_data: serde_json::Value = find(find_ep).query_async(client).await?;
+        let firewall_policy_id = find_data["id"]
+            .as_str()
+            .expect("Resource ID is a string")
+            .to_string();
+
+        let mut find_builder = find_firewall_rule::Request::builder();
+        find_builder.id(&self.firewall_rule);
+        let find_ep = find_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+        let find_data: serde_json::Value = find(find_ep).query_async(client).await?;
+        let firewall_rule_id = find_data["id"]
+            .as_str()
+            .expect("Resource ID is a string")
+            .to_string();
+
+        let mut ep_builder = insert_rule::Request::builder();
+
+        // Set path parameters
+        ep_builder.id(firewall_policy_id);
+        // Set query parameters
+        // Set body parameters
+        ep_builder.firewall_rule_id(firewall_rule_id);
+        if let Some(val) = &self.insert_after {
+            let mut find_builder = find_firewall_rule::Request::builder();
+            find_builder.id(val);
+            let find_ep = find_builder
+                .build()
+                .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+            let find_data: serde_json::Value = find(find_ep).query_async(client).await?;
+            let insert_after = find_data["id"]
+                .as_str()
+                .expect("Resource ID is a string")
+                .to_string();
+            ep_builder.insert_after(insert_after);
+        }
+        if let Some(val) = &self.insert_before {
+            let mut find_builder = find_firewall_rule::Request::builder();
+            find_builder.id(val);
+            let find_ep = find_builder
+                .build()
+                .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+            let find_data: serde_json::Value = find(find_ep).query_async(client).await?;
+            let insert_before = find_data["id"]
+                .as_str()
+                .expect("Resource ID is a string")
+                .to_string();
+            ep_builder.insert_before(insert_before);
+        }
+
+        let ep = ep_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+
+        let data = ep.query_async(client).await?;
+        op.output_single::<ResponseData>(data)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,237 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// WARNING: This file is automatically generated from OpenAPI schema using
+// `openstack-codegenerator`.
+
+//! Set FirewallPolicy command
+//!
+//! Wraps invoking of the `v2.0/fwaas/firewall_policies/{id}` with `PUT` method
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use anyhow::Result;
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::output::OutputProcessor;
+use crate::Cli;
+use crate::OpenStackCliError;
+use crate::OutputConfig;
+use crate::StructTable;
+
+use crate::common::BoolString;
+use openstack_sdk::api::find;
+use openstack_sdk::api::network::v2::fwaas::firewall_policy::find;
+use openstack_sdk::api::network::v2::fwaas::firewall_policy::set;
+use openstack_sdk::api::QueryAsync;
+use serde_json::Value;
+use structable_derive::StructTable;
+
+/// Updates a firewall policy.
+///
+/// Normal response codes: 200
+///
+/// Error response codes: 400, 401, 404
+///
+#[derive(Args)]
+#[command(about = "Update firewall policy")]
+pub struct FirewallPolicyCommand {
+    /// Request Query parameters
+    #[command(flatten)]
+    query: QueryParameters,
+
+    /// Path parameters
+    #[command(flatten)]
+    path: PathParameters,
+
+    /// A `firewall_policy` object.
+    ///
+    #[command(flatten)]
+    firewall_policy: FirewallPolicy,
+}
+
+/// Query parameters
+#[derive(Args)]
+struct QueryParameters {}
+
+/// Path parameters
+#[derive(Args)]
+struct PathParameters {
+    /// id parameter for /v2.0/fwaas/firewall_policies/{id} API
+    ///
+    #[arg(
+        help_heading = "Path parameters",
+        id = "path_param_id",
+        value_name = "ID"
+    )]
+    id: String,
+}
+/// FirewallPolicy Body data
+#[derive(Args, Clone)]
+struct FirewallPolicy {
+    /// Set to `true` to make this firewall policy audited. Each time the
+    /// firewall policy or the associated firewall rules are changed, this
+    /// attribute is set to `false` and must be explicitly set to `true`
+    /// through an update operation. Default is `false`.
+    ///
+    #[arg(action=clap::ArgAction::Set, help_heading = "Body parameters", long)]
+    audited: Option<bool>,
+
+    /// A human-readable description for the resource. Default is an empty
+    /// string.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    description: Option<String>,
+
+    /// A list of the IDs of the firewall rules associated with the firewall
+    /// policy. The order of the rules defines their precedence.
+    ///
+    #[arg(action=clap::ArgAction::Append, help_heading = "Body parameters", long)]
+    firewall_rules: Option<Vec<String>>,
+
+    /// Human-readable name of the resource. Default is an empty string.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    name: Option<String>,
+
+    /// Set to `true` to make this firewall policy visible to other projects.
+    /// Default is `false`.
+    ///
+    #[arg(action=clap::ArgAction::Set, help_heading = "Body parameters", long)]
+    shared: Option<bool>,
+}
+
+/// FirewallPolicy response representation
+#[derive(Deserialize, Serialize, Clone, StructTable)]
+struct ResponseData {
+    /// Each time that the firewall policy or its associated rules are changed,
+    /// the API sets this attribute to `false`. To audit the policy, explicitly
+    /// set this attribute to `true`.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    audited: Option<BoolString>,
+
+    /// A human-readable description for the resource.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    description: Option<String>,
+
+    /// A list of the IDs of the firewall rules associated with the firewall
+    /// policy.
+    ///
+    #[serde()]
+    #[structable(optional, pretty)]
+    firewall_rules: Option<Value>,
+
+    /// The ID of the firewall policy.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    id: Option<String>,
+
+    /// Human-readable name of the resource.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    name: Option<String>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    project_id: Option<String>,
+
+    /// Indicates whether this firewall policy is shared across all projects.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    shared: Option<BoolString>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    tenant_id: Option<String>,
+}
+
+impl FirewallPolicyCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        client: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        info!("Set FirewallPolicy");
+
+        let op = OutputProcessor::from_args(parsed_args);
+        op.validate_args(parsed_args)?;
+
+        let mut find_builder = find::Request::builder();
+
+        find_builder.id(&self.path.id);
+        let find_ep = find_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+        let find_data: serde_json::Value = find(find_ep).query_async(client).await?;
+
+        let mut ep_builder = set::Request::builder();
+
+        // Set path parameters
+        let resource_id = find_data["id"]
+            .as_str()
+            .expect("Resource ID is a string")
+            .to_string();
+        ep_builder.id(resource_id.clone());
+        // Set query parameters
+        // Set body parameters
+        // Set Request.firewall_policy data
+        let args = &self.firewall_policy;
+        let mut firewall_policy_builder = set::FirewallPolicyBuilder::default();
+        if let Some(val) = &args.audited {
+            firewall_policy_builder.audited(*val);
+        }
+
+        if let Some(val) = &args.description {
+            firewall_policy_builder.description(val);
+        }
+
+        if let Some(val) = &args.firewall_rules {
+            firewall_policy_builder
+                .firewall_rules(val.iter().map(|v| v.into()).collect::<Vec<_>>());
+        }
+
+        if let Some(val) = &args.name {
+            firewall_policy_builder.name(val);
+        }
+
+        if let Some(val) = &args.shared {
+            firewall_policy_builder.shared(*val);
+        }
+
+        ep_builder.firewall_policy(firewall_policy_builder.build().unwrap());
+
+        let ep = ep_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+
+        let data = ep.query_async(client).await?;
+        op.output_single::<ResponseData>(data)?;
+        Ok(())
+    }
+}
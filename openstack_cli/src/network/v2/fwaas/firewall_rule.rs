@@ -0,0 +1,66 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! FirewallRule resource commands
+
+use clap::{Parser, Subcommand};
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::{Cli, OpenStackCliError};
+
+mod create;
+mod delete;
+mod list;
+mod set;
+mod show;
+
+/// Firewall rules
+///
+/// A firewall rule matches traffic by protocol, addresses and ports and
+/// defines the action taken on it when it is part of a firewall policy.
+#[derive(Parser)]
+pub struct FirewallRuleCommand {
+    /// subcommand
+    #[command(subcommand)]
+    command: FirewallRuleCommands,
+}
+
+/// Supported subcommands
+#[allow(missing_docs)]
+#[derive(Subcommand)]
+pub enum FirewallRuleCommands {
+    Create(Box<create::FirewallRuleCommand>),
+    Delete(Box<delete::FirewallRuleCommand>),
+    List(Box<list::FirewallRulesCommand>),
+    Set(Box<set::FirewallRuleCommand>),
+    Show(Box<show::FirewallRuleCommand>),
+}
+
+impl FirewallRuleCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        session: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        match &self.command {
+            FirewallRuleCommands::Create(cmd) => cmd.take_action(parsed_args, session).await,
+            FirewallRuleCommands::Delete(cmd) => cmd.take_action(parsed_args, session).await,
+            FirewallRuleCommands::List(cmd) => cmd.take_action(parsed_args, session).await,
+            FirewallRuleCommands::Set(cmd) => cmd.take_action(parsed_args, session).await,
+            FirewallRuleCommands::Show(cmd) => cmd.take_action(parsed_args, session).await,
+        }
+    }
+}
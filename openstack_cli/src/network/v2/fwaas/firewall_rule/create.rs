@@ -0,0 +1,373 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// WARNING: This file is automatically generated from OpenAPI schema using
+// `openstack-codegenerator`.
+
+//! Create FirewallRule command
+//!
+//! Wraps invoking of the `v2.0/fwaas/firewall_rules` with `POST` method
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use anyhow::Result;
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::output::OutputProcessor;
+use crate::Cli;
+use crate::OpenStackCliError;
+use crate::OutputConfig;
+use crate::StructTable;
+
+use crate::common::BoolString;
+use clap::ValueEnum;
+use openstack_sdk::api::network::v2::fwaas::firewall_rule::create;
+use openstack_sdk::api::QueryAsync;
+use serde_json::Value;
+use structable_derive::StructTable;
+
+/// Creates a firewall rule.
+///
+/// Normal response codes: 201
+///
+/// Error response codes: 400, 401
+///
+#[derive(Args)]
+#[command(about = "Create firewall rule")]
+pub struct FirewallRuleCommand {
+    /// Request Query parameters
+    #[command(flatten)]
+    query: QueryParameters,
+
+    /// Path parameters
+    #[command(flatten)]
+    path: PathParameters,
+
+    /// A `firewall_rule` object.
+    ///
+    #[command(flatten)]
+    firewall_rule: FirewallRule,
+}
+
+/// Query parameters
+#[derive(Args)]
+struct QueryParameters {}
+
+/// Path parameters
+#[derive(Args)]
+struct PathParameters {}
+#[derive(Clone, Eq, Ord, PartialEq, PartialOrd, ValueEnum)]
+enum Action {
+    Allow,
+    Deny,
+    Reject,
+}
+
+/// FirewallRule Body data
+#[derive(Args, Clone)]
+struct FirewallRule {
+    /// The action that the API performs on traffic that matches the firewall
+    /// rule. Valid values are `allow`, `deny` or `reject`. Default is `deny`.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    action: Option<Action>,
+
+    /// A human-readable description for the resource. Default is an empty
+    /// string.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    description: Option<String>,
+
+    /// The ID of the remote destination firewall group.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    destination_firewall_group_id: Option<String>,
+
+    /// The destination IPv4 or IPv6 address or CIDR to which the firewall rule
+    /// is applied.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    destination_ip_address: Option<String>,
+
+    /// The destination port or port range to which the firewall rule is
+    /// applied. A valid value is a port number, as an integer, or a port
+    /// range, in the format of a `:` separated range. In the case of a port
+    /// range, both ends of the range are included.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    destination_port: Option<String>,
+
+    /// Set to `false` to disable this rule in the firewall policy. Facilitates
+    /// selectively turning off rules without having to disassociate the rule
+    /// from the firewall policy. Default is `true`.
+    ///
+    #[arg(action=clap::ArgAction::Set, help_heading = "Body parameters", long)]
+    enabled: Option<bool>,
+
+    /// The IP protocol version for the firewall rule. Valid values are `4` or
+    /// `6`. Default is `4`.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    ip_version: Option<i32>,
+
+    /// Human-readable name of the resource. Default is an empty string.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    name: Option<String>,
+
+    /// The IP protocol for the firewall rule. Valid values are `icmp`, `tcp`,
+    /// `udp`, `null` or a protocol number.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    protocol: Option<String>,
+
+    /// Set to `true` to make this firewall rule visible to other projects.
+    /// Default is `false`.
+    ///
+    #[arg(action=clap::ArgAction::Set, help_heading = "Body parameters", long)]
+    shared: Option<bool>,
+
+    /// The ID of the remote source firewall group.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    source_firewall_group_id: Option<String>,
+
+    /// The source IPv4 or IPv6 address or CIDR to which the firewall rule is
+    /// applied.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    source_ip_address: Option<String>,
+
+    /// The source port or port range to which the firewall rule is applied. A
+    /// valid value is a port number, as an integer, or a port range, in the
+    /// format of a `:` separated range. In the case of a port range, both ends
+    /// of the range are included.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    source_port: Option<String>,
+
+    /// The ID of the project that owns the resource. Only administrative and
+    /// users with advsvc role can specify a project ID other than their own.
+    /// You cannot change this value through authorization policies.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    tenant_id: Option<String>,
+}
+
+/// FirewallRule response representation
+#[derive(Deserialize, Serialize, Clone, StructTable)]
+struct ResponseData {
+    /// The action that the API performs on traffic that matches the firewall
+    /// rule. Valid values are `allow`, `deny` or `reject`.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    action: Option<String>,
+
+    /// A human-readable description for the resource.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    description: Option<String>,
+
+    /// The ID of the remote destination firewall group.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    destination_firewall_group_id: Option<String>,
+
+    /// The destination IPv4 or IPv6 address or CIDR to which the firewall rule
+    /// is applied.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    destination_ip_address: Option<String>,
+
+    /// The destination port or port range to which the firewall rule is
+    /// applied.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    destination_port: Option<String>,
+
+    /// Indicates whether this firewall rule is enabled or not.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    enabled: Option<BoolString>,
+
+    /// The ID of the firewall policy.
+    ///
+    #[serde()]
+    #[structable(optional, pretty)]
+    firewall_policy_id: Option<Value>,
+
+    /// The ID of the firewall rule.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    id: Option<String>,
+
+    /// The IP protocol version for the firewall rule. Valid values are `4` or
+    /// `6`.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    ip_version: Option<i32>,
+
+    /// Human-readable name of the resource.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    name: Option<String>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    project_id: Option<String>,
+
+    /// The IP protocol for the firewall rule.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    protocol: Option<String>,
+
+    /// Indicates whether this firewall rule is shared across all projects.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    shared: Option<BoolString>,
+
+    /// The ID of the remote source firewall group.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    source_firewall_group_id: Option<String>,
+
+    /// The source IPv4 or IPv6 address or CIDR to which the firewall rule is
+    /// applied.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    source_ip_address: Option<String>,
+
+    /// The source port or port range to which the firewall rule is applied.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    source_port: Option<String>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    tenant_id: Option<String>,
+}
+
+impl FirewallRuleCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        client: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        info!("Create FirewallRule");
+
+        let op = OutputProcessor::from_args(parsed_args);
+        op.validate_args(parsed_args)?;
+
+        let mut ep_builder = create::Request::builder();
+
+        // Set path parameters
+        // Set query parameters
+        // Set body parameters
+        // Set Request.firewall_rule data
+        let args = &self.firewall_rule;
+        let mut firewall_rule_builder = create::FirewallRuleBuilder::default();
+        if let Some(val) = &args.action {
+            let tmp = match val {
+                Action::Allow => create::Action::Allow,
+                Action::Deny => create::Action::Deny,
+                Action::Reject => create::Action::Reject,
+            };
+            firewall_rule_builder.action(tmp);
+        }
+
+        if let Some(val) = &args.description {
+            firewall_rule_builder.description(val);
+        }
+
+        if let Some(val) = &args.destination_firewall_group_id {
+            firewall_rule_builder.destination_firewall_group_id(val);
+        }
+
+        if let Some(val) = &args.destination_ip_address {
+            firewall_rule_builder.destination_ip_address(val);
+        }
+
+        if let Some(val) = &args.destination_port {
+            firewall_rule_builder.destination_port(val);
+        }
+
+        if let Some(val) = &args.enabled {
+            firewall_rule_builder.enabled(*val);
+        }
+
+        if let Some(val) = &args.ip_version {
+            firewall_rule_builder.ip_version(*val);
+        }
+
+        if let Some(val) = &args.name {
+            firewall_rule_builder.name(val);
+        }
+
+        if let Some(val) = &args.protocol {
+            firewall_rule_builder.protocol(Some(val.into()));
+        }
+
+        if let Some(val) = &args.shared {
+            firewall_rule_builder.shared(*val);
+        }
+
+        if let Some(val) = &args.source_firewall_group_id {
+            firewall_rule_builder.source_firewall_group_id(val);
+        }
+
+        if let Some(val) = &args.source_ip_address {
+            firewall_rule_builder.source_ip_address(val);
+        }
+
+        if let Some(val) = &args.source_port {
+            firewall_rule_builder.source_port(val);
+        }
+
+        if let Some(val) = &args.tenant_id {
+            firewall_rule_builder.tenant_id(val);
+        }
+
+        ep_builder.firewall_rule(firewall_rule_builder.build().unwrap());
+
+        let ep = ep_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+
+        let data = ep.query_async(client).await?;
+        op.output_single::<ResponseData>(data)?;
+        Ok(())
+    }
+}
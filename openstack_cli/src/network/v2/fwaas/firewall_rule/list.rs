@@ -0,0 +1,368 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// WARNING: This file is automatically generated from OpenAPI schema using
+// `openstack-codegenerator`.
+
+//! List FirewallRules command
+//!
+//! Wraps invoking of the `v2.0/fwaas/firewall_rules` with `GET` method
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use anyhow::Result;
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::output::OutputProcessor;
+use crate::Cli;
+use crate::OpenStackCliError;
+use crate::OutputConfig;
+use crate::StructTable;
+
+use crate::common::BoolString;
+use openstack_sdk::api::network::v2::fwaas::firewall_rule::list;
+use openstack_sdk::api::{paged, Pagination};
+use serde_json::Value;
+use structable_derive::StructTable;
+
+/// Lists firewall rules that belong to a project.
+///
+/// Additionally, you can filter results by using query string parameters. For
+/// information, see
+/// [Filtering and Column Selection](https://wiki.openstack.org/wiki/Neutron/APIv2-specification#Filtering_and_Column_Selection).
+///
+/// Use the `fields` query parameter to control which fields are returned in
+/// the response body. For information, see
+/// [Filtering and Column Selection](https://wiki.openstack.org/wiki/Neutron/APIv2-specification#Filtering_and_Column_Selection).
+///
+/// Normal response codes: 200
+///
+/// Error response codes: 401
+///
+#[derive(Args)]
+#[command(about = "List firewall rules")]
+pub struct FirewallRulesCommand {
+    /// Request Query parameters
+    #[command(flatten)]
+    query: QueryParameters,
+
+    /// Path parameters
+    #[command(flatten)]
+    path: PathParameters,
+
+    /// Total limit of entities count to return. Use this when there are too many entries.
+    #[arg(long, default_value_t = 10000)]
+    max_items: usize,
+}
+
+/// Query parameters
+#[derive(Args)]
+struct QueryParameters {
+    /// action query parameter for /v2.0/fwaas/firewall_rules API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    action: Option<String>,
+
+    /// description query parameter for /v2.0/fwaas/firewall_rules API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    description: Option<String>,
+
+    /// destination_firewall_group_id query parameter for /v2.0/fwaas/firewall_rules API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    destination_firewall_group_id: Option<String>,
+
+    /// destination_ip_address query parameter for /v2.0/fwaas/firewall_rules API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    destination_ip_address: Option<String>,
+
+    /// destination_port query parameter for /v2.0/fwaas/firewall_rules API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    destination_port: Option<String>,
+
+    /// enabled query parameter for /v2.0/fwaas/firewall_rules API
+    ///
+    #[arg(action=clap::ArgAction::Set, help_heading = "Query parameters", long)]
+    enabled: Option<bool>,
+
+    /// id query parameter for /v2.0/fwaas/firewall_rules API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    id: Option<String>,
+
+    /// ip_version query parameter for /v2.0/fwaas/firewall_rules API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    ip_version: Option<i32>,
+
+    /// Requests a page size of items. Returns a number of items up to a limit
+    /// value. Use the limit parameter to make an initial limited request and
+    /// use the ID of the last-seen item from the response as the marker
+    /// parameter value in a subsequent limited request.
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    limit: Option<i32>,
+
+    /// The ID of the last-seen item. Use the limit parameter to make an
+    /// initial limited request and use the ID of the last-seen item from the
+    /// response as the marker parameter value in a subsequent limited
+    /// request.
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    marker: Option<String>,
+
+    /// name query parameter for /v2.0/fwaas/firewall_rules API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    name: Option<String>,
+
+    /// project_id query parameter for /v2.0/fwaas/firewall_rules API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    project_id: Option<String>,
+
+    /// protocol query parameter for /v2.0/fwaas/firewall_rules API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    protocol: Option<String>,
+
+    /// shared query parameter for /v2.0/fwaas/firewall_rules API
+    ///
+    #[arg(action=clap::ArgAction::Set, help_heading = "Query parameters", long)]
+    shared: Option<bool>,
+
+    /// source_firewall_group_id query parameter for /v2.0/fwaas/firewall_rules API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    source_firewall_group_id: Option<String>,
+
+    /// source_ip_address query parameter for /v2.0/fwaas/firewall_rules API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    source_ip_address: Option<String>,
+
+    /// source_port query parameter for /v2.0/fwaas/firewall_rules API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    source_port: Option<String>,
+
+    /// tenant_id query parameter for /v2.0/fwaas/firewall_rules API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    tenant_id: Option<String>,
+}
+
+/// Path parameters
+#[derive(Args)]
+struct PathParameters {}
+/// FirewallRules response representation
+#[derive(Deserialize, Serialize, Clone, StructTable)]
+struct ResponseData {
+    /// The action that the API performs on traffic that matches the firewall
+    /// rule. Valid values are `allow`, `deny` or `reject`.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    action: Option<String>,
+
+    /// A human-readable description for the resource.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    description: Option<String>,
+
+    /// The ID of the remote destination firewall group.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    destination_firewall_group_id: Option<String>,
+
+    /// The destination IPv4 or IPv6 address or CIDR to which the firewall rule
+    /// is applied.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    destination_ip_address: Option<String>,
+
+    /// The destination port or port range to which the firewall rule is
+    /// applied.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    destination_port: Option<String>,
+
+    /// Indicates whether this firewall rule is enabled or not.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    enabled: Option<BoolString>,
+
+    /// The ID of the firewall policy.
+    ///
+    #[serde()]
+    #[structable(optional, pretty, wide)]
+    firewall_policy_id: Option<Value>,
+
+    /// The ID of the firewall rule.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    id: Option<String>,
+
+    /// The IP protocol version for the firewall rule. Valid values are `4` or
+    /// `6`.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    ip_version: Option<i32>,
+
+    /// Human-readable name of the resource.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    name: Option<String>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    project_id: Option<String>,
+
+    /// The IP protocol for the firewall rule.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    protocol: Option<String>,
+
+    /// Indicates whether this firewall rule is shared across all projects.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    shared: Option<BoolString>,
+
+    /// The ID of the remote source firewall group.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    source_firewall_group_id: Option<String>,
+
+    /// The source IPv4 or IPv6 address or CIDR to which the firewall rule is
+    /// applied.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    source_ip_address: Option<String>,
+
+    /// The source port or port range to which the firewall rule is applied.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    source_port: Option<String>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    tenant_id: Option<String>,
+}
+
+impl FirewallRulesCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        client: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        info!("List FirewallRules");
+
+        let op = OutputProcessor::from_args(parsed_args);
+        op.validate_args(parsed_args)?;
+
+        let mut ep_builder = list::Request::builder();
+
+        // Set path parameters
+        // Set query parameters
+        if let Some(val) = &self.query.limit {
+            ep_builder.limit(*val);
+        }
+        if let Some(val) = &self.query.marker {
+            ep_builder.marker(val);
+        }
+        if let Some(val) = &self.query.action {
+            ep_builder.action(val);
+        }
+        if let Some(val) = &self.query.description {
+            ep_builder.description(val);
+        }
+        if let Some(val) = &self.query.destination_firewall_group_id {
+            ep_builder.destination_firewall_group_id(val);
+        }
+        if let Some(val) = &self.query.destination_ip_address {
+            ep_builder.destination_ip_address(val);
+        }
+        if let Some(val) = &self.query.destination_port {
+            ep_builder.destination_port(val);
+        }
+        if let Some(val) = &self.query.enabled {
+            ep_builder.enabled(*val);
+        }
+        if let Some(val) = &self.query.id {
+            ep_builder.id(val);
+        }
+        if let Some(val) = &self.query.ip_version {
+            ep_builder.ip_version(*val);
+        }
+        if let Some(val) = &self.query.name {
+            ep_builder.name(val);
+        }
+        if let Some(val) = &self.query.project_id {
+            ep_builder.project_id(val);
+        }
+        if let Some(val) = &self.query.protocol {
+            ep_builder.protocol(val);
+        }
+        if let Some(val) = &self.query.shared {
+            ep_builder.shared(*val);
+        }
+        if let Some(val) = &self.query.source_firewall_group_id {
+            ep_builder.source_firewall_group_id(val);
+        }
+        if let Some(val) = &self.query.source_ip_address {
+            ep_builder.source_ip_address(val);
+        }
+        if let Some(val) = &self.query.source_port {
+            ep_builder.source_port(val);
+        }
+        if let Some(val) = &self.query.tenant_id {
+            ep_builder.tenant_id(val);
+        }
+        // Set body parameters
+
+        let ep = ep_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+
+        let ep = paged(ep, Pagination::Limit(self.max_items)).prefetch(true);
+
+        op.output_list_stream::<ResponseData, _, _>(ep.iter_async(client))
+            .await?;
+        Ok(())
+    }
+}
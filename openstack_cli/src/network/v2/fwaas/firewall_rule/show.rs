@@ -0,0 +1,215 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// WARNING: This file is automatically generated from OpenAPI schema using
+// `openstack-codegenerator`.
+
+//! Show FirewallRule command
+//!
+//! Wraps invoking of the `v2.0/fwaas/firewall_rules/{id}` with `GET` method
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use anyhow::Result;
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::output::OutputProcessor;
+use crate::Cli;
+use crate::OpenStackCliError;
+use crate::OutputConfig;
+use crate::StructTable;
+
+use crate::common::BoolString;
+use openstack_sdk::api::find;
+use openstack_sdk::api::network::v2::fwaas::firewall_rule::find;
+use openstack_sdk::api::QueryAsync;
+use serde_json::Value;
+use structable_derive::StructTable;
+
+/// Shows details for a firewall rule.
+///
+/// Use the `fields` query parameter to control which fields are returned in
+/// the response body. For information, see
+/// [Filtering and Column Selection](https://wiki.openstack.org/wiki/Neutron/APIv2-specification#Filtering_and_Column_Selection).
+///
+/// Normal response codes: 200
+///
+/// Error response codes: 401, 404
+///
+#[derive(Args)]
+#[command(about = "Show firewall rule details")]
+pub struct FirewallRuleCommand {
+    /// Request Query parameters
+    #[command(flatten)]
+    query: QueryParameters,
+
+    /// Path parameters
+    #[command(flatten)]
+    path: PathParameters,
+}
+
+/// Query parameters
+#[derive(Args)]
+struct QueryParameters {}
+
+/// Path parameters
+#[derive(Args)]
+struct PathParameters {
+    /// id parameter for /v2.0/fwaas/firewall_rules/{id} API
+    ///
+    #[arg(
+        help_heading = "Path parameters",
+        id = "path_param_id",
+        value_name = "ID"
+    )]
+    id: String,
+}
+/// FirewallRule response representation
+#[derive(Deserialize, Serialize, Clone, StructTable)]
+struct ResponseData {
+    /// The action that the API performs on traffic that matches the firewall
+    /// rule. Valid values are `allow`, `deny` or `reject`.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    action: Option<String>,
+
+    /// A human-readable description for the resource.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    description: Option<String>,
+
+    /// The ID of the remote destination firewall group.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    destination_firewall_group_id: Option<String>,
+
+    /// The destination IPv4 or IPv6 address or CIDR to which the firewall rule
+    /// is applied.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    destination_ip_address: Option<String>,
+
+    /// The destination port or port range to which the firewall rule is
+    /// applied.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    destination_port: Option<String>,
+
+    /// Indicates whether this firewall rule is enabled or not.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    enabled: Option<BoolString>,
+
+    /// The ID of the firewall policy.
+    ///
+    #[serde()]
+    #[structable(optional, pretty)]
+    firewall_policy_id: Option<Value>,
+
+    /// The ID of the firewall rule.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    id: Option<String>,
+
+    /// The IP protocol version for the firewall rule. Valid values are `4` or
+    /// `6`.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    ip_version: Option<i32>,
+
+    /// Human-readable name of the resource.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    name: Option<String>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    project_id: Option<String>,
+
+    /// The IP protocol for the firewall rule.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    protocol: Option<String>,
+
+    /// Indicates whether this firewall rule is shared across all projects.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    shared: Option<BoolString>,
+
+    /// The ID of the remote source firewall group.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    source_firewall_group_id: Option<String>,
+
+    /// The source IPv4 or IPv6 address or CIDR to which the firewall rule is
+    /// applied.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    source_ip_address: Option<String>,
+
+    /// The source port or port range to which the firewall rule is applied.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    source_port: Option<String>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    tenant_id: Option<String>,
+}
+
+impl FirewallRuleCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        client: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        info!("Show FirewallRule");
+
+        let op = OutputProcessor::from_args(parsed_args);
+        op.validate_args(parsed_args)?;
+
+        let mut find_builder = find::Request::builder();
+
+        find_builder.id(&self.path.id);
+        let find_ep = find_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+        let find_data: serde_json::Value = find(find_ep).query_async(client).await?;
+
+        op.output_single::<ResponseData>(find_data)?;
+        Ok(())
+    }
+}
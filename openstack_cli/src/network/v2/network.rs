@@ -16,16 +16,55 @@
 
 use clap::{Parser, Subcommand};
 
+use openstack_sdk::types::network::v2::Network;
 use openstack_sdk::AsyncOpenStack;
 
+use crate::network::v2::tag::{impl_tagged_resource, TagCommand};
+use crate::output::impl_struct_table;
 use crate::{Cli, OpenStackCliError};
 
 mod create;
 mod delete;
 mod dhcp_agent;
 mod list;
+mod set;
 mod show;
-mod tag;
+
+impl_struct_table!(
+    Network,
+    ["created_at", "id", "name", "status", "updated_at"],
+    [
+        "admin_state_up",
+        "availability_zone_hints",
+        "availability_zones",
+        "description",
+        "dns_domain",
+        "ipv4_address_scope",
+        "ipv6_address_scope",
+        "is_default",
+        "l2_adjacency",
+        "mtu",
+        "port_security_enabled",
+        "provider:network_type",
+        "provider:physical_network",
+        "provider:segmentation_id",
+        "qos_policy_id",
+        "revision_number",
+        "router:external",
+        "segments",
+        "shared",
+        "subnets",
+        "tags",
+        "tenant_id"
+    ]
+);
+
+impl_tagged_resource!(
+    TaggedNetwork,
+    openstack_sdk::api::network::v2::network::find::Request<'static>,
+    "networks",
+    "NETWORK"
+);
 
 /// Network commands
 #[derive(Parser)]
@@ -43,8 +82,9 @@ pub enum NetworkCommands {
     Delete(Box<delete::NetworkCommand>),
     DhcpAgent(Box<dhcp_agent::DhcpAgentCommand>),
     List(Box<list::NetworksCommand>),
+    Set(Box<set::NetworkCommand>),
     Show(Box<show::NetworkCommand>),
-    Tag(Box<tag::TagCommand>),
+    Tag(Box<TagCommand<TaggedNetwork>>),
 }
 
 impl NetworkCommand {
@@ -59,6 +99,7 @@ impl NetworkCommand {
             NetworkCommands::Delete(cmd) => cmd.take_action(parsed_args, session).await,
             NetworkCommands::DhcpAgent(cmd) => cmd.take_action(parsed_args, session).await,
             NetworkCommands::List(cmd) => cmd.take_action(parsed_args, session).await,
+            NetworkCommands::Set(cmd) => cmd.take_action(parsed_args, session).await,
             NetworkCommands::Show(cmd) => cmd.take_action(parsed_args, session).await,
             NetworkCommands::Tag(cmd) => cmd.take_action(parsed_args, session).await,
         }
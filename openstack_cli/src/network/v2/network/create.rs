@@ -163,6 +163,17 @@ struct Network {
     ///
     #[arg(help_heading = "Body parameters", long)]
     tenant_id: Option<String>,
+
+    /// Tag to set on the resource. Repeat the option to set multiple tags.
+    ///
+    #[arg(
+        action=clap::ArgAction::Append,
+        help_heading = "Body parameters",
+        long = "tag",
+        value_name = "TAG",
+        visible_alias = "tags"
+    )]
+    tags: Option<Vec<String>>,
 }
 
 /// Network response representation
@@ -429,6 +440,10 @@ impl NetworkCommand {
             network_builder.description(val);
         }
 
+        if let Some(val) = &args.tags {
+            network_builder.tags(val.iter().map(|v| v.into()).collect::<Vec<_>>());
+        }
+
         ep_builder.network(network_builder.build().unwrap());
 
         let ep = ep_builder
@@ -20,7 +20,6 @@
 //! Wraps invoking of the `v2.0/networks` with `GET` method
 
 use clap::Args;
-use serde::{Deserialize, Serialize};
 use tracing::info;
 
 use anyhow::Result;
@@ -30,15 +29,10 @@ use openstack_sdk::AsyncOpenStack;
 use crate::output::OutputProcessor;
 use crate::Cli;
 use crate::OpenStackCliError;
-use crate::OutputConfig;
-use crate::StructTable;
 
-use crate::common::BoolString;
-use crate::common::IntString;
 use openstack_sdk::api::network::v2::network::list;
-use openstack_sdk::api::QueryAsync;
-use serde_json::Value;
-use structable_derive::StructTable;
+use openstack_sdk::api::{paged, Pagination};
+use openstack_sdk::types::network::v2::Network;
 
 /// Lists networks to which the project has access.
 ///
@@ -70,6 +64,10 @@ pub struct NetworksCommand {
     /// Path parameters
     #[command(flatten)]
     path: PathParameters,
+
+    /// Total limit of entities count to return. Use this when there are too many entries.
+    #[arg(long, default_value_t = 10000)]
+    max_items: usize,
 }
 
 /// Query parameters
@@ -95,6 +93,22 @@ struct QueryParameters {
     #[arg(action=clap::ArgAction::Set, help_heading = "Query parameters", long)]
     is_default: Option<bool>,
 
+    /// Requests a page size of items. Returns a number of items up to a limit
+    /// value. Use the limit parameter to make an initial limited request and
+    /// use the ID of the last-seen item from the response as the marker
+    /// parameter value in a subsequent limited request.
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    limit: Option<i32>,
+
+    /// The ID of the last-seen item. Use the limit parameter to make an
+    /// initial limited request and use the ID of the last-seen item from the
+    /// response as the marker parameter value in a subsequent limited
+    /// request.
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    marker: Option<String>,
+
     /// mtu query parameter for /v2.0/networks API
     ///
     #[arg(help_heading = "Query parameters", long)]
@@ -169,176 +183,6 @@ struct QueryParameters {
 /// Path parameters
 #[derive(Args)]
 struct PathParameters {}
-/// Networks response representation
-#[derive(Deserialize, Serialize, Clone, StructTable)]
-struct ResponseData {
-    /// The administrative state of the network, which is up (`true`) or down
-    /// (`false`).
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    admin_state_up: Option<BoolString>,
-
-    /// The availability zone candidate for the network.
-    ///
-    #[serde()]
-    #[structable(optional, pretty, wide)]
-    availability_zone_hints: Option<Value>,
-
-    /// The availability zone for the network.
-    ///
-    #[serde()]
-    #[structable(optional, pretty, wide)]
-    availability_zones: Option<Value>,
-
-    /// Time at which the resource has been created (in UTC ISO8601 format).
-    ///
-    #[serde()]
-    #[structable(optional)]
-    created_at: Option<String>,
-
-    /// A human-readable description for the resource.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    description: Option<String>,
-
-    /// A valid DNS domain.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    dns_domain: Option<String>,
-
-    /// The ID of the network.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    id: Option<String>,
-
-    /// The ID of the IPv4 address scope that the network is associated with.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    ipv4_address_scope: Option<String>,
-
-    /// The ID of the IPv6 address scope that the network is associated with.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    ipv6_address_scope: Option<String>,
-
-    /// The network is default pool or not.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    is_default: Option<BoolString>,
-
-    /// Indicates whether L2 connectivity is available throughout the
-    /// `network`.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    l2_adjacency: Option<String>,
-
-    /// The maximum transmission unit (MTU) value to address fragmentation.
-    /// Minimum value is 68 for IPv4, and 1280 for IPv6.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    mtu: Option<i32>,
-
-    /// Human-readable name of the network.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    name: Option<String>,
-
-    /// The port security status of the network. Valid values are enabled
-    /// (`true`) and disabled (`false`). This value is used as the default
-    /// value of `port_security_enabled` field of a newly created port.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    port_security_enabled: Option<BoolString>,
-
-    #[serde(rename = "provider:network_type")]
-    #[structable(optional, title = "provider:network_type", wide)]
-    provider_network_type: Option<String>,
-
-    #[serde(rename = "provider:physical_network")]
-    #[structable(optional, title = "provider:physical_network", wide)]
-    provider_physical_network: Option<String>,
-
-    #[serde(rename = "provider:segmentation_id")]
-    #[structable(optional, title = "provider:segmentation_id", wide)]
-    provider_segmentation_id: Option<IntString>,
-
-    /// The ID of the QoS policy associated with the network.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    qos_policy_id: Option<String>,
-
-    /// The revision number of the resource.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    revision_number: Option<i32>,
-
-    /// Defines whether the network may be used for creation of floating IPs.
-    /// Only networks with this flag may be an external gateway for routers.
-    /// The network must have an external routing facility that is not managed
-    /// by the networking service. If the network is updated from external to
-    /// internal the unused floating IPs of this network are automatically
-    /// deleted when extension `floatingip-autodelete-internal` is present.
-    ///
-    #[serde(rename = "router:external")]
-    #[structable(optional, title = "router:external", wide)]
-    router_external: Option<BoolString>,
-
-    /// A list of provider `segment` objects.
-    ///
-    #[serde()]
-    #[structable(optional, pretty, wide)]
-    segments: Option<Value>,
-
-    /// Indicates whether this network is shared across all tenants. By
-    /// default, only administrative users can change this value.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    shared: Option<BoolString>,
-
-    /// The network status. Values are `ACTIVE`, `DOWN`, `BUILD` or `ERROR`.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    status: Option<String>,
-
-    /// The associated subnets.
-    ///
-    #[serde()]
-    #[structable(optional, pretty, wide)]
-    subnets: Option<Value>,
-
-    /// The list of tags on the resource.
-    ///
-    #[serde()]
-    #[structable(optional, pretty, wide)]
-    tags: Option<Value>,
-
-    /// The ID of the project.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    tenant_id: Option<String>,
-
-    /// Time at which the resource has been updated (in UTC ISO8601 format).
-    ///
-    #[serde()]
-    #[structable(optional)]
-    updated_at: Option<String>,
-}
 
 impl NetworksCommand {
     /// Perform command action
@@ -356,6 +200,12 @@ impl NetworksCommand {
 
         // Set path parameters
         // Set query parameters
+        if let Some(val) = &self.query.limit {
+            ep_builder.limit(*val);
+        }
+        if let Some(val) = &self.query.marker {
+            ep_builder.marker(val);
+        }
         if let Some(val) = &self.query.id {
             ep_builder.id(val);
         }
@@ -416,9 +266,10 @@ impl NetworksCommand {
             .build()
             .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
 
-        let data: Vec<serde_json::Value> = ep.query_async(client).await?;
+        let ep = paged(ep, Pagination::Limit(self.max_items)).prefetch(true);
 
-        op.output_list::<ResponseData>(data)?;
+        op.output_list_stream::<Network, _, _>(ep.iter_async(client))
+            .await?;
         Ok(())
     }
 }
@@ -36,9 +36,12 @@ use crate::StructTable;
 use crate::common::parse_json;
 use crate::common::BoolString;
 use crate::common::IntString;
+use openstack_sdk::api::conditional;
 use openstack_sdk::api::find;
 use openstack_sdk::api::network::v2::network::find;
 use openstack_sdk::api::network::v2::network::set;
+use openstack_sdk::api::network::v2::qos::policy::find as find_qos_policy;
+use openstack_sdk::api::Precondition;
 use openstack_sdk::api::QueryAsync;
 use serde_json::Value;
 use structable_derive::StructTable;
@@ -64,6 +67,12 @@ pub struct NetworkCommand {
     ///
     #[command(flatten)]
     network: Network,
+
+    /// Only update the resource when its current `revision_number` matches
+    /// the given value.
+    ///
+    #[arg(help_heading = "Header parameters", long, value_name = "REVISION")]
+    if_revision: Option<u64>,
 }
 
 /// Query parameters
@@ -134,11 +143,25 @@ struct Network {
     #[arg(help_heading = "Body parameters", long)]
     provider_segmentation_id: Option<String>,
 
-    /// The ID of the QoS policy associated with the network.
+    /// The QoS policy (name or ID) associated with the network.
     ///
-    #[arg(help_heading = "Body parameters", long)]
+    #[arg(
+        help_heading = "Body parameters",
+        long,
+        value_name = "QOS_POLICY",
+        visible_alias = "qos-policy"
+    )]
     qos_policy_id: Option<String>,
 
+    /// Detach the QoS policy from the network.
+    ///
+    #[arg(
+        help_heading = "Body parameters",
+        long,
+        conflicts_with = "qos_policy_id"
+    )]
+    no_qos_policy: bool,
+
     /// Indicates whether the network has an external routing facility that’s
     /// not managed by the networking service.
     ///
@@ -406,7 +429,19 @@ impl NetworkCommand {
         }
 
         if let Some(val) = &args.qos_policy_id {
-            network_builder.qos_policy_id(Some(val.into()));
+            let mut find_builder = find_qos_policy::Request::builder();
+            find_builder.id(val);
+            let find_ep = find_builder
+                .build()
+                .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+            let qos_policy: serde_json::Value = find(find_ep).query_async(client).await?;
+            let qos_policy_id = qos_policy["id"]
+                .as_str()
+                .expect("Resource ID is a string")
+                .to_string();
+            network_builder.qos_policy_id(Some(qos_policy_id.into()));
+        } else if args.no_qos_policy {
+            network_builder.qos_policy_id(None);
         }
 
         if let Some(val) = &args.is_default {
@@ -427,7 +462,14 @@ impl NetworkCommand {
             .build()
             .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
 
-        let data = ep.query_async(client).await?;
+        let data = match self.if_revision {
+            Some(revision) => {
+                conditional(ep, Precondition::RevisionNumber(revision))
+                    .query_async(client)
+                    .await?
+            }
+            None => ep.query_async(client).await?,
+        };
         op.output_single::<ResponseData>(data)?;
         Ok(())
     }
@@ -0,0 +1,57 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! NetworkIpAvailability resource commands
+
+use clap::{Parser, Subcommand};
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::{Cli, OpenStackCliError};
+
+mod list;
+mod show;
+
+/// Network IP availability
+///
+/// Lists and shows the number of total and used IP addresses of networks and
+/// their subnets. This is an admin-only extension.
+#[derive(Parser)]
+pub struct NetworkIpAvailabilityCommand {
+    /// subcommand
+    #[command(subcommand)]
+    command: NetworkIpAvailabilityCommands,
+}
+
+/// Supported subcommands
+#[allow(missing_docs)]
+#[derive(Subcommand)]
+pub enum NetworkIpAvailabilityCommands {
+    List(Box<list::NetworkIpAvailabilitiesCommand>),
+    Show(Box<show::NetworkIpAvailabilityCommand>),
+}
+
+impl NetworkIpAvailabilityCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        session: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        match &self.command {
+            NetworkIpAvailabilityCommands::List(cmd) => cmd.take_action(parsed_args, session).await,
+            NetworkIpAvailabilityCommands::Show(cmd) => cmd.take_action(parsed_args, session).await,
+        }
+    }
+}
@@ -0,0 +1,211 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// WARNING: This file is automatically generated from OpenAPI schema using
+// `openstack-codegenerator`.
+
+//! List NetworkIpAvailabilities command
+//!
+//! Wraps invoking of the `v2.0/network-ip-availabilities` with `GET` method
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use anyhow::Result;
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::output::OutputProcessor;
+use crate::Cli;
+use crate::OpenStackCliError;
+use crate::OutputConfig;
+use crate::StructTable;
+
+use openstack_sdk::api::network::v2::network_ip_availability::list;
+use openstack_sdk::api::{paged, Pagination};
+use serde_json::Value;
+use structable_derive::StructTable;
+
+/// Lists network IP availability of all networks.
+///
+/// By default, all network IP availability are returned. Otherwise,
+/// `network_id`, `network_name`, `tenant_id`, `project_id` and `ip_version`
+/// filters can be used to filter the results.
+///
+/// Normal response codes: 200
+///
+/// Error response codes: 401, 403
+///
+#[derive(Args)]
+#[command(about = "List network IP availability")]
+pub struct NetworkIpAvailabilitiesCommand {
+    /// Request Query parameters
+    #[command(flatten)]
+    query: QueryParameters,
+
+    /// Path parameters
+    #[command(flatten)]
+    path: PathParameters,
+
+    /// Total limit of entities count to return. Use this when there are too many entries.
+    #[arg(long, default_value_t = 10000)]
+    max_items: usize,
+}
+
+/// Query parameters
+#[derive(Args)]
+struct QueryParameters {
+    /// ip_version query parameter for /v2.0/network-ip-availabilities API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    ip_version: Option<i32>,
+
+    /// Requests a page size of items. Returns a number of items up to a limit
+    /// value. Use the limit parameter to make an initial limited request and
+    /// use the ID of the last-seen item from the response as the marker
+    /// parameter value in a subsequent limited request.
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    limit: Option<i32>,
+
+    /// The ID of the last-seen item. Use the limit parameter to make an
+    /// initial limited request and use the ID of the last-seen item from the
+    /// response as the marker parameter value in a subsequent limited
+    /// request.
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    marker: Option<String>,
+
+    /// network_id query parameter for /v2.0/network-ip-availabilities API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    network_id: Option<String>,
+
+    /// network_name query parameter for /v2.0/network-ip-availabilities API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    network_name: Option<String>,
+
+    /// project_id query parameter for /v2.0/network-ip-availabilities API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    project_id: Option<String>,
+
+    /// tenant_id query parameter for /v2.0/network-ip-availabilities API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    tenant_id: Option<String>,
+}
+
+/// Path parameters
+#[derive(Args)]
+struct PathParameters {}
+/// NetworkIpAvailabilities response representation
+#[derive(Deserialize, Serialize, Clone, StructTable)]
+struct ResponseData {
+    /// The ID of the network whose IP availability detail is reported.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    network_id: Option<String>,
+
+    /// The name of the network whose IP availability detail is reported.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    network_name: Option<String>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    project_id: Option<String>,
+
+    /// A list of dictionaries showing subnet IP availability. It contains
+    /// information for every subnet associated to the network.
+    ///
+    #[serde()]
+    #[structable(optional, pretty, wide)]
+    subnet_ip_availability: Option<Value>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    tenant_id: Option<String>,
+
+    /// The total number of IP addresses in a network.
+    ///
+    #[serde()]
+    #[structable(optional, pretty)]
+    total_ips: Option<Value>,
+
+    /// The number of used IP addresses of all subnets in a network.
+    ///
+    #[serde()]
+    #[structable(optional, pretty)]
+    used_ips: Option<Value>,
+}
+
+impl NetworkIpAvailabilitiesCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        client: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        info!("List NetworkIpAvailabilities");
+
+        let op = OutputProcessor::from_args(parsed_args);
+        op.validate_args(parsed_args)?;
+
+        let mut ep_builder = list::Request::builder();
+
+        // Set path parameters
+        // Set query parameters
+        if let Some(val) = &self.query.limit {
+            ep_builder.limit(*val);
+        }
+        if let Some(val) = &self.query.marker {
+            ep_builder.marker(val);
+        }
+        if let Some(val) = &self.query.ip_version {
+            ep_builder.ip_version(*val);
+        }
+        if let Some(val) = &self.query.network_id {
+            ep_builder.network_id(val);
+        }
+        if let Some(val) = &self.query.network_name {
+            ep_builder.network_name(val);
+        }
+        if let Some(val) = &self.query.project_id {
+            ep_builder.project_id(val);
+        }
+        if let Some(val) = &self.query.tenant_id {
+            ep_builder.tenant_id(val);
+        }
+        // Set body parameters
+
+        let ep = ep_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+
+        let ep = paged(ep, Pagination::Limit(self.max_items)).prefetch(true);
+
+        op.output_list_stream::<ResponseData, _, _>(ep.iter_async(client))
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,162 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Show NetworkIpAvailability command
+//!
+//! Wraps invoking of the `v2.0/network-ip-availabilities/{network_id}` with `GET` method
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use anyhow::Result;
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::output::OutputProcessor;
+use crate::Cli;
+use crate::OpenStackCliError;
+use crate::OutputConfig;
+use crate::StructTable;
+
+use openstack_sdk::api::find;
+use openstack_sdk::api::network::v2::network::find as find_network;
+use openstack_sdk::api::network::v2::network_ip_availability::get;
+use openstack_sdk::api::QueryAsync;
+use serde_json::Value;
+use structable_derive::StructTable;
+
+/// Shows network IP availability details for a network.
+///
+/// By default, all network IP availability are returned. Filtering is not
+/// supported for this API.
+///
+/// Normal response codes: 200
+///
+/// Error response codes: 401, 403, 404
+///
+#[derive(Args)]
+#[command(about = "Show network IP availability")]
+pub struct NetworkIpAvailabilityCommand {
+    /// Request Query parameters
+    #[command(flatten)]
+    query: QueryParameters,
+
+    /// Path parameters
+    #[command(flatten)]
+    path: PathParameters,
+}
+
+/// Query parameters
+#[derive(Args)]
+struct QueryParameters {}
+
+/// Path parameters
+#[derive(Args)]
+struct PathParameters {
+    /// Network (name or ID) to show the IP availability for
+    ///
+    #[arg(
+        help_heading = "Path parameters",
+        id = "path_param_network_id",
+        value_name = "NETWORK"
+    )]
+    network_id: String,
+}
+/// NetworkIpAvailability response representation
+#[derive(Deserialize, Serialize, Clone, StructTable)]
+struct ResponseData {
+    /// The ID of the network whose IP availability detail is reported.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    network_id: Option<String>,
+
+    /// The name of the network whose IP availability detail is reported.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    network_name: Option<String>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    project_id: Option<String>,
+
+    /// A list of dictionaries showing subnet IP availability. It contains
+    /// information for every subnet associated to the network.
+    ///
+    #[serde()]
+    #[structable(optional, pretty)]
+    subnet_ip_availability: Option<Value>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    tenant_id: Option<String>,
+
+    /// The total number of IP addresses in a network.
+    ///
+    #[serde()]
+    #[structable(optional, pretty)]
+    total_ips: Option<Value>,
+
+    /// The number of used IP addresses of all subnets in a network.
+    ///
+    #[serde()]
+    #[structable(optional, pretty)]
+    used_ips: Option<Value>,
+}
+
+impl NetworkIpAvailabilityCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        client: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        info!("Show NetworkIpAvailability");
+
+        let op = OutputProcessor::from_args(parsed_args);
+        op.validate_args(parsed_args)?;
+
+        let mut find_builder = find_network::Request::builder();
+        find_builder.id(&self.path.network_id);
+        let find_ep = find_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+        let find_data: serde_json::Value = find(find_ep).query_async(client).await?;
+        let network_id = find_data["id"]
+            .as_str()
+            .expect("Resource ID is a string")
+            .to_string();
+
+        let mut ep_builder = get::Request::builder();
+
+        // Set path parameters
+        ep_builder.network_id(network_id);
+        // Set query parameters
+        // Set body parameters
+
+        let ep = ep_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+
+        let data = ep.query_async(client).await?;
+        op.output_single::<ResponseData>(data)?;
+        Ok(())
+    }
+}
@@ -16,15 +16,64 @@
 
 use clap::{Parser, Subcommand};
 
+use openstack_sdk::types::network::v2::Port;
 use openstack_sdk::AsyncOpenStack;
 
+use crate::network::v2::tag::{impl_tagged_resource, TagCommand};
+use crate::output::impl_struct_table;
 use crate::{Cli, OpenStackCliError};
 
 mod create;
 mod delete;
 mod list;
+mod set;
 mod show;
-mod tag;
+
+impl_struct_table!(
+    Port,
+    ["created_at", "id", "name", "status", "updated_at"],
+    [
+        "admin_state_up",
+        "allowed_address_pairs",
+        "binding:host_id",
+        "binding:profile",
+        "binding:vif_details",
+        "binding:vif_type",
+        "binding:vnic_type",
+        "data_plane_status",
+        "description",
+        "device_id",
+        "device_owner",
+        "device_profile",
+        "dns_assignment",
+        "dns_domain",
+        "dns_name",
+        "extra_dhcp_opts",
+        "fixed_ips",
+        "hardware_offload_type",
+        "hints",
+        "ip_allocation",
+        "mac_address",
+        "network_id",
+        "numa_affinity_policy",
+        "port_security_enabled",
+        "propagate_uplink_status",
+        "qos_network_policy_id",
+        "qos_policy_id",
+        "resource_request",
+        "revision_number",
+        "security_groups",
+        "tags",
+        "tenant_id"
+    ]
+);
+
+impl_tagged_resource!(
+    TaggedPort,
+    openstack_sdk::api::network::v2::port::find::Request<'static>,
+    "ports",
+    "PORT"
+);
 
 /// Port commands
 #[derive(Parser)]
@@ -41,8 +90,9 @@ pub enum PortCommands {
     Create(Box<create::PortCommand>),
     Delete(delete::PortCommand),
     List(Box<list::PortsCommand>),
+    Set(Box<set::PortCommand>),
     Show(Box<show::PortCommand>),
-    Tag(Box<tag::TagCommand>),
+    Tag(Box<TagCommand<TaggedPort>>),
 }
 
 impl PortCommand {
@@ -56,6 +106,7 @@ impl PortCommand {
             PortCommands::Create(cmd) => cmd.take_action(parsed_args, session).await,
             PortCommands::Delete(cmd) => cmd.take_action(parsed_args, session).await,
             PortCommands::List(cmd) => cmd.take_action(parsed_args, session).await,
+            PortCommands::Set(cmd) => cmd.take_action(parsed_args, session).await,
             PortCommands::Show(cmd) => cmd.take_action(parsed_args, session).await,
             PortCommands::Tag(cmd) => cmd.take_action(parsed_args, session).await,
         }
@@ -276,7 +276,15 @@ struct Port {
     #[arg(action=clap::ArgAction::Append, help_heading = "Body parameters", long)]
     security_groups: Option<Vec<String>>,
 
-    #[arg(action=clap::ArgAction::Append, help_heading = "Body parameters", long)]
+    /// Tag to set on the resource. Repeat the option to set multiple tags.
+    ///
+    #[arg(
+        action=clap::ArgAction::Append,
+        help_heading = "Body parameters",
+        long = "tag",
+        value_name = "TAG",
+        visible_alias = "tags"
+    )]
     tags: Option<Vec<String>>,
 
     /// The ID of the project that owns the resource. Only administrative and
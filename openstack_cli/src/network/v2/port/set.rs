@@ -37,9 +37,12 @@ use crate::common::parse_json;
 use crate::common::parse_key_val;
 use crate::common::BoolString;
 use clap::ValueEnum;
+use openstack_sdk::api::conditional;
 use openstack_sdk::api::find;
 use openstack_sdk::api::network::v2::port::find;
 use openstack_sdk::api::network::v2::port::set;
+use openstack_sdk::api::network::v2::qos::policy::find as find_qos_policy;
+use openstack_sdk::api::Precondition;
 use openstack_sdk::api::QueryAsync;
 use serde_json::Value;
 use structable_derive::StructTable;
@@ -90,6 +93,12 @@ pub struct PortCommand {
     ///
     #[command(flatten)]
     port: Port,
+
+    /// Only update the resource when its current `revision_number` matches
+    /// the given value.
+    ///
+    #[arg(help_heading = "Header parameters", long, value_name = "REVISION")]
+    if_revision: Option<u64>,
 }
 
 /// Query parameters
@@ -289,11 +298,25 @@ struct Port {
     #[arg(action=clap::ArgAction::Set, help_heading = "Body parameters", long)]
     port_security_enabled: Option<bool>,
 
-    /// QoS policy associated with the port.
+    /// QoS policy (name or ID) associated with the port.
     ///
-    #[arg(help_heading = "Body parameters", long)]
+    #[arg(
+        help_heading = "Body parameters",
+        long,
+        value_name = "QOS_POLICY",
+        visible_alias = "qos-policy"
+    )]
     qos_policy_id: Option<String>,
 
+    /// Detach the QoS policy from the port.
+    ///
+    #[arg(
+        help_heading = "Body parameters",
+        long,
+        conflicts_with = "qos_policy_id"
+    )]
+    no_qos_policy: bool,
+
     /// The IDs of security groups applied to the port.
     ///
     #[arg(action=clap::ArgAction::Append, help_heading = "Body parameters", long)]
@@ -667,7 +690,7 @@ impl PortCommand {
                         v.as_object()
                             .expect("Is a valid Json object")
                             .into_iter()
-                            .map(|(k, v)| (k.into(), v.clone().into()))
+                            .map(|(k, v)| (k.into(), v.clone()))
                             .collect::<BTreeMap<_, Value>>()
                     })
                     .collect::<Vec<_>>(),
@@ -719,7 +742,19 @@ impl PortCommand {
         }
 
         if let Some(val) = &args.qos_policy_id {
-            port_builder.qos_policy_id(Some(val.into()));
+            let mut find_builder = find_qos_policy::Request::builder();
+            find_builder.id(val);
+            let find_ep = find_builder
+                .build()
+                .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+            let qos_policy: serde_json::Value = find(find_ep).query_async(client).await?;
+            let qos_policy_id = qos_policy["id"]
+                .as_str()
+                .expect("Resource ID is a string")
+                .to_string();
+            port_builder.qos_policy_id(Some(qos_policy_id.into()));
+        } else if args.no_qos_policy {
+            port_builder.qos_policy_id(None);
         }
 
         if let Some(val) = &args.dns_name {
@@ -744,7 +779,14 @@ impl PortCommand {
             .build()
             .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
 
-        let data = ep.query_async(client).await?;
+        let data = match self.if_revision {
+            Some(revision) => {
+                conditional(ep, Precondition::RevisionNumber(revision))
+                    .query_async(client)
+                    .await?
+            }
+            None => ep.query_async(client).await?,
+        };
         op.output_single::<ResponseData>(data)?;
         Ok(())
     }
@@ -20,7 +20,6 @@
 //! Wraps invoking of the `v2.0/ports/{port_id}` with `GET` method
 
 use clap::Args;
-use serde::{Deserialize, Serialize};
 use tracing::info;
 
 use anyhow::Result;
@@ -30,15 +29,11 @@ use openstack_sdk::AsyncOpenStack;
 use crate::output::OutputProcessor;
 use crate::Cli;
 use crate::OpenStackCliError;
-use crate::OutputConfig;
-use crate::StructTable;
 
-use crate::common::BoolString;
 use openstack_sdk::api::find;
 use openstack_sdk::api::network::v2::port::find;
 use openstack_sdk::api::QueryAsync;
-use serde_json::Value;
-use structable_derive::StructTable;
+use openstack_sdk::types::network::v2::Port;
 
 /// Shows details for a port.
 ///
@@ -79,287 +74,6 @@ struct PathParameters {
     )]
     id: String,
 }
-/// Port response representation
-#[derive(Deserialize, Serialize, Clone, StructTable)]
-struct ResponseData {
-    /// The administrative state of the resource, which is up (`true`) or down
-    /// (`false`).
-    ///
-    #[serde()]
-    #[structable(optional)]
-    admin_state_up: Option<BoolString>,
-
-    /// A set of zero or more allowed address pair objects each where address
-    /// pair object contains an `ip_address` and `mac_address`. While the
-    /// `ip_address` is required, the `mac_address` will be taken from the port
-    /// if not specified. The value of `ip_address` can be an IP Address or a
-    /// CIDR (if supported by the underlying extension plugin). A server
-    /// connected to the port can send a packet with source address which
-    /// matches one of the specified allowed address pairs.
-    ///
-    #[serde()]
-    #[structable(optional, pretty)]
-    allowed_address_pairs: Option<Value>,
-
-    /// The ID of the host where the port resides.
-    ///
-    #[serde(rename = "binding:host_id")]
-    #[structable(optional, title = "binding:host_id")]
-    binding_host_id: Option<String>,
-
-    /// A dictionary that enables the application running on the specific host
-    /// to pass and receive vif port information specific to the networking
-    /// back-end. The networking API does not define a specific format of this
-    /// field. If the update request is null this response field will be {}.
-    ///
-    #[serde(rename = "binding:profile")]
-    #[structable(optional, pretty, title = "binding:profile")]
-    binding_profile: Option<Value>,
-
-    /// A dictionary which contains additional information on the port.
-    /// Currently the following fields are defined: `port_filter` and
-    /// `ovs_hybrid_plug`. `port_filter` is a boolean indicating the networking
-    /// service provides port filtering features such as security group and/or
-    /// anti MAC/IP spoofing. `ovs_hybrid_plug` is a boolean used to inform an
-    /// API consumer like nova that the hybrid plugging strategy for OVS should
-    /// be used.
-    ///
-    #[serde(rename = "binding:vif_details")]
-    #[structable(optional, pretty, title = "binding:vif_details")]
-    binding_vif_details: Option<Value>,
-
-    /// The type of which mechanism is used for the port. An API consumer like
-    /// nova can use this to determine an appropriate way to attach a device
-    /// (for example an interface of a virtual server) to the port. Available
-    /// values currently defined includes `ovs`, `bridge`, `macvtap`, `hw_veb`,
-    /// `hostdev_physical`, `vhostuser`, `distributed` and `other`. There are
-    /// also special values: `unbound` and `binding_failed`. `unbound` means
-    /// the port is not bound to a networking back-end. `binding_failed` means
-    /// an error that the port failed to be bound to a networking back-end.
-    ///
-    #[serde(rename = "binding:vif_type")]
-    #[structable(optional, title = "binding:vif_type")]
-    binding_vif_type: Option<String>,
-
-    /// The type of vNIC which this port should be attached to. This is used to
-    /// determine which mechanism driver(s) to be used to bind the port. The
-    /// valid values are `normal`, `macvtap`, `direct`, `baremetal`,
-    /// `direct-physical`, `virtio-forwarder`, `smart-nic` and
-    /// `remote-managed`. What type of vNIC is actually available depends on
-    /// deployments.
-    ///
-    #[serde(rename = "binding:vnic_type")]
-    #[structable(optional, title = "binding:vnic_type")]
-    binding_vnic_type: Option<String>,
-
-    /// Time at which the resource has been created (in UTC ISO8601 format).
-    ///
-    #[serde()]
-    #[structable(optional)]
-    created_at: Option<String>,
-
-    /// Status of the underlying data plane of a port.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    data_plane_status: Option<String>,
-
-    /// A human-readable description for the resource.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    description: Option<String>,
-
-    /// The ID of the device that uses this port. For example, a server
-    /// instance or a logical router.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    device_id: Option<String>,
-
-    /// The entity type that uses this port. For example, `compute:nova`
-    /// (server instance), `network:dhcp` (DHCP agent) or
-    /// `network:router_interface` (router interface).
-    ///
-    #[serde()]
-    #[structable(optional)]
-    device_owner: Option<String>,
-
-    #[serde()]
-    #[structable(optional)]
-    device_profile: Option<String>,
-
-    /// Data assigned to a port by the Networking internal DNS including the
-    /// `hostname`, `ip_address` and `fqdn`.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    dns_assignment: Option<String>,
-
-    /// A valid DNS domain.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    dns_domain: Option<String>,
-
-    /// A valid DNS name.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    dns_name: Option<String>,
-
-    /// A set of zero or more extra DHCP option pairs. An option pair consists
-    /// of an option value and name.
-    ///
-    #[serde()]
-    #[structable(optional, pretty)]
-    extra_dhcp_opts: Option<Value>,
-
-    /// The IP addresses for the port. If the port has multiple IP addresses,
-    /// this field has multiple entries. Each entry consists of IP address
-    /// (`ip_address`) and the subnet ID from which the IP address is assigned
-    /// (`subnet_id`).
-    ///
-    #[serde()]
-    #[structable(optional, pretty)]
-    fixed_ips: Option<Value>,
-
-    #[serde()]
-    #[structable(optional)]
-    hardware_offload_type: Option<String>,
-
-    /// Admin-only. The following values control Open vSwitch’s Userspace Tx
-    /// packet steering feature:
-    ///
-    /// - `{"openvswitch": {"other_config": {"tx-steering": "hash|thread"}}}`
-    ///
-    #[serde()]
-    #[structable(optional, pretty)]
-    hints: Option<Value>,
-
-    /// The ID of the resource.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    id: Option<String>,
-
-    /// Indicates when ports use either `deferred`, `immediate` or no IP
-    /// allocation (`none`).
-    ///
-    #[serde()]
-    #[structable(optional)]
-    ip_allocation: Option<String>,
-
-    /// The MAC address of the port. If the port uses the `direct-physical`
-    /// `vnic_type` then the value of this field is overwritten with the MAC
-    /// address provided in the active binding:profile if any.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    mac_address: Option<String>,
-
-    /// Human-readable name of the resource.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    name: Option<String>,
-
-    /// The ID of the attached network.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    network_id: Option<String>,
-
-    /// The port NUMA affinity policy requested during the virtual machine
-    /// scheduling. Values: `None`, `required`, `preferred` or `legacy`.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    numa_affinity_policy: Option<String>,
-
-    /// The port security status. A valid value is enabled (`true`) or disabled
-    /// (`false`). If port security is enabled for the port, security group
-    /// rules and anti-spoofing rules are applied to the traffic on the port.
-    /// If disabled, no such rules are applied.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    port_security_enabled: Option<BoolString>,
-
-    /// The uplink status propagation of the port. Valid values are enabled
-    /// (`true`) and disabled (`false`).
-    ///
-    #[serde()]
-    #[structable(optional)]
-    propagate_uplink_status: Option<BoolString>,
-
-    /// The ID of the QoS policy of the network where this port is plugged.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    qos_network_policy_id: Option<String>,
-
-    /// The ID of the QoS policy associated with the port.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    qos_policy_id: Option<String>,
-
-    /// Expose Placement resources (i.e.: `minimum-bandwidth`) and traits
-    /// (i.e.: `vnic-type`, `physnet`) requested by a port to Nova and
-    /// Placement. A `resource_request` object contains `request_groups` and
-    /// `same_subtree` keys. `request_groups` is a list of dicts, where each
-    /// dict represents one group of resources and traits that needs to be
-    /// fulfilled from a single resource provider. Every dict in the list must
-    /// contain `id`, `required` and `resources` keys. The `id` field is a
-    /// string which represents a unique UUID that is generated for each group
-    /// by combining the `port_id` and UUIDs of the QoS rules contributing to
-    /// the group via the UUID5 method. `required` key contains the traits
-    /// (generated from the `vnic_type` and the `physnet`) required by the
-    /// port, and a `resources` key contains a mapping of requested resource
-    /// class name and requested amount from the QoS policy. `same_subtree` key
-    /// contains a list of `id` values from every resource group.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    resource_request: Option<String>,
-
-    /// The revision number of the resource.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    revision_number: Option<i32>,
-
-    /// The IDs of security groups applied to the port.
-    ///
-    #[serde()]
-    #[structable(optional, pretty)]
-    security_groups: Option<Value>,
-
-    /// The port status. Values are `ACTIVE`, `DOWN`, `BUILD` and `ERROR`.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    status: Option<String>,
-
-    /// The list of tags on the resource.
-    ///
-    #[serde()]
-    #[structable(optional, pretty)]
-    tags: Option<Value>,
-
-    /// The ID of the project.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    tenant_id: Option<String>,
-
-    /// Time at which the resource has been updated (in UTC ISO8601 format).
-    ///
-    #[serde()]
-    #[structable(optional)]
-    updated_at: Option<String>,
-}
 
 impl PortCommand {
     /// Perform command action
@@ -381,7 +95,7 @@ impl PortCommand {
             .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
         let find_data: serde_json::Value = find(find_ep).query_async(client).await?;
 
-        op.output_single::<ResponseData>(find_data)?;
+        op.output_single::<Port>(find_data)?;
         Ok(())
     }
 }
@@ -0,0 +1,57 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Neutron QoS commands
+
+use clap::{Parser, Subcommand};
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::{Cli, OpenStackCliError};
+
+mod policy;
+mod rule_type;
+
+/// Quality of Service
+///
+/// The QoS extension enables to apply bandwidth limit, DSCP marking, minimum
+/// bandwidth and minimum packet rate policies to the ports and networks.
+#[derive(Parser)]
+pub struct QosCommand {
+    /// subcommand
+    #[command(subcommand)]
+    command: QosCommands,
+}
+
+/// Supported subcommands
+#[allow(missing_docs)]
+#[derive(Subcommand)]
+pub enum QosCommands {
+    Policy(Box<policy::PolicyCommand>),
+    RuleType(Box<rule_type::RuleTypeCommand>),
+}
+
+impl QosCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        session: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        match &self.command {
+            QosCommands::Policy(cmd) => cmd.take_action(parsed_args, session).await,
+            QosCommands::RuleType(cmd) => cmd.take_action(parsed_args, session).await,
+        }
+    }
+}
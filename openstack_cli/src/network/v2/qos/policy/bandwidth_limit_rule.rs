@@ -0,0 +1,67 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! BandwidthLimitRule resource commands
+
+use clap::{Parser, Subcommand};
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::{Cli, OpenStackCliError};
+
+mod create;
+mod delete;
+mod list;
+mod set;
+mod show;
+
+/// QoS bandwidth limit rules
+///
+/// Lists, creates, shows details for, updates, and deletes bandwidth limit
+/// rules of a QoS policy. The rules limit the maximum bandwidth of the ports
+/// and networks the policy is attached to.
+#[derive(Parser)]
+pub struct BandwidthLimitRuleCommand {
+    /// subcommand
+    #[command(subcommand)]
+    command: BandwidthLimitRuleCommands,
+}
+
+/// Supported subcommands
+#[allow(missing_docs)]
+#[derive(Subcommand)]
+pub enum BandwidthLimitRuleCommands {
+    Create(Box<create::BandwidthLimitRuleCommand>),
+    Delete(Box<delete::BandwidthLimitRuleCommand>),
+    List(Box<list::BandwidthLimitRulesCommand>),
+    Set(Box<set::BandwidthLimitRuleCommand>),
+    Show(Box<show::BandwidthLimitRuleCommand>),
+}
+
+impl BandwidthLimitRuleCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        session: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        match &self.command {
+            BandwidthLimitRuleCommands::Create(cmd) => cmd.take_action(parsed_args, session).await,
+            BandwidthLimitRuleCommands::Delete(cmd) => cmd.take_action(parsed_args, session).await,
+            BandwidthLimitRuleCommands::List(cmd) => cmd.take_action(parsed_args, session).await,
+            BandwidthLimitRuleCommands::Set(cmd) => cmd.take_action(parsed_args, session).await,
+            BandwidthLimitRuleCommands::Show(cmd) => cmd.take_action(parsed_args, session).await,
+        }
+    }
+}
@@ -0,0 +1,200 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// WARNING: This file is automatically generated from OpenAPI schema using
+// `openstack-codegenerator`.
+
+//! List BandwidthLimitRules command
+//!
+//! Wraps invoking of the `v2.0/qos/policies/{policy_id}/bandwidth_limit_rules` with `GET` method
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use anyhow::Result;
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::output::OutputProcessor;
+use crate::Cli;
+use crate::OpenStackCliError;
+use crate::OutputConfig;
+use crate::StructTable;
+
+use openstack_sdk::api::network::v2::qos::policy::bandwidth_limit_rule::list;
+use openstack_sdk::api::{paged, Pagination};
+use structable_derive::StructTable;
+
+/// Lists bandwidth limit rules for a QoS policy.
+///
+/// The list might be empty.
+///
+/// Additionally, you can filter results by using query string parameters. For
+/// information, see
+/// [Filtering and Column Selection](https://wiki.openstack.org/wiki/Neutron/APIv2-specification#Filtering_and_Column_Selection).
+///
+/// Use the `fields` query parameter to control which fields are returned in
+/// the response body. For information, see
+/// [Filtering and Column Selection](https://wiki.openstack.org/wiki/Neutron/APIv2-specification#Filtering_and_Column_Selection).
+///
+/// Normal response codes: 200
+///
+/// Error response codes: 401, 404
+///
+#[derive(Args)]
+#[command(about = "List bandwidth limit rules for QoS policy")]
+pub struct BandwidthLimitRulesCommand {
+    /// Request Query parameters
+    #[command(flatten)]
+    query: QueryParameters,
+
+    /// Path parameters
+    #[command(flatten)]
+    path: PathParameters,
+
+    /// Total limit of entities count to return. Use this when there are too many entries.
+    #[arg(long, default_value_t = 10000)]
+    max_items: usize,
+}
+
+/// Query parameters
+#[derive(Args)]
+struct QueryParameters {
+    /// direction query parameter for /v2.0/qos/policies/{policy_id}/bandwidth_limit_rules API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    direction: Option<String>,
+
+    /// id query parameter for /v2.0/qos/policies/{policy_id}/bandwidth_limit_rules API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    id: Option<String>,
+
+    /// Requests a page size of items. Returns a number of items up to a limit
+    /// value. Use the limit parameter to make an initial limited request and
+    /// use the ID of the last-seen item from the response as the marker
+    /// parameter value in a subsequent limited request.
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    limit: Option<i32>,
+
+    /// The ID of the last-seen item. Use the limit parameter to make an
+    /// initial limited request and use the ID of the last-seen item from the
+    /// response as the marker parameter value in a subsequent limited
+    /// request.
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    marker: Option<String>,
+
+    /// max_burst_kbps query parameter for /v2.0/qos/policies/{policy_id}/bandwidth_limit_rules API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    max_burst_kbps: Option<i32>,
+
+    /// max_kbps query parameter for /v2.0/qos/policies/{policy_id}/bandwidth_limit_rules API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    max_kbps: Option<i32>,
+}
+
+/// Path parameters
+#[derive(Args)]
+struct PathParameters {
+    /// policy_id parameter for /v2.0/qos/policies/{policy_id}/bandwidth_limit_rules API
+    ///
+    #[arg(
+        help_heading = "Path parameters",
+        id = "path_param_policy_id",
+        value_name = "POLICY_ID"
+    )]
+    policy_id: String,
+}
+/// BandwidthLimitRules response representation
+#[derive(Deserialize, Serialize, Clone, StructTable)]
+struct ResponseData {
+    /// The direction of the traffic to which the QoS rule is applied, as seen
+    /// from the point of view of the `port`.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    direction: Option<String>,
+
+    /// The ID of the QoS bandwidth limit rule.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    id: Option<String>,
+
+    /// The maximum burst size (in kilobits).
+    ///
+    #[serde()]
+    #[structable(optional)]
+    max_burst_kbps: Option<i32>,
+
+    /// The maximum KBPS (kilobits per second) value.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    max_kbps: Option<i32>,
+}
+
+impl BandwidthLimitRulesCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        client: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        info!("List BandwidthLimitRules");
+
+        let op = OutputProcessor::from_args(parsed_args);
+        op.validate_args(parsed_args)?;
+
+        let mut ep_builder = list::Request::builder();
+
+        // Set path parameters
+        ep_builder.policy_id(&self.path.policy_id);
+        // Set query parameters
+        if let Some(val) = &self.query.limit {
+            ep_builder.limit(*val);
+        }
+        if let Some(val) = &self.query.marker {
+            ep_builder.marker(val);
+        }
+        if let Some(val) = &self.query.id {
+            ep_builder.id(val);
+        }
+        if let Some(val) = &self.query.direction {
+            ep_builder.direction(val);
+        }
+        if let Some(val) = &self.query.max_burst_kbps {
+            ep_builder.max_burst_kbps(*val);
+        }
+        if let Some(val) = &self.query.max_kbps {
+            ep_builder.max_kbps(*val);
+        }
+        // Set body parameters
+
+        let ep = ep_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+
+        let ep = paged(ep, Pagination::Limit(self.max_items)).prefetch(true);
+
+        op.output_list_stream::<ResponseData, _, _>(ep.iter_async(client))
+            .await?;
+        Ok(())
+    }
+}
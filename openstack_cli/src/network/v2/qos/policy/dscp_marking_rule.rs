@@ -0,0 +1,67 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! DscpMarkingRule resource commands
+
+use clap::{Parser, Subcommand};
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::{Cli, OpenStackCliError};
+
+mod create;
+mod delete;
+mod list;
+mod set;
+mod show;
+
+/// QoS DSCP marking rules
+///
+/// Lists, creates, shows details for, updates, and deletes DSCP marking rules
+/// of a QoS policy. The rules mark the outgoing traffic of the ports and
+/// networks the policy is attached to with the given DSCP value.
+#[derive(Parser)]
+pub struct DscpMarkingRuleCommand {
+    /// subcommand
+    #[command(subcommand)]
+    command: DscpMarkingRuleCommands,
+}
+
+/// Supported subcommands
+#[allow(missing_docs)]
+#[derive(Subcommand)]
+pub enum DscpMarkingRuleCommands {
+    Create(Box<create::DscpMarkingRuleCommand>),
+    Delete(Box<delete::DscpMarkingRuleCommand>),
+    List(Box<list::DscpMarkingRulesCommand>),
+    Set(Box<set::DscpMarkingRuleCommand>),
+    Show(Box<show::DscpMarkingRuleCommand>),
+}
+
+impl DscpMarkingRuleCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        session: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        match &self.command {
+            DscpMarkingRuleCommands::Create(cmd) => cmd.take_action(parsed_args, session).await,
+            DscpMarkingRuleCommands::Delete(cmd) => cmd.take_action(parsed_args, session).await,
+            DscpMarkingRuleCommands::List(cmd) => cmd.take_action(parsed_args, session).await,
+            DscpMarkingRuleCommands::Set(cmd) => cmd.take_action(parsed_args, session).await,
+            DscpMarkingRuleCommands::Show(cmd) => cmd.take_action(parsed_args, session).await,
+        }
+    }
+}
@@ -0,0 +1,139 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// WARNING: This file is automatically generated from OpenAPI schema using
+// `openstack-codegenerator`.
+
+//! Create DscpMarkingRule command
+//!
+//! Wraps invoking of the `v2.0/qos/policies/{policy_id}/dscp_marking_rules` with `POST` method
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use anyhow::Result;
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::output::OutputProcessor;
+use crate::Cli;
+use crate::OpenStackCliError;
+use crate::OutputConfig;
+use crate::StructTable;
+
+use openstack_sdk::api::network::v2::qos::policy::dscp_marking_rule::create;
+use openstack_sdk::api::QueryAsync;
+use structable_derive::StructTable;
+
+/// Creates a DSCP marking rule for a QoS policy.
+///
+/// Normal response codes: 201
+///
+/// Error response codes: 400, 401, 404, 409
+///
+#[derive(Args)]
+#[command(about = "Create DSCP marking rule")]
+pub struct DscpMarkingRuleCommand {
+    /// Request Query parameters
+    #[command(flatten)]
+    query: QueryParameters,
+
+    /// Path parameters
+    #[command(flatten)]
+    path: PathParameters,
+
+    /// A `dscp_marking_rule` object.
+    ///
+    #[command(flatten)]
+    dscp_marking_rule: DscpMarkingRule,
+}
+
+/// Query parameters
+#[derive(Args)]
+struct QueryParameters {}
+
+/// Path parameters
+#[derive(Args)]
+struct PathParameters {
+    /// policy_id parameter for /v2.0/qos/policies/{policy_id}/dscp_marking_rules API
+    ///
+    #[arg(
+        help_heading = "Path parameters",
+        id = "path_param_policy_id",
+        value_name = "POLICY_ID"
+    )]
+    policy_id: String,
+}
+/// DscpMarkingRule Body data
+#[derive(Args, Clone)]
+struct DscpMarkingRule {
+    /// The DSCP mark value. Valid values are `0`, `8`, `10`, `12`, `14`, `16`,
+    /// `18`, `20`, `22`, `24`, `26`, `28`, `30`, `32`, `34`, `36`, `38`, `40`,
+    /// `46`, `48` and `56`.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    dscp_mark: i32,
+}
+
+/// DscpMarkingRule response representation
+#[derive(Deserialize, Serialize, Clone, StructTable)]
+struct ResponseData {
+    /// The DSCP mark value.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    dscp_mark: Option<i32>,
+
+    /// The ID of the QoS DSCP marking rule.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    id: Option<String>,
+}
+
+impl DscpMarkingRuleCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        client: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        info!("Create DscpMarkingRule");
+
+        let op = OutputProcessor::from_args(parsed_args);
+        op.validate_args(parsed_args)?;
+
+        let mut ep_builder = create::Request::builder();
+
+        // Set path parameters
+        ep_builder.policy_id(&self.path.policy_id);
+        // Set query parameters
+        // Set body parameters
+        // Set Request.dscp_marking_rule data
+        let args = &self.dscp_marking_rule;
+        let mut dscp_marking_rule_builder = create::DscpMarkingRuleBuilder::default();
+        dscp_marking_rule_builder.dscp_mark(args.dscp_mark);
+
+        ep_builder.dscp_marking_rule(dscp_marking_rule_builder.build().unwrap());
+
+        let ep = ep_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+
+        let data = ep.query_async(client).await?;
+        op.output_single::<ResponseData>(data)?;
+        Ok(())
+    }
+}
@@ -15,9 +15,9 @@
 // WARNING: This file is automatically generated from OpenAPI schema using
 // `openstack-codegenerator`.
 
-//! Set Tag command
+//! Set DscpMarkingRule command
 //!
-//! Wraps invoking of the `v2.0/subnets/{subnet_id}/tags/{id}` with `PUT` method
+//! Wraps invoking of the `v2.0/qos/policies/{policy_id}/dscp_marking_rules/{id}` with `PUT` method
 
 use clap::Args;
 use serde::{Deserialize, Serialize};
@@ -33,16 +33,19 @@ use crate::OpenStackCliError;
 use crate::OutputConfig;
 use crate::StructTable;
 
-use bytes::Bytes;
-use http::Response;
-use openstack_sdk::api::network::v2::subnet::tag::set;
-use openstack_sdk::api::RawQueryAsync;
+use openstack_sdk::api::network::v2::qos::policy::dscp_marking_rule::set;
+use openstack_sdk::api::QueryAsync;
 use structable_derive::StructTable;
 
-/// Command without description in OpenAPI
+/// Updates a DSCP marking rule for a QoS policy.
+///
+/// Normal response codes: 200
+///
+/// Error response codes: 400, 401, 404
 ///
 #[derive(Args)]
-pub struct TagCommand {
+#[command(about = "Update DSCP marking rule")]
+pub struct DscpMarkingRuleCommand {
     /// Request Query parameters
     #[command(flatten)]
     query: QueryParameters,
@@ -50,6 +53,11 @@ pub struct TagCommand {
     /// Path parameters
     #[command(flatten)]
     path: PathParameters,
+
+    /// A `dscp_marking_rule` object.
+    ///
+    #[command(flatten)]
+    dscp_marking_rule: DscpMarkingRule,
 }
 
 /// Query parameters
@@ -59,16 +67,16 @@ struct QueryParameters {}
 /// Path parameters
 #[derive(Args)]
 struct PathParameters {
-    /// subnet_id parameter for /v2.0/subnets/{subnet_id}/tags/{id} API
+    /// policy_id parameter for /v2.0/qos/policies/{policy_id}/dscp_marking_rules/{id} API
     ///
     #[arg(
         help_heading = "Path parameters",
-        id = "path_param_subnet_id",
-        value_name = "SUBNET_ID"
+        id = "path_param_policy_id",
+        value_name = "POLICY_ID"
     )]
-    subnet_id: String,
+    policy_id: String,
 
-    /// id parameter for /v2.0/subnets/{subnet_id}/tags/{id} API
+    /// id parameter for /v2.0/qos/policies/{policy_id}/dscp_marking_rules/{id} API
     ///
     #[arg(
         help_heading = "Path parameters",
@@ -77,18 +85,41 @@ struct PathParameters {
     )]
     id: String,
 }
-/// Tag response representation
+/// DscpMarkingRule Body data
+#[derive(Args, Clone)]
+struct DscpMarkingRule {
+    /// The DSCP mark value. Valid values are `0`, `8`, `10`, `12`, `14`, `16`,
+    /// `18`, `20`, `22`, `24`, `26`, `28`, `30`, `32`, `34`, `36`, `38`, `40`,
+    /// `46`, `48` and `56`.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    dscp_mark: Option<i32>,
+}
+
+/// DscpMarkingRule response representation
 #[derive(Deserialize, Serialize, Clone, StructTable)]
-struct ResponseData {}
+struct ResponseData {
+    /// The DSCP mark value.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    dscp_mark: Option<i32>,
+
+    /// The ID of the QoS DSCP marking rule.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    id: Option<String>,
+}
 
-impl TagCommand {
+impl DscpMarkingRuleCommand {
     /// Perform command action
     pub async fn take_action(
         &self,
         parsed_args: &Cli,
         client: &mut AsyncOpenStack,
     ) -> Result<(), OpenStackCliError> {
-        info!("Set Tag");
+        info!("Set DscpMarkingRule");
 
         let op = OutputProcessor::from_args(parsed_args);
         op.validate_args(parsed_args)?;
@@ -96,19 +127,25 @@ impl TagCommand {
         let mut ep_builder = set::Request::builder();
 
         // Set path parameters
-        ep_builder.subnet_id(&self.path.subnet_id);
+        ep_builder.policy_id(&self.path.policy_id);
         ep_builder.id(&self.path.id);
         // Set query parameters
         // Set body parameters
+        // Set Request.dscp_marking_rule data
+        let args = &self.dscp_marking_rule;
+        let mut dscp_marking_rule_builder = set::DscpMarkingRuleBuilder::default();
+        if let Some(val) = &args.dscp_mark {
+            dscp_marking_rule_builder.dscp_mark(*val);
+        }
+
+        ep_builder.dscp_marking_rule(dscp_marking_rule_builder.build().unwrap());
 
         let ep = ep_builder
             .build()
             .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
 
-        let _rsp: Response<Bytes> = ep.raw_query_async(client).await?;
-        let data = ResponseData {};
-        // Maybe output some headers metadata
-        op.output_human::<ResponseData>(&data)?;
+        let data = ep.query_async(client).await?;
+        op.output_single::<ResponseData>(data)?;
         Ok(())
     }
 }
@@ -15,9 +15,9 @@
 // WARNING: This file is automatically generated from OpenAPI schema using
 // `openstack-codegenerator`.
 
-//! Show Tag command
+//! Show DscpMarkingRule command
 //!
-//! Wraps invoking of the `v2.0/routers/{router_id}/tags/{id}` with `GET` method
+//! Wraps invoking of the `v2.0/qos/policies/{policy_id}/dscp_marking_rules/{id}` with `GET` method
 
 use clap::Args;
 use serde::{Deserialize, Serialize};
@@ -33,16 +33,23 @@ use crate::OpenStackCliError;
 use crate::OutputConfig;
 use crate::StructTable;
 
-use bytes::Bytes;
-use http::Response;
-use openstack_sdk::api::network::v2::router::tag::get;
-use openstack_sdk::api::RawQueryAsync;
+use openstack_sdk::api::network::v2::qos::policy::dscp_marking_rule::get;
+use openstack_sdk::api::QueryAsync;
 use structable_derive::StructTable;
 
-/// Command without description in OpenAPI
+/// Shows details for a DSCP marking rule for a QoS policy.
+///
+/// Use the `fields` query parameter to control which fields are returned in
+/// the response body. For information, see
+/// [Filtering and Column Selection](https://wiki.openstack.org/wiki/Neutron/APIv2-specification#Filtering_and_Column_Selection).
+///
+/// Normal response codes: 200
+///
+/// Error response codes: 401, 404
 ///
 #[derive(Args)]
-pub struct TagCommand {
+#[command(about = "Show DSCP marking rule details")]
+pub struct DscpMarkingRuleCommand {
     /// Request Query parameters
     #[command(flatten)]
     query: QueryParameters,
@@ -59,16 +66,16 @@ struct QueryParameters {}
 /// Path parameters
 #[derive(Args)]
 struct PathParameters {
-    /// router_id parameter for /v2.0/routers/{router_id}/tags/{id} API
+    /// policy_id parameter for /v2.0/qos/policies/{policy_id}/dscp_marking_rules/{id} API
     ///
     #[arg(
         help_heading = "Path parameters",
-        id = "path_param_router_id",
-        value_name = "ROUTER_ID"
+        id = "path_param_policy_id",
+        value_name = "POLICY_ID"
     )]
-    router_id: String,
+    policy_id: String,
 
-    /// id parameter for /v2.0/routers/{router_id}/tags/{id} API
+    /// id parameter for /v2.0/qos/policies/{policy_id}/dscp_marking_rules/{id} API
     ///
     #[arg(
         help_heading = "Path parameters",
@@ -77,18 +84,30 @@ struct PathParameters {
     )]
     id: String,
 }
-/// Tag response representation
+/// DscpMarkingRule response representation
 #[derive(Deserialize, Serialize, Clone, StructTable)]
-struct ResponseData {}
+struct ResponseData {
+    /// The DSCP mark value.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    dscp_mark: Option<i32>,
+
+    /// The ID of the QoS DSCP marking rule.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    id: Option<String>,
+}
 
-impl TagCommand {
+impl DscpMarkingRuleCommand {
     /// Perform command action
     pub async fn take_action(
         &self,
         parsed_args: &Cli,
         client: &mut AsyncOpenStack,
     ) -> Result<(), OpenStackCliError> {
-        info!("Show Tag");
+        info!("Show DscpMarkingRule");
 
         let op = OutputProcessor::from_args(parsed_args);
         op.validate_args(parsed_args)?;
@@ -96,7 +115,7 @@ impl TagCommand {
         let mut ep_builder = get::Request::builder();
 
         // Set path parameters
-        ep_builder.router_id(&self.path.router_id);
+        ep_builder.policy_id(&self.path.policy_id);
         ep_builder.id(&self.path.id);
         // Set query parameters
         // Set body parameters
@@ -105,10 +124,8 @@ impl TagCommand {
             .build()
             .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
 
-        let _rsp: Response<Bytes> = ep.raw_query_async(client).await?;
-        let data = ResponseData {};
-        // Maybe output some headers metadata
-        op.output_human::<ResponseData>(&data)?;
+        let data = ep.query_async(client).await?;
+        op.output_single::<ResponseData>(data)?;
         Ok(())
     }
 }
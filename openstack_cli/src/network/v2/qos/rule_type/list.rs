@@ -15,9 +15,9 @@
 // WARNING: This file is automatically generated from OpenAPI schema using
 // `openstack-codegenerator`.
 
-//! List Tags command
+//! List RuleTypes command
 //!
-//! Wraps invoking of the `v2.0/routers/{router_id}/tags` with `GET` method
+//! Wraps invoking of the `v2.0/qos/rule-types` with `GET` method
 
 use clap::Args;
 use serde::{Deserialize, Serialize};
@@ -33,13 +33,20 @@ use crate::OpenStackCliError;
 use crate::OutputConfig;
 use crate::StructTable;
 
-use openstack_sdk::api::network::v2::router::tag::list;
+use openstack_sdk::api::network::v2::qos::rule_type::list;
 use openstack_sdk::api::QueryAsync;
+use serde_json::Value;
+use structable_derive::StructTable;
 
-/// Command without description in OpenAPI
+/// Lists available qos rule types.
+///
+/// Normal response codes: 200
+///
+/// Error response codes: 401
 ///
 #[derive(Args)]
-pub struct TagsCommand {
+#[command(about = "List QoS rule types")]
+pub struct RuleTypesCommand {
     /// Request Query parameters
     #[command(flatten)]
     query: QueryParameters,
@@ -51,52 +58,47 @@ pub struct TagsCommand {
 
 /// Query parameters
 #[derive(Args)]
-struct QueryParameters {}
+struct QueryParameters {
+    /// all_rules query parameter for /v2.0/qos/rule-types API
+    ///
+    #[arg(action=clap::ArgAction::Set, help_heading = "Query parameters", long)]
+    all_rules: Option<bool>,
+
+    /// all_supported query parameter for /v2.0/qos/rule-types API
+    ///
+    #[arg(action=clap::ArgAction::Set, help_heading = "Query parameters", long)]
+    all_supported: Option<bool>,
+}
 
 /// Path parameters
 #[derive(Args)]
-struct PathParameters {
-    /// router_id parameter for /v2.0/routers/{router_id}/tags/{id} API
+struct PathParameters {}
+/// RuleTypes response representation
+#[derive(Deserialize, Serialize, Clone, StructTable)]
+struct ResponseData {
+    /// The type of QoS rule.
     ///
-    #[arg(
-        help_heading = "Path parameters",
-        id = "path_param_router_id",
-        value_name = "ROUTER_ID"
-    )]
-    router_id: String,
-}
-/// Tags response representation
-#[derive(Deserialize, Serialize, Clone)]
-struct ResponseData(String);
-
-impl StructTable for ResponseData {
-    fn build(&self, _: &OutputConfig) -> (Vec<String>, Vec<Vec<String>>) {
-        let headers: Vec<String> = Vec::from(["Value".to_string()]);
-        let res: Vec<Vec<String>> = Vec::from([Vec::from([self.0.to_string()])]);
-        (headers, res)
-    }
-}
+    #[serde(rename = "type")]
+    #[structable(optional, title = "type")]
+    _type: Option<String>,
 
-impl StructTable for Vec<ResponseData> {
-    fn build(&self, _: &OutputConfig) -> (Vec<String>, Vec<Vec<String>>) {
-        let headers: Vec<String> = Vec::from(["Values".to_string()]);
-        let res: Vec<Vec<String>> = Vec::from([Vec::from([self
-            .iter()
-            .map(|v| v.0.to_string())
-            .collect::<Vec<_>>()
-            .join(", ")])]);
-        (headers, res)
-    }
+    /// List of loaded QoS drivers with supported rule type parameters with
+    /// possible values for each. Each driver is represented by a dict with the
+    /// keys `name` and `supported_parameters`.
+    ///
+    #[serde()]
+    #[structable(optional, pretty)]
+    drivers: Option<Value>,
 }
 
-impl TagsCommand {
+impl RuleTypesCommand {
     /// Perform command action
     pub async fn take_action(
         &self,
         parsed_args: &Cli,
         client: &mut AsyncOpenStack,
     ) -> Result<(), OpenStackCliError> {
-        info!("List Tags");
+        info!("List RuleTypes");
 
         let op = OutputProcessor::from_args(parsed_args);
         op.validate_args(parsed_args)?;
@@ -104,8 +106,13 @@ impl TagsCommand {
         let mut ep_builder = list::Request::builder();
 
         // Set path parameters
-        ep_builder.router_id(&self.path.router_id);
         // Set query parameters
+        if let Some(val) = &self.query.all_supported {
+            ep_builder.all_supported(*val);
+        }
+        if let Some(val) = &self.query.all_rules {
+            ep_builder.all_rules(*val);
+        }
         // Set body parameters
 
         let ep = ep_builder
@@ -15,9 +15,9 @@
 // WARNING: This file is automatically generated from OpenAPI schema using
 // `openstack-codegenerator`.
 
-//! Get Uptime command
+//! Show RuleType command
 //!
-//! Wraps invoking of the `v2.1/os-hypervisors/{id}/uptime` with `GET` method
+//! Wraps invoking of the `v2.0/qos/rule-types/{id}` with `GET` method
 
 use clap::Args;
 use serde::{Deserialize, Serialize};
@@ -33,26 +33,22 @@ use crate::OpenStackCliError;
 use crate::OutputConfig;
 use crate::StructTable;
 
-use bytes::Bytes;
-use http::Response;
-use openstack_sdk::api::compute::v2::hypervisor::uptime::get;
-use openstack_sdk::api::RawQueryAsync;
+use openstack_sdk::api::network::v2::qos::rule_type::get;
+use openstack_sdk::api::QueryAsync;
+use serde_json::Value;
 use structable_derive::StructTable;
 
-/// Shows the uptime for a given hypervisor.
+/// Shows details for an available QoS rule type.
 ///
-/// Policy defaults enable only users with the administrative role to perform
-/// this operation. Cloud providers can change these permissions through the
-/// `policy.json` file.
+/// You can access the `name` and the `supported_parameters` of each driver.
 ///
 /// Normal response codes: 200
 ///
-/// Error response codes: badRequest(400), unauthorized(401), forbidden(403),
-/// itemNotFound(404), NotImplemented(501)
+/// Error response codes: 401, 404
 ///
 #[derive(Args)]
-#[command(about = "Show Hypervisor Uptime (DEPRECATED)")]
-pub struct UptimeCommand {
+#[command(about = "Show QoS rule type details")]
+pub struct RuleTypeCommand {
     /// Request Query parameters
     #[command(flatten)]
     query: QueryParameters,
@@ -69,7 +65,7 @@ struct QueryParameters {}
 /// Path parameters
 #[derive(Args)]
 struct PathParameters {
-    /// id parameter for /v2.1/os-hypervisors/{id}/uptime API
+    /// id parameter for /v2.0/qos/rule-types/{id} API
     ///
     #[arg(
         help_heading = "Path parameters",
@@ -78,18 +74,32 @@ struct PathParameters {
     )]
     id: String,
 }
-/// Uptime response representation
+/// RuleType response representation
 #[derive(Deserialize, Serialize, Clone, StructTable)]
-struct ResponseData {}
+struct ResponseData {
+    /// The type of QoS rule.
+    ///
+    #[serde(rename = "type")]
+    #[structable(optional, title = "type")]
+    _type: Option<String>,
+
+    /// List of loaded QoS drivers with supported rule type parameters with
+    /// possible values for each. Each driver is represented by a dict with the
+    /// keys `name` and `supported_parameters`.
+    ///
+    #[serde()]
+    #[structable(optional, pretty)]
+    drivers: Option<Value>,
+}
 
-impl UptimeCommand {
+impl RuleTypeCommand {
     /// Perform command action
     pub async fn take_action(
         &self,
         parsed_args: &Cli,
         client: &mut AsyncOpenStack,
     ) -> Result<(), OpenStackCliError> {
-        info!("Get Uptime");
+        info!("Show RuleType");
 
         let op = OutputProcessor::from_args(parsed_args);
         op.validate_args(parsed_args)?;
@@ -105,10 +115,8 @@ impl UptimeCommand {
             .build()
             .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
 
-        let _rsp: Response<Bytes> = ep.raw_query_async(client).await?;
-        let data = ResponseData {};
-        // Maybe output some headers metadata
-        op.output_human::<ResponseData>(&data)?;
+        let data = ep.query_async(client).await?;
+        op.output_single::<ResponseData>(data)?;
         Ok(())
     }
 }
@@ -0,0 +1,135 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Show Quota details command
+//!
+//! Wraps invoking of the `v2.0/quotas/{project_id}/details.json` with `GET` method
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use anyhow::Result;
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::output::OutputProcessor;
+use crate::Cli;
+use crate::OpenStackCliError;
+use crate::OutputConfig;
+use crate::StructTable;
+
+use openstack_sdk::api::network::v2::quota::details;
+use openstack_sdk::api::QueryAsync;
+use serde_json::Value;
+use structable_derive::StructTable;
+
+use crate::common::{find_project_id, flatten_quota_details};
+
+/// Shows quota usage details for a project.
+///
+/// Every resource is reported with its `limit` together with the `used` and
+/// `reserved` amounts.
+///
+/// Normal response codes: 200
+///
+/// Error response codes: 401, 403
+///
+#[derive(Args)]
+#[command(about = "Show quota usage for a project")]
+pub struct QuotaCommand {
+    /// Request Query parameters
+    #[command(flatten)]
+    query: QueryParameters,
+
+    /// Path parameters
+    #[command(flatten)]
+    path: PathParameters,
+}
+
+/// Query parameters
+#[derive(Args)]
+struct QueryParameters {}
+
+/// Path parameters
+#[derive(Args)]
+struct PathParameters {
+    /// Project (name or ID). Defaults to the currently authorized project.
+    ///
+    #[arg(
+        help_heading = "Path parameters",
+        id = "path_param_project_id",
+        value_name = "PROJECT"
+    )]
+    project_id: Option<String>,
+}
+
+/// Quota usage response representation
+#[derive(Deserialize, Serialize, Clone, StructTable)]
+struct ResponseData {
+    /// The name of the resource.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    resource: Option<String>,
+
+    /// The maximum amount of the resource allowed for the project. A value
+    /// of `-1` means no limit.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    limit: Option<i32>,
+
+    /// The amount of the resource currently used by the project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    used: Option<i32>,
+
+    /// The amount of the resource reserved for the project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    reserved: Option<i32>,
+}
+
+impl QuotaCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        client: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        info!("Show Quota details");
+
+        let op = OutputProcessor::from_args(parsed_args);
+        op.validate_args(parsed_args)?;
+
+        let mut ep_builder = details::Request::builder();
+
+        // Set path parameters
+        ep_builder.project_id(find_project_id(client, self.path.project_id.as_ref()).await?);
+        // Set query parameters
+        // Set body parameters
+
+        let ep = ep_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+
+        let data: Value = ep.query_async(client).await?;
+        let usage = flatten_quota_details(&data, "used");
+        op.output_list::<ResponseData>(usage)?;
+        Ok(())
+    }
+}
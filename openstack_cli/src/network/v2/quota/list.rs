@@ -0,0 +1,157 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// WARNING: This file is automatically generated from OpenAPI schema using
+// `openstack-codegenerator`.
+
+//! List Quotas command
+//!
+//! Wraps invoking of the `v2.0/quotas` with `GET` method
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use anyhow::Result;
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::output::OutputProcessor;
+use crate::Cli;
+use crate::OpenStackCliError;
+use crate::OutputConfig;
+use crate::StructTable;
+
+use openstack_sdk::api::network::v2::quota::list;
+use openstack_sdk::api::QueryAsync;
+use structable_derive::StructTable;
+
+/// Lists quotas for projects with non-default quota values.
+///
+/// Normal response codes: 200
+///
+/// Error response codes: 401, 403
+///
+#[derive(Args)]
+#[command(about = "List quotas for projects with non-default quota values")]
+pub struct QuotasCommand {
+    /// Request Query parameters
+    #[command(flatten)]
+    query: QueryParameters,
+
+    /// Path parameters
+    #[command(flatten)]
+    path: PathParameters,
+}
+
+/// Query parameters
+#[derive(Args)]
+struct QueryParameters {}
+
+/// Path parameters
+#[derive(Args)]
+struct PathParameters {}
+/// Quotas response representation
+#[derive(Deserialize, Serialize, Clone, StructTable)]
+struct ResponseData {
+    /// The number of floating IP addresses allowed for each project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    floatingip: Option<i32>,
+
+    /// The number of networks allowed for each project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    network: Option<i32>,
+
+    /// The number of ports allowed for each project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    port: Option<i32>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    project_id: Option<String>,
+
+    /// The number of role-based access control (RBAC) policies for each
+    /// project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    rbac_policy: Option<i32>,
+
+    /// The number of routers allowed for each project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    router: Option<i32>,
+
+    /// The number of security groups allowed for each project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    security_group: Option<i32>,
+
+    /// The number of security group rules allowed for each project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    security_group_rule: Option<i32>,
+
+    /// The number of subnets allowed for each project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    subnet: Option<i32>,
+
+    /// The number of subnet pools allowed for each project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    subnetpool: Option<i32>,
+}
+
+impl QuotasCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        client: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        info!("List Quotas");
+
+        let op = OutputProcessor::from_args(parsed_args);
+        op.validate_args(parsed_args)?;
+
+        let ep_builder = list::Request::builder();
+
+        // Set path parameters
+        // Set query parameters
+        // Set body parameters
+
+        let ep = ep_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+
+        let data: Vec<serde_json::Value> = ep.query_async(client).await?;
+
+        op.output_list::<ResponseData>(data)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,268 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Set Quota command
+//!
+//! Wraps invoking of the `v2.0/quotas/{project_id}` with `PUT` method
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use anyhow::Result;
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::output::OutputProcessor;
+use crate::Cli;
+use crate::OpenStackCliError;
+use crate::OutputConfig;
+use crate::StructTable;
+
+use openstack_sdk::api::network::v2::quota::set;
+use openstack_sdk::api::QueryAsync;
+use structable_derive::StructTable;
+
+use crate::common::find_project_id;
+
+/// Updates quotas for a project. Use when non-default quotas are desired.
+///
+/// Normal response codes: 200
+///
+/// Error response codes: 401, 403
+///
+#[derive(Args)]
+#[command(about = "Update quota for a project")]
+pub struct QuotaCommand {
+    /// Request Query parameters
+    #[command(flatten)]
+    query: QueryParameters,
+
+    /// Path parameters
+    #[command(flatten)]
+    path: PathParameters,
+
+    /// A `quota` object.
+    ///
+    #[command(flatten)]
+    quota: Quota,
+}
+
+/// Query parameters
+#[derive(Args)]
+struct QueryParameters {}
+
+/// Path parameters
+#[derive(Args)]
+struct PathParameters {
+    /// Project (name or ID). Defaults to the currently authorized project.
+    ///
+    #[arg(
+        help_heading = "Path parameters",
+        id = "path_param_project_id",
+        value_name = "PROJECT"
+    )]
+    project_id: Option<String>,
+}
+/// Quota Body data
+#[derive(Args, Clone)]
+struct Quota {
+    /// The number of floating IP addresses allowed for each project. A value
+    /// of `-1` means no limit.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    floatingip: Option<i32>,
+
+    /// The number of networks allowed for each project. A value of `-1` means
+    /// no limit.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    network: Option<i32>,
+
+    /// The number of ports allowed for each project. A value of `-1` means no
+    /// limit.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    port: Option<i32>,
+
+    /// The number of role-based access control (RBAC) policies for each
+    /// project. A value of `-1` means no limit.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    rbac_policy: Option<i32>,
+
+    /// The number of routers allowed for each project. A value of `-1` means
+    /// no limit.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    router: Option<i32>,
+
+    /// The number of security groups allowed for each project. A value of `-1`
+    /// means no limit.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    security_group: Option<i32>,
+
+    /// The number of security group rules allowed for each project. A value of
+    /// `-1` means no limit.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    security_group_rule: Option<i32>,
+
+    /// The number of subnets allowed for each project. A value of `-1` means
+    /// no limit.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    subnet: Option<i32>,
+
+    /// The number of subnet pools allowed for each project. A value of `-1`
+    /// means no limit.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    subnetpool: Option<i32>,
+}
+
+/// Quota response representation
+#[derive(Deserialize, Serialize, Clone, StructTable)]
+struct ResponseData {
+    /// The number of floating IP addresses allowed for each project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    floatingip: Option<i32>,
+
+    /// The number of networks allowed for each project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    network: Option<i32>,
+
+    /// The number of ports allowed for each project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    port: Option<i32>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    project_id: Option<String>,
+
+    /// The number of role-based access control (RBAC) policies for each
+    /// project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    rbac_policy: Option<i32>,
+
+    /// The number of routers allowed for each project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    router: Option<i32>,
+
+    /// The number of security groups allowed for each project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    security_group: Option<i32>,
+
+    /// The number of security group rules allowed for each project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    security_group_rule: Option<i32>,
+
+    /// The number of subnets allowed for each project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    subnet: Option<i32>,
+
+    /// The number of subnet pools allowed for each project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    subnetpool: Option<i32>,
+}
+
+impl QuotaCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        client: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        info!("Set Quota");
+
+        let op = OutputProcessor::from_args(parsed_args);
+        op.validate_args(parsed_args)?;
+
+        let mut ep_builder = set::Request::builder();
+
+        // Set path parameters
+        ep_builder.project_id(find_project_id(client, self.path.project_id.as_ref()).await?);
+        // Set query parameters
+        // Set body parameters
+        // Set Request.quota data
+        let args = &self.quota;
+        let mut quota_builder = set::QuotaBuilder::default();
+        if let Some(val) = &args.floatingip {
+            quota_builder.floatingip(*val);
+        }
+
+        if let Some(val) = &args.network {
+            quota_builder.network(*val);
+        }
+
+        if let Some(val) = &args.port {
+            quota_builder.port(*val);
+        }
+
+        if let Some(val) = &args.rbac_policy {
+            quota_builder.rbac_policy(*val);
+        }
+
+        if let Some(val) = &args.router {
+            quota_builder.router(*val);
+        }
+
+        if let Some(val) = &args.security_group {
+            quota_builder.security_group(*val);
+        }
+
+        if let Some(val) = &args.security_group_rule {
+            quota_builder.security_group_rule(*val);
+        }
+
+        if let Some(val) = &args.subnet {
+            quota_builder.subnet(*val);
+        }
+
+        if let Some(val) = &args.subnetpool {
+            quota_builder.subnetpool(*val);
+        }
+
+        ep_builder.quota(quota_builder.build().unwrap());
+
+        let ep = ep_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+
+        let data = ep.query_async(client).await?;
+        op.output_single::<ResponseData>(data)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,305 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Create RbacPolicy command
+//!
+//! Wraps invoking of the `v2.0/rbac-policies` with `POST` method
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use anyhow::Result;
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::output::OutputProcessor;
+use crate::Cli;
+use crate::OpenStackCliError;
+use crate::OutputConfig;
+use crate::StructTable;
+
+use clap::ValueEnum;
+use openstack_sdk::api::find;
+use openstack_sdk::api::network::v2::address_group::find as find_address_group;
+use openstack_sdk::api::network::v2::address_scope::find as find_address_scope;
+use openstack_sdk::api::network::v2::network::find as find_network;
+use openstack_sdk::api::network::v2::qos::policy::find as find_qos_policy;
+use openstack_sdk::api::network::v2::rbac_policy::create;
+use openstack_sdk::api::network::v2::security_group::find as find_security_group;
+use openstack_sdk::api::network::v2::subnetpool::find as find_subnetpool;
+use openstack_sdk::api::QueryAsync;
+use structable_derive::StructTable;
+
+/// Create RBAC policy for given tenant.
+///
+/// Normal response codes: 201
+///
+/// Error response codes: 400, 401
+///
+#[derive(Args)]
+#[command(about = "Create RBAC policy")]
+pub struct RbacPolicyCommand {
+    /// Request Query parameters
+    #[command(flatten)]
+    query: QueryParameters,
+
+    /// Path parameters
+    #[command(flatten)]
+    path: PathParameters,
+
+    /// A `rbac_policy` object.
+    ///
+    #[command(flatten)]
+    rbac_policy: RbacPolicy,
+}
+
+/// Query parameters
+#[derive(Args)]
+struct QueryParameters {}
+
+/// Path parameters
+#[derive(Args)]
+struct PathParameters {}
+#[derive(Clone, Eq, Ord, PartialEq, PartialOrd, ValueEnum)]
+enum Action {
+    AccessAsExternal,
+    AccessAsShared,
+}
+
+#[derive(Clone, Eq, Ord, PartialEq, PartialOrd, ValueEnum)]
+enum ObjectType {
+    AddressGroup,
+    AddressScope,
+    Network,
+    QosPolicy,
+    SecurityGroup,
+    Subnetpool,
+}
+
+/// RbacPolicy Body data
+#[derive(Args, Clone)]
+struct RbacPolicy {
+    /// Action for the RBAC policy which is `access_as_external` or
+    /// `access_as_shared`.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    action: Action,
+
+    /// The ID of the `object_type` resource. An `object_type` of `network`
+    /// returns a network ID, an `object_type` of `qos-policy` returns a QoS
+    /// policy ID, an `object_type` of `security-group` returns a security
+    /// group ID, an `object_type` of `address-scope` returns a address scope
+    /// ID, an `object_type` of `subnetpool` returns a subnetpool ID and an
+    /// `object_type` of `address-group` returns an address group ID. The
+    /// resource can also be given by name.
+    ///
+    #[arg(
+        help_heading = "Body parameters",
+        long,
+        value_name = "OBJECT",
+        visible_alias = "object"
+    )]
+    object_id: String,
+
+    /// The type of the object that the RBAC policy affects. Types include
+    /// `qos-policy`, `network`, `security-group`, `address-scope`,
+    /// `subnetpool` or `address-group`.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    object_type: ObjectType,
+
+    /// The ID of the tenant to which the RBAC policy will be enforced. Use `*`
+    /// to grant access to all projects.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    target_tenant: String,
+
+    /// The ID of the project that owns the resource. Only administrative and
+    /// users with advsvc role can specify a project ID other than their own.
+    /// You cannot change this value through authorization policies.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    tenant_id: Option<String>,
+}
+
+/// RbacPolicy response representation
+#[derive(Deserialize, Serialize, Clone, StructTable)]
+struct ResponseData {
+    /// Action for the RBAC policy which is `access_as_external` or
+    /// `access_as_shared`.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    action: Option<String>,
+
+    /// The ID of the RBAC policy.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    id: Option<String>,
+
+    /// The ID of the `object_type` resource. An `object_type` of `network`
+    /// returns a network ID, an `object_type` of `qos-policy` returns a QoS
+    /// policy ID, an `object_type` of `security-group` returns a security
+    /// group ID, an `object_type` of `address-scope` returns a address scope
+    /// ID, an `object_type` of `subnetpool` returns a subnetpool ID and an
+    /// `object_type` of `address-group` returns an address group ID.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    object_id: Option<String>,
+
+    /// The type of the object that the RBAC policy affects. Types include
+    /// `qos-policy`, `network`, `security-group`, `address-scope`,
+    /// `subnetpool` or `address-group`.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    object_type: Option<String>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    project_id: Option<String>,
+
+    /// The ID of the tenant to which the RBAC policy will be enforced.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    target_tenant: Option<String>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    tenant_id: Option<String>,
+}
+
+impl RbacPolicyCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        client: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        info!("Create RbacPolicy");
+
+        let op = OutputProcessor::from_args(parsed_args);
+        op.validate_args(parsed_args)?;
+
+        let args = &self.rbac_policy;
+
+        // Resolve the object name or ID according to its type
+        let object_id = &args.object_id;
+        let find_data: serde_json::Value = match args.object_type {
+            ObjectType::AddressGroup => {
+                let mut find_builder = find_address_group::Request::builder();
+                find_builder.id(object_id);
+                let find_ep = find_builder
+                    .build()
+                    .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+                find(find_ep).query_async(client).await?
+            }
+            ObjectType::AddressScope => {
+                let mut find_builder = find_address_scope::Request::builder();
+                find_builder.id(object_id);
+                let find_ep = find_builder
+                    .build()
+                    .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+                find(find_ep).query_async(client).await?
+            }
+            ObjectType::Network => {
+                let mut find_builder = find_network::Request::builder();
+                find_builder.id(object_id);
+                let find_ep = find_builder
+                    .build()
+                    .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+                find(find_ep).query_async(client).await?
+            }
+            ObjectType::QosPolicy => {
+                let mut find_builder = find_qos_policy::Request::builder();
+                find_builder.id(object_id);
+                let find_ep = find_builder
+                    .build()
+                    .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+                find(find_ep).query_async(client).await?
+            }
+            ObjectType::SecurityGroup => {
+                let mut find_builder = find_security_group::Request::builder();
+                find_builder.id(object_id);
+                let find_ep = find_builder
+                    .build()
+                    .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+                find(find_ep).query_async(client).await?
+            }
+            ObjectType::Subnetpool => {
+                let mut find_builder = find_subnetpool::Request::builder();
+                find_builder.id(object_id);
+                let find_ep = find_builder
+                    .build()
+                    .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+                find(find_ep).query_async(client).await?
+            }
+        };
+        let object_id = find_data["id"]
+            .as_str()
+            .expect("Resource ID is a string")
+            .to_string();
+
+        let mut ep_builder = create::Request::builder();
+
+        // Set path parameters
+        // Set query parameters
+        // Set body parameters
+        // Set Request.rbac_policy data
+        let mut rbac_policy_builder = create::RbacPolicyBuilder::default();
+        let val = &args.action;
+        let tmp = match val {
+            Action::AccessAsExternal => create::Action::AccessAsExternal,
+            Action::AccessAsShared => create::Action::AccessAsShared,
+        };
+        rbac_policy_builder.action(tmp);
+
+        rbac_policy_builder.object_id(object_id);
+
+        let val = &args.object_type;
+        let tmp = match val {
+            ObjectType::AddressGroup => create::ObjectType::AddressGroup,
+            ObjectType::AddressScope => create::ObjectType::AddressScope,
+            ObjectType::Network => create::ObjectType::Network,
+            ObjectType::QosPolicy => create::ObjectType::QosPolicy,
+            ObjectType::SecurityGroup => create::ObjectType::SecurityGroup,
+            ObjectType::Subnetpool => create::ObjectType::Subnetpool,
+        };
+        rbac_policy_builder.object_type(tmp);
+
+        rbac_policy_builder.target_tenant(&args.target_tenant);
+
+        if let Some(val) = &args.tenant_id {
+            rbac_policy_builder.tenant_id(val);
+        }
+
+        ep_builder.rbac_policy(rbac_policy_builder.build().unwrap());
+
+        let ep = ep_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+
+        let data = ep.query_async(client).await?;
+        op.output_single::<ResponseData>(data)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,177 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// WARNING: This file is automatically generated from OpenAPI schema using
+// `openstack-codegenerator`.
+
+//! Set RbacPolicy command
+//!
+//! Wraps invoking of the `v2.0/rbac-policies/{id}` with `PUT` method
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use anyhow::Result;
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::output::OutputProcessor;
+use crate::Cli;
+use crate::OpenStackCliError;
+use crate::OutputConfig;
+use crate::StructTable;
+
+use openstack_sdk::api::network::v2::rbac_policy::set;
+use openstack_sdk::api::QueryAsync;
+use structable_derive::StructTable;
+
+/// Update RBAC policy for given tenant.
+///
+/// Normal response codes: 200
+///
+/// Error response codes: 400, 401, 403, 404
+///
+#[derive(Args)]
+#[command(about = "Update RBAC policy")]
+pub struct RbacPolicyCommand {
+    /// Request Query parameters
+    #[command(flatten)]
+    query: QueryParameters,
+
+    /// Path parameters
+    #[command(flatten)]
+    path: PathParameters,
+
+    /// A `rbac_policy` object.
+    ///
+    #[command(flatten)]
+    rbac_policy: RbacPolicy,
+}
+
+/// Query parameters
+#[derive(Args)]
+struct QueryParameters {}
+
+/// Path parameters
+#[derive(Args)]
+struct PathParameters {
+    /// id parameter for /v2.0/rbac-policies/{id} API
+    ///
+    #[arg(
+        help_heading = "Path parameters",
+        id = "path_param_id",
+        value_name = "ID"
+    )]
+    id: String,
+}
+/// RbacPolicy Body data
+#[derive(Args, Clone)]
+struct RbacPolicy {
+    /// The ID of the tenant to which the RBAC policy will be enforced.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    target_tenant: Option<String>,
+}
+
+/// RbacPolicy response representation
+#[derive(Deserialize, Serialize, Clone, StructTable)]
+struct ResponseData {
+    /// Action for the RBAC policy which is `access_as_external` or
+    /// `access_as_shared`.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    action: Option<String>,
+
+    /// The ID of the RBAC policy.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    id: Option<String>,
+
+    /// The ID of the `object_type` resource. An `object_type` of `network`
+    /// returns a network ID, an `object_type` of `qos-policy` returns a QoS
+    /// policy ID, an `object_type` of `security-group` returns a security
+    /// group ID, an `object_type` of `address-scope` returns a address scope
+    /// ID, an `object_type` of `subnetpool` returns a subnetpool ID and an
+    /// `object_type` of `address-group` returns an address group ID.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    object_id: Option<String>,
+
+    /// The type of the object that the RBAC policy affects. Types include
+    /// `qos-policy`, `network`, `security-group`, `address-scope`,
+    /// `subnetpool` or `address-group`.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    object_type: Option<String>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    project_id: Option<String>,
+
+    /// The ID of the tenant to which the RBAC policy will be enforced.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    target_tenant: Option<String>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    tenant_id: Option<String>,
+}
+
+impl RbacPolicyCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        client: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        info!("Set RbacPolicy");
+
+        let op = OutputProcessor::from_args(parsed_args);
+        op.validate_args(parsed_args)?;
+
+        let mut ep_builder = set::Request::builder();
+
+        // Set path parameters
+        ep_builder.id(&self.path.id);
+        // Set query parameters
+        // Set body parameters
+        // Set Request.rbac_policy data
+        let args = &self.rbac_policy;
+        let mut rbac_policy_builder = set::RbacPolicyBuilder::default();
+        if let Some(val) = &args.target_tenant {
+            rbac_policy_builder.target_tenant(val);
+        }
+
+        ep_builder.rbac_policy(rbac_policy_builder.build().unwrap());
+
+        let ep = ep_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+
+        let data = ep.query_async(client).await?;
+        op.output_single::<ResponseData>(data)?;
+        Ok(())
+    }
+}
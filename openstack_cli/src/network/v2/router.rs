@@ -16,8 +16,11 @@
 
 use clap::{Parser, Subcommand};
 
+use openstack_sdk::types::network::v2::Router;
 use openstack_sdk::AsyncOpenStack;
 
+use crate::network::v2::tag::{impl_tagged_resource, TagCommand};
+use crate::output::impl_struct_table;
 use crate::{Cli, OpenStackCliError};
 
 mod add_external_gateways;
@@ -31,8 +34,36 @@ mod list;
 mod remove_external_gateways;
 mod remove_extraroutes;
 mod remove_router_interface;
+mod set;
 mod show;
-mod tag;
+
+impl_struct_table!(
+    Router,
+    ["created_at", "id", "name", "status", "updated_at"],
+    [
+        "admin_state_up",
+        "availability_zone_hints",
+        "availability_zones",
+        "conntrack_helpers",
+        "description",
+        "distributed",
+        "enable_ndp_proxy",
+        "external_gateway_info",
+        "flavor_id",
+        "ha",
+        "revision_number",
+        "routes",
+        "tags",
+        "tenant_id"
+    ]
+);
+
+impl_tagged_resource!(
+    TaggedRouter,
+    openstack_sdk::api::network::v2::router::find::Request<'static>,
+    "routers",
+    "ROUTER"
+);
 
 /// Router commands
 #[derive(Parser)]
@@ -57,8 +88,9 @@ pub enum RouterCommands {
     RemoveExternalGateway(Box<remove_external_gateways::RouterCommand>),
     RemoveExtraroute(Box<remove_extraroutes::RouterCommand>),
     RemoveRouterInterface(Box<remove_router_interface::RouterCommand>),
+    Set(Box<set::RouterCommand>),
     Show(show::RouterCommand),
-    Tag(tag::TagCommand),
+    Tag(Box<TagCommand<TaggedRouter>>),
 }
 
 impl RouterCommand {
@@ -84,6 +116,7 @@ impl RouterCommand {
             RouterCommands::RemoveRouterInterface(cmd) => {
                 cmd.take_action(parsed_args, session).await
             }
+            RouterCommands::Set(cmd) => cmd.take_action(parsed_args, session).await,
             RouterCommands::Show(cmd) => cmd.take_action(parsed_args, session).await,
             RouterCommands::Tag(cmd) => cmd.take_action(parsed_args, session).await,
         }
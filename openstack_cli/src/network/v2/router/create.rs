@@ -158,6 +158,17 @@ struct Router {
     ///
     #[arg(help_heading = "Body parameters", long)]
     tenant_id: Option<String>,
+
+    /// Tag to set on the resource. Repeat the option to set multiple tags.
+    ///
+    #[arg(
+        action=clap::ArgAction::Append,
+        help_heading = "Body parameters",
+        long = "tag",
+        value_name = "TAG",
+        visible_alias = "tags"
+    )]
+    tags: Option<Vec<String>>,
 }
 
 /// Router response representation
@@ -375,6 +386,10 @@ impl RouterCommand {
             router_builder.description(val);
         }
 
+        if let Some(val) = &args.tags {
+            router_builder.tags(val.iter().map(|v| v.into()).collect::<Vec<_>>());
+        }
+
         ep_builder.router(router_builder.build().unwrap());
 
         let ep = ep_builder
@@ -20,7 +20,6 @@
 //! Wraps invoking of the `v2.0/routers` with `GET` method
 
 use clap::Args;
-use serde::{Deserialize, Serialize};
 use tracing::info;
 
 use anyhow::Result;
@@ -30,14 +29,10 @@ use openstack_sdk::AsyncOpenStack;
 use crate::output::OutputProcessor;
 use crate::Cli;
 use crate::OpenStackCliError;
-use crate::OutputConfig;
-use crate::StructTable;
 
-use crate::common::BoolString;
 use openstack_sdk::api::network::v2::router::list;
-use openstack_sdk::api::QueryAsync;
-use serde_json::Value;
-use structable_derive::StructTable;
+use openstack_sdk::api::{paged, Pagination};
+use openstack_sdk::types::network::v2::Router;
 
 /// Lists logical routers that the project who submits the request can access.
 ///
@@ -64,6 +59,10 @@ pub struct RoutersCommand {
     /// Path parameters
     #[command(flatten)]
     path: PathParameters,
+
+    /// Total limit of entities count to return. Use this when there are too many entries.
+    #[arg(long, default_value_t = 10000)]
+    max_items: usize,
 }
 
 /// Query parameters
@@ -89,6 +88,22 @@ struct QueryParameters {
     #[arg(help_heading = "Query parameters", long)]
     id: Option<String>,
 
+    /// Requests a page size of items. Returns a number of items up to a limit
+    /// value. Use the limit parameter to make an initial limited request and
+    /// use the ID of the last-seen item from the response as the marker
+    /// parameter value in a subsequent limited request.
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    limit: Option<i32>,
+
+    /// The ID of the last-seen item. Use the limit parameter to make an
+    /// initial limited request and use the ID of the last-seen item from the
+    /// response as the marker parameter value in a subsequent limited
+    /// request.
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    marker: Option<String>,
+
     /// name query parameter for /v2.0/routers API
     ///
     #[arg(help_heading = "Query parameters", long)]
@@ -128,142 +143,6 @@ struct QueryParameters {
 /// Path parameters
 #[derive(Args)]
 struct PathParameters {}
-/// Routers response representation
-#[derive(Deserialize, Serialize, Clone, StructTable)]
-struct ResponseData {
-    /// The administrative state of the resource, which is up (`true`) or down
-    /// (`false`).
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    admin_state_up: Option<BoolString>,
-
-    /// The availability zone candidates for the router. It is available when
-    /// `router_availability_zone` extension is enabled.
-    ///
-    #[serde()]
-    #[structable(optional, pretty, wide)]
-    availability_zone_hints: Option<Value>,
-
-    /// The availability zone(s) for the router. It is available when
-    /// `router_availability_zone` extension is enabled.
-    ///
-    #[serde()]
-    #[structable(optional, pretty, wide)]
-    availability_zones: Option<Value>,
-
-    /// The associated conntrack helper resources for the roter. If the router
-    /// has multiple conntrack helper resources, this field has multiple
-    /// entries. Each entry consists of netfilter conntrack helper (`helper`),
-    /// the network protocol (`protocol`), the network port (`port`).
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    conntrack_helpers: Option<String>,
-
-    /// Time at which the resource has been created (in UTC ISO8601 format).
-    ///
-    #[serde()]
-    #[structable(optional)]
-    created_at: Option<String>,
-
-    /// A human-readable description for the resource.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    description: Option<String>,
-
-    /// `true` indicates a distributed router. It is available when `dvr`
-    /// extension is enabled.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    distributed: Option<BoolString>,
-
-    /// Enable NDP proxy attribute. `true` means NDP proxy is enabled for the
-    /// router, the IPv6 address of internal subnets attached to the router can
-    /// be published to external by create `ndp_proxy`. `false` means NDP proxy
-    /// is disabled, the IPv6 address of internal subnets attached to the
-    /// router can not be published to external by `ndp_proxy`. It is available
-    /// when `router-extend-ndp-proxy` extension is enabled.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    enable_ndp_proxy: Option<BoolString>,
-
-    /// The external gateway information of the router. If the router has an
-    /// external gateway, this would be a dict with `network_id`,
-    /// `enable_snat`, `external_fixed_ips`, `qos_policy_id`,
-    /// `enable_default_route_ecmp` and `enable_default_route_bfd`. Otherwise,
-    /// this would be `null`.
-    ///
-    #[serde()]
-    #[structable(optional, pretty, wide)]
-    external_gateway_info: Option<Value>,
-
-    /// The ID of the flavor associated with the router.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    flavor_id: Option<String>,
-
-    /// `true` indicates a highly-available router. It is available when
-    /// `l3-ha` extension is enabled.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    ha: Option<BoolString>,
-
-    /// The ID of the router.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    id: Option<String>,
-
-    /// Human-readable name of the resource.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    name: Option<String>,
-
-    /// The revision number of the resource.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    revision_number: Option<i32>,
-
-    /// The extra routes configuration for L3 router. A list of dictionaries
-    /// with `destination` and `nexthop` parameters. It is available when
-    /// `extraroute` extension is enabled.
-    ///
-    #[serde()]
-    #[structable(optional, pretty, wide)]
-    routes: Option<Value>,
-
-    /// The router status.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    status: Option<String>,
-
-    /// The list of tags on the resource.
-    ///
-    #[serde()]
-    #[structable(optional, pretty, wide)]
-    tags: Option<Value>,
-
-    /// The ID of the project.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    tenant_id: Option<String>,
-
-    /// Time at which the resource has been updated (in UTC ISO8601 format).
-    ///
-    #[serde()]
-    #[structable(optional)]
-    updated_at: Option<String>,
-}
 
 impl RoutersCommand {
     /// Perform command action
@@ -281,6 +160,12 @@ impl RoutersCommand {
 
         // Set path parameters
         // Set query parameters
+        if let Some(val) = &self.query.limit {
+            ep_builder.limit(*val);
+        }
+        if let Some(val) = &self.query.marker {
+            ep_builder.marker(val);
+        }
         if let Some(val) = &self.query.id {
             ep_builder.id(val);
         }
@@ -320,9 +205,10 @@ impl RoutersCommand {
             .build()
             .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
 
-        let data: Vec<serde_json::Value> = ep.query_async(client).await?;
+        let ep = paged(ep, Pagination::Limit(self.max_items)).prefetch(true);
 
-        op.output_list::<ResponseData>(data)?;
+        op.output_list_stream::<Router, _, _>(ep.iter_async(client))
+            .await?;
         Ok(())
     }
 }
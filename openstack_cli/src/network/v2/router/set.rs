@@ -35,9 +35,11 @@ use crate::StructTable;
 
 use crate::common::parse_json;
 use crate::common::BoolString;
+use openstack_sdk::api::conditional;
 use openstack_sdk::api::find;
 use openstack_sdk::api::network::v2::router::find;
 use openstack_sdk::api::network::v2::router::set;
+use openstack_sdk::api::Precondition;
 use openstack_sdk::api::QueryAsync;
 use serde_json::Value;
 use structable_derive::StructTable;
@@ -67,6 +69,12 @@ pub struct RouterCommand {
     ///
     #[command(flatten)]
     router: Router,
+
+    /// Only update the resource when its current `revision_number` matches
+    /// the given value.
+    ///
+    #[arg(help_heading = "Header parameters", long, value_name = "REVISION")]
+    if_revision: Option<u64>,
 }
 
 /// Query parameters
@@ -384,7 +392,14 @@ impl RouterCommand {
             .build()
             .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
 
-        let data = ep.query_async(client).await?;
+        let data = match self.if_revision {
+            Some(revision) => {
+                conditional(ep, Precondition::RevisionNumber(revision))
+                    .query_async(client)
+                    .await?
+            }
+            None => ep.query_async(client).await?,
+        };
         op.output_single::<ResponseData>(data)?;
         Ok(())
     }
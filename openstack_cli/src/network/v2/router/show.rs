@@ -20,7 +20,6 @@
 //! Wraps invoking of the `v2.0/routers/{id}` with `GET` method
 
 use clap::Args;
-use serde::{Deserialize, Serialize};
 use tracing::info;
 
 use anyhow::Result;
@@ -30,15 +29,11 @@ use openstack_sdk::AsyncOpenStack;
 use crate::output::OutputProcessor;
 use crate::Cli;
 use crate::OpenStackCliError;
-use crate::OutputConfig;
-use crate::StructTable;
 
-use crate::common::BoolString;
 use openstack_sdk::api::find;
 use openstack_sdk::api::network::v2::router::find;
 use openstack_sdk::api::QueryAsync;
-use serde_json::Value;
-use structable_derive::StructTable;
+use openstack_sdk::types::network::v2::Router;
 
 /// Shows details for a router.
 ///
@@ -78,142 +73,6 @@ struct PathParameters {
     )]
     id: String,
 }
-/// Router response representation
-#[derive(Deserialize, Serialize, Clone, StructTable)]
-struct ResponseData {
-    /// The administrative state of the resource, which is up (`true`) or down
-    /// (`false`).
-    ///
-    #[serde()]
-    #[structable(optional)]
-    admin_state_up: Option<BoolString>,
-
-    /// The availability zone candidates for the router. It is available when
-    /// `router_availability_zone` extension is enabled.
-    ///
-    #[serde()]
-    #[structable(optional, pretty)]
-    availability_zone_hints: Option<Value>,
-
-    /// The availability zone(s) for the router. It is available when
-    /// `router_availability_zone` extension is enabled.
-    ///
-    #[serde()]
-    #[structable(optional, pretty)]
-    availability_zones: Option<Value>,
-
-    /// The associated conntrack helper resources for the roter. If the router
-    /// has multiple conntrack helper resources, this field has multiple
-    /// entries. Each entry consists of netfilter conntrack helper (`helper`),
-    /// the network protocol (`protocol`), the network port (`port`).
-    ///
-    #[serde()]
-    #[structable(optional)]
-    conntrack_helpers: Option<String>,
-
-    /// Time at which the resource has been created (in UTC ISO8601 format).
-    ///
-    #[serde()]
-    #[structable(optional)]
-    created_at: Option<String>,
-
-    /// A human-readable description for the resource.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    description: Option<String>,
-
-    /// `true` indicates a distributed router. It is available when `dvr`
-    /// extension is enabled.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    distributed: Option<BoolString>,
-
-    /// Enable NDP proxy attribute. `true` means NDP proxy is enabled for the
-    /// router, the IPv6 address of internal subnets attached to the router can
-    /// be published to external by create `ndp_proxy`. `false` means NDP proxy
-    /// is disabled, the IPv6 address of internal subnets attached to the
-    /// router can not be published to external by `ndp_proxy`. It is available
-    /// when `router-extend-ndp-proxy` extension is enabled.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    enable_ndp_proxy: Option<BoolString>,
-
-    /// The external gateway information of the router. If the router has an
-    /// external gateway, this would be a dict with `network_id`,
-    /// `enable_snat`, `external_fixed_ips`, `qos_policy_id`,
-    /// `enable_default_route_ecmp` and `enable_default_route_bfd`. Otherwise,
-    /// this would be `null`.
-    ///
-    #[serde()]
-    #[structable(optional, pretty)]
-    external_gateway_info: Option<Value>,
-
-    /// The ID of the flavor associated with the router.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    flavor_id: Option<String>,
-
-    /// `true` indicates a highly-available router. It is available when
-    /// `l3-ha` extension is enabled.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    ha: Option<BoolString>,
-
-    /// The ID of the router.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    id: Option<String>,
-
-    /// Human-readable name of the resource.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    name: Option<String>,
-
-    /// The revision number of the resource.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    revision_number: Option<i32>,
-
-    /// The extra routes configuration for L3 router. A list of dictionaries
-    /// with `destination` and `nexthop` parameters. It is available when
-    /// `extraroute` extension is enabled.
-    ///
-    #[serde()]
-    #[structable(optional, pretty)]
-    routes: Option<Value>,
-
-    /// The router status.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    status: Option<String>,
-
-    /// The list of tags on the resource.
-    ///
-    #[serde()]
-    #[structable(optional, pretty)]
-    tags: Option<Value>,
-
-    /// The ID of the project.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    tenant_id: Option<String>,
-
-    /// Time at which the resource has been updated (in UTC ISO8601 format).
-    ///
-    #[serde()]
-    #[structable(optional)]
-    updated_at: Option<String>,
-}
 
 impl RouterCommand {
     /// Perform command action
@@ -235,7 +94,7 @@ impl RouterCommand {
             .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
         let find_data: serde_json::Value = find(find_ep).query_async(client).await?;
 
-        op.output_single::<ResponseData>(find_data)?;
+        op.output_single::<Router>(find_data)?;
         Ok(())
     }
 }
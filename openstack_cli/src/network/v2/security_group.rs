@@ -0,0 +1,75 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! SecurityGroup resource commands
+
+use clap::{Parser, Subcommand};
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::network::v2::tag::{impl_tagged_resource, TagCommand};
+use crate::{Cli, OpenStackCliError};
+
+mod create;
+mod delete;
+mod list;
+mod set;
+mod show;
+
+impl_tagged_resource!(
+    TaggedSecurityGroup,
+    openstack_sdk::api::network::v2::security_group::find::Request<'static>,
+    "security-groups",
+    "SECURITY_GROUP"
+);
+
+/// Security groups
+///
+/// Lists, creates, shows details for, updates, and deletes security groups.
+#[derive(Parser)]
+pub struct SecurityGroupCommand {
+    /// subcommand
+    #[command(subcommand)]
+    command: SecurityGroupCommands,
+}
+
+/// Supported subcommands
+#[allow(missing_docs)]
+#[derive(Subcommand)]
+pub enum SecurityGroupCommands {
+    Create(Box<create::SecurityGroupCommand>),
+    Delete(Box<delete::SecurityGroupCommand>),
+    List(Box<list::SecurityGroupsCommand>),
+    Set(Box<set::SecurityGroupCommand>),
+    Show(Box<show::SecurityGroupCommand>),
+    Tag(Box<TagCommand<TaggedSecurityGroup>>),
+}
+
+impl SecurityGroupCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        session: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        match &self.command {
+            SecurityGroupCommands::Create(cmd) => cmd.take_action(parsed_args, session).await,
+            SecurityGroupCommands::Delete(cmd) => cmd.take_action(parsed_args, session).await,
+            SecurityGroupCommands::List(cmd) => cmd.take_action(parsed_args, session).await,
+            SecurityGroupCommands::Set(cmd) => cmd.take_action(parsed_args, session).await,
+            SecurityGroupCommands::Show(cmd) => cmd.take_action(parsed_args, session).await,
+            SecurityGroupCommands::Tag(cmd) => cmd.take_action(parsed_args, session).await,
+        }
+    }
+}
@@ -0,0 +1,243 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Create Segment command
+//!
+//! Wraps invoking of the `v2.0/segments` with `POST` method
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use anyhow::Result;
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::output::OutputProcessor;
+use crate::Cli;
+use crate::OpenStackCliError;
+use crate::OutputConfig;
+use crate::StructTable;
+
+use openstack_sdk::api::find;
+use openstack_sdk::api::network::v2::network::find as find_network;
+use openstack_sdk::api::network::v2::segment::create;
+use openstack_sdk::api::QueryAsync;
+use structable_derive::StructTable;
+
+/// Creates a segment.
+///
+/// Normal response codes: 201
+///
+/// Error response codes: 400, 401
+///
+#[derive(Args)]
+#[command(about = "Create segment")]
+pub struct SegmentCommand {
+    /// Request Query parameters
+    #[command(flatten)]
+    query: QueryParameters,
+
+    /// Path parameters
+    #[command(flatten)]
+    path: PathParameters,
+
+    /// A `segment` object.
+    ///
+    #[command(flatten)]
+    segment: Segment,
+}
+
+/// Query parameters
+#[derive(Args)]
+struct QueryParameters {}
+
+/// Path parameters
+#[derive(Args)]
+struct PathParameters {}
+/// Segment Body data
+#[derive(Args, Clone)]
+struct Segment {
+    /// A human-readable description for the resource. Default is an empty
+    /// string.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    description: Option<String>,
+
+    /// Human-readable name of the segment.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    name: Option<String>,
+
+    /// The name or ID of the attached network.
+    ///
+    #[arg(
+        help_heading = "Body parameters",
+        long,
+        value_name = "NETWORK",
+        visible_alias = "network"
+    )]
+    network_id: String,
+
+    /// The type of physical network that maps to this network resource. For
+    /// example, `flat`, `vlan`, `vxlan`, or `gre`.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    network_type: String,
+
+    /// The physical network where this network/segment is implemented.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    physical_network: Option<String>,
+
+    /// The ID of the isolated segment on the physical network. The
+    /// `network_type` attribute defines the segmentation model. For example,
+    /// if the `network_type` value is `vlan`, this ID is a vlan identifier. If
+    /// the `network_type` value is `gre`, this ID is a gre key.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    segmentation_id: Option<i32>,
+}
+
+/// Segment response representation
+#[derive(Deserialize, Serialize, Clone, StructTable)]
+struct ResponseData {
+    /// Time at which the resource has been created (in UTC ISO8601 format).
+    ///
+    #[serde()]
+    #[structable(optional)]
+    created_at: Option<String>,
+
+    /// A human-readable description for the resource.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    description: Option<String>,
+
+    /// The ID of the segment.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    id: Option<String>,
+
+    /// Human-readable name of the segment.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    name: Option<String>,
+
+    /// The ID of the attached network.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    network_id: Option<String>,
+
+    /// The type of physical network that maps to this network resource. For
+    /// example, `flat`, `vlan`, `vxlan`, or `gre`.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    network_type: Option<String>,
+
+    /// The physical network where this network/segment is implemented.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    physical_network: Option<String>,
+
+    /// The revision number of the resource.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    revision_number: Option<i32>,
+
+    /// The ID of the isolated segment on the physical network. The
+    /// `network_type` attribute defines the segmentation model. For example,
+    /// if the `network_type` value is `vlan`, this ID is a vlan identifier. If
+    /// the `network_type` value is `gre`, this ID is a gre key.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    segmentation_id: Option<i32>,
+
+    /// Time at which the resource has been updated (in UTC ISO8601 format).
+    ///
+    #[serde()]
+    #[structable(optional)]
+    updated_at: Option<String>,
+}
+
+impl SegmentCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        client: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        info!("Create Segment");
+
+        let op = OutputProcessor::from_args(parsed_args);
+        op.validate_args(parsed_args)?;
+
+        let args = &self.segment;
+
+        let mut find_builder = find_network::Request::builder();
+        find_builder.id(&args.network_id);
+        let find_ep = find_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+        let find_data: serde_json::Value = find(find_ep).query_async(client).await?;
+        let network_id = find_data["id"]
+            .as_str()
+            .expect("Resource ID is a string")
+            .to_string();
+
+        let mut ep_builder = create::Request::builder();
+
+        // Set path parameters
+        // Set query parameters
+        // Set body parameters
+        // Set Request.segment data
+        let mut segment_builder = create::SegmentBuilder::default();
+        if let Some(val) = &args.description {
+            segment_builder.description(val);
+        }
+
+        if let Some(val) = &args.name {
+            segment_builder.name(val);
+        }
+
+        segment_builder.network_id(network_id);
+
+        segment_builder.network_type(&args.network_type);
+
+        if let Some(val) = &args.physical_network {
+            segment_builder.physical_network(val);
+        }
+
+        if let Some(val) = &args.segmentation_id {
+            segment_builder.segmentation_id(*val);
+        }
+
+        ep_builder.segment(segment_builder.build().unwrap());
+
+        let ep = ep_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+
+        let data = ep.query_async(client).await?;
+        op.output_single::<ResponseData>(data)?;
+        Ok(())
+    }
+}
@@ -16,8 +16,11 @@
 
 use clap::{Parser, Subcommand};
 
+use openstack_sdk::types::network::v2::Subnet;
 use openstack_sdk::AsyncOpenStack;
 
+use crate::network::v2::tag::{impl_tagged_resource, TagCommand};
+use crate::output::impl_struct_table;
 use crate::{Cli, OpenStackCliError};
 
 mod create;
@@ -25,7 +28,38 @@ mod delete;
 mod list;
 mod set;
 mod show;
-mod tag;
+
+impl_struct_table!(
+    Subnet,
+    ["created_at", "id", "name", "updated_at"],
+    [
+        "allocation_pools",
+        "cidr",
+        "description",
+        "dns_nameservers",
+        "dns_publish_fixed_ip",
+        "enable_dhcp",
+        "gateway_ip",
+        "host_routes",
+        "ip_version",
+        "ipv6_address_mode",
+        "ipv6_ra_mode",
+        "network_id",
+        "revision_number",
+        "segment_id",
+        "service_types",
+        "subnetpool_id",
+        "tags",
+        "tenant_id"
+    ]
+);
+
+impl_tagged_resource!(
+    TaggedSubnet,
+    openstack_sdk::api::network::v2::subnet::find::Request<'static>,
+    "subnets",
+    "SUBNET"
+);
 
 /// Subnet commands
 #[derive(Parser)]
@@ -44,7 +78,7 @@ pub enum SubnetCommands {
     List(Box<list::SubnetsCommand>),
     Set(Box<set::SubnetCommand>),
     Show(Box<show::SubnetCommand>),
-    Tag(Box<tag::TagCommand>),
+    Tag(Box<TagCommand<TaggedSubnet>>),
 }
 
 impl SubnetCommand {
@@ -234,6 +234,17 @@ struct Subnet {
     ///
     #[arg(action=clap::ArgAction::Set, help_heading = "Body parameters", long)]
     use_default_subnetpool: Option<bool>,
+
+    /// Tag to set on the resource. Repeat the option to set multiple tags.
+    ///
+    #[arg(
+        action=clap::ArgAction::Append,
+        help_heading = "Body parameters",
+        long = "tag",
+        value_name = "TAG",
+        visible_alias = "tags"
+    )]
+    tags: Option<Vec<String>>,
 }
 
 /// Subnet response representation
@@ -488,6 +499,10 @@ impl SubnetCommand {
             subnet_builder.segment_id(Some(val.into()));
         }
 
+        if let Some(val) = &args.tags {
+            subnet_builder.tags(val.iter().map(|v| v.into()).collect::<Vec<_>>());
+        }
+
         ep_builder.subnet(subnet_builder.build().unwrap());
 
         let ep = ep_builder
@@ -20,7 +20,6 @@
 //! Wraps invoking of the `v2.0/subnets` with `GET` method
 
 use clap::Args;
-use serde::{Deserialize, Serialize};
 use tracing::info;
 
 use anyhow::Result;
@@ -30,14 +29,10 @@ use openstack_sdk::AsyncOpenStack;
 use crate::output::OutputProcessor;
 use crate::Cli;
 use crate::OpenStackCliError;
-use crate::OutputConfig;
-use crate::StructTable;
 
-use crate::common::BoolString;
 use openstack_sdk::api::network::v2::subnet::list;
-use openstack_sdk::api::QueryAsync;
-use serde_json::Value;
-use structable_derive::StructTable;
+use openstack_sdk::api::{paged, Pagination};
+use openstack_sdk::types::network::v2::Subnet;
 
 /// Lists subnets that the project has access to.
 ///
@@ -65,6 +60,10 @@ pub struct SubnetsCommand {
     /// Path parameters
     #[command(flatten)]
     path: PathParameters,
+
+    /// Total limit of entities count to return. Use this when there are too many entries.
+    #[arg(long, default_value_t = 10000)]
+    max_items: usize,
 }
 
 /// Query parameters
@@ -110,6 +109,22 @@ struct QueryParameters {
     #[arg(help_heading = "Query parameters", long, value_parser = ["dhcpv6-stateful","dhcpv6-stateless","slaac"])]
     ipv6_ra_mode: Option<String>,
 
+    /// Requests a page size of items. Returns a number of items up to a limit
+    /// value. Use the limit parameter to make an initial limited request and
+    /// use the ID of the last-seen item from the response as the marker
+    /// parameter value in a subsequent limited request.
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    limit: Option<i32>,
+
+    /// The ID of the last-seen item. Use the limit parameter to make an
+    /// initial limited request and use the ID of the last-seen item from the
+    /// response as the marker parameter value in a subsequent limited
+    /// request.
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    marker: Option<String>,
+
     /// name query parameter for /v2.0/subnets API
     ///
     #[arg(help_heading = "Query parameters", long)]
@@ -169,147 +184,6 @@ struct QueryParameters {
 /// Path parameters
 #[derive(Args)]
 struct PathParameters {}
-/// Subnets response representation
-#[derive(Deserialize, Serialize, Clone, StructTable)]
-struct ResponseData {
-    /// Allocation pools with `start` and `end` IP addresses for this subnet.
-    ///
-    #[serde()]
-    #[structable(optional, pretty, wide)]
-    allocation_pools: Option<Value>,
-
-    /// The CIDR of the subnet.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    cidr: Option<String>,
-
-    /// Time at which the resource has been created (in UTC ISO8601 format).
-    ///
-    #[serde()]
-    #[structable(optional)]
-    created_at: Option<String>,
-
-    /// A human-readable description for the resource.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    description: Option<String>,
-
-    /// List of dns name servers associated with the subnet.
-    ///
-    #[serde()]
-    #[structable(optional, pretty, wide)]
-    dns_nameservers: Option<Value>,
-
-    /// Whether to publish DNS records for IPs from this subnet.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    dns_publish_fixed_ip: Option<BoolString>,
-
-    /// Indicates whether dhcp is enabled or disabled for the subnet.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    enable_dhcp: Option<BoolString>,
-
-    /// Gateway IP of this subnet. If the value is `null` that implies no
-    /// gateway is associated with the subnet.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    gateway_ip: Option<String>,
-
-    /// Additional routes for the subnet. A list of dictionaries with
-    /// `destination` and `nexthop` parameters.
-    ///
-    #[serde()]
-    #[structable(optional, pretty, wide)]
-    host_routes: Option<Value>,
-
-    /// The ID of the subnet.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    id: Option<String>,
-
-    /// The IP protocol version. Value is `4` or `6`.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    ip_version: Option<i32>,
-
-    /// The IPv6 address modes specifies mechanisms for assigning IP addresses.
-    /// Value is `slaac`, `dhcpv6-stateful`, `dhcpv6-stateless` or `null`.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    ipv6_address_mode: Option<String>,
-
-    /// The IPv6 router advertisement specifies whether the networking service
-    /// should transmit ICMPv6 packets, for a subnet. Value is `slaac`,
-    /// `dhcpv6-stateful`, `dhcpv6-stateless` or `null`.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    ipv6_ra_mode: Option<String>,
-
-    /// Human-readable name of the resource.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    name: Option<String>,
-
-    /// The ID of the network to which the subnet belongs.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    network_id: Option<String>,
-
-    /// The revision number of the resource.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    revision_number: Option<i32>,
-
-    /// The ID of a network segment the subnet is associated with. It is
-    /// available when `segment` extension is enabled.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    segment_id: Option<String>,
-
-    /// The service types associated with the subnet.
-    ///
-    #[serde()]
-    #[structable(optional, pretty, wide)]
-    service_types: Option<Value>,
-
-    /// The ID of the subnet pool associated with the subnet.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    subnetpool_id: Option<String>,
-
-    /// The list of tags on the resource.
-    ///
-    #[serde()]
-    #[structable(optional, pretty, wide)]
-    tags: Option<Value>,
-
-    /// The ID of the project.
-    ///
-    #[serde()]
-    #[structable(optional, wide)]
-    tenant_id: Option<String>,
-
-    /// Time at which the resource has been updated (in UTC ISO8601 format).
-    ///
-    #[serde()]
-    #[structable(optional)]
-    updated_at: Option<String>,
-}
 
 impl SubnetsCommand {
     /// Perform command action
@@ -327,6 +201,12 @@ impl SubnetsCommand {
 
         // Set path parameters
         // Set query parameters
+        if let Some(val) = &self.query.limit {
+            ep_builder.limit(*val);
+        }
+        if let Some(val) = &self.query.marker {
+            ep_builder.marker(val);
+        }
         if let Some(val) = &self.query.id {
             ep_builder.id(val);
         }
@@ -390,9 +270,10 @@ impl SubnetsCommand {
             .build()
             .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
 
-        let data: Vec<serde_json::Value> = ep.query_async(client).await?;
+        let ep = paged(ep, Pagination::Limit(self.max_items)).prefetch(true);
 
-        op.output_list::<ResponseData>(data)?;
+        op.output_list_stream::<Subnet, _, _>(ep.iter_async(client))
+            .await?;
         Ok(())
     }
 }
@@ -20,7 +20,6 @@
 //! Wraps invoking of the `v2.0/subnets/{subnet_id}` with `GET` method
 
 use clap::Args;
-use serde::{Deserialize, Serialize};
 use tracing::info;
 
 use anyhow::Result;
@@ -30,15 +29,11 @@ use openstack_sdk::AsyncOpenStack;
 use crate::output::OutputProcessor;
 use crate::Cli;
 use crate::OpenStackCliError;
-use crate::OutputConfig;
-use crate::StructTable;
 
-use crate::common::BoolString;
 use openstack_sdk::api::find;
 use openstack_sdk::api::network::v2::subnet::find;
 use openstack_sdk::api::QueryAsync;
-use serde_json::Value;
-use structable_derive::StructTable;
+use openstack_sdk::types::network::v2::Subnet;
 
 /// Shows details for a subnet.
 ///
@@ -76,147 +71,6 @@ struct PathParameters {
     )]
     id: String,
 }
-/// Subnet response representation
-#[derive(Deserialize, Serialize, Clone, StructTable)]
-struct ResponseData {
-    /// Allocation pools with `start` and `end` IP addresses for this subnet.
-    ///
-    #[serde()]
-    #[structable(optional, pretty)]
-    allocation_pools: Option<Value>,
-
-    /// The CIDR of the subnet.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    cidr: Option<String>,
-
-    /// Time at which the resource has been created (in UTC ISO8601 format).
-    ///
-    #[serde()]
-    #[structable(optional)]
-    created_at: Option<String>,
-
-    /// A human-readable description for the resource.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    description: Option<String>,
-
-    /// List of dns name servers associated with the subnet.
-    ///
-    #[serde()]
-    #[structable(optional, pretty)]
-    dns_nameservers: Option<Value>,
-
-    /// Whether to publish DNS records for IPs from this subnet.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    dns_publish_fixed_ip: Option<BoolString>,
-
-    /// Indicates whether dhcp is enabled or disabled for the subnet.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    enable_dhcp: Option<BoolString>,
-
-    /// Gateway IP of this subnet. If the value is `null` that implies no
-    /// gateway is associated with the subnet.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    gateway_ip: Option<String>,
-
-    /// Additional routes for the subnet. A list of dictionaries with
-    /// `destination` and `nexthop` parameters.
-    ///
-    #[serde()]
-    #[structable(optional, pretty)]
-    host_routes: Option<Value>,
-
-    /// The ID of the subnet.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    id: Option<String>,
-
-    /// The IP protocol version. Value is `4` or `6`.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    ip_version: Option<i32>,
-
-    /// The IPv6 address modes specifies mechanisms for assigning IP addresses.
-    /// Value is `slaac`, `dhcpv6-stateful`, `dhcpv6-stateless` or `null`.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    ipv6_address_mode: Option<String>,
-
-    /// The IPv6 router advertisement specifies whether the networking service
-    /// should transmit ICMPv6 packets, for a subnet. Value is `slaac`,
-    /// `dhcpv6-stateful`, `dhcpv6-stateless` or `null`.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    ipv6_ra_mode: Option<String>,
-
-    /// Human-readable name of the resource.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    name: Option<String>,
-
-    /// The ID of the network to which the subnet belongs.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    network_id: Option<String>,
-
-    /// The revision number of the resource.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    revision_number: Option<i32>,
-
-    /// The ID of a network segment the subnet is associated with. It is
-    /// available when `segment` extension is enabled.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    segment_id: Option<String>,
-
-    /// The service types associated with the subnet.
-    ///
-    #[serde()]
-    #[structable(optional, pretty)]
-    service_types: Option<Value>,
-
-    /// The ID of the subnet pool associated with the subnet.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    subnetpool_id: Option<String>,
-
-    /// The list of tags on the resource.
-    ///
-    #[serde()]
-    #[structable(optional, pretty)]
-    tags: Option<Value>,
-
-    /// The ID of the project.
-    ///
-    #[serde()]
-    #[structable(optional)]
-    tenant_id: Option<String>,
-
-    /// Time at which the resource has been updated (in UTC ISO8601 format).
-    ///
-    #[serde()]
-    #[structable(optional)]
-    updated_at: Option<String>,
-}
 
 impl SubnetCommand {
     /// Perform command action
@@ -238,7 +92,7 @@ impl SubnetCommand {
             .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
         let find_data: serde_json::Value = find(find_ep).query_async(client).await?;
 
-        op.output_single::<ResponseData>(find_data)?;
+        op.output_single::<Subnet>(find_data)?;
         Ok(())
     }
 }
@@ -1 +0,0 @@
-../_tag.rs_
\ No newline at end of file
@@ -0,0 +1,77 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Subnetpool resource commands
+
+use clap::{Parser, Subcommand};
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::network::v2::tag::{impl_tagged_resource, TagCommand};
+use crate::{Cli, OpenStackCliError};
+
+mod create;
+mod delete;
+mod list;
+mod set;
+mod show;
+
+impl_tagged_resource!(
+    TaggedSubnetpool,
+    openstack_sdk::api::network::v2::subnetpool::find::Request<'static>,
+    "subnetpools",
+    "SUBNETPOOL"
+);
+
+/// Subnet pools
+///
+/// Lists, creates, shows details for, updates, and deletes subnet pools.
+/// Subnet pools are used to allocate subnet prefixes for projects without the
+/// need to provide the CIDR explicitly.
+#[derive(Parser)]
+pub struct SubnetpoolCommand {
+    /// subcommand
+    #[command(subcommand)]
+    command: SubnetpoolCommands,
+}
+
+/// Supported subcommands
+#[allow(missing_docs)]
+#[derive(Subcommand)]
+pub enum SubnetpoolCommands {
+    Create(Box<create::SubnetpoolCommand>),
+    Delete(Box<delete::SubnetpoolCommand>),
+    List(Box<list::SubnetpoolsCommand>),
+    Set(Box<set::SubnetpoolCommand>),
+    Show(Box<show::SubnetpoolCommand>),
+    Tag(Box<TagCommand<TaggedSubnetpool>>),
+}
+
+impl SubnetpoolCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        session: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        match &self.command {
+            SubnetpoolCommands::Create(cmd) => cmd.take_action(parsed_args, session).await,
+            SubnetpoolCommands::Delete(cmd) => cmd.take_action(parsed_args, session).await,
+            SubnetpoolCommands::List(cmd) => cmd.take_action(parsed_args, session).await,
+            SubnetpoolCommands::Set(cmd) => cmd.take_action(parsed_args, session).await,
+            SubnetpoolCommands::Show(cmd) => cmd.take_action(parsed_args, session).await,
+            SubnetpoolCommands::Tag(cmd) => cmd.take_action(parsed_args, session).await,
+        }
+    }
+}
@@ -0,0 +1,396 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// WARNING: This file is automatically generated from OpenAPI schema using
+// `openstack-codegenerator`.
+
+//! List Subnetpools command
+//!
+//! Wraps invoking of the `v2.0/subnetpools` with `GET` method
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use anyhow::Result;
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::output::OutputProcessor;
+use crate::Cli;
+use crate::OpenStackCliError;
+use crate::OutputConfig;
+use crate::StructTable;
+
+use crate::common::BoolString;
+use openstack_sdk::api::network::v2::subnetpool::list;
+use openstack_sdk::api::{paged, Pagination};
+use serde_json::Value;
+use structable_derive::StructTable;
+
+/// Lists subnet pools that the project has access to.
+///
+/// Default policy settings return only the subnet pools owned by the project
+/// of the user submitting the request, unless the user has administrative
+/// role.
+///
+/// Additionally, you can filter results by using query string parameters. For
+/// information, see
+/// [Filtering and Column Selection](https://wiki.openstack.org/wiki/Neutron/APIv2-specification#Filtering_and_Column_Selection).
+///
+/// Use the `fields` query parameter to control which fields are returned in
+/// the response body. For information, see
+/// [Filtering and Column Selection](https://wiki.openstack.org/wiki/Neutron/APIv2-specification#Filtering_and_Column_Selection).
+///
+/// You can also use the `tags`, `tags-any`, `not-tags`, `not-tags-any` query
+/// parameter to filter the response with tags. For information, see
+/// [REST API Impact](http://specs.openstack.org/openstack/neutron-specs/specs/mitaka/add-tags-to-core-resources.html#rest-api-impact).
+///
+/// Normal response codes: 200
+///
+/// Error response codes: 401
+///
+#[derive(Args)]
+#[command(about = "List subnet pools")]
+pub struct SubnetpoolsCommand {
+    /// Request Query parameters
+    #[command(flatten)]
+    query: QueryParameters,
+
+    /// Path parameters
+    #[command(flatten)]
+    path: PathParameters,
+
+    /// Total limit of entities count to return. Use this when there are too many entries.
+    #[arg(long, default_value_t = 10000)]
+    max_items: usize,
+}
+
+/// Query parameters
+#[derive(Args)]
+struct QueryParameters {
+    /// address_scope_id query parameter for /v2.0/subnetpools API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    address_scope_id: Option<String>,
+
+    /// default_prefixlen query parameter for /v2.0/subnetpools API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    default_prefixlen: Option<i32>,
+
+    /// default_quota query parameter for /v2.0/subnetpools API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    default_quota: Option<i32>,
+
+    /// description query parameter for /v2.0/subnetpools API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    description: Option<String>,
+
+    /// id query parameter for /v2.0/subnetpools API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    id: Option<String>,
+
+    /// ip_version query parameter for /v2.0/subnetpools API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    ip_version: Option<i32>,
+
+    /// is_default query parameter for /v2.0/subnetpools API
+    ///
+    #[arg(action=clap::ArgAction::Set, help_heading = "Query parameters", long)]
+    is_default: Option<bool>,
+
+    /// Requests a page size of items. Returns a number of items up to a limit
+    /// value. Use the limit parameter to make an initial limited request and
+    /// use the ID of the last-seen item from the response as the marker
+    /// parameter value in a subsequent limited request.
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    limit: Option<i32>,
+
+    /// The ID of the last-seen item. Use the limit parameter to make an
+    /// initial limited request and use the ID of the last-seen item from the
+    /// response as the marker parameter value in a subsequent limited
+    /// request.
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    marker: Option<String>,
+
+    /// max_prefixlen query parameter for /v2.0/subnetpools API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    max_prefixlen: Option<i32>,
+
+    /// min_prefixlen query parameter for /v2.0/subnetpools API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    min_prefixlen: Option<i32>,
+
+    /// name query parameter for /v2.0/subnetpools API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    name: Option<String>,
+
+    /// not-tags query parameter for /v2.0/subnetpools API
+    ///
+    #[arg(action=clap::ArgAction::Append, help_heading = "Query parameters", long)]
+    not_tags: Option<Vec<String>>,
+
+    /// not-tags-any query parameter for /v2.0/subnetpools API
+    ///
+    #[arg(action=clap::ArgAction::Append, help_heading = "Query parameters", long)]
+    not_tags_any: Option<Vec<String>>,
+
+    /// project_id query parameter for /v2.0/subnetpools API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    project_id: Option<String>,
+
+    /// revision_number query parameter for /v2.0/subnetpools API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    revision_number: Option<String>,
+
+    /// shared query parameter for /v2.0/subnetpools API
+    ///
+    #[arg(action=clap::ArgAction::Set, help_heading = "Query parameters", long)]
+    shared: Option<bool>,
+
+    /// tags query parameter for /v2.0/subnetpools API
+    ///
+    #[arg(action=clap::ArgAction::Append, help_heading = "Query parameters", long)]
+    tags: Option<Vec<String>>,
+
+    /// tags-any query parameter for /v2.0/subnetpools API
+    ///
+    #[arg(action=clap::ArgAction::Append, help_heading = "Query parameters", long)]
+    tags_any: Option<Vec<String>>,
+
+    /// tenant_id query parameter for /v2.0/subnetpools API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    tenant_id: Option<String>,
+}
+
+/// Path parameters
+#[derive(Args)]
+struct PathParameters {}
+/// Subnetpools response representation
+#[derive(Deserialize, Serialize, Clone, StructTable)]
+struct ResponseData {
+    /// An address scope to assign to the subnet pool.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    address_scope_id: Option<String>,
+
+    /// Time at which the resource has been created (in UTC ISO8601 format).
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    created_at: Option<String>,
+
+    /// The size of the prefix to allocate when the `cidr` or `prefixlen`
+    /// attributes are omitted when you create the subnet. Default is
+    /// `min_prefixlen`.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    default_prefixlen: Option<i32>,
+
+    /// A per-project quota on the prefix space that can be allocated from the
+    /// subnet pool for project subnets.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    default_quota: Option<i32>,
+
+    /// A human-readable description for the resource.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    description: Option<String>,
+
+    /// The ID of the subnet pool.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    id: Option<String>,
+
+    /// The IP protocol version. Valid value is `4` or `6`.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    ip_version: Option<i32>,
+
+    /// The subnetpool is default pool or not.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    is_default: Option<BoolString>,
+
+    /// The maximum prefix size that can be allocated from the subnet pool.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    max_prefixlen: Option<i32>,
+
+    /// The smallest prefix that can be allocated from a subnet pool.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    min_prefixlen: Option<i32>,
+
+    /// Human-readable name of the resource.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    name: Option<String>,
+
+    /// A list of subnet prefixes that are assigned to the subnet pool.
+    ///
+    #[serde()]
+    #[structable(optional, pretty)]
+    prefixes: Option<Value>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    project_id: Option<String>,
+
+    /// The revision number of the resource.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    revision_number: Option<i32>,
+
+    /// Indicates whether this resource is shared across all projects.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    shared: Option<BoolString>,
+
+    /// The list of tags on the resource.
+    ///
+    #[serde()]
+    #[structable(optional, pretty, wide)]
+    tags: Option<Value>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    tenant_id: Option<String>,
+
+    /// Time at which the resource has been updated (in UTC ISO8601 format).
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    updated_at: Option<String>,
+}
+
+impl SubnetpoolsCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        client: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        info!("List Subnetpools");
+
+        let op = OutputProcessor::from_args(parsed_args);
+        op.validate_args(parsed_args)?;
+
+        let mut ep_builder = list::Request::builder();
+
+        // Set path parameters
+        // Set query parameters
+        if let Some(val) = &self.query.limit {
+            ep_builder.limit(*val);
+        }
+        if let Some(val) = &self.query.marker {
+            ep_builder.marker(val);
+        }
+        if let Some(val) = &self.query.address_scope_id {
+            ep_builder.address_scope_id(val);
+        }
+        if let Some(val) = &self.query.default_prefixlen {
+            ep_builder.default_prefixlen(*val);
+        }
+        if let Some(val) = &self.query.default_quota {
+            ep_builder.default_quota(*val);
+        }
+        if let Some(val) = &self.query.description {
+            ep_builder.description(val);
+        }
+        if let Some(val) = &self.query.id {
+            ep_builder.id(val);
+        }
+        if let Some(val) = &self.query.ip_version {
+            ep_builder.ip_version(*val);
+        }
+        if let Some(val) = &self.query.is_default {
+            ep_builder.is_default(*val);
+        }
+        if let Some(val) = &self.query.max_prefixlen {
+            ep_builder.max_prefixlen(*val);
+        }
+        if let Some(val) = &self.query.min_prefixlen {
+            ep_builder.min_prefixlen(*val);
+        }
+        if let Some(val) = &self.query.name {
+            ep_builder.name(val);
+        }
+        if let Some(val) = &self.query.project_id {
+            ep_builder.project_id(val);
+        }
+        if let Some(val) = &self.query.revision_number {
+            ep_builder.revision_number(val);
+        }
+        if let Some(val) = &self.query.shared {
+            ep_builder.shared(*val);
+        }
+        if let Some(val) = &self.query.tenant_id {
+            ep_builder.tenant_id(val);
+        }
+        if let Some(val) = &self.query.tags {
+            ep_builder.tags(val.iter());
+        }
+        if let Some(val) = &self.query.tags_any {
+            ep_builder.tags_any(val.iter());
+        }
+        if let Some(val) = &self.query.not_tags {
+            ep_builder.not_tags(val.iter());
+        }
+        if let Some(val) = &self.query.not_tags_any {
+            ep_builder.not_tags_any(val.iter());
+        }
+        // Set body parameters
+
+        let ep = ep_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+
+        let ep = paged(ep, Pagination::Limit(self.max_items)).prefetch(true);
+
+        op.output_list_stream::<ResponseData, _, _>(ep.iter_async(client))
+            .await?;
+        Ok(())
+    }
+}
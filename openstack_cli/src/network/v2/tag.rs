@@ -0,0 +1,148 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Resource Tag commands
+//!
+//! Tags of all taggable network resources are managed the same way. Commands
+//! are implemented once for any [`TaggedResource`] so that every resource
+//! exposes the same command interface.
+
+use clap::{Parser, Subcommand};
+use serde_json::Value;
+
+use openstack_sdk::api::QueryAsync;
+use openstack_sdk::AsyncOpenStack;
+
+use crate::common::batch_query;
+use crate::{Cli, OpenStackCliError};
+
+mod delete;
+mod delete_all;
+mod list;
+mod replace;
+mod set;
+mod show;
+
+/// Network resource supporting tags
+pub(crate) trait TaggedResource: Send + Sync + 'static {
+    /// Collection path of the resource (i.e. `ports` or `qos/policies`)
+    const COLLECTION: &'static str;
+    /// Placeholder of the resource in the help (i.e. `PORT`)
+    const VALUE_NAME: &'static str;
+
+    /// Endpoint fetching the resource by its name or ID
+    type Find: QueryAsync<Value, AsyncOpenStack> + Send + Sync;
+
+    /// Build the endpoint fetching the resource by its name or ID
+    fn find(id: &str) -> Result<Self::Find, OpenStackCliError>;
+}
+
+/// Implement [`TaggedResource`] for the resource using its `find` endpoint
+macro_rules! impl_tagged_resource {
+    ($type:ident, $find:path, $collection:literal, $value_name:literal) => {
+        /// Tagged resource
+        pub(crate) struct $type;
+
+        impl $crate::network::v2::tag::TaggedResource for $type {
+            const COLLECTION: &'static str = $collection;
+            const VALUE_NAME: &'static str = $value_name;
+
+            type Find = openstack_sdk::api::Find<$find>;
+
+            fn find(id: &str) -> Result<Self::Find, $crate::OpenStackCliError> {
+                <$find>::builder()
+                    .id(id.to_string())
+                    .build()
+                    .map(openstack_sdk::api::find)
+                    .map_err(|x| $crate::OpenStackCliError::EndpointBuild(x.to_string()))
+            }
+        }
+    };
+}
+pub(crate) use impl_tagged_resource;
+
+/// Resource tags
+///
+/// Lists, adds, removes, replaces or clears the tags of a resource and checks
+/// whether a resource has a given tag. Commands modifying tags accept multiple
+/// resources at once.
+#[derive(Parser)]
+pub struct TagCommand<R: TaggedResource> {
+    /// subcommand
+    #[command(subcommand)]
+    command: TagCommands<R>,
+}
+
+/// Supported subcommands
+#[allow(missing_docs)]
+#[derive(Subcommand)]
+pub enum TagCommands<R: TaggedResource> {
+    Add(Box<set::TagCommand<R>>),
+    Check(Box<show::TagCommand<R>>),
+    #[command(visible_alias = "purge")]
+    Clear(Box<delete_all::TagCommand<R>>),
+    List(Box<list::TagsCommand<R>>),
+    #[command(visible_alias = "delete")]
+    Remove(Box<delete::TagCommand<R>>),
+    Replace(Box<replace::TagCommand<R>>),
+}
+
+impl<R: TaggedResource> TagCommand<R> {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        session: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        match &self.command {
+            TagCommands::Add(cmd) => cmd.take_action(parsed_args, session).await,
+            TagCommands::Check(cmd) => cmd.take_action(parsed_args, session).await,
+            TagCommands::Clear(cmd) => cmd.take_action(parsed_args, session).await,
+            TagCommands::List(cmd) => cmd.take_action(parsed_args, session).await,
+            TagCommands::Remove(cmd) => cmd.take_action(parsed_args, session).await,
+            TagCommands::Replace(cmd) => cmd.take_action(parsed_args, session).await,
+        }
+    }
+}
+
+/// Resolve the resource name or ID into the ID
+async fn resolve_id<R: TaggedResource>(
+    client: &AsyncOpenStack,
+    id: &str,
+) -> Result<String, OpenStackCliError> {
+    let find_data: Value = R::find(id)?.query_async(client).await?;
+    Ok(find_data["id"]
+        .as_str()
+        .expect("Resource ID is a string")
+        .to_string())
+}
+
+/// Resolve names or IDs of the resources into IDs
+///
+/// Resources that cannot be found are reported and skipped.
+async fn resolve_ids<R: TaggedResource>(
+    client: &AsyncOpenStack,
+    ids: &[String],
+) -> Result<Vec<String>, OpenStackCliError> {
+    let find_eps = ids
+        .iter()
+        .map(|id| R::find(id))
+        .collect::<Result<Vec<_>, _>>()?;
+    let found: Vec<Option<Value>> = batch_query(client, find_eps, ids, "Resolving").await?;
+    Ok(found
+        .iter()
+        .flatten()
+        .filter_map(|x| x["id"].as_str().map(String::from))
+        .collect())
+}
@@ -0,0 +1,111 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Clear Tags command
+//!
+//! Wraps invoking of the `v2.0/{collection}/{resource_id}/tags` with `DELETE`
+//! method
+
+use clap::Args;
+use std::marker::PhantomData;
+use tracing::info;
+
+use anyhow::Result;
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::output::OutputProcessor;
+use crate::Cli;
+use crate::OpenStackCliError;
+
+use super::{resolve_ids, TaggedResource};
+use crate::common::batch_query;
+use crate::common::check_batch_result;
+use openstack_sdk::api::ignore;
+use openstack_sdk::api::network::v2::tag::delete_all;
+
+/// Removes all tags from one or more resources.
+///
+#[derive(Args)]
+#[command(about = "Clear tags of resources")]
+pub struct TagCommand<R: TaggedResource> {
+    /// Request Query parameters
+    #[command(flatten)]
+    query: QueryParameters,
+
+    /// Path parameters
+    #[command(flatten)]
+    path: PathParameters<R>,
+}
+
+/// Query parameters
+#[derive(Args)]
+struct QueryParameters {}
+
+/// Path parameters
+#[derive(Args)]
+struct PathParameters<R: TaggedResource> {
+    /// Resource names or IDs
+    ///
+    #[arg(
+        help_heading = "Path parameters",
+        id = "path_param_resource_id",
+        value_name = R::VALUE_NAME,
+        num_args = 1..,
+        required = true,
+    )]
+    resource_id: Vec<String>,
+
+    #[arg(skip)]
+    _resource: PhantomData<R>,
+}
+
+impl<R: TaggedResource> TagCommand<R> {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        client: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        info!("Clear Tags");
+
+        let op = OutputProcessor::from_args(parsed_args);
+        op.validate_args(parsed_args)?;
+
+        // Resolve names into IDs
+        let ids = resolve_ids::<R>(client, &self.path.resource_id).await?;
+
+        let clear_eps = ids
+            .iter()
+            .map(|id| {
+                let mut ep_builder = delete_all::Request::builder();
+
+                // Set path parameters
+                ep_builder.collection(R::COLLECTION);
+                ep_builder.resource_id(id);
+
+                ep_builder
+                    .build()
+                    .map(ignore)
+                    .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let results: Vec<Option<()>> =
+            batch_query(client, clear_eps, &ids, "Clearing tags").await?;
+        check_batch_result(
+            self.path.resource_id.len(),
+            results.iter().flatten().count(),
+        )
+    }
+}
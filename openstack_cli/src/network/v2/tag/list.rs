@@ -11,16 +11,15 @@
 // limitations under the License.
 //
 // SPDX-License-Identifier: Apache-2.0
-//
-// WARNING: This file is automatically generated from OpenAPI schema using
-// `openstack-codegenerator`.
 
 //! List Tags command
 //!
-//! Wraps invoking of the `v2.0/floatingips/{floatingip_id}/tags` with `GET` method
+//! Wraps invoking of the `v2.0/{collection}/{resource_id}/tags` with `GET`
+//! method
 
 use clap::Args;
 use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
 use tracing::info;
 
 use anyhow::Result;
@@ -33,20 +32,23 @@ use crate::OpenStackCliError;
 use crate::OutputConfig;
 use crate::StructTable;
 
-use openstack_sdk::api::network::v2::floatingip::tag::list;
+use super::{resolve_id, TaggedResource};
+use openstack_sdk::api::network::v2::tag::list;
 use openstack_sdk::api::QueryAsync;
+use serde_json::Value;
 
-/// Command without description in OpenAPI
+/// Lists tags of a resource.
 ///
 #[derive(Args)]
-pub struct TagsCommand {
+#[command(about = "List tags of resource")]
+pub struct TagsCommand<R: TaggedResource> {
     /// Request Query parameters
     #[command(flatten)]
     query: QueryParameters,
 
     /// Path parameters
     #[command(flatten)]
-    path: PathParameters,
+    path: PathParameters<R>,
 }
 
 /// Query parameters
@@ -55,16 +57,18 @@ struct QueryParameters {}
 
 /// Path parameters
 #[derive(Args)]
-struct PathParameters {
-    /// floatingip_id parameter for /v2.0/floatingips/{floatingip_id}/tags/{id}
-    /// API
+struct PathParameters<R: TaggedResource> {
+    /// Resource name or ID
     ///
     #[arg(
         help_heading = "Path parameters",
-        id = "path_param_floatingip_id",
-        value_name = "FLOATINGIP_ID"
+        id = "path_param_resource_id",
+        value_name = R::VALUE_NAME
     )]
-    floatingip_id: String,
+    resource_id: String,
+
+    #[arg(skip)]
+    _resource: PhantomData<R>,
 }
 /// Tags response representation
 #[derive(Deserialize, Serialize, Clone)]
@@ -90,7 +94,7 @@ impl StructTable for Vec<ResponseData> {
     }
 }
 
-impl TagsCommand {
+impl<R: TaggedResource> TagsCommand<R> {
     /// Perform command action
     pub async fn take_action(
         &self,
@@ -102,18 +106,20 @@ impl TagsCommand {
         let op = OutputProcessor::from_args(parsed_args);
         op.validate_args(parsed_args)?;
 
+        // Resolve name into ID
+        let resource_id = resolve_id::<R>(client, &self.path.resource_id).await?;
+
         let mut ep_builder = list::Request::builder();
 
         // Set path parameters
-        ep_builder.floatingip_id(&self.path.floatingip_id);
-        // Set query parameters
-        // Set body parameters
+        ep_builder.collection(R::COLLECTION);
+        ep_builder.resource_id(resource_id);
 
         let ep = ep_builder
             .build()
             .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
 
-        let data: Vec<serde_json::Value> = ep.query_async(client).await?;
+        let data: Vec<Value> = ep.query_async(client).await?;
 
         op.output_list::<ResponseData>(data)?;
         Ok(())
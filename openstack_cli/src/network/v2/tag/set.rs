@@ -0,0 +1,127 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Add Tags command
+//!
+//! Wraps invoking of the `v2.0/{collection}/{resource_id}/tags/{id}` with
+//! `PUT` method
+
+use clap::Args;
+use std::marker::PhantomData;
+use tracing::info;
+
+use anyhow::Result;
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::output::OutputProcessor;
+use crate::Cli;
+use crate::OpenStackCliError;
+
+use super::{resolve_ids, TaggedResource};
+use crate::common::batch_query;
+use crate::common::check_batch_result;
+use openstack_sdk::api::ignore;
+use openstack_sdk::api::network::v2::tag::set;
+
+/// Adds one or more tags to one or more resources.
+///
+/// Adding a tag that is already set is not an error.
+///
+#[derive(Args)]
+#[command(about = "Add tags to resources")]
+pub struct TagCommand<R: TaggedResource> {
+    /// Request Query parameters
+    #[command(flatten)]
+    query: QueryParameters,
+
+    /// Path parameters
+    #[command(flatten)]
+    path: PathParameters<R>,
+
+    /// Tag to add. Repeat the option to add multiple tags.
+    ///
+    #[arg(
+        action=clap::ArgAction::Append,
+        long = "tag",
+        required = true,
+        value_name = "TAG"
+    )]
+    tags: Vec<String>,
+}
+
+/// Query parameters
+#[derive(Args)]
+struct QueryParameters {}
+
+/// Path parameters
+#[derive(Args)]
+struct PathParameters<R: TaggedResource> {
+    /// Resource names or IDs
+    ///
+    #[arg(
+        help_heading = "Path parameters",
+        id = "path_param_resource_id",
+        value_name = R::VALUE_NAME,
+        num_args = 1..,
+        required = true,
+    )]
+    resource_id: Vec<String>,
+
+    #[arg(skip)]
+    _resource: PhantomData<R>,
+}
+
+impl<R: TaggedResource> TagCommand<R> {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        client: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        info!("Add Tags");
+
+        let op = OutputProcessor::from_args(parsed_args);
+        op.validate_args(parsed_args)?;
+
+        // Resolve names into IDs
+        let ids = resolve_ids::<R>(client, &self.path.resource_id).await?;
+
+        let mut labels: Vec<String> = Vec::new();
+        let mut tag_eps = Vec::new();
+        for id in ids.iter() {
+            for tag in self.tags.iter() {
+                let mut ep_builder = set::Request::builder();
+
+                // Set path parameters
+                ep_builder.collection(R::COLLECTION);
+                ep_builder.resource_id(id);
+                ep_builder.id(tag);
+
+                tag_eps.push(
+                    ep_builder
+                        .build()
+                        .map(ignore)
+                        .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?,
+                );
+                labels.push(format!("{} {}", id, tag));
+            }
+        }
+        let results: Vec<Option<()>> = batch_query(client, tag_eps, &labels, "Adding tags").await?;
+        check_batch_result(
+            self.path.resource_id.len() * self.tags.len(),
+            results.iter().flatten().count(),
+        )
+    }
+}
@@ -11,16 +11,15 @@
 // limitations under the License.
 //
 // SPDX-License-Identifier: Apache-2.0
-//
-// WARNING: This file is automatically generated from OpenAPI schema using
-// `openstack-codegenerator`.
 
-//! Show Tag command
+//! Check Tag command
 //!
-//! Wraps invoking of the `v2.0/subnets/{subnet_id}/tags/{id}` with `GET` method
+//! Wraps invoking of the `v2.0/{collection}/{resource_id}/tags/{id}` with
+//! `GET` method
 
 use clap::Args;
 use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
 use tracing::info;
 
 use anyhow::Result;
@@ -33,23 +32,27 @@ use crate::OpenStackCliError;
 use crate::OutputConfig;
 use crate::StructTable;
 
+use super::{resolve_id, TaggedResource};
 use bytes::Bytes;
 use http::Response;
-use openstack_sdk::api::network::v2::subnet::tag::get;
+use openstack_sdk::api::network::v2::tag::get;
 use openstack_sdk::api::RawQueryAsync;
 use structable_derive::StructTable;
 
-/// Command without description in OpenAPI
+/// Checks whether a resource has the given tag.
+///
+/// The command fails when the tag is not set on the resource.
 ///
 #[derive(Args)]
-pub struct TagCommand {
+#[command(about = "Check tag of resource")]
+pub struct TagCommand<R: TaggedResource> {
     /// Request Query parameters
     #[command(flatten)]
     query: QueryParameters,
 
     /// Path parameters
     #[command(flatten)]
-    path: PathParameters,
+    path: PathParameters<R>,
 }
 
 /// Query parameters
@@ -58,48 +61,53 @@ struct QueryParameters {}
 
 /// Path parameters
 #[derive(Args)]
-struct PathParameters {
-    /// subnet_id parameter for /v2.0/subnets/{subnet_id}/tags/{id} API
+struct PathParameters<R: TaggedResource> {
+    /// Resource name or ID
     ///
     #[arg(
         help_heading = "Path parameters",
-        id = "path_param_subnet_id",
-        value_name = "SUBNET_ID"
+        id = "path_param_resource_id",
+        value_name = R::VALUE_NAME
     )]
-    subnet_id: String,
+    resource_id: String,
 
-    /// id parameter for /v2.0/subnets/{subnet_id}/tags/{id} API
+    /// Tag to check
     ///
     #[arg(
         help_heading = "Path parameters",
         id = "path_param_id",
-        value_name = "ID"
+        value_name = "TAG"
     )]
     id: String,
+
+    #[arg(skip)]
+    _resource: PhantomData<R>,
 }
 /// Tag response representation
 #[derive(Deserialize, Serialize, Clone, StructTable)]
 struct ResponseData {}
 
-impl TagCommand {
+impl<R: TaggedResource> TagCommand<R> {
     /// Perform command action
     pub async fn take_action(
         &self,
         parsed_args: &Cli,
         client: &mut AsyncOpenStack,
     ) -> Result<(), OpenStackCliError> {
-        info!("Show Tag");
+        info!("Check Tag");
 
         let op = OutputProcessor::from_args(parsed_args);
         op.validate_args(parsed_args)?;
 
+        // Resolve name into ID
+        let resource_id = resolve_id::<R>(client, &self.path.resource_id).await?;
+
         let mut ep_builder = get::Request::builder();
 
         // Set path parameters
-        ep_builder.subnet_id(&self.path.subnet_id);
+        ep_builder.collection(R::COLLECTION);
+        ep_builder.resource_id(resource_id);
         ep_builder.id(&self.path.id);
-        // Set query parameters
-        // Set body parameters
 
         let ep = ep_builder
             .build()
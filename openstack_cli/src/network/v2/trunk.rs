@@ -0,0 +1,186 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Trunk resource commands
+
+use clap::{Parser, Subcommand};
+
+use openstack_sdk::api::find;
+use openstack_sdk::api::network::v2::port::find as find_port;
+use openstack_sdk::api::QueryAsync;
+use openstack_sdk::AsyncOpenStack;
+
+use crate::network::v2::tag::{impl_tagged_resource, TagCommand};
+use crate::{Cli, OpenStackCliError};
+
+mod add_subports;
+mod create;
+mod delete;
+mod get_subports;
+mod list;
+mod remove_subports;
+mod set;
+mod show;
+
+impl_tagged_resource!(
+    TaggedTrunk,
+    openstack_sdk::api::network::v2::trunk::find::Request<'static>,
+    "trunks",
+    "TRUNK"
+);
+
+/// Trunks
+///
+/// The trunk extension can be used to multiplex packets coming from and going
+/// to multiple neutron logical networks using a single neutron logical port. A
+/// trunk is mapped to a parent port to which the subports carrying the traffic
+/// of the VLAN-aware instances are attached.
+#[derive(Parser)]
+pub struct TrunkCommand {
+    /// subcommand
+    #[command(subcommand)]
+    command: TrunkCommands,
+}
+
+/// Supported subcommands
+#[allow(missing_docs)]
+#[derive(Subcommand)]
+pub enum TrunkCommands {
+    AddSubports(Box<add_subports::TrunkCommand>),
+    Create(Box<create::TrunkCommand>),
+    Delete(Box<delete::TrunkCommand>),
+    GetSubports(Box<get_subports::TrunkCommand>),
+    List(Box<list::TrunksCommand>),
+    RemoveSubports(Box<remove_subports::TrunkCommand>),
+    Set(Box<set::TrunkCommand>),
+    Show(Box<show::TrunkCommand>),
+    Tag(Box<TagCommand<TaggedTrunk>>),
+}
+
+impl TrunkCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        session: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        match &self.command {
+            TrunkCommands::AddSubports(cmd) => cmd.take_action(parsed_args, session).await,
+            TrunkCommands::Create(cmd) => cmd.take_action(parsed_args, session).await,
+            TrunkCommands::Delete(cmd) => cmd.take_action(parsed_args, session).await,
+            TrunkCommands::GetSubports(cmd) => cmd.take_action(parsed_args, session).await,
+            TrunkCommands::List(cmd) => cmd.take_action(parsed_args, session).await,
+            TrunkCommands::RemoveSubports(cmd) => cmd.take_action(parsed_args, session).await,
+            TrunkCommands::Set(cmd) => cmd.take_action(parsed_args, session).await,
+            TrunkCommands::Show(cmd) => cmd.take_action(parsed_args, session).await,
+            TrunkCommands::Tag(cmd) => cmd.take_action(parsed_args, session).await,
+        }
+    }
+}
+
+/// Segmentation type of the subport
+#[derive(Clone, Debug, PartialEq)]
+enum SegmentationType {
+    Inherit,
+    Vlan,
+}
+
+/// Subport given as
+/// `port=<name|id>[,segmentation-type=<vlan|inherit>][,segmentation-id=<id>]`
+#[derive(Clone, Debug, PartialEq)]
+struct SubportSpec {
+    port: String,
+    segmentation_type: Option<SegmentationType>,
+    segmentation_id: Option<i32>,
+}
+
+/// Parse subport specification from the command line
+fn parse_subport(value: &str) -> Result<SubportSpec, String> {
+    let mut port = None;
+    let mut segmentation_type = None;
+    let mut segmentation_id = None;
+    for pair in value.split(',').filter(|x| !x.is_empty()) {
+        let (key, val) = pair
+            .split_once('=')
+            .ok_or_else(|| format!("invalid KEY=value: no `=` found in `{pair}`"))?;
+        match key.trim() {
+            "port" => port = Some(val.trim().to_string()),
+            "segmentation-type" => {
+                segmentation_type = Some(match val.trim() {
+                    "inherit" => SegmentationType::Inherit,
+                    "vlan" => SegmentationType::Vlan,
+                    other => return Err(format!("unsupported segmentation type `{other}`")),
+                })
+            }
+            "segmentation-id" => {
+                segmentation_id = Some(
+                    val.trim()
+                        .parse::<i32>()
+                        .map_err(|_| format!("`{val}` is not a valid segmentation ID"))?,
+                )
+            }
+            other => return Err(format!("unknown subport key `{other}`")),
+        }
+    }
+    Ok(SubportSpec {
+        port: port.ok_or("subport `port` is required")?,
+        segmentation_type,
+        segmentation_id,
+    })
+}
+
+/// Resolve port name or ID into the port ID
+async fn find_port_id(
+    client: &mut AsyncOpenStack,
+    port: &str,
+) -> Result<String, OpenStackCliError> {
+    let mut find_builder = find_port::Request::builder();
+    find_builder.id(port);
+    let find_ep = find_builder
+        .build()
+        .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+    let find_data: serde_json::Value = find(find_ep).query_async(client).await?;
+    Ok(find_data["id"]
+        .as_str()
+        .expect("Resource ID is a string")
+        .to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_subport() {
+        assert_eq!(
+            SubportSpec {
+                port: "foo".to_string(),
+                segmentation_type: Some(SegmentationType::Vlan),
+                segmentation_id: Some(100),
+            },
+            parse_subport("port=foo,segmentation-type=vlan,segmentation-id=100").unwrap()
+        );
+        assert_eq!(
+            SubportSpec {
+                port: "foo".to_string(),
+                segmentation_type: None,
+                segmentation_id: None,
+            },
+            parse_subport("port=foo").unwrap()
+        );
+        assert!(parse_subport("segmentation-id=100").is_err());
+        assert!(parse_subport("port=foo,segmentation-id=bar").is_err());
+        assert!(parse_subport("port=foo,vlan=100").is_err());
+    }
+}
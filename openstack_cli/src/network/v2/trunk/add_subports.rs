@@ -0,0 +1,241 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Add Subports Trunk command
+//!
+//! Wraps invoking of the `v2.0/trunks/{id}/add_subports` with `PUT` method
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use anyhow::Result;
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::output::OutputProcessor;
+use crate::Cli;
+use crate::OpenStackCliError;
+use crate::OutputConfig;
+use crate::StructTable;
+
+use crate::common::BoolString;
+use openstack_sdk::api::find;
+use openstack_sdk::api::network::v2::trunk::add_subports;
+use openstack_sdk::api::network::v2::trunk::find as find_trunk;
+use openstack_sdk::api::QueryAsync;
+use serde_json::Value;
+use structable_derive::StructTable;
+
+use super::{find_port_id, parse_subport, SegmentationType, SubportSpec};
+
+/// Adds one or more subports to a trunk.
+///
+/// Subports are given as
+/// `port=<name|id>,segmentation-type=<vlan|inherit>,segmentation-id=<id>`.
+/// The segmentation type and ID can be omitted when the `inherit`
+/// segmentation type is supported by the back-end.
+///
+/// Normal response codes: 200
+///
+/// Error response codes: 400, 401, 404, 409, 412
+///
+#[derive(Args)]
+#[command(about = "Add subports to trunk")]
+pub struct TrunkCommand {
+    /// Request Query parameters
+    #[command(flatten)]
+    query: QueryParameters,
+
+    /// Path parameters
+    #[command(flatten)]
+    path: PathParameters,
+
+    /// Subport to add to the trunk given as
+    /// `port=<name|id>,segmentation-type=<vlan|inherit>,segmentation-id=<id>`.
+    /// Repeat the option to add multiple subports.
+    ///
+    #[arg(
+        action=clap::ArgAction::Append,
+        help_heading = "Body parameters",
+        long = "subport",
+        required = true,
+        value_name = "SUBPORT",
+        value_parser = parse_subport
+    )]
+    sub_ports: Vec<SubportSpec>,
+}
+
+/// Query parameters
+#[derive(Args)]
+struct QueryParameters {}
+
+/// Path parameters
+#[derive(Args)]
+struct PathParameters {
+    /// Trunk resource name or ID
+    ///
+    #[arg(
+        help_heading = "Path parameters",
+        id = "path_param_id",
+        value_name = "TRUNK"
+    )]
+    id: String,
+}
+
+/// Trunk response representation
+#[derive(Deserialize, Serialize, Clone, StructTable)]
+struct ResponseData {
+    /// The administrative state of the resource, which is up (`true`) or down
+    /// (`false`).
+    ///
+    #[serde()]
+    #[structable(optional)]
+    admin_state_up: Option<BoolString>,
+
+    /// Time at which the resource has been created (in UTC ISO8601 format).
+    ///
+    #[serde()]
+    #[structable(optional)]
+    created_at: Option<String>,
+
+    /// A human-readable description for the resource.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    description: Option<String>,
+
+    /// The ID of the trunk.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    id: Option<String>,
+
+    /// Human-readable name of the resource.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    name: Option<String>,
+
+    /// The ID of the parent port.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    port_id: Option<String>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    project_id: Option<String>,
+
+    /// The revision number of the resource.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    revision_number: Option<i32>,
+
+    /// The status for the trunk. Possible values are `ACTIVE`, `DOWN`,
+    /// `BUILD`, `DEGRADED`, and `ERROR`.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    status: Option<String>,
+
+    /// A list of ports associated with the trunk.
+    ///
+    #[serde()]
+    #[structable(optional, pretty)]
+    sub_ports: Option<Value>,
+
+    /// The list of tags on the resource.
+    ///
+    #[serde()]
+    #[structable(optional, pretty)]
+    tags: Option<Value>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    tenant_id: Option<String>,
+
+    /// Time at which the resource has been updated (in UTC ISO8601 format).
+    ///
+    #[serde()]
+    #[structable(optional)]
+    updated_at: Option<String>,
+}
+
+impl TrunkCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        client: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        info!("Add Subports Trunk");
+
+        let op = OutputProcessor::from_args(parsed_args);
+        op.validate_args(parsed_args)?;
+
+        let mut find_builder = find_trunk::Request::builder();
+        find_builder.id(&self.path.id);
+        let find_ep = find_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+        let find_data: serde_json::Value = find(find_ep).query_async(client).await?;
+        let trunk_id = find_data["id"]
+            .as_str()
+            .expect("Resource ID is a string")
+            .to_string();
+
+        let mut sub_ports: Vec<add_subports::SubPorts> = Vec::new();
+        for sub_port in self.sub_ports.iter() {
+            let mut sub_port_builder = add_subports::SubPortsBuilder::default();
+            sub_port_builder.port_id(find_port_id(client, &sub_port.port).await?);
+            if let Some(val) = &sub_port.segmentation_type {
+                let tmp = match val {
+                    SegmentationType::Inherit => add_subports::SegmentationType::Inherit,
+                    SegmentationType::Vlan => add_subports::SegmentationType::Vlan,
+                };
+                sub_port_builder.segmentation_type(tmp);
+            }
+            if let Some(val) = sub_port.segmentation_id {
+                sub_port_builder.segmentation_id(val);
+            }
+            sub_ports.push(
+                sub_port_builder
+                    .build()
+                    .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?,
+            );
+        }
+
+        let mut ep_builder = add_subports::Request::builder();
+
+        // Set path parameters
+        ep_builder.id(trunk_id);
+        // Set query parameters
+        // Set body parameters
+        ep_builder.sub_ports(sub_ports);
+
+        let ep = ep_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+
+        let data = ep.query_async(client).await?;
+        op.output_single::<ResponseData>(data)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,297 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Create Trunk command
+//!
+//! Wraps invoking of the `v2.0/trunks` with `POST` method
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use anyhow::Result;
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::output::OutputProcessor;
+use crate::Cli;
+use crate::OpenStackCliError;
+use crate::OutputConfig;
+use crate::StructTable;
+
+use crate::common::BoolString;
+use openstack_sdk::api::network::v2::trunk::create;
+use openstack_sdk::api::QueryAsync;
+use serde_json::Value;
+use structable_derive::StructTable;
+
+use super::{find_port_id, parse_subport, SegmentationType, SubportSpec};
+
+/// Create a trunk.
+///
+/// Normal response codes: 201
+///
+/// Error response codes: 400, 401, 403, 404, 409, 503
+///
+#[derive(Args)]
+#[command(about = "Create trunk")]
+pub struct TrunkCommand {
+    /// Request Query parameters
+    #[command(flatten)]
+    query: QueryParameters,
+
+    /// Path parameters
+    #[command(flatten)]
+    path: PathParameters,
+
+    /// A `trunk` object.
+    ///
+    #[command(flatten)]
+    trunk: Trunk,
+}
+
+/// Query parameters
+#[derive(Args)]
+struct QueryParameters {}
+
+/// Path parameters
+#[derive(Args)]
+struct PathParameters {}
+/// Trunk Body data
+#[derive(Args, Clone)]
+struct Trunk {
+    /// The administrative state of the trunk, which is up (`true`) or down
+    /// (`false`). Default is `true`.
+    ///
+    #[arg(action=clap::ArgAction::Set, help_heading = "Body parameters", long)]
+    admin_state_up: Option<bool>,
+
+    /// A human-readable description for the resource. Default is an empty
+    /// string.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    description: Option<String>,
+
+    /// Human-readable name of the resource. Default is an empty string.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    name: Option<String>,
+
+    /// The name or ID of the parent port.
+    ///
+    #[arg(
+        help_heading = "Body parameters",
+        long,
+        value_name = "PORT",
+        visible_alias = "port"
+    )]
+    port_id: String,
+
+    /// Subport to attach to the trunk given as
+    /// `port=<name|id>,segmentation-type=<vlan|inherit>,segmentation-id=<id>`.
+    /// Repeat the option to attach multiple subports.
+    ///
+    #[arg(
+        action=clap::ArgAction::Append,
+        help_heading = "Body parameters",
+        long = "subport",
+        value_name = "SUBPORT",
+        value_parser = parse_subport
+    )]
+    sub_ports: Option<Vec<SubportSpec>>,
+
+    /// The ID of the project that owns the resource. Only administrative and
+    /// users with advsvc role can specify a project ID other than their own.
+    /// You cannot change this value through authorization policies.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    tenant_id: Option<String>,
+
+    /// Tag to set on the resource. Repeat the option to set multiple tags.
+    ///
+    #[arg(
+        action=clap::ArgAction::Append,
+        help_heading = "Body parameters",
+        long = "tag",
+        value_name = "TAG",
+        visible_alias = "tags"
+    )]
+    tags: Option<Vec<String>>,
+}
+
+/// Trunk response representation
+#[derive(Deserialize, Serialize, Clone, StructTable)]
+struct ResponseData {
+    /// The administrative state of the resource, which is up (`true`) or down
+    /// (`false`).
+    ///
+    #[serde()]
+    #[structable(optional)]
+    admin_state_up: Option<BoolString>,
+
+    /// Time at which the resource has been created (in UTC ISO8601 format).
+    ///
+    #[serde()]
+    #[structable(optional)]
+    created_at: Option<String>,
+
+    /// A human-readable description for the resource.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    description: Option<String>,
+
+    /// The ID of the trunk.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    id: Option<String>,
+
+    /// Human-readable name of the resource.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    name: Option<String>,
+
+    /// The ID of the parent port.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    port_id: Option<String>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    project_id: Option<String>,
+
+    /// The revision number of the resource.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    revision_number: Option<i32>,
+
+    /// The status for the trunk. Possible values are `ACTIVE`, `DOWN`,
+    /// `BUILD`, `DEGRADED`, and `ERROR`.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    status: Option<String>,
+
+    /// A list of ports associated with the trunk.
+    ///
+    #[serde()]
+    #[structable(optional, pretty)]
+    sub_ports: Option<Value>,
+
+    /// The list of tags on the resource.
+    ///
+    #[serde()]
+    #[structable(optional, pretty)]
+    tags: Option<Value>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    tenant_id: Option<String>,
+
+    /// Time at which the resource has been updated (in UTC ISO8601 format).
+    ///
+    #[serde()]
+    #[structable(optional)]
+    updated_at: Option<String>,
+}
+
+impl TrunkCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        client: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        info!("Create Trunk");
+
+        let op = OutputProcessor::from_args(parsed_args);
+        op.validate_args(parsed_args)?;
+
+        let args = &self.trunk;
+
+        let port_id = find_port_id(client, &args.port_id).await?;
+
+        let mut sub_ports: Vec<create::SubPorts> = Vec::new();
+        for sub_port in args.sub_ports.iter().flatten() {
+            let mut sub_port_builder = create::SubPortsBuilder::default();
+            sub_port_builder.port_id(find_port_id(client, &sub_port.port).await?);
+            if let Some(val) = &sub_port.segmentation_type {
+                let tmp = match val {
+                    SegmentationType::Inherit => create::SegmentationType::Inherit,
+                    SegmentationType::Vlan => create::SegmentationType::Vlan,
+                };
+                sub_port_builder.segmentation_type(tmp);
+            }
+            if let Some(val) = sub_port.segmentation_id {
+                sub_port_builder.segmentation_id(val);
+            }
+            sub_ports.push(
+                sub_port_builder
+                    .build()
+                    .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?,
+            );
+        }
+
+        let mut ep_builder = create::Request::builder();
+
+        // Set path parameters
+        // Set query parameters
+        // Set body parameters
+        // Set Request.trunk data
+        let mut trunk_builder = create::TrunkBuilder::default();
+        if let Some(val) = &args.admin_state_up {
+            trunk_builder.admin_state_up(*val);
+        }
+
+        if let Some(val) = &args.description {
+            trunk_builder.description(val);
+        }
+
+        if let Some(val) = &args.name {
+            trunk_builder.name(val);
+        }
+
+        trunk_builder.port_id(port_id);
+
+        if !sub_ports.is_empty() {
+            trunk_builder.sub_ports(sub_ports);
+        }
+
+        if let Some(val) = &args.tenant_id {
+            trunk_builder.tenant_id(val);
+        }
+
+        if let Some(val) = &args.tags {
+            trunk_builder.tags(val.iter().map(|v| v.into()).collect::<Vec<_>>());
+        }
+
+        ep_builder.trunk(trunk_builder.build().unwrap());
+
+        let ep = ep_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+
+        let data = ep.query_async(client).await?;
+        op.output_single::<ResponseData>(data)?;
+        Ok(())
+    }
+}
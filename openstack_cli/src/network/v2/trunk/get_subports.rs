@@ -11,13 +11,10 @@
 // limitations under the License.
 //
 // SPDX-License-Identifier: Apache-2.0
-//
-// WARNING: This file is automatically generated from OpenAPI schema using
-// `openstack-codegenerator`.
 
-//! List Tags command
+//! Get Subports Trunk command
 //!
-//! Wraps invoking of the `v2.0/subnets/{subnet_id}/tags` with `GET` method
+//! Wraps invoking of the `v2.0/trunks/{id}/get_subports` with `GET` method
 
 use clap::Args;
 use serde::{Deserialize, Serialize};
@@ -33,13 +30,21 @@ use crate::OpenStackCliError;
 use crate::OutputConfig;
 use crate::StructTable;
 
-use openstack_sdk::api::network::v2::subnet::tag::list;
+use openstack_sdk::api::find;
+use openstack_sdk::api::network::v2::trunk::find as find_trunk;
+use openstack_sdk::api::network::v2::trunk::get_subports;
 use openstack_sdk::api::QueryAsync;
+use structable_derive::StructTable;
 
-/// Command without description in OpenAPI
+/// List subports for a trunk.
+///
+/// Normal response codes: 200
+///
+/// Error response codes: 400, 401, 404
 ///
 #[derive(Args)]
-pub struct TagsCommand {
+#[command(about = "List trunk subports")]
+pub struct TrunkCommand {
     /// Request Query parameters
     #[command(flatten)]
     query: QueryParameters,
@@ -56,55 +61,65 @@ struct QueryParameters {}
 /// Path parameters
 #[derive(Args)]
 struct PathParameters {
-    /// subnet_id parameter for /v2.0/subnets/{subnet_id}/tags/{id} API
+    /// Trunk resource name or ID
     ///
     #[arg(
         help_heading = "Path parameters",
-        id = "path_param_subnet_id",
-        value_name = "SUBNET_ID"
+        id = "path_param_id",
+        value_name = "TRUNK"
     )]
-    subnet_id: String,
-}
-/// Tags response representation
-#[derive(Deserialize, Serialize, Clone)]
-struct ResponseData(String);
-
-impl StructTable for ResponseData {
-    fn build(&self, _: &OutputConfig) -> (Vec<String>, Vec<Vec<String>>) {
-        let headers: Vec<String> = Vec::from(["Value".to_string()]);
-        let res: Vec<Vec<String>> = Vec::from([Vec::from([self.0.to_string()])]);
-        (headers, res)
-    }
+    id: String,
 }
 
-impl StructTable for Vec<ResponseData> {
-    fn build(&self, _: &OutputConfig) -> (Vec<String>, Vec<Vec<String>>) {
-        let headers: Vec<String> = Vec::from(["Values".to_string()]);
-        let res: Vec<Vec<String>> = Vec::from([Vec::from([self
-            .iter()
-            .map(|v| v.0.to_string())
-            .collect::<Vec<_>>()
-            .join(", ")])]);
-        (headers, res)
-    }
+/// Subport response representation
+#[derive(Deserialize, Serialize, Clone, StructTable)]
+struct ResponseData {
+    /// The ID of the port attached as a subport.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    port_id: Option<String>,
+
+    /// The segmentation type of the subport.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    segmentation_type: Option<String>,
+
+    /// The segmentation ID on which the subport traffic is carried.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    segmentation_id: Option<i32>,
 }
 
-impl TagsCommand {
+impl TrunkCommand {
     /// Perform command action
     pub async fn take_action(
         &self,
         parsed_args: &Cli,
         client: &mut AsyncOpenStack,
     ) -> Result<(), OpenStackCliError> {
-        info!("List Tags");
+        info!("Get Subports Trunk");
 
         let op = OutputProcessor::from_args(parsed_args);
         op.validate_args(parsed_args)?;
 
-        let mut ep_builder = list::Request::builder();
+        let mut find_builder = find_trunk::Request::builder();
+        find_builder.id(&self.path.id);
+        let find_ep = find_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+        let find_data: serde_json::Value = find(find_ep).query_async(client).await?;
+        let trunk_id = find_data["id"]
+            .as_str()
+            .expect("Resource ID is a string")
+            .to_string();
+
+        let mut ep_builder = get_subports::Request::builder();
 
         // Set path parameters
-        ep_builder.subnet_id(&self.path.subnet_id);
+        ep_builder.id(trunk_id);
         // Set query parameters
         // Set body parameters
 
@@ -113,7 +128,6 @@ impl TagsCommand {
             .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
 
         let data: Vec<serde_json::Value> = ep.query_async(client).await?;
-
         op.output_list::<ResponseData>(data)?;
         Ok(())
     }
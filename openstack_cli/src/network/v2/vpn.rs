@@ -0,0 +1,69 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Neutron VPNaaS commands
+
+use clap::{Parser, Subcommand};
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::{Cli, OpenStackCliError};
+
+mod endpoint_group;
+mod ikepolicy;
+mod ipsec_site_connection;
+mod ipsecpolicy;
+mod vpnservice;
+
+/// VPN as a Service
+///
+/// The VPNaaS extension (`vpnaas`) manages IPsec site-to-site connections
+/// together with the IKE and IPsec policies, VPN services and endpoint groups
+/// they are built of.
+#[derive(Parser)]
+pub struct VpnCommand {
+    /// subcommand
+    #[command(subcommand)]
+    command: VpnCommands,
+}
+
+/// Supported subcommands
+#[allow(missing_docs)]
+#[derive(Subcommand)]
+pub enum VpnCommands {
+    EndpointGroup(Box<endpoint_group::EndpointGroupCommand>),
+    Ikepolicy(Box<ikepolicy::IkepolicyCommand>),
+    IpsecSiteConnection(Box<ipsec_site_connection::IpsecSiteConnectionCommand>),
+    Ipsecpolicy(Box<ipsecpolicy::IpsecpolicyCommand>),
+    Vpnservice(Box<vpnservice::VpnserviceCommand>),
+}
+
+impl VpnCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        session: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        session.ensure_network_extension("vpnaas").await?;
+
+        match &self.command {
+            VpnCommands::EndpointGroup(cmd) => cmd.take_action(parsed_args, session).await,
+            VpnCommands::Ikepolicy(cmd) => cmd.take_action(parsed_args, session).await,
+            VpnCommands::IpsecSiteConnection(cmd) => cmd.take_action(parsed_args, session).await,
+            VpnCommands::Ipsecpolicy(cmd) => cmd.take_action(parsed_args, session).await,
+            VpnCommands::Vpnservice(cmd) => cmd.take_action(parsed_args, session).await,
+        }
+    }
+}
@@ -0,0 +1,220 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// WARNING: This file is automatically generated from OpenAPI schema using
+// `openstack-codegenerator`.
+
+//! Create EndpointGroup command
+//!
+//! Wraps invoking of the `v2.0/vpn/endpoint-groups` with `POST` method
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use anyhow::Result;
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::output::OutputProcessor;
+use crate::Cli;
+use crate::OpenStackCliError;
+use crate::OutputConfig;
+use crate::StructTable;
+
+use clap::ValueEnum;
+use openstack_sdk::api::network::v2::vpn::endpoint_group::create;
+use openstack_sdk::api::QueryAsync;
+use serde_json::Value;
+use structable_derive::StructTable;
+
+/// Creates a VPN endpoint group.
+///
+/// Normal response codes: 201
+///
+/// Error response codes: 400, 401
+///
+#[derive(Args)]
+#[command(about = "Create VPN endpoint group")]
+pub struct EndpointGroupCommand {
+    /// Request Query parameters
+    #[command(flatten)]
+    query: QueryParameters,
+
+    /// Path parameters
+    #[command(flatten)]
+    path: PathParameters,
+
+    /// A `endpoint_group` object.
+    ///
+    #[command(flatten)]
+    endpoint_group: EndpointGroup,
+}
+
+/// Query parameters
+#[derive(Args)]
+struct QueryParameters {}
+
+/// Path parameters
+#[derive(Args)]
+struct PathParameters {}
+#[derive(Clone, Eq, Ord, PartialEq, PartialOrd, ValueEnum)]
+enum Type {
+    Cidr,
+    Network,
+    Router,
+    Subnet,
+    Vlan,
+}
+
+/// EndpointGroup Body data
+#[derive(Args, Clone)]
+struct EndpointGroup {
+    /// The type of the endpoints in the group. A valid value is `subnet`,
+    /// `cidr`, `network`, `router`, or `vlan`. Only `subnet` and `cidr` are
+    /// supported at this moment.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    _type: Type,
+
+    /// A human-readable description for the resource. Default is an empty
+    /// string.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    description: Option<String>,
+
+    /// List of endpoints of the same type, for the endpoint group. The values
+    /// will depend on type.
+    ///
+    #[arg(action=clap::ArgAction::Append, help_heading = "Body parameters", long)]
+    endpoints: Vec<String>,
+
+    /// Human-readable name of the resource. Default is an empty string.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    name: Option<String>,
+
+    /// The ID of the project that owns the resource. Only administrative and
+    /// users with advsvc role can specify a project ID other than their own.
+    /// You cannot change this value through authorization policies.
+    ///
+    #[arg(help_heading = "Body parameters", long)]
+    tenant_id: Option<String>,
+}
+
+/// EndpointGroup response representation
+#[derive(Deserialize, Serialize, Clone, StructTable)]
+struct ResponseData {
+    /// The type of the endpoints in the group. A valid value is `subnet`,
+    /// `cidr`, `network`, `router`, or `vlan`. Only `subnet` and `cidr` are
+    /// supported at this moment.
+    ///
+    #[serde(rename = "type")]
+    #[structable(optional, title = "type")]
+    _type: Option<String>,
+
+    /// A human-readable description for the resource.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    description: Option<String>,
+
+    /// List of endpoints of the same type, for the endpoint group. The values
+    /// will depend on type.
+    ///
+    #[serde()]
+    #[structable(optional, pretty)]
+    endpoints: Option<Value>,
+
+    /// The ID for the endpoint group.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    id: Option<String>,
+
+    /// Human-readable name of the resource.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    name: Option<String>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    project_id: Option<String>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    tenant_id: Option<String>,
+}
+
+impl EndpointGroupCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        client: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        info!("Create EndpointGroup");
+
+        let op = OutputProcessor::from_args(parsed_args);
+        op.validate_args(parsed_args)?;
+
+        let mut ep_builder = create::Request::builder();
+
+        // Set path parameters
+        // Set query parameters
+        // Set body parameters
+        // Set Request.endpoint_group data
+        let args = &self.endpoint_group;
+        let mut endpoint_group_builder = create::EndpointGroupBuilder::default();
+        if let Some(val) = &args.description {
+            endpoint_group_builder.description(val);
+        }
+
+        if let Some(val) = &args.name {
+            endpoint_group_builder.name(val);
+        }
+
+        endpoint_group_builder
+            .endpoints(args.endpoints.iter().map(|v| v.into()).collect::<Vec<_>>());
+
+        let val = &args._type;
+        let tmp = match val {
+            Type::Cidr => create::Type::Cidr,
+            Type::Network => create::Type::Network,
+            Type::Router => create::Type::Router,
+            Type::Subnet => create::Type::Subnet,
+            Type::Vlan => create::Type::Vlan,
+        };
+        endpoint_group_builder._type(tmp);
+
+        if let Some(val) = &args.tenant_id {
+            endpoint_group_builder.tenant_id(val);
+        }
+
+        ep_builder.endpoint_group(endpoint_group_builder.build().unwrap());
+
+        let ep = ep_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+
+        let data = ep.query_async(client).await?;
+        op.output_single::<ResponseData>(data)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,283 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// WARNING: This file is automatically generated from OpenAPI schema using
+// `openstack-codegenerator`.
+
+//! List Ikepolicies command
+//!
+//! Wraps invoking of the `v2.0/vpn/ikepolicies` with `GET` method
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use anyhow::Result;
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::output::OutputProcessor;
+use crate::Cli;
+use crate::OpenStackCliError;
+use crate::OutputConfig;
+use crate::StructTable;
+
+use openstack_sdk::api::network::v2::vpn::ikepolicy::list;
+use openstack_sdk::api::{paged, Pagination};
+use serde_json::Value;
+use structable_derive::StructTable;
+
+/// Lists IKE policies that belong to a project.
+///
+/// Additionally, you can filter results by using query string parameters. For
+/// information, see
+/// [Filtering and Column Selection](https://wiki.openstack.org/wiki/Neutron/APIv2-specification#Filtering_and_Column_Selection).
+///
+/// Use the `fields` query parameter to control which fields are returned in
+/// the response body. For information, see
+/// [Filtering and Column Selection](https://wiki.openstack.org/wiki/Neutron/APIv2-specification#Filtering_and_Column_Selection).
+///
+/// Normal response codes: 200
+///
+/// Error response codes: 401
+///
+#[derive(Args)]
+#[command(about = "List IKE policies")]
+pub struct IkepoliciesCommand {
+    /// Request Query parameters
+    #[command(flatten)]
+    query: QueryParameters,
+
+    /// Path parameters
+    #[command(flatten)]
+    path: PathParameters,
+
+    /// Total limit of entities count to return. Use this when there are too many entries.
+    #[arg(long, default_value_t = 10000)]
+    max_items: usize,
+}
+
+/// Query parameters
+#[derive(Args)]
+struct QueryParameters {
+    /// auth_algorithm query parameter for /v2.0/vpn/ikepolicies API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    auth_algorithm: Option<String>,
+
+    /// description query parameter for /v2.0/vpn/ikepolicies API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    description: Option<String>,
+
+    /// encryption_algorithm query parameter for /v2.0/vpn/ikepolicies API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    encryption_algorithm: Option<String>,
+
+    /// id query parameter for /v2.0/vpn/ikepolicies API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    id: Option<String>,
+
+    /// ike_version query parameter for /v2.0/vpn/ikepolicies API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    ike_version: Option<String>,
+
+    /// Requests a page size of items. Returns a number of items up to a limit
+    /// value. Use the limit parameter to make an initial limited request and
+    /// use the ID of the last-seen item from the response as the marker
+    /// parameter value in a subsequent limited request.
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    limit: Option<i32>,
+
+    /// The ID of the last-seen item. Use the limit parameter to make an
+    /// initial limited request and use the ID of the last-seen item from the
+    /// response as the marker parameter value in a subsequent limited
+    /// request.
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    marker: Option<String>,
+
+    /// name query parameter for /v2.0/vpn/ikepolicies API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    name: Option<String>,
+
+    /// pfs query parameter for /v2.0/vpn/ikepolicies API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    pfs: Option<String>,
+
+    /// phase1_negotiation_mode query parameter for /v2.0/vpn/ikepolicies API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    phase1_negotiation_mode: Option<String>,
+
+    /// project_id query parameter for /v2.0/vpn/ikepolicies API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    project_id: Option<String>,
+
+    /// tenant_id query parameter for /v2.0/vpn/ikepolicies API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    tenant_id: Option<String>,
+}
+
+/// Path parameters
+#[derive(Args)]
+struct PathParameters {}
+/// Ikepolicies response representation
+#[derive(Deserialize, Serialize, Clone, StructTable)]
+struct ResponseData {
+    /// The authentication hash algorithm. Valid values are `sha1`, `sha256`,
+    /// `sha384`, `sha512`, `aes-xcbc`, `aes-cmac`.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    auth_algorithm: Option<String>,
+
+    /// A human-readable description for the resource.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    description: Option<String>,
+
+    /// The encryption algorithm.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    encryption_algorithm: Option<String>,
+
+    /// The ID of the IKE policy.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    id: Option<String>,
+
+    /// The IKE version. A valid value is `v1` or `v2`.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    ike_version: Option<String>,
+
+    /// The lifetime of the security association. The lifetime consists of a
+    /// unit and integer value. You can omit either the unit or value portion
+    /// of the lifetime. Default unit is seconds and default value is 3600.
+    ///
+    #[serde()]
+    #[structable(optional, pretty, wide)]
+    lifetime: Option<Value>,
+
+    /// Human-readable name of the resource.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    name: Option<String>,
+
+    /// Perfect forward secrecy (PFS). A valid value is `Group2`, `Group5`,
+    /// `Group14` to `Group31`.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    pfs: Option<String>,
+
+    /// The IKE mode. A valid value is `main`, which is the default, or
+    /// `aggressive`.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    phase1_negotiation_mode: Option<String>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    project_id: Option<String>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    tenant_id: Option<String>,
+}
+
+impl IkepoliciesCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        client: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        info!("List Ikepolicies");
+
+        let op = OutputProcessor::from_args(parsed_args);
+        op.validate_args(parsed_args)?;
+
+        let mut ep_builder = list::Request::builder();
+
+        // Set path parameters
+        // Set query parameters
+        if let Some(val) = &self.query.limit {
+            ep_builder.limit(*val);
+        }
+        if let Some(val) = &self.query.marker {
+            ep_builder.marker(val);
+        }
+        if let Some(val) = &self.query.auth_algorithm {
+            ep_builder.auth_algorithm(val);
+        }
+        if let Some(val) = &self.query.description {
+            ep_builder.description(val);
+        }
+        if let Some(val) = &self.query.encryption_algorithm {
+            ep_builder.encryption_algorithm(val);
+        }
+        if let Some(val) = &self.query.id {
+            ep_builder.id(val);
+        }
+        if let Some(val) = &self.query.ike_version {
+            ep_builder.ike_version(val);
+        }
+        if let Some(val) = &self.query.name {
+            ep_builder.name(val);
+        }
+        if let Some(val) = &self.query.pfs {
+            ep_builder.pfs(val);
+        }
+        if let Some(val) = &self.query.phase1_negotiation_mode {
+            ep_builder.phase1_negotiation_mode(val);
+        }
+        if let Some(val) = &self.query.project_id {
+            ep_builder.project_id(val);
+        }
+        if let Some(val) = &self.query.tenant_id {
+            ep_builder.tenant_id(val);
+        }
+        // Set body parameters
+
+        let ep = ep_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+
+        let ep = paged(ep, Pagination::Limit(self.max_items)).prefetch(true);
+
+        op.output_list_stream::<ResponseData, _, _>(ep.iter_async(client))
+            .await?;
+        Ok(())
+    }
+}
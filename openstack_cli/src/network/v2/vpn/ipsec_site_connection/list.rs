@@ -0,0 +1,423 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// WARNING: This file is automatically generated from OpenAPI schema using
+// `openstack-codegenerator`.
+
+//! List IpsecSiteConnections command
+//!
+//! Wraps invoking of the `v2.0/vpn/ipsec-site-connections` with `GET` method
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use anyhow::Result;
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::output::OutputProcessor;
+use crate::Cli;
+use crate::OpenStackCliError;
+use crate::OutputConfig;
+use crate::StructTable;
+
+use crate::common::BoolString;
+use openstack_sdk::api::network::v2::vpn::ipsec_site_connection::list;
+use openstack_sdk::api::{paged, Pagination};
+use serde_json::Value;
+use structable_derive::StructTable;
+
+/// Lists IPsec site-to-site connections that belong to a project.
+///
+/// Additionally, you can filter results by using query string parameters. For
+/// information, see
+/// [Filtering and Column Selection](https://wiki.openstack.org/wiki/Neutron/APIv2-specification#Filtering_and_Column_Selection).
+///
+/// Use the `fields` query parameter to control which fields are returned in
+/// the response body. For information, see
+/// [Filtering and Column Selection](https://wiki.openstack.org/wiki/Neutron/APIv2-specification#Filtering_and_Column_Selection).
+///
+/// Normal response codes: 200
+///
+/// Error response codes: 401
+///
+#[derive(Args)]
+#[command(about = "List IPsec site-to-site connections")]
+pub struct IpsecSiteConnectionsCommand {
+    /// Request Query parameters
+    #[command(flatten)]
+    query: QueryParameters,
+
+    /// Path parameters
+    #[command(flatten)]
+    path: PathParameters,
+
+    /// Total limit of entities count to return. Use this when there are too many entries.
+    #[arg(long, default_value_t = 10000)]
+    max_items: usize,
+}
+
+/// Query parameters
+#[derive(Args)]
+struct QueryParameters {
+    /// admin_state_up query parameter for /v2.0/vpn/ipsec-site-connections API
+    ///
+    #[arg(action=clap::ArgAction::Set, help_heading = "Query parameters", long)]
+    admin_state_up: Option<bool>,
+
+    /// auth_mode query parameter for /v2.0/vpn/ipsec-site-connections API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    auth_mode: Option<String>,
+
+    /// description query parameter for /v2.0/vpn/ipsec-site-connections API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    description: Option<String>,
+
+    /// id query parameter for /v2.0/vpn/ipsec-site-connections API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    id: Option<String>,
+
+    /// ikepolicy_id query parameter for /v2.0/vpn/ipsec-site-connections API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    ikepolicy_id: Option<String>,
+
+    /// initiator query parameter for /v2.0/vpn/ipsec-site-connections API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    initiator: Option<String>,
+
+    /// ipsecpolicy_id query parameter for /v2.0/vpn/ipsec-site-connections API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    ipsecpolicy_id: Option<String>,
+
+    /// Requests a page size of items. Returns a number of items up to a limit
+    /// value. Use the limit parameter to make an initial limited request and
+    /// use the ID of the last-seen item from the response as the marker
+    /// parameter value in a subsequent limited request.
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    limit: Option<i32>,
+
+    /// local_ep_group_id query parameter for /v2.0/vpn/ipsec-site-connections API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    local_ep_group_id: Option<String>,
+
+    /// local_id query parameter for /v2.0/vpn/ipsec-site-connections API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    local_id: Option<String>,
+
+    /// The ID of the last-seen item. Use the limit parameter to make an
+    /// initial limited request and use the ID of the last-seen item from the
+    /// response as the marker parameter value in a subsequent limited
+    /// request.
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    marker: Option<String>,
+
+    /// mtu query parameter for /v2.0/vpn/ipsec-site-connections API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    mtu: Option<i32>,
+
+    /// name query parameter for /v2.0/vpn/ipsec-site-connections API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    name: Option<String>,
+
+    /// peer_address query parameter for /v2.0/vpn/ipsec-site-connections API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    peer_address: Option<String>,
+
+    /// peer_ep_group_id query parameter for /v2.0/vpn/ipsec-site-connections API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    peer_ep_group_id: Option<String>,
+
+    /// peer_id query parameter for /v2.0/vpn/ipsec-site-connections API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    peer_id: Option<String>,
+
+    /// project_id query parameter for /v2.0/vpn/ipsec-site-connections API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    project_id: Option<String>,
+
+    /// route_mode query parameter for /v2.0/vpn/ipsec-site-connections API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    route_mode: Option<String>,
+
+    /// status query parameter for /v2.0/vpn/ipsec-site-connections API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    status: Option<String>,
+
+    /// tenant_id query parameter for /v2.0/vpn/ipsec-site-connections API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    tenant_id: Option<String>,
+
+    /// vpnservice_id query parameter for /v2.0/vpn/ipsec-site-connections API
+    ///
+    #[arg(help_heading = "Query parameters", long)]
+    vpnservice_id: Option<String>,
+}
+
+/// Path parameters
+#[derive(Args)]
+struct PathParameters {}
+/// IpsecSiteConnections response representation
+#[derive(Deserialize, Serialize, Clone, StructTable)]
+struct ResponseData {
+    /// The administrative state of the resource, which is up (`true`) or down
+    /// (`false`).
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    admin_state_up: Option<BoolString>,
+
+    /// The authentication mode. A valid value is `psk`, which is the default.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    auth_mode: Option<String>,
+
+    /// A human-readable description for the resource.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    description: Option<String>,
+
+    /// A dictionary with dead peer detection (DPD) protocol controls.
+    ///
+    #[serde()]
+    #[structable(optional, pretty, wide)]
+    dpd: Option<Value>,
+
+    /// The ID of the IPsec site-to-site connection.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    id: Option<String>,
+
+    /// The ID of the IKE policy.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    ikepolicy_id: Option<String>,
+
+    /// Indicates whether this VPN can only respond to connections or both
+    /// respond to and initiate connections. A valid value is `response- only`
+    /// or `bi-directional`. Default is `bi-directional`.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    initiator: Option<String>,
+
+    /// The ID of the IPsec policy.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    ipsecpolicy_id: Option<String>,
+
+    /// The ID for the endpoint group that contains private subnets for the
+    /// local side of the connection.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    local_ep_group_id: Option<String>,
+
+    /// An ID to be used instead of the external IP address for a virtual
+    /// router used in traffic between instances on different networks in
+    /// east-west traffic.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    local_id: Option<String>,
+
+    /// The maximum transmission unit (MTU) value to address fragmentation.
+    /// Minimum value is 68 for IPv4, and 1280 for IPv6.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    mtu: Option<i32>,
+
+    /// Human-readable name of the resource.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    name: Option<String>,
+
+    /// The peer gateway public IPv4 or IPv6 address or FQDN.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    peer_address: Option<String>,
+
+    /// (Deprecated) Unique list of valid peer private CIDRs in the form <
+    /// net_address > / < prefix >.
+    ///
+    #[serde()]
+    #[structable(optional, pretty, wide)]
+    peer_cidrs: Option<Value>,
+
+    /// The ID for the endpoint group that contains private CIDRs in the form <
+    /// net_address > / < prefix > for the peer side of the connection.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    peer_ep_group_id: Option<String>,
+
+    /// The peer router identity for authentication. A valid value is an IPv4
+    /// address, IPv6 address, e-mail address, key ID, or FQDN.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    peer_id: Option<String>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    project_id: Option<String>,
+
+    /// The route mode. A valid value is `static`, which is the default.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    route_mode: Option<String>,
+
+    /// Indicates whether the IPsec connection is currently operational. Values
+    /// are `ACTIVE`, `DOWN`, `BUILD`, `ERROR`, `PENDING_CREATE`,
+    /// `PENDING_UPDATE`, or `PENDING_DELETE`.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    status: Option<String>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    tenant_id: Option<String>,
+
+    /// The ID of the VPN service.
+    ///
+    #[serde()]
+    #[structable(optional, wide)]
+    vpnservice_id: Option<String>,
+}
+
+impl IpsecSiteConnectionsCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        client: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        info!("List IpsecSiteConnections");
+
+        let op = OutputProcessor::from_args(parsed_args);
+        op.validate_args(parsed_args)?;
+
+        let mut ep_builder = list::Request::builder();
+
+        // Set path parameters
+        // Set query parameters
+        if let Some(val) = &self.query.limit {
+            ep_builder.limit(*val);
+        }
+        if let Some(val) = &self.query.marker {
+            ep_builder.marker(val);
+        }
+        if let Some(val) = &self.query.admin_state_up {
+            ep_builder.admin_state_up(*val);
+        }
+        if let Some(val) = &self.query.auth_mode {
+            ep_builder.auth_mode(val);
+        }
+        if let Some(val) = &self.query.description {
+            ep_builder.description(val);
+        }
+        if let Some(val) = &self.query.id {
+            ep_builder.id(val);
+        }
+        if let Some(val) = &self.query.ikepolicy_id {
+            ep_builder.ikepolicy_id(val);
+        }
+        if let Some(val) = &self.query.initiator {
+            ep_builder.initiator(val);
+        }
+        if let Some(val) = &self.query.ipsecpolicy_id {
+            ep_builder.ipsecpolicy_id(val);
+        }
+        if let Some(val) = &self.query.local_ep_group_id {
+            ep_builder.local_ep_group_id(val);
+        }
+        if let Some(val) = &self.query.local_id {
+            ep_builder.local_id(val);
+        }
+        if let Some(val) = &self.query.mtu {
+            ep_builder.mtu(*val);
+        }
+        if let Some(val) = &self.query.name {
+            ep_builder.name(val);
+        }
+        if let Some(val) = &self.query.peer_address {
+            ep_builder.peer_address(val);
+        }
+        if let Some(val) = &self.query.peer_ep_group_id {
+            ep_builder.peer_ep_group_id(val);
+        }
+        if let Some(val) = &self.query.peer_id {
+            ep_builder.peer_id(val);
+        }
+        if let Some(val) = &self.query.project_id {
+            ep_builder.project_id(val);
+        }
+        if let Some(val) = &self.query.route_mode {
+            ep_builder.route_mode(val);
+        }
+        if let Some(val) = &self.query.status {
+            ep_builder.status(val);
+        }
+        if let Some(val) = &self.query.tenant_id {
+            ep_builder.tenant_id(val);
+        }
+        if let Some(val) = &self.query.vpnservice_id {
+            ep_builder.vpnservice_id(val);
+        }
+        // Set body parameters
+
+        let ep = ep_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+
+        let ep = paged(ep, Pagination::Limit(self.max_items)).prefetch(true);
+
+        op.output_list_stream::<ResponseData, _, _>(ep.iter_async(client))
+            .await?;
+        Ok(())
+    }
+}
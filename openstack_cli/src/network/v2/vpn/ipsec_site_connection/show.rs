@@ -0,0 +1,246 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// WARNING: This file is automatically generated from OpenAPI schema using
+// `openstack-codegenerator`.
+
+//! Show IpsecSiteConnection command
+//!
+//! Wraps invoking of the `v2.0/vpn/ipsec-site-connections/{id}` with `GET` method
+
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use anyhow::Result;
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::output::OutputProcessor;
+use crate::Cli;
+use crate::OpenStackCliError;
+use crate::OutputConfig;
+use crate::StructTable;
+
+use crate::common::BoolString;
+use openstack_sdk::api::find;
+use openstack_sdk::api::network::v2::vpn::ipsec_site_connection::find;
+use openstack_sdk::api::QueryAsync;
+use serde_json::Value;
+use structable_derive::StructTable;
+
+/// Shows details for a IPsec site-to-site connection.
+///
+/// Use the `fields` query parameter to control which fields are returned in
+/// the response body. For information, see
+/// [Filtering and Column Selection](https://wiki.openstack.org/wiki/Neutron/APIv2-specification#Filtering_and_Column_Selection).
+///
+/// Normal response codes: 200
+///
+/// Error response codes: 401, 404
+///
+#[derive(Args)]
+#[command(about = "Show IPsec site-to-site connection details")]
+pub struct IpsecSiteConnectionCommand {
+    /// Request Query parameters
+    #[command(flatten)]
+    query: QueryParameters,
+
+    /// Path parameters
+    #[command(flatten)]
+    path: PathParameters,
+}
+
+/// Query parameters
+#[derive(Args)]
+struct QueryParameters {}
+
+/// Path parameters
+#[derive(Args)]
+struct PathParameters {
+    /// id parameter for /v2.0/vpn/ipsec-site-connections/{id} API
+    ///
+    #[arg(
+        help_heading = "Path parameters",
+        id = "path_param_id",
+        value_name = "ID"
+    )]
+    id: String,
+}
+/// IpsecSiteConnection response representation
+#[derive(Deserialize, Serialize, Clone, StructTable)]
+struct ResponseData {
+    /// The administrative state of the resource, which is up (`true`) or down
+    /// (`false`).
+    ///
+    #[serde()]
+    #[structable(optional)]
+    admin_state_up: Option<BoolString>,
+
+    /// The authentication mode. A valid value is `psk`, which is the default.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    auth_mode: Option<String>,
+
+    /// A human-readable description for the resource.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    description: Option<String>,
+
+    /// A dictionary with dead peer detection (DPD) protocol controls.
+    ///
+    #[serde()]
+    #[structable(optional, pretty)]
+    dpd: Option<Value>,
+
+    /// The ID of the IPsec site-to-site connection.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    id: Option<String>,
+
+    /// The ID of the IKE policy.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    ikepolicy_id: Option<String>,
+
+    /// Indicates whether this VPN can only respond to connections or both
+    /// respond to and initiate connections. A valid value is `response- only`
+    /// or `bi-directional`. Default is `bi-directional`.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    initiator: Option<String>,
+
+    /// The ID of the IPsec policy.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    ipsecpolicy_id: Option<String>,
+
+    /// The ID for the endpoint group that contains private subnets for the
+    /// local side of the connection.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    local_ep_group_id: Option<String>,
+
+    /// An ID to be used instead of the external IP address for a virtual
+    /// router used in traffic between instances on different networks in
+    /// east-west traffic.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    local_id: Option<String>,
+
+    /// The maximum transmission unit (MTU) value to address fragmentation.
+    /// Minimum value is 68 for IPv4, and 1280 for IPv6.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    mtu: Option<i32>,
+
+    /// Human-readable name of the resource.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    name: Option<String>,
+
+    /// The peer gateway public IPv4 or IPv6 address or FQDN.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    peer_address: Option<String>,
+
+    /// (Deprecated) Unique list of valid peer private CIDRs in the form <
+    /// net_address > / < prefix >.
+    ///
+    #[serde()]
+    #[structable(optional, pretty)]
+    peer_cidrs: Option<Value>,
+
+    /// The ID for the endpoint group that contains private CIDRs in the form <
+    /// net_address > / < prefix > for the peer side of the connection.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    peer_ep_group_id: Option<String>,
+
+    /// The peer router identity for authentication. A valid value is an IPv4
+    /// address, IPv6 address, e-mail address, key ID, or FQDN.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    peer_id: Option<String>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    project_id: Option<String>,
+
+    /// The route mode. A valid value is `static`, which is the default.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    route_mode: Option<String>,
+
+    /// Indicates whether the IPsec connection is currently operational. Values
+    /// are `ACTIVE`, `DOWN`, `BUILD`, `ERROR`, `PENDING_CREATE`,
+    /// `PENDING_UPDATE`, or `PENDING_DELETE`.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    status: Option<String>,
+
+    /// The ID of the project.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    tenant_id: Option<String>,
+
+    /// The ID of the VPN service.
+    ///
+    #[serde()]
+    #[structable(optional)]
+    vpnservice_id: Option<String>,
+}
+
+impl IpsecSiteConnectionCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        client: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        info!("Show IpsecSiteConnection");
+
+        let op = OutputProcessor::from_args(parsed_args);
+        op.validate_args(parsed_args)?;
+
+        let mut find_builder = find::Request::builder();
+
+        find_builder.id(&self.path.id);
+        let find_ep = find_builder
+            .build()
+            .map_err(|x| OpenStackCliError::EndpointBuild(x.to_string()))?;
+        let find_data: serde_json::Value = find(find_ep).query_async(client).await?;
+
+        op.output_single::<ResponseData>(find_data)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,66 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Vpnservice resource commands
+
+use clap::{Parser, Subcommand};
+
+use openstack_sdk::AsyncOpenStack;
+
+use crate::{Cli, OpenStackCliError};
+
+mod create;
+mod delete;
+mod list;
+mod set;
+mod show;
+
+/// VPN services
+///
+/// A VPN service is associated with a router and holds the IPsec site-to-site
+/// connections terminated on it.
+#[derive(Parser)]
+pub struct VpnserviceCommand {
+    /// subcommand
+    #[command(subcommand)]
+    command: VpnserviceCommands,
+}
+
+/// Supported subcommands
+#[allow(missing_docs)]
+#[derive(Subcommand)]
+pub enum VpnserviceCommands {
+    Create(Box<create::VpnserviceCommand>),
+    Delete(Box<delete::VpnserviceCommand>),
+    List(Box<list::VpnservicesCommand>),
+    Set(Box<set::VpnserviceCommand>),
+    Show(Box<show::VpnserviceCommand>),
+}
+
+impl VpnserviceCommand {
+    /// Perform command action
+    pub async fn take_action(
+        &self,
+        parsed_args: &Cli,
+        session: &mut AsyncOpenStack,
+    ) -> Result<(), OpenStackCliError> {
+        match &self.command {
+            VpnserviceCommands::Create(cmd) => cmd.take_action(parsed_args, session).await,
+            VpnserviceCommands::Delete(cmd) => cmd.take_action(parsed_args, session).await,
+            VpnserviceCommands::List(cmd) => cmd.take_action(parsed_args, session).await,
+            VpnserviceCommands::Set(cmd) => cmd.take_action(parsed_args, session).await,
+            VpnserviceCommands::Show(cmd) => cmd.take_action(parsed_args, session).await,
+        }
+    }
+}
@@ -15,8 +15,10 @@
 //! Object Store `container` command with subcommands
 use clap::{Parser, Subcommand};
 
+use openstack_sdk::types::object_store::v1::Container;
 use openstack_sdk::AsyncOpenStack;
 
+use crate::output::impl_struct_table;
 use crate::{Cli, OpenStackCliError};
 
 mod create;
@@ -25,6 +27,8 @@ mod list;
 mod set;
 mod show;
 
+impl_struct_table!(Container, ["name"], ["count", "bytes", "last_modified"]);
+
 /// Container commands
 #[derive(Parser)]
 pub struct ContainerCommand {
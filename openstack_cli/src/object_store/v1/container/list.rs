@@ -16,7 +16,6 @@
 //! account.
 use clap::Args;
 
-use serde::{Deserialize, Serialize};
 use tracing::info;
 
 use anyhow::Result;
@@ -24,14 +23,11 @@ use anyhow::Result;
 use crate::output::OutputProcessor;
 use crate::Cli;
 use crate::OpenStackCliError;
-use crate::OutputConfig;
-use crate::StructTable;
-use structable_derive::StructTable;
 
+use openstack_sdk::types::object_store::v1::Container;
 use openstack_sdk::{types::ServiceType, AsyncOpenStack};
 
 use openstack_sdk::api::object_store::v1::account::get;
-use openstack_sdk::api::QueryAsync;
 use openstack_sdk::api::{paged, Pagination};
 
 /// Shows details for an account and lists containers, sorted by name, in the
@@ -85,27 +81,6 @@ pub struct ContainersCommand {
     max_items: usize,
 }
 
-/// Containers
-#[derive(Deserialize, Debug, Clone, Serialize, StructTable)]
-pub struct Containers {
-    /// The number of objects in the container.
-    #[structable(optional, wide)]
-    count: Option<u64>,
-
-    /// The total number of bytes that are stored in Object Storage for the
-    /// account.
-    #[structable(optional, wide)]
-    bytes: Option<u64>,
-
-    /// The name of the container.
-    #[structable(optional)]
-    name: Option<String>,
-
-    /// Last modification date of the container
-    #[structable(optional, wide)]
-    last_modified: Option<String>,
-}
-
 impl ContainersCommand {
     pub async fn take_action(
         &self,
@@ -147,11 +122,10 @@ impl ContainersCommand {
         client
             .discover_service_endpoint(&ServiceType::ObjectStore)
             .await?;
-        let data: Vec<serde_json::Value> = paged(ep, Pagination::Limit(self.max_items))
-            .query_async(client)
-            .await?;
+        let ep = paged(ep, Pagination::Limit(self.max_items)).prefetch(true);
 
-        op.output_list::<Containers>(data)?;
+        op.output_list_stream::<Container, _, _>(ep.iter_async(client))
+            .await?;
         Ok(())
     }
 }
@@ -15,8 +15,10 @@
 //!
 use clap::{Parser, Subcommand};
 
+use openstack_sdk::types::object_store::v1::Object;
 use openstack_sdk::AsyncOpenStack;
 
+use crate::output::impl_struct_table;
 use crate::{Cli, OpenStackCliError};
 
 mod delete;
@@ -25,6 +27,18 @@ mod list;
 mod show;
 mod upload;
 
+impl_struct_table!(
+    Object,
+    ["name"],
+    [
+        "content_type",
+        "bytes",
+        "hash",
+        "last_modified",
+        "symlink_path"
+    ]
+);
+
 /// Object commands
 #[derive(Parser)]
 pub struct ObjectCommand {
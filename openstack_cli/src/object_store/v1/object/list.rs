@@ -21,7 +21,6 @@
 //! request.
 use clap::Args;
 
-use serde::{Deserialize, Serialize};
 use tracing::info;
 
 use anyhow::Result;
@@ -29,14 +28,11 @@ use anyhow::Result;
 use crate::output::OutputProcessor;
 use crate::Cli;
 use crate::OpenStackCliError;
-use crate::OutputConfig;
-use crate::StructTable;
-use structable_derive::StructTable;
 
+use openstack_sdk::types::object_store::v1::Object;
 use openstack_sdk::{types::ServiceType, AsyncOpenStack};
 
 use openstack_sdk::api::object_store::v1::container::get;
-use openstack_sdk::api::QueryAsync;
 use openstack_sdk::api::{paged, Pagination};
 
 /// Shows details for a container and lists objects, sorted by name, in the
@@ -105,37 +101,6 @@ pub struct ObjectsCommand {
     max_items: usize,
 }
 
-/// Objects
-#[derive(Deserialize, Debug, Clone, Serialize, StructTable)]
-pub struct Objects {
-    /// The name of the container.
-    #[structable(optional)]
-    name: Option<String>,
-
-    /// The content type of the object.
-    #[structable(optional, wide)]
-    content_type: Option<String>,
-
-    /// The total number of bytes that are stored in Object Storage for the
-    /// container.
-    #[structable(optional, wide)]
-    bytes: Option<u64>,
-
-    /// The MD5 checksum value of the object content.
-    #[structable(optional, wide)]
-    hash: Option<String>,
-
-    /// The date and time when the object was last modified. The date and time
-    /// stamp format is ISO 8601
-    #[structable(optional, wide)]
-    last_modified: Option<String>,
-
-    /// This field exists only when the object is symlink. This is the target
-    /// path of the symlink object.
-    #[structable(optional, wide)]
-    symlink_path: Option<String>,
-}
-
 impl ObjectsCommand {
     pub async fn take_action(
         &self,
@@ -178,11 +143,10 @@ impl ObjectsCommand {
         client
             .discover_service_endpoint(&ServiceType::ObjectStore)
             .await?;
-        let data: Vec<serde_json::Value> = paged(ep, Pagination::Limit(self.max_items))
-            .query_async(client)
-            .await?;
+        let ep = paged(ep, Pagination::Limit(self.max_items)).prefetch(true);
 
-        op.output_list::<Objects>(data)?;
+        op.output_list_stream::<Object, _, _>(ep.iter_async(client))
+            .await?;
         Ok(())
     }
 }
@@ -18,7 +18,10 @@ use anyhow::Context;
 use std::collections::BTreeSet;
 use std::io::{self, Write};
 
+use futures::{Stream, StreamExt};
 use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
 
 use cli_table::{print_stdout, Table};
 
@@ -42,6 +45,108 @@ pub trait StructTable {
     fn build(&self, options: &OutputConfig) -> (Vec<String>, Vec<Vec<String>>);
 }
 
+/// Implement [`StructTable`] for the data types of the `openstack_sdk`.
+///
+/// Single entity is represented with all of the present attributes. List of
+/// entities shows the given columns, the wide mode adds the wide columns
+/// followed by all other attributes returned by the API.
+macro_rules! impl_struct_table {
+    ($type:ty, [$($column:literal),* $(,)?], [$($wide:literal),* $(,)?]) => {
+        impl $crate::output::StructTable for $type {
+            fn build(
+                &self,
+                options: &$crate::output::OutputConfig,
+            ) -> (Vec<String>, Vec<Vec<String>>) {
+                $crate::output::build_serde_table(self, options)
+            }
+        }
+
+        impl $crate::output::StructTable for Vec<$type> {
+            fn build(
+                &self,
+                options: &$crate::output::OutputConfig,
+            ) -> (Vec<String>, Vec<Vec<String>>) {
+                $crate::output::build_serde_list_table(
+                    self,
+                    &[$($column),*],
+                    &[$($wide),*],
+                    options,
+                )
+            }
+        }
+    };
+}
+pub(crate) use impl_struct_table;
+
+/// Convert attribute value into the table cell
+fn value_to_cell(value: &Value, pretty: bool) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(val) => val.clone(),
+        other if pretty => serde_json::to_string_pretty(other).unwrap_or(other.to_string()),
+        other => other.to_string(),
+    }
+}
+
+/// Build table of attributes of the single serializable entity
+pub(crate) fn build_serde_table<T: Serialize>(
+    data: &T,
+    options: &OutputConfig,
+) -> (Vec<String>, Vec<Vec<String>>) {
+    let headers: Vec<String> = Vec::from(["Attribute".to_string(), "Value".to_string()]);
+    let mut res: Vec<Vec<String>> = Vec::new();
+    if let Ok(Value::Object(attrs)) = serde_json::to_value(data) {
+        for (key, val) in attrs.iter() {
+            if !val.is_null() && (options.fields.is_empty() || options.fields.contains(key)) {
+                res.push(Vec::from([key.clone(), value_to_cell(val, options.pretty)]));
+            }
+        }
+    }
+    (headers, res)
+}
+
+/// Build table of the serializable entities
+pub(crate) fn build_serde_list_table<T: Serialize>(
+    data: &[T],
+    columns: &[&str],
+    wide_columns: &[&str],
+    options: &OutputConfig,
+) -> (Vec<String>, Vec<Vec<String>>) {
+    let items: Vec<serde_json::Map<String, Value>> = data
+        .iter()
+        .filter_map(|x| match serde_json::to_value(x) {
+            Ok(Value::Object(attrs)) => Some(attrs),
+            _ => None,
+        })
+        .collect();
+    let mut headers: Vec<String> = columns.iter().map(|x| x.to_string()).collect();
+    if options.wide {
+        let extra: BTreeSet<&String> = items
+            .iter()
+            .flat_map(|x| x.keys())
+            .filter(|x| !columns.contains(&x.as_str()) && !wide_columns.contains(&x.as_str()))
+            .collect();
+        headers.extend(wide_columns.iter().map(|x| x.to_string()));
+        headers.extend(extra.into_iter().cloned());
+    }
+    headers.retain(|x| options.fields.is_empty() || options.fields.contains(x));
+
+    let res: Vec<Vec<String>> = items
+        .iter()
+        .map(|item| {
+            headers
+                .iter()
+                .map(|key| {
+                    item.get(key)
+                        .map(|val| value_to_cell(val, options.pretty))
+                        .unwrap_or_default()
+                })
+                .collect()
+        })
+        .collect();
+    (headers, res)
+}
+
 /// Output Processor
 pub(crate) struct OutputProcessor {
     /// Output configuration
@@ -98,6 +203,57 @@ impl OutputProcessor {
         }
     }
 
+    /// Output List of resources coming from the stream (paginated listing)
+    ///
+    /// Machine readable output is written as soon as every item arrives.
+    /// Table output requires all items and is produced at the end. When the
+    /// stream fails in the middle the JSON array is closed after the items
+    /// received so far before the error is returned.
+    pub(crate) async fn output_list_stream<T, S, E>(&self, data: S) -> Result<(), OpenStackCliError>
+    where
+        Vec<T>: StructTable,
+        T: DeserializeOwned,
+        S: Stream<Item = Result<serde_json::Value, E>>,
+        OpenStackCliError: From<E>,
+    {
+        let mut data = std::pin::pin!(data);
+        match self.target {
+            OutputFor::Human => {
+                let mut items: Vec<serde_json::Value> = Vec::new();
+                while let Some(item) = data.next().await {
+                    items.push(item?);
+                }
+                self.output_list::<T>(items)
+            }
+            _ => {
+                let mut first = true;
+                let mut result = Ok(());
+                while let Some(item) = data.next().await {
+                    let item = match item {
+                        Ok(item) => item,
+                        Err(err) => {
+                            result = Err(OpenStackCliError::from(err));
+                            break;
+                        }
+                    };
+                    let mut stdout = io::stdout();
+                    stdout.write_all(if first { b"[" } else { b"," })?;
+                    if self.config.pretty {
+                        serde_json::to_writer_pretty(&mut stdout, &item)?;
+                    } else {
+                        serde_json::to_writer(&mut stdout, &item)?;
+                    }
+                    stdout.flush()?;
+                    first = false;
+                }
+                // Items already written stay a valid (truncated) JSON
+                // document even when fetching of the following page failed.
+                io::stdout().write_all(if first { b"[]\n" } else { b"]\n" })?;
+                result
+            }
+        }
+    }
+
     /// Output List of resources
     pub(crate) fn output_single<T>(&self, data: serde_json::Value) -> Result<(), OpenStackCliError>
     where
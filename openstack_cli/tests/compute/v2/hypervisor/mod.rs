@@ -13,4 +13,6 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod list_autogen;
+mod server;
 mod show_autogen;
+mod uptime;
@@ -25,7 +25,7 @@ fn help() -> Result<(), Box<dyn std::error::Error>> {
     cmd.arg("compute")
         .arg("hypervisor")
         .arg("server")
-        .arg("get")
+        .arg("list21")
         .arg("--help");
     cmd.assert().success();
 
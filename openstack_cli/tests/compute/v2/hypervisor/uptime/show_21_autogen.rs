@@ -25,7 +25,7 @@ fn help() -> Result<(), Box<dyn std::error::Error>> {
     cmd.arg("compute")
         .arg("hypervisor")
         .arg("uptime")
-        .arg("get")
+        .arg("show21")
         .arg("--help");
     cmd.assert().success();
 
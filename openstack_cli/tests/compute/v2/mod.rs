@@ -17,7 +17,12 @@ mod availability_zone;
 mod flavor;
 mod hypervisor;
 mod keypair;
+mod limit;
+mod quota;
 mod server;
+mod server_group;
+mod service;
+mod usage;
 
 use assert_cmd::prelude::*;
 use std::process::Command;
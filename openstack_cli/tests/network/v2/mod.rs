@@ -14,13 +14,27 @@
 
 mod address_group;
 mod address_scope;
+mod agent;
 mod availability_zone;
+mod bgpvpn;
 mod extension;
 mod floatingip;
+mod fwaas;
 mod network;
+mod network_ip_availability;
 mod port;
+mod qos;
+mod quota;
+mod rbac_policy;
 mod router;
+mod security_group;
+mod security_group_rule;
+mod segment;
+mod service_provider;
 mod subnet;
+mod subnetpool;
+mod trunk;
+mod vpn;
 
 use assert_cmd::prelude::*;
 use std::process::Command;
@@ -14,11 +14,11 @@
 
 mod create_autogen;
 mod delete_autogen;
-mod list_autogen;
-mod show_autogen;
-// mod set_autogen;
 mod dhcp_agent;
 mod list;
+mod list_autogen;
+mod set_autogen;
+mod show_autogen;
 mod tag;
 
 use assert_cmd::prelude::*;
@@ -18,7 +18,7 @@ mod create_autogen;
 mod delete_autogen;
 mod list;
 mod list_autogen;
-// mod set_autogen;
+mod set_autogen;
 mod show_autogen;
 mod tag;
 
@@ -15,7 +15,7 @@
 mod delete_all_autogen;
 mod delete_autogen;
 mod list_autogen;
-// mod replace_autogen;
+mod replace_autogen;
 mod set_autogen;
 mod show_autogen;
 
@@ -0,0 +1,37 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+mod bandwidth_limit_rule;
+mod create_autogen;
+mod delete_autogen;
+mod dscp_marking_rule;
+mod list_autogen;
+mod minimum_bandwidth_rule;
+mod minimum_packet_rate_rule;
+mod set_autogen;
+mod show_autogen;
+mod tag;
+
+use assert_cmd::prelude::*;
+use std::process::Command;
+
+#[test]
+fn help() -> Result<(), Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("osc")?;
+
+    cmd.arg("network").arg("qos").arg("policy").arg("--help");
+    cmd.assert().success();
+
+    Ok(())
+}
@@ -16,8 +16,11 @@
 //!
 //! This module provides implementation for the individual APIs as well as the necessary logic
 //!
+#[cfg(feature = "async")]
+mod batch;
 mod client;
 mod common;
+mod conditional;
 mod error;
 mod find;
 mod ignore;
@@ -26,6 +29,8 @@ mod paged;
 mod params;
 pub(crate) mod query;
 mod rest_endpoint;
+mod validation;
+mod wait;
 
 #[cfg(feature = "block_storage")]
 pub mod block_storage;
@@ -47,6 +52,7 @@ pub mod object_store;
 
 pub use self::error::ApiError;
 pub use self::error::BodyError;
+pub use self::error::ServiceError;
 
 pub use self::client::RestClient;
 
@@ -71,10 +77,21 @@ pub mod rest_endpoint_prelude;
 pub use self::paged::paged;
 pub use self::paged::Pageable;
 pub use self::paged::Paged;
+#[cfg(feature = "sync")]
+pub use self::paged::PagedIter;
+#[cfg(feature = "async")]
+pub use self::paged::PagedStream;
 pub use self::paged::Pagination;
 pub use self::paged::PaginationError;
 
 pub use self::find::find;
+pub use self::find::Find;
+
+pub use self::conditional::conditional;
+#[cfg(feature = "async")]
+pub use self::conditional::read_modify_write;
+pub use self::conditional::Conditional;
+pub use self::conditional::Precondition;
 
 pub use self::params::JsonBodyParams;
 pub use self::params::ParamValue;
@@ -82,3 +99,17 @@ pub use self::params::QueryParams;
 
 pub use self::ignore::ignore;
 pub use self::ignore::Ignore;
+
+#[cfg(feature = "async")]
+pub use self::batch::batch;
+#[cfg(feature = "async")]
+pub use self::batch::Batch;
+
+pub use self::validation::FieldError;
+pub use self::validation::Format;
+pub use self::validation::Validator;
+
+pub use self::wait::wait_for_delete;
+pub use self::wait::wait_for_status;
+pub use self::wait::WaitForDelete;
+pub use self::wait::WaitForStatus;
@@ -0,0 +1,215 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Concurrent execution of multiple queries
+//!
+//! Every query is executed independently. Failure of one of them does not
+//! stop the others and the result is reported for every query separately.
+//!
+//! ```rust,ignore
+//! let eps = ids.iter().map(|id| ignore(delete::Request::builder().id(id).build().unwrap()));
+//! let results: Vec<Result<(), _>> = batch(eps)
+//!     .concurrency(20)
+//!     .rate_limit(50)
+//!     .query_async(&client)
+//!     .await?;
+//! ```
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use futures::stream::{self, Stream, StreamExt};
+use tokio::time::Instant;
+
+use crate::api::{ApiError, AsyncClient, QueryAsync};
+
+/// Default amount of queries being executed at the same time.
+const DEFAULT_CONCURRENCY: usize = 10;
+
+/// A query modifier that executes multiple queries concurrently.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Batch<E> {
+    endpoints: Vec<E>,
+    concurrency: usize,
+    rate_limit: Option<u32>,
+}
+
+/// Execute multiple queries concurrently.
+pub fn batch<I, E>(endpoints: I) -> Batch<E>
+where
+    I: IntoIterator<Item = E>,
+{
+    Batch {
+        endpoints: endpoints.into_iter().collect(),
+        concurrency: DEFAULT_CONCURRENCY,
+        rate_limit: None,
+    }
+}
+
+impl<E> Batch<E> {
+    /// Maximal amount of queries being executed at the same time.
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Maximal amount of queries started per second.
+    pub fn rate_limit(mut self, requests_per_second: u32) -> Self {
+        self.rate_limit = Some(requests_per_second).filter(|x| *x > 0);
+        self
+    }
+
+    /// Number of queries in the batch.
+    pub fn len(&self) -> usize {
+        self.endpoints.len()
+    }
+
+    /// Whether the batch has no queries.
+    pub fn is_empty(&self) -> bool {
+        self.endpoints.is_empty()
+    }
+
+    /// Create a stream of the query results in the order of completion.
+    ///
+    /// Every item contains the index of the query in the batch and its
+    /// result.
+    pub fn iter_async<'a, T, C>(
+        &'a self,
+        client: &'a C,
+    ) -> impl Stream<Item = (usize, Result<T, ApiError<C::Error>>)> + 'a
+    where
+        E: QueryAsync<T, C> + Sync,
+        T: Send + 'static,
+        C: AsyncClient + Sync,
+    {
+        let start = Instant::now();
+        let delay = self.rate_limit.map(|rate| Duration::from_secs(1) / rate);
+        stream::iter(self.endpoints.iter().enumerate())
+            .map(move |(idx, ep)| async move {
+                if let Some(delay) = delay {
+                    // Spread queries evenly to respect the rate limit
+                    tokio::time::sleep_until(start + delay * idx as u32).await;
+                }
+                (idx, ep.query_async(client).await)
+            })
+            .buffer_unordered(self.concurrency)
+    }
+}
+
+#[async_trait]
+impl<E, T, C> QueryAsync<Vec<Result<T, ApiError<C::Error>>>, C> for Batch<E>
+where
+    E: QueryAsync<T, C> + Sync + Send,
+    T: Send + 'static,
+    C: AsyncClient + Sync,
+{
+    async fn query_async(
+        &self,
+        client: &C,
+    ) -> Result<Vec<Result<T, ApiError<C::Error>>>, ApiError<C::Error>> {
+        let mut results: Vec<Option<Result<T, ApiError<C::Error>>>> =
+            (0..self.endpoints.len()).map(|_| None).collect();
+        let mut stream = std::pin::pin!(self.iter_async(client));
+        while let Some((idx, res)) = stream.next().await {
+            results[idx] = Some(res);
+        }
+        Ok(results
+            .into_iter()
+            .map(|x| x.expect("every query is completed"))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use futures::StreamExt;
+    use http::StatusCode;
+    use serde_json::{json, Value};
+
+    use crate::api::rest_endpoint_prelude::*;
+    use crate::api::{self, ApiError, QueryAsync};
+    use crate::test::client::MockAsyncServerClient;
+    use crate::types::ServiceType;
+
+    struct Dummy {
+        id: &'static str,
+    }
+
+    impl RestEndpoint for Dummy {
+        fn method(&self) -> http::Method {
+            http::Method::GET
+        }
+
+        fn endpoint(&self) -> Cow<'static, str> {
+            format!("dummy/{}", self.id).into()
+        }
+
+        fn service_type(&self) -> ServiceType {
+            ServiceType::Other("dummy".to_string())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_batch_results() {
+        let client = MockAsyncServerClient::new().await;
+        let mock_ok = client.server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path_contains("/dummy/ok");
+            then.status(200).json_body(json!({"id": "ok"}));
+        });
+        let mock_missing = client.server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/dummy/missing");
+            then.status(StatusCode::NOT_FOUND.into());
+        });
+
+        let res: Vec<Result<Value, _>> = api::batch([
+            Dummy { id: "ok1" },
+            Dummy { id: "missing" },
+            Dummy { id: "ok2" },
+        ])
+        .concurrency(2)
+        .query_async(&client)
+        .await
+        .unwrap();
+
+        assert_eq!(res.len(), 3);
+        assert_eq!(res[0].as_ref().unwrap()["id"], "ok");
+        assert!(matches!(res[1], Err(ApiError::ResourceNotFound)));
+        assert_eq!(res[2].as_ref().unwrap()["id"], "ok");
+        mock_ok.assert_hits(2);
+        mock_missing.assert();
+    }
+
+    #[tokio::test]
+    async fn test_batch_rate_limit() {
+        let client = MockAsyncServerClient::new().await;
+        let mock = client.server.mock(|when, then| {
+            when.method(httpmock::Method::GET);
+            then.status(200).json_body(json!({}));
+        });
+
+        let ids = ["1", "2", "3", "4", "5"];
+        let start = std::time::Instant::now();
+        let batch = api::batch(ids.iter().map(|id| Dummy { id })).rate_limit(50);
+        let res: Vec<(usize, Result<Value, _>)> = batch.iter_async(&client).collect().await;
+
+        assert_eq!(res.len(), 5);
+        assert!(res.iter().all(|(_, x)| x.is_ok()));
+        // 5 requests with 50 req/s start over at least 80ms
+        assert!(start.elapsed() >= Duration::from_millis(80));
+        mock.assert_hits(5);
+    }
+}
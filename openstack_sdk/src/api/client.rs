@@ -27,6 +27,8 @@ use url::Url;
 
 use crate::api::ApiError;
 use crate::catalog::ServiceEndpoint;
+#[cfg(feature = "sync")]
+use crate::types::BoxedRead;
 use crate::types::{identity::v3::Project, BoxedAsyncRead, ServiceType};
 
 /// A trait representing a client which can communicate with a OpenStack service API via REST API.
@@ -49,6 +51,11 @@ pub trait RestClient {
         &self,
         service_type: &ServiceType,
     ) -> Result<ServiceEndpoint, ApiError<Self::Error>>;
+
+    /// Whether the request data should be validated before sending
+    fn validate_requests(&self) -> bool {
+        true
+    }
 }
 
 /// A trait representing a client which can communicate with a OpenStack cloud APIs.
@@ -60,6 +67,20 @@ pub trait Client: RestClient {
         request: RequestBuilder,
         body: Vec<u8>,
     ) -> Result<Response<Bytes>, ApiError<Self::Error>>;
+
+    /// Send a REST query with the body read from `Read`.
+    fn rest_read_body(
+        &self,
+        request: RequestBuilder,
+        body: BoxedRead,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>>;
+
+    /// Send a REST query and return body as `Read`.
+    fn download(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<(HeaderMap, BoxedRead), ApiError<Self::Error>>;
 }
 
 /// A trait representing an asynchronous client which can communicate with OpenStack cloud.
@@ -14,9 +14,18 @@
 
 //! `Compute` Service bindings
 pub mod aggregate;
+pub mod assisted_volume_snapshot;
 pub mod availability_zone;
 pub mod extension;
 pub mod flavor;
 pub mod hypervisor;
+pub mod instance_usage_audit_log;
 pub mod keypair;
+pub mod limit;
+pub mod quota_class_set;
+pub mod quota_set;
 pub mod server;
+pub mod server_external_event;
+pub mod server_group;
+pub mod service;
+pub mod simple_tenant_usage;
@@ -0,0 +1,306 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// WARNING: This file is automatically generated from OpenAPI schema using
+// `openstack-codegenerator`.
+
+//! Creates an assisted volume snapshot.
+//!
+//! Policy defaults enable only users with the administrative role to perform
+//! this operation. Cloud providers can change these permissions through the
+//! `policy.json` file.
+//!
+//! Normal response codes: 200
+//!
+//! Error response codes: badRequest(400), unauthorized(401), forbidden(403)
+//!
+use derive_builder::Builder;
+use http::{HeaderMap, HeaderName, HeaderValue};
+
+use crate::api::rest_endpoint_prelude::*;
+
+use serde::Deserialize;
+use serde::Serialize;
+use std::borrow::Cow;
+
+#[derive(Debug, Deserialize, Clone, Serialize)]
+pub enum Type {
+    #[serde(rename = "qcow2")]
+    Qcow2,
+}
+
+/// Information for snapshot creation.
+///
+#[derive(Builder, Debug, Deserialize, Clone, Serialize)]
+#[builder(setter(strip_option))]
+pub struct CreateInfo<'a> {
+    /// It is an arbitrary string that gets passed back to the user.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into))]
+    pub(crate) id: Option<Cow<'a, str>>,
+
+    /// The name of the qcow2 file that Block Storage creates, which becomes
+    /// the active image for the VM.
+    ///
+    #[serde()]
+    #[builder(setter(into))]
+    pub(crate) new_file: Cow<'a, str>,
+
+    /// The UUID for a snapshot.
+    ///
+    #[serde()]
+    #[builder(setter(into))]
+    pub(crate) snapshot_id: Cow<'a, str>,
+
+    /// The snapshot type. A valid value is `qcow2`.
+    ///
+    #[serde(rename = "type")]
+    #[builder()]
+    pub(crate) _type: Type,
+}
+
+/// A partial representation of a snapshot that is used to create a snapshot.
+///
+#[derive(Builder, Debug, Deserialize, Clone, Serialize)]
+#[builder(setter(strip_option))]
+pub struct Snapshot<'a> {
+    /// Information for snapshot creation.
+    ///
+    #[serde()]
+    #[builder(setter(into))]
+    pub(crate) create_info: CreateInfo<'a>,
+
+    /// The source volume ID.
+    ///
+    #[serde()]
+    #[builder(setter(into))]
+    pub(crate) volume_id: Cow<'a, str>,
+}
+
+#[derive(Builder, Debug, Clone)]
+#[builder(setter(strip_option))]
+pub struct Request<'a> {
+    /// A partial representation of a snapshot that is used to create a
+    /// snapshot.
+    ///
+    #[builder(setter(into))]
+    pub(crate) snapshot: Snapshot<'a>,
+
+    #[builder(setter(name = "_headers"), default, private)]
+    _headers: Option<HeaderMap>,
+}
+impl<'a> Request<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> RequestBuilder<'a> {
+        RequestBuilder::default()
+    }
+}
+
+impl<'a> RequestBuilder<'a> {
+    /// Add a single header to the Assisted_Volume_Snapshot.
+    pub fn header(&mut self, header_name: &'static str, header_value: &'static str) -> &mut Self
+where {
+        self._headers
+            .get_or_insert(None)
+            .get_or_insert_with(HeaderMap::new)
+            .insert(header_name, HeaderValue::from_static(header_value));
+        self
+    }
+
+    /// Add multiple headers.
+    pub fn headers<I, T>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = T>,
+        T: Into<(Option<HeaderName>, HeaderValue)>,
+    {
+        self._headers
+            .get_or_insert(None)
+            .get_or_insert_with(HeaderMap::new)
+            .extend(iter.map(Into::into));
+        self
+    }
+}
+
+impl<'a> RestEndpoint for Request<'a> {
+    fn method(&self) -> http::Method {
+        http::Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "v2.1/os-assisted-volume-snapshots".to_string().into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        QueryParams::default()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = JsonBodyParams::default();
+
+        params.push("snapshot", serde_json::to_value(&self.snapshot)?);
+
+        params.into_body()
+    }
+
+    fn service_type(&self) -> ServiceType {
+        ServiceType::Compute
+    }
+
+    fn response_key(&self) -> Option<Cow<'static, str>> {
+        Some("snapshot".into())
+    }
+
+    /// Returns headers to be set into the request
+    fn request_headers(&self) -> Option<&HeaderMap> {
+        self._headers.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(unused_imports)]
+    use super::*;
+    use crate::api::Query;
+    use crate::test::client::MockServerClient;
+    use crate::types::ServiceType;
+    use http::{HeaderName, HeaderValue};
+    use serde_json::json;
+
+    #[test]
+    fn test_service_type() {
+        assert_eq!(
+            Request::builder()
+                .snapshot(
+                    SnapshotBuilder::default()
+                        .create_info(
+                            CreateInfoBuilder::default()
+                                .new_file("foo")
+                                .snapshot_id("foo")
+                                ._type(Type::Qcow2)
+                                .build()
+                                .unwrap(),
+                        )
+                        .volume_id("foo")
+                        .build()
+                        .unwrap()
+                )
+                .build()
+                .unwrap()
+                .service_type(),
+            ServiceType::Compute
+        );
+    }
+
+    #[test]
+    fn test_response_key() {
+        assert_eq!(
+            Request::builder()
+                .snapshot(
+                    SnapshotBuilder::default()
+                        .create_info(
+                            CreateInfoBuilder::default()
+                                .new_file("foo")
+                                .snapshot_id("foo")
+                                ._type(Type::Qcow2)
+                                .build()
+                                .unwrap(),
+                        )
+                        .volume_id("foo")
+                        .build()
+                        .unwrap()
+                )
+                .build()
+                .unwrap()
+                .response_key()
+                .unwrap(),
+            "snapshot"
+        );
+    }
+
+    #[test]
+    fn endpoint() {
+        let client = MockServerClient::new();
+        let mock = client.server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/v2.1/os-assisted-volume-snapshots".to_string());
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(json!({ "snapshot": {} }));
+        });
+
+        let endpoint = Request::builder()
+            .snapshot(
+                SnapshotBuilder::default()
+                    .create_info(
+                        CreateInfoBuilder::default()
+                            .new_file("foo")
+                            .snapshot_id("foo")
+                            ._type(Type::Qcow2)
+                            .build()
+                            .unwrap(),
+                    )
+                    .volume_id("foo")
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        let _: serde_json::Value = endpoint.query(&client).unwrap();
+        mock.assert();
+    }
+
+    #[test]
+    fn endpoint_headers() {
+        let client = MockServerClient::new();
+        let mock = client.server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/v2.1/os-assisted-volume-snapshots".to_string())
+                .header("foo", "bar")
+                .header("not_foo", "not_bar");
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(json!({ "snapshot": {} }));
+        });
+
+        let endpoint = Request::builder()
+            .snapshot(
+                SnapshotBuilder::default()
+                    .create_info(
+                        CreateInfoBuilder::default()
+                            .new_file("foo")
+                            .snapshot_id("foo")
+                            ._type(Type::Qcow2)
+                            .build()
+                            .unwrap(),
+                    )
+                    .volume_id("foo")
+                    .build()
+                    .unwrap(),
+            )
+            .headers(
+                [(
+                    Some(HeaderName::from_static("foo")),
+                    HeaderValue::from_static("bar"),
+                )]
+                .into_iter(),
+            )
+            .header("not_foo", "not_bar")
+            .build()
+            .unwrap();
+        let _: serde_json::Value = endpoint.query(&client).unwrap();
+        mock.assert();
+    }
+}
@@ -16,6 +16,7 @@
 // `openstack-codegenerator`.
 
 //! `/v2.1/os-hypervisors` REST operations of compute
+pub mod find;
 pub mod get;
 pub mod list;
 pub mod list_detailed;
@@ -93,11 +93,12 @@ impl<'a> Findable for Request<'a> {
         data: Vec<serde_json::Value>,
     ) -> Result<serde_json::Value, ApiError<C::Error>> {
         // hypervisor is not supporting name as query parameter to the list.
-        // Therefore it is necessary to go through complete list of results.
+        // Therefore it is necessary to go through complete list of results
+        // and match on the `hypervisor_hostname`.
         let mut maybe_result: Option<serde_json::Value> = None;
         for item in data.iter() {
             trace!("Validate item {:?} is what we search for", item);
-            if let Some(name_as_val) = item.get("name") {
+            if let Some(name_as_val) = item.get("hypervisor_hostname") {
                 if let Some(name) = name_as_val.as_str() {
                     if name == self.id {
                         if maybe_result.is_none() {
@@ -0,0 +1,171 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// WARNING: This file is automatically generated from OpenAPI schema using
+// `openstack-codegenerator`.
+
+//! Lists usage audits for all servers on all compute hosts where usage
+//! auditing is configured.
+//!
+//! Policy defaults enable only users with the administrative role to perform
+//! this operation. Cloud providers can change these permissions through the
+//! `policy.json` file.
+//!
+//! Normal response codes: 200
+//!
+//! Error response codes: unauthorized(401), forbidden(403)
+//!
+use derive_builder::Builder;
+use http::{HeaderMap, HeaderName, HeaderValue};
+
+use crate::api::rest_endpoint_prelude::*;
+
+use std::borrow::Cow;
+
+#[derive(Builder, Debug, Clone)]
+#[builder(setter(strip_option))]
+pub struct Request {
+    #[builder(setter(name = "_headers"), default, private)]
+    _headers: Option<HeaderMap>,
+}
+impl Request {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> RequestBuilder {
+        RequestBuilder::default()
+    }
+}
+
+impl RequestBuilder {
+    /// Add a single header to the Instance_Usage_Audit_Log.
+    pub fn header(&mut self, header_name: &'static str, header_value: &'static str) -> &mut Self
+where {
+        self._headers
+            .get_or_insert(None)
+            .get_or_insert_with(HeaderMap::new)
+            .insert(header_name, HeaderValue::from_static(header_value));
+        self
+    }
+
+    /// Add multiple headers.
+    pub fn headers<I, T>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = T>,
+        T: Into<(Option<HeaderName>, HeaderValue)>,
+    {
+        self._headers
+            .get_or_insert(None)
+            .get_or_insert_with(HeaderMap::new)
+            .extend(iter.map(Into::into));
+        self
+    }
+}
+
+impl RestEndpoint for Request {
+    fn method(&self) -> http::Method {
+        http::Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "v2.1/os-instance-usage-audit-log".to_string().into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        QueryParams::default()
+    }
+
+    fn service_type(&self) -> ServiceType {
+        ServiceType::Compute
+    }
+
+    fn response_key(&self) -> Option<Cow<'static, str>> {
+        Some("instance_usage_audit_logs".into())
+    }
+
+    /// Returns headers to be set into the request
+    fn request_headers(&self) -> Option<&HeaderMap> {
+        self._headers.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(unused_imports)]
+    use super::*;
+    use crate::api::Query;
+    use crate::test::client::MockServerClient;
+    use crate::types::ServiceType;
+    use http::{HeaderName, HeaderValue};
+    use serde_json::json;
+
+    #[test]
+    fn test_service_type() {
+        assert_eq!(
+            Request::builder().build().unwrap().service_type(),
+            ServiceType::Compute
+        );
+    }
+
+    #[test]
+    fn test_response_key() {
+        assert_eq!(
+            Request::builder().build().unwrap().response_key().unwrap(),
+            "instance_usage_audit_logs"
+        );
+    }
+
+    #[test]
+    fn endpoint() {
+        let client = MockServerClient::new();
+        let mock = client.server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/v2.1/os-instance-usage-audit-log".to_string());
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(json!({ "instance_usage_audit_logs": {} }));
+        });
+
+        let endpoint = Request::builder().build().unwrap();
+        let _: serde_json::Value = endpoint.query(&client).unwrap();
+        mock.assert();
+    }
+
+    #[test]
+    fn endpoint_headers() {
+        let client = MockServerClient::new();
+        let mock = client.server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/v2.1/os-instance-usage-audit-log".to_string())
+                .header("foo", "bar")
+                .header("not_foo", "not_bar");
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(json!({ "instance_usage_audit_logs": {} }));
+        });
+
+        let endpoint = Request::builder()
+            .headers(
+                [(
+                    Some(HeaderName::from_static("foo")),
+                    HeaderValue::from_static("bar"),
+                )]
+                .into_iter(),
+            )
+            .header("not_foo", "not_bar")
+            .build()
+            .unwrap();
+        let _: serde_json::Value = endpoint.query(&client).unwrap();
+        mock.assert();
+    }
+}
@@ -0,0 +1,172 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// WARNING: This file is automatically generated from OpenAPI schema using
+// `openstack-codegenerator`.
+
+//! Shows rate and absolute limits for the project.
+//!
+//! Normal response codes: 200
+//!
+//! Error response codes: unauthorized(401), forbidden(403)
+//!
+use derive_builder::Builder;
+use http::{HeaderMap, HeaderName, HeaderValue};
+
+use crate::api::rest_endpoint_prelude::*;
+
+use std::borrow::Cow;
+
+#[derive(Builder, Debug, Clone)]
+#[builder(setter(strip_option))]
+pub struct Request<'a> {
+    #[builder(default, setter(into))]
+    tenant_id: Option<Cow<'a, str>>,
+
+    #[builder(setter(name = "_headers"), default, private)]
+    _headers: Option<HeaderMap>,
+}
+impl<'a> Request<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> RequestBuilder<'a> {
+        RequestBuilder::default()
+    }
+}
+
+impl<'a> RequestBuilder<'a> {
+    /// Add a single header to the Limit.
+    pub fn header(&mut self, header_name: &'static str, header_value: &'static str) -> &mut Self
+where {
+        self._headers
+            .get_or_insert(None)
+            .get_or_insert_with(HeaderMap::new)
+            .insert(header_name, HeaderValue::from_static(header_value));
+        self
+    }
+
+    /// Add multiple headers.
+    pub fn headers<I, T>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = T>,
+        T: Into<(Option<HeaderName>, HeaderValue)>,
+    {
+        self._headers
+            .get_or_insert(None)
+            .get_or_insert_with(HeaderMap::new)
+            .extend(iter.map(Into::into));
+        self
+    }
+}
+
+impl<'a> RestEndpoint for Request<'a> {
+    fn method(&self) -> http::Method {
+        http::Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "v2.1/limits".to_string().into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+        params.push_opt("tenant_id", self.tenant_id.as_ref());
+
+        params
+    }
+
+    fn service_type(&self) -> ServiceType {
+        ServiceType::Compute
+    }
+
+    fn response_key(&self) -> Option<Cow<'static, str>> {
+        Some("limits".into())
+    }
+
+    /// Returns headers to be set into the request
+    fn request_headers(&self) -> Option<&HeaderMap> {
+        self._headers.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(unused_imports)]
+    use super::*;
+    use crate::api::Query;
+    use crate::test::client::MockServerClient;
+    use crate::types::ServiceType;
+    use http::{HeaderName, HeaderValue};
+    use serde_json::json;
+
+    #[test]
+    fn test_service_type() {
+        assert_eq!(
+            Request::builder().build().unwrap().service_type(),
+            ServiceType::Compute
+        );
+    }
+
+    #[test]
+    fn test_response_key() {
+        assert_eq!(
+            Request::builder().build().unwrap().response_key().unwrap(),
+            "limits"
+        );
+    }
+
+    #[test]
+    fn endpoint() {
+        let client = MockServerClient::new();
+        let mock = client.server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/v2.1/limits".to_string());
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(json!({ "limits": {} }));
+        });
+
+        let endpoint = Request::builder().build().unwrap();
+        let _: serde_json::Value = endpoint.query(&client).unwrap();
+        mock.assert();
+    }
+
+    #[test]
+    fn endpoint_headers() {
+        let client = MockServerClient::new();
+        let mock = client.server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/v2.1/limits".to_string())
+                .header("foo", "bar")
+                .header("not_foo", "not_bar");
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(json!({ "limits": {} }));
+        });
+
+        let endpoint = Request::builder()
+            .headers(
+                [(
+                    Some(HeaderName::from_static("foo")),
+                    HeaderValue::from_static("bar"),
+                )]
+                .into_iter(),
+            )
+            .header("not_foo", "not_bar")
+            .build()
+            .unwrap();
+        let _: serde_json::Value = endpoint.query(&client).unwrap();
+        mock.assert();
+    }
+}
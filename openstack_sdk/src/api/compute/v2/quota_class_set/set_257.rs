@@ -0,0 +1,325 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// WARNING: This file is automatically generated from OpenAPI schema using
+// `openstack-codegenerator`.
+
+//! Update the quotas for the Quota Class.
+//!
+//! If the requested Quota Class is not found in the DB, then the API will
+//! create the one. Only 'default' quota class is valid and used to set the
+//! default quotas, all other quota class would not be used anywhere.
+//!
+//! Normal response codes: 200
+//!
+//! Error response codes: badRequest(400), unauthorized(401), forbidden(403)
+//!
+use derive_builder::Builder;
+use http::{HeaderMap, HeaderName, HeaderValue};
+
+use crate::api::rest_endpoint_prelude::*;
+
+use serde::Deserialize;
+use serde::Serialize;
+use std::borrow::Cow;
+
+/// A `quota_class_set` object.
+///
+#[derive(Builder, Debug, Deserialize, Clone, Serialize)]
+#[builder(setter(strip_option))]
+pub struct QuotaClassSet {
+    /// The number of allowed server cores for each tenant. A value of `-1`
+    /// means no limit.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub(crate) cores: Option<i32>,
+
+    /// The number of allowed servers for each tenant. A value of `-1` means no
+    /// limit.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub(crate) instances: Option<i32>,
+
+    /// The number of allowed key pairs for each user. A value of `-1` means no
+    /// limit.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub(crate) key_pairs: Option<i32>,
+
+    /// The number of allowed metadata items for each server. A value of `-1`
+    /// means no limit.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub(crate) metadata_items: Option<i32>,
+
+    /// The amount of allowed server RAM, in MiB, for each tenant. A value of
+    /// `-1` means no limit.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub(crate) ram: Option<i32>,
+
+    /// The number of allowed members for each server group. A value of `-1`
+    /// means no limit.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub(crate) server_group_members: Option<i32>,
+
+    /// The number of allowed server groups for each tenant. A value of `-1`
+    /// means no limit.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub(crate) server_groups: Option<i32>,
+}
+
+#[derive(Builder, Debug, Clone)]
+#[builder(setter(strip_option))]
+pub struct Request<'a> {
+    /// A `quota_class_set` object.
+    ///
+    #[builder(setter(into))]
+    pub(crate) quota_class_set: QuotaClassSet,
+
+    /// id parameter for /v2.1/os-quota-class-sets/{id} API
+    ///
+    #[builder(default, setter(into))]
+    id: Cow<'a, str>,
+
+    #[builder(setter(name = "_headers"), default, private)]
+    _headers: Option<HeaderMap>,
+}
+impl<'a> Request<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> RequestBuilder<'a> {
+        RequestBuilder::default()
+    }
+}
+
+impl<'a> RequestBuilder<'a> {
+    /// Add a single header to the Quota_Class_Set.
+    pub fn header(&mut self, header_name: &'static str, header_value: &'static str) -> &mut Self
+where {
+        self._headers
+            .get_or_insert(None)
+            .get_or_insert_with(HeaderMap::new)
+            .insert(header_name, HeaderValue::from_static(header_value));
+        self
+    }
+
+    /// Add multiple headers.
+    pub fn headers<I, T>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = T>,
+        T: Into<(Option<HeaderName>, HeaderValue)>,
+    {
+        self._headers
+            .get_or_insert(None)
+            .get_or_insert_with(HeaderMap::new)
+            .extend(iter.map(Into::into));
+        self
+    }
+}
+
+impl<'a> RestEndpoint for Request<'a> {
+    fn method(&self) -> http::Method {
+        http::Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("v2.1/os-quota-class-sets/{id}", id = self.id.as_ref(),).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        QueryParams::default()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = JsonBodyParams::default();
+
+        params.push(
+            "quota_class_set",
+            serde_json::to_value(&self.quota_class_set)?,
+        );
+
+        params.into_body()
+    }
+
+    fn service_type(&self) -> ServiceType {
+        ServiceType::Compute
+    }
+
+    fn response_key(&self) -> Option<Cow<'static, str>> {
+        Some("quota_class_set".into())
+    }
+
+    /// Returns headers to be set into the request
+    fn request_headers(&self) -> Option<&HeaderMap> {
+        self._headers.as_ref()
+    }
+
+    fn validate(&self) -> Result<(), BodyError> {
+        let quota_class_set = &self.quota_class_set;
+        Validator::new()
+            .range(
+                "quota_class_set.cores",
+                quota_class_set.cores,
+                Some(-1),
+                None,
+            )
+            .range(
+                "quota_class_set.instances",
+                quota_class_set.instances,
+                Some(-1),
+                None,
+            )
+            .range(
+                "quota_class_set.key_pairs",
+                quota_class_set.key_pairs,
+                Some(-1),
+                None,
+            )
+            .range(
+                "quota_class_set.metadata_items",
+                quota_class_set.metadata_items,
+                Some(-1),
+                None,
+            )
+            .range("quota_class_set.ram", quota_class_set.ram, Some(-1), None)
+            .range(
+                "quota_class_set.server_group_members",
+                quota_class_set.server_group_members,
+                Some(-1),
+                None,
+            )
+            .range(
+                "quota_class_set.server_groups",
+                quota_class_set.server_groups,
+                Some(-1),
+                None,
+            )
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(unused_imports)]
+    use super::*;
+    use crate::api::Query;
+    use crate::test::client::MockServerClient;
+    use crate::types::ServiceType;
+    use http::{HeaderName, HeaderValue};
+    use serde_json::json;
+
+    #[test]
+    fn test_service_type() {
+        assert_eq!(
+            Request::builder()
+                .quota_class_set(QuotaClassSetBuilder::default().build().unwrap())
+                .build()
+                .unwrap()
+                .service_type(),
+            ServiceType::Compute
+        );
+    }
+
+    #[test]
+    fn test_response_key() {
+        assert_eq!(
+            Request::builder()
+                .quota_class_set(QuotaClassSetBuilder::default().build().unwrap())
+                .build()
+                .unwrap()
+                .response_key()
+                .unwrap(),
+            "quota_class_set"
+        );
+    }
+
+    #[test]
+    fn endpoint() {
+        let client = MockServerClient::new();
+        let mock = client.server.mock(|when, then| {
+            when.method(httpmock::Method::PUT)
+                .path(format!("/v2.1/os-quota-class-sets/{id}", id = "id",));
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(json!({ "quota_class_set": {} }));
+        });
+
+        let endpoint = Request::builder()
+            .id("id")
+            .quota_class_set(QuotaClassSetBuilder::default().build().unwrap())
+            .build()
+            .unwrap();
+        let _: serde_json::Value = endpoint.query(&client).unwrap();
+        mock.assert();
+    }
+
+    #[test]
+    fn endpoint_headers() {
+        let client = MockServerClient::new();
+        let mock = client.server.mock(|when, then| {
+            when.method(httpmock::Method::PUT)
+                .path(format!("/v2.1/os-quota-class-sets/{id}", id = "id",))
+                .header("foo", "bar")
+                .header("not_foo", "not_bar");
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(json!({ "quota_class_set": {} }));
+        });
+
+        let endpoint = Request::builder()
+            .id("id")
+            .quota_class_set(QuotaClassSetBuilder::default().build().unwrap())
+            .headers(
+                [(
+                    Some(HeaderName::from_static("foo")),
+                    HeaderValue::from_static("bar"),
+                )]
+                .into_iter(),
+            )
+            .header("not_foo", "not_bar")
+            .build()
+            .unwrap();
+        let _: serde_json::Value = endpoint.query(&client).unwrap();
+        mock.assert();
+    }
+
+    #[test]
+    fn test_validate() {
+        let endpoint = Request::builder()
+            .quota_class_set(
+                QuotaClassSetBuilder::default()
+                    .cores(-1)
+                    .instances(-2)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        let Err(BodyError::Validation { errors }) = endpoint.validate() else {
+            panic!("request is expected to be invalid");
+        };
+        let fields: Vec<&str> = errors.iter().map(|x| x.field.as_str()).collect();
+        assert_eq!(fields, ["quota_class_set.instances"]);
+    }
+}
@@ -0,0 +1,416 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// WARNING: This file is automatically generated from OpenAPI schema using
+// `openstack-codegenerator`.
+
+//! Update the quotas for a project or a project and a user.
+//!
+//! Users can force the update even if the quota has already been used and the
+//! reserved quota exceeds the new quota. To force the update, specify the
+//! `"force": "True"`. Default is `False`.
+//!
+//! Normal response codes: 200
+//!
+//! Error response codes: badRequest(400), unauthorized(401), forbidden(403)
+//!
+use derive_builder::Builder;
+use http::{HeaderMap, HeaderName, HeaderValue};
+
+use crate::api::rest_endpoint_prelude::*;
+
+use serde::Deserialize;
+use serde::Serialize;
+use std::borrow::Cow;
+
+/// A `quota_set` object.
+///
+#[derive(Builder, Debug, Deserialize, Clone, Serialize)]
+#[builder(setter(strip_option))]
+pub struct QuotaSet {
+    /// The number of allowed server cores for each tenant. A value of `-1`
+    /// means no limit.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub(crate) cores: Option<i32>,
+
+    /// The number of allowed fixed IP addresses for each tenant. Must be equal
+    /// to or greater than the number of allowed servers. A value of `-1` means
+    /// no limit.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub(crate) fixed_ips: Option<i32>,
+
+    /// The number of allowed floating IP addresses for each tenant. A value of
+    /// `-1` means no limit.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub(crate) floating_ips: Option<i32>,
+
+    /// You can force the update even if the quota has already been used and
+    /// the reserved quota exceeds the new quota. To force the update, specify
+    /// the `"force": "True"`. Default is `False`.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub(crate) force: Option<bool>,
+
+    /// The number of allowed bytes of content for each injected file. A value
+    /// of `-1` means no limit.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub(crate) injected_file_content_bytes: Option<i32>,
+
+    /// The number of allowed bytes for each injected file path. A value of
+    /// `-1` means no limit.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub(crate) injected_file_path_bytes: Option<i32>,
+
+    /// The number of allowed injected files for each tenant. A value of `-1`
+    /// means no limit.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub(crate) injected_files: Option<i32>,
+
+    /// The number of allowed servers for each tenant. A value of `-1` means no
+    /// limit.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub(crate) instances: Option<i32>,
+
+    /// The number of allowed key pairs for each user. A value of `-1` means no
+    /// limit.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub(crate) key_pairs: Option<i32>,
+
+    /// The number of allowed metadata items for each server. A value of `-1`
+    /// means no limit.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub(crate) metadata_items: Option<i32>,
+
+    /// The number of allowed networks for each tenant. A value of `-1` means
+    /// no limit.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub(crate) networks: Option<i32>,
+
+    /// The amount of allowed server RAM, in MiB, for each tenant. A value of
+    /// `-1` means no limit.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub(crate) ram: Option<i32>,
+
+    /// The number of allowed rules for each security group. A value of `-1`
+    /// means no limit.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub(crate) security_group_rules: Option<i32>,
+
+    /// The number of allowed security groups for each tenant. A value of `-1`
+    /// means no limit.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub(crate) security_groups: Option<i32>,
+
+    /// The number of allowed members for each server group. A value of `-1`
+    /// means no limit.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub(crate) server_group_members: Option<i32>,
+
+    /// The number of allowed server groups for each tenant. A value of `-1`
+    /// means no limit.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub(crate) server_groups: Option<i32>,
+}
+
+#[derive(Builder, Debug, Clone)]
+#[builder(setter(strip_option))]
+pub struct Request<'a> {
+    /// A `quota_set` object.
+    ///
+    #[builder(setter(into))]
+    pub(crate) quota_set: QuotaSet,
+
+    /// id parameter for /v2.1/os-quota-sets/{id} API
+    ///
+    #[builder(default, setter(into))]
+    id: Cow<'a, str>,
+
+    #[builder(default, setter(into))]
+    user_id: Option<Cow<'a, str>>,
+
+    #[builder(setter(name = "_headers"), default, private)]
+    _headers: Option<HeaderMap>,
+}
+impl<'a> Request<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> RequestBuilder<'a> {
+        RequestBuilder::default()
+    }
+}
+
+impl<'a> RequestBuilder<'a> {
+    /// Add a single header to the Quota_Set.
+    pub fn header(&mut self, header_name: &'static str, header_value: &'static str) -> &mut Self
+where {
+        self._headers
+            .get_or_insert(None)
+            .get_or_insert_with(HeaderMap::new)
+            .insert(header_name, HeaderValue::from_static(header_value));
+        self
+    }
+
+    /// Add multiple headers.
+    pub fn headers<I, T>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = T>,
+        T: Into<(Option<HeaderName>, HeaderValue)>,
+    {
+        self._headers
+            .get_or_insert(None)
+            .get_or_insert_with(HeaderMap::new)
+            .extend(iter.map(Into::into));
+        self
+    }
+}
+
+impl<'a> RestEndpoint for Request<'a> {
+    fn method(&self) -> http::Method {
+        http::Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("v2.1/os-quota-sets/{id}", id = self.id.as_ref(),).into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+        params.push_opt("user_id", self.user_id.as_ref());
+
+        params
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = JsonBodyParams::default();
+
+        params.push("quota_set", serde_json::to_value(&self.quota_set)?);
+
+        params.into_body()
+    }
+
+    fn service_type(&self) -> ServiceType {
+        ServiceType::Compute
+    }
+
+    fn response_key(&self) -> Option<Cow<'static, str>> {
+        Some("quota_set".into())
+    }
+
+    /// Returns headers to be set into the request
+    fn request_headers(&self) -> Option<&HeaderMap> {
+        self._headers.as_ref()
+    }
+
+    fn validate(&self) -> Result<(), BodyError> {
+        let quota_set = &self.quota_set;
+        Validator::new()
+            .range("quota_set.cores", quota_set.cores, Some(-1), None)
+            .range("quota_set.fixed_ips", quota_set.fixed_ips, Some(-1), None)
+            .range(
+                "quota_set.floating_ips",
+                quota_set.floating_ips,
+                Some(-1),
+                None,
+            )
+            .range(
+                "quota_set.injected_file_content_bytes",
+                quota_set.injected_file_content_bytes,
+                Some(-1),
+                None,
+            )
+            .range(
+                "quota_set.injected_file_path_bytes",
+                quota_set.injected_file_path_bytes,
+                Some(-1),
+                None,
+            )
+            .range(
+                "quota_set.injected_files",
+                quota_set.injected_files,
+                Some(-1),
+                None,
+            )
+            .range("quota_set.instances", quota_set.instances, Some(-1), None)
+            .range("quota_set.key_pairs", quota_set.key_pairs, Some(-1), None)
+            .range(
+                "quota_set.metadata_items",
+                quota_set.metadata_items,
+                Some(-1),
+                None,
+            )
+            .range("quota_set.networks", quota_set.networks, Some(-1), None)
+            .range("quota_set.ram", quota_set.ram, Some(-1), None)
+            .range(
+                "quota_set.security_group_rules",
+                quota_set.security_group_rules,
+                Some(-1),
+                None,
+            )
+            .range(
+                "quota_set.security_groups",
+                quota_set.security_groups,
+                Some(-1),
+                None,
+            )
+            .range(
+                "quota_set.server_group_members",
+                quota_set.server_group_members,
+                Some(-1),
+                None,
+            )
+            .range(
+                "quota_set.server_groups",
+                quota_set.server_groups,
+                Some(-1),
+                None,
+            )
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(unused_imports)]
+    use super::*;
+    use crate::api::Query;
+    use crate::test::client::MockServerClient;
+    use crate::types::ServiceType;
+    use http::{HeaderName, HeaderValue};
+    use serde_json::json;
+
+    #[test]
+    fn test_service_type() {
+        assert_eq!(
+            Request::builder()
+                .quota_set(QuotaSetBuilder::default().build().unwrap())
+                .build()
+                .unwrap()
+                .service_type(),
+            ServiceType::Compute
+        );
+    }
+
+    #[test]
+    fn test_response_key() {
+        assert_eq!(
+            Request::builder()
+                .quota_set(QuotaSetBuilder::default().build().unwrap())
+                .build()
+                .unwrap()
+                .response_key()
+                .unwrap(),
+            "quota_set"
+        );
+    }
+
+    #[test]
+    fn endpoint() {
+        let client = MockServerClient::new();
+        let mock = client.server.mock(|when, then| {
+            when.method(httpmock::Method::PUT)
+                .path(format!("/v2.1/os-quota-sets/{id}", id = "id",));
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(json!({ "quota_set": {} }));
+        });
+
+        let endpoint = Request::builder()
+            .id("id")
+            .quota_set(QuotaSetBuilder::default().build().unwrap())
+            .build()
+            .unwrap();
+        let _: serde_json::Value = endpoint.query(&client).unwrap();
+        mock.assert();
+    }
+
+    #[test]
+    fn endpoint_headers() {
+        let client = MockServerClient::new();
+        let mock = client.server.mock(|when, then| {
+            when.method(httpmock::Method::PUT)
+                .path(format!("/v2.1/os-quota-sets/{id}", id = "id",))
+                .header("foo", "bar")
+                .header("not_foo", "not_bar");
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(json!({ "quota_set": {} }));
+        });
+
+        let endpoint = Request::builder()
+            .id("id")
+            .quota_set(QuotaSetBuilder::default().build().unwrap())
+            .headers(
+                [(
+                    Some(HeaderName::from_static("foo")),
+                    HeaderValue::from_static("bar"),
+                )]
+                .into_iter(),
+            )
+            .header("not_foo", "not_bar")
+            .build()
+            .unwrap();
+        let _: serde_json::Value = endpoint.query(&client).unwrap();
+        mock.assert();
+    }
+
+    #[test]
+    fn test_validate() {
+        let endpoint = Request::builder()
+            .quota_set(
+                QuotaSetBuilder::default()
+                    .cores(-1)
+                    .fixed_ips(-2)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        let Err(BodyError::Validation { errors }) = endpoint.validate() else {
+            panic!("request is expected to be invalid");
+        };
+        let fields: Vec<&str> = errors.iter().map(|x| x.field.as_str()).collect();
+        assert_eq!(fields, ["quota_set.fixed_ips"]);
+    }
+}
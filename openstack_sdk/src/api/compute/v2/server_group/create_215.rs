@@ -0,0 +1,300 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// WARNING: This file is automatically generated from OpenAPI schema using
+// `openstack-codegenerator`.
+
+//! Creates a server group.
+//!
+//! Normal response codes: 200
+//!
+//! Error response codes: badRequest(400), unauthorized(401), forbidden(403),
+//! conflict(409)
+//!
+use derive_builder::Builder;
+use http::{HeaderMap, HeaderName, HeaderValue};
+
+use crate::api::rest_endpoint_prelude::*;
+
+use serde::Deserialize;
+use serde::Serialize;
+use std::borrow::Cow;
+
+#[derive(Debug, Deserialize, Clone, Serialize)]
+pub enum Policies {
+    #[serde(rename = "affinity")]
+    Affinity,
+    #[serde(rename = "anti-affinity")]
+    AntiAffinity,
+    #[serde(rename = "soft-affinity")]
+    SoftAffinity,
+    #[serde(rename = "soft-anti-affinity")]
+    SoftAntiAffinity,
+}
+
+/// The server group object.
+///
+#[derive(Builder, Debug, Deserialize, Clone, Serialize)]
+#[builder(setter(strip_option))]
+pub struct ServerGroup<'a> {
+    /// The name of the server group.
+    ///
+    #[serde()]
+    #[builder(setter(into))]
+    pub(crate) name: Cow<'a, str>,
+
+    /// A list of exactly one policy name to associate with the server group.
+    /// The current valid policy names are:
+    ///
+    /// - `anti-affinity` - servers in this group must be scheduled to
+    ///   different hosts.
+    /// - `affinity` - servers in this group must be scheduled to the same
+    ///   host.
+    /// - `soft-anti-affinity` - servers in this group should be scheduled to
+    ///   different hosts if possible, but if not possible then they should
+    ///   still be scheduled instead of resulting in a build failure.
+    /// - `soft-affinity` - servers in this group should be scheduled to the
+    ///   same host if possible, but if not possible then they should still be
+    ///   scheduled instead of resulting in a build failure.
+    ///
+    /// **New in version 2.15** for the `soft-*` policies.
+    ///
+    #[serde()]
+    #[builder(setter(into))]
+    pub(crate) policies: Vec<Policies>,
+}
+
+#[derive(Builder, Debug, Clone)]
+#[builder(setter(strip_option))]
+pub struct Request<'a> {
+    /// The server group object.
+    ///
+    #[builder(setter(into))]
+    pub(crate) server_group: ServerGroup<'a>,
+
+    #[builder(setter(name = "_headers"), default, private)]
+    _headers: Option<HeaderMap>,
+}
+impl<'a> Request<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> RequestBuilder<'a> {
+        RequestBuilder::default()
+    }
+}
+
+impl<'a> RequestBuilder<'a> {
+    /// Add a single header to the Server_Group.
+    pub fn header(&mut self, header_name: &'static str, header_value: &'static str) -> &mut Self
+where {
+        self._headers
+            .get_or_insert(None)
+            .get_or_insert_with(HeaderMap::new)
+            .insert(header_name, HeaderValue::from_static(header_value));
+        self
+    }
+
+    /// Add multiple headers.
+    pub fn headers<I, T>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = T>,
+        T: Into<(Option<HeaderName>, HeaderValue)>,
+    {
+        self._headers
+            .get_or_insert(None)
+            .get_or_insert_with(HeaderMap::new)
+            .extend(iter.map(Into::into));
+        self
+    }
+}
+
+impl<'a> RestEndpoint for Request<'a> {
+    fn method(&self) -> http::Method {
+        http::Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "v2.1/os-server-groups".to_string().into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        QueryParams::default()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = JsonBodyParams::default();
+
+        params.push("server_group", serde_json::to_value(&self.server_group)?);
+
+        params.into_body()
+    }
+
+    fn service_type(&self) -> ServiceType {
+        ServiceType::Compute
+    }
+
+    fn response_key(&self) -> Option<Cow<'static, str>> {
+        Some("server_group".into())
+    }
+
+    /// Returns headers to be set into the request
+    fn request_headers(&self) -> Option<&HeaderMap> {
+        self._headers.as_ref()
+    }
+
+    fn validate(&self) -> Result<(), BodyError> {
+        let server_group = &self.server_group;
+        Validator::new()
+            .length(
+                "server_group.name",
+                Some(&server_group.name),
+                Some(1),
+                Some(255),
+            )
+            .items(
+                "server_group.policies",
+                Some(&server_group.policies),
+                Some(1),
+                Some(1),
+            )
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(unused_imports)]
+    use super::*;
+    use crate::api::Query;
+    use crate::test::client::MockServerClient;
+    use crate::types::ServiceType;
+    use http::{HeaderName, HeaderValue};
+    use serde_json::json;
+
+    #[test]
+    fn test_service_type() {
+        assert_eq!(
+            Request::builder()
+                .server_group(
+                    ServerGroupBuilder::default()
+                        .name("foo")
+                        .policies(Vec::from([Policies::Affinity]))
+                        .build()
+                        .unwrap()
+                )
+                .build()
+                .unwrap()
+                .service_type(),
+            ServiceType::Compute
+        );
+    }
+
+    #[test]
+    fn test_response_key() {
+        assert_eq!(
+            Request::builder()
+                .server_group(
+                    ServerGroupBuilder::default()
+                        .name("foo")
+                        .policies(Vec::from([Policies::Affinity]))
+                        .build()
+                        .unwrap()
+                )
+                .build()
+                .unwrap()
+                .response_key()
+                .unwrap(),
+            "server_group"
+        );
+    }
+
+    #[test]
+    fn endpoint() {
+        let client = MockServerClient::new();
+        let mock = client.server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/v2.1/os-server-groups".to_string());
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(json!({ "server_group": {} }));
+        });
+
+        let endpoint = Request::builder()
+            .server_group(
+                ServerGroupBuilder::default()
+                    .name("foo")
+                    .policies(Vec::from([Policies::Affinity]))
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        let _: serde_json::Value = endpoint.query(&client).unwrap();
+        mock.assert();
+    }
+
+    #[test]
+    fn endpoint_headers() {
+        let client = MockServerClient::new();
+        let mock = client.server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/v2.1/os-server-groups".to_string())
+                .header("foo", "bar")
+                .header("not_foo", "not_bar");
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(json!({ "server_group": {} }));
+        });
+
+        let endpoint = Request::builder()
+            .server_group(
+                ServerGroupBuilder::default()
+                    .name("foo")
+                    .policies(Vec::from([Policies::Affinity]))
+                    .build()
+                    .unwrap(),
+            )
+            .headers(
+                [(
+                    Some(HeaderName::from_static("foo")),
+                    HeaderValue::from_static("bar"),
+                )]
+                .into_iter(),
+            )
+            .header("not_foo", "not_bar")
+            .build()
+            .unwrap();
+        let _: serde_json::Value = endpoint.query(&client).unwrap();
+        mock.assert();
+    }
+
+    #[test]
+    fn test_validate() {
+        let endpoint = Request::builder()
+            .server_group(
+                ServerGroupBuilder::default()
+                    .name("foo")
+                    .policies(Vec::from([Policies::Affinity, Policies::AntiAffinity]))
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        let Err(BodyError::Validation { errors }) = endpoint.validate() else {
+            panic!("request is expected to be invalid");
+        };
+        let fields: Vec<&str> = errors.iter().map(|x| x.field.as_str()).collect();
+        assert_eq!(fields, ["server_group.policies"]);
+    }
+}
@@ -0,0 +1,328 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Conditional requests (optimistic concurrency)
+//!
+//! Resources may be modified concurrently by different clients. To prevent
+//! overwriting changes of others the update is sent with the version of the
+//! resource it was based on (`If-Match: revision_number=N` for Neutron,
+//! `ETag` for services supporting it). When the resource was modified in
+//! the meanwhile the service responds with `412` reported as
+//! [`ApiError::PreconditionFailed`].
+//!
+//! ```rust,ignore
+//! let net: Value = network::get::Request::builder().id(id).build()?.query_async(&client).await?;
+//! let precondition = Precondition::from_value(&net).expect("revision_number is present");
+//! let ep = network::set::Request::builder().id(id).network(...).build()?;
+//! let net: Value = conditional(ep, precondition).query_async(&client).await?;
+//! ```
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+#[cfg(feature = "async")]
+use std::error::Error;
+#[cfg(feature = "async")]
+use std::future::Future;
+
+use bytes::Bytes;
+use http::{header, HeaderMap, HeaderValue, Method, Response};
+use serde_json::Value;
+#[cfg(feature = "async")]
+use tracing::debug;
+
+#[cfg(feature = "async")]
+use crate::api::ApiError;
+use crate::api::{BodyError, QueryParams, RestEndpoint};
+use crate::types::ServiceType;
+
+/// Expected version of the resource
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Precondition {
+    /// Neutron resource `revision_number` (`If-Match: revision_number=N`).
+    RevisionNumber(u64),
+    /// Entity tag of the resource (`If-Match`).
+    ETag(HeaderValue),
+    /// Entity tag that the resource must not match (`If-None-Match`). `*`
+    /// can be used to prevent overwriting of the existing resource.
+    NotETag(HeaderValue),
+}
+
+impl Precondition {
+    /// Version of the resource from the response headers (`ETag`).
+    pub fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        headers.get(header::ETAG).cloned().map(Self::ETag)
+    }
+
+    /// Version of the resource from its data (`revision_number`).
+    ///
+    /// Resource may be wrapped into the object with the single key (i.e.
+    /// `{"network": {...}}`).
+    pub fn from_value(value: &Value) -> Option<Self> {
+        let revision = value.get("revision_number").or_else(|| match value {
+            Value::Object(obj) if obj.len() == 1 => obj.values().next()?.get("revision_number"),
+            _ => None,
+        })?;
+        revision.as_u64().map(Self::RevisionNumber)
+    }
+
+    /// Version of the resource from the response (`ETag` header or
+    /// `revision_number` of the resource in the body).
+    pub fn from_response(rsp: &Response<Bytes>) -> Option<Self> {
+        Self::from_headers(rsp.headers()).or_else(|| {
+            serde_json::from_slice::<Value>(rsp.body())
+                .ok()
+                .and_then(|x| Self::from_value(&x))
+        })
+    }
+
+    /// Request header expressing the precondition.
+    fn header(&self) -> (header::HeaderName, HeaderValue) {
+        match self {
+            Self::RevisionNumber(revision) => (
+                header::IF_MATCH,
+                HeaderValue::from_str(&format!("revision_number={}", revision))
+                    .expect("revision number is a valid header value"),
+            ),
+            Self::ETag(etag) => (header::IF_MATCH, etag.clone()),
+            Self::NotETag(etag) => (header::IF_NONE_MATCH, etag.clone()),
+        }
+    }
+}
+
+/// A query modifier that only applies the request when the resource is
+/// still in the expected version.
+#[derive(Debug, Clone)]
+pub struct Conditional<E> {
+    endpoint: E,
+    headers: HeaderMap,
+}
+
+/// Send the request only if the resource matches the precondition.
+pub fn conditional<E>(endpoint: E, precondition: Precondition) -> Conditional<E>
+where
+    E: RestEndpoint,
+{
+    let mut headers = endpoint.request_headers().cloned().unwrap_or_default();
+    let (name, value) = precondition.header();
+    headers.insert(name, value);
+    Conditional { endpoint, headers }
+}
+
+impl<E> RestEndpoint for Conditional<E>
+where
+    E: RestEndpoint,
+{
+    fn method(&self) -> Method {
+        self.endpoint.method()
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        self.endpoint.endpoint()
+    }
+
+    fn service_type(&self) -> ServiceType {
+        self.endpoint.service_type()
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        self.endpoint.parameters()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        self.endpoint.body()
+    }
+
+    fn response_key(&self) -> Option<Cow<'static, str>> {
+        self.endpoint.response_key()
+    }
+
+    fn response_list_item_key(&self) -> Option<Cow<'static, str>> {
+        self.endpoint.response_list_item_key()
+    }
+
+    fn response_headers(&self) -> HashMap<&str, &str> {
+        self.endpoint.response_headers()
+    }
+
+    fn request_headers(&self) -> Option<&HeaderMap> {
+        Some(&self.headers)
+    }
+
+    fn validate(&self) -> Result<(), BodyError> {
+        self.endpoint.validate()
+    }
+}
+
+/// Repeat the read-modify-write cycle while the resource is being modified
+/// concurrently.
+///
+/// The `op` is expected to read the resource, apply the modification and
+/// send it with the [`conditional`] request. It is repeated when the
+/// service reports [`ApiError::PreconditionFailed`] at most `attempts`
+/// times.
+///
+/// ```rust,ignore
+/// let tags = read_modify_write(3, || async {
+///     let net: Value = get_ep.query_async(&client).await?;
+///     let mut tags: Vec<String> = serde_json::from_value(net["tags"].clone())?;
+///     tags.push("new".into());
+///     let ep = tag::replace::Request::builder().network_id(id).tags(tags).build()?;
+///     conditional(ep, Precondition::from_value(&net).unwrap()).query_async(&client).await
+/// })
+/// .await?;
+/// ```
+#[cfg(feature = "async")]
+pub async fn read_modify_write<T, E, F, Fut>(attempts: usize, mut op: F) -> Result<T, ApiError<E>>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, ApiError<E>>>,
+    E: Error + Send + Sync + 'static,
+{
+    let mut attempt = 1;
+    loop {
+        match op().await {
+            Err(ApiError::PreconditionFailed { error }) if attempt < attempts => {
+                debug!("Resource was modified concurrently ({}), retrying", error);
+                attempt += 1;
+            }
+            res => return res,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::HeaderValue;
+    use serde_json::{json, Value};
+
+    use crate::api::rest_endpoint_prelude::*;
+    use crate::api::{self, ApiError, Precondition};
+    use crate::types::ServiceType;
+
+    struct Dummy;
+
+    impl RestEndpoint for Dummy {
+        fn method(&self) -> http::Method {
+            http::Method::PUT
+        }
+
+        fn endpoint(&self) -> Cow<'static, str> {
+            "dummy".into()
+        }
+
+        fn service_type(&self) -> ServiceType {
+            ServiceType::Other("dummy".to_string())
+        }
+    }
+
+    #[test]
+    fn test_precondition_from_value() {
+        assert_eq!(
+            Precondition::from_value(&json!({"network": {"id": "1", "revision_number": 3}})),
+            Some(Precondition::RevisionNumber(3))
+        );
+        assert_eq!(
+            Precondition::from_value(&json!({"id": "1", "revision_number": 4})),
+            Some(Precondition::RevisionNumber(4))
+        );
+        assert_eq!(Precondition::from_value(&json!({"id": "1"})), None);
+    }
+
+    #[test]
+    fn test_precondition_from_response() {
+        let rsp = http::Response::builder()
+            .header("ETag", "\"abc\"")
+            .body(bytes::Bytes::from_static(b"{\"revision_number\": 1}"))
+            .unwrap();
+        assert_eq!(
+            Precondition::from_response(&rsp),
+            Some(Precondition::ETag(HeaderValue::from_static("\"abc\"")))
+        );
+    }
+
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_conditional_header() {
+        use crate::api::Query;
+        use crate::test::client::MockServerClient;
+
+        let client = MockServerClient::new();
+        let mock = client.server.mock(|when, then| {
+            when.method(httpmock::Method::PUT)
+                .path("/dummy")
+                .header("if-match", "revision_number=2");
+            then.status(412).json_body(json!({"NeutronError": {
+                "type": "RevisionNumberConstraintFailed",
+                "message": "Constrained to 2, but current revision is 3",
+                "detail": ""
+            }}));
+        });
+
+        let ep = api::conditional(Dummy, Precondition::RevisionNumber(2));
+        let res: Result<Value, _> = ep.query(&client);
+        assert!(matches!(res, Err(ApiError::PreconditionFailed { .. })));
+        mock.assert();
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_read_modify_write() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        use crate::api::network::v2::network::{get, set};
+        use crate::api::QueryAsync;
+        use crate::test_utils::{FakeCloud, FakeResource};
+        use crate::AsyncOpenStack;
+
+        let cloud = FakeCloud::new();
+        let net = cloud.add_resource(FakeResource::Network, json!({"name": "net"}));
+        let net_id = net["id"].as_str().unwrap();
+        let mut session = AsyncOpenStack::new(&cloud.cloud_config()).await.unwrap();
+        session
+            .discover_service_endpoint(&ServiceType::Network)
+            .await
+            .unwrap();
+
+        let set_ep = |name: &'static str| {
+            set::Request::builder()
+                .id(net_id)
+                .network(set::NetworkBuilder::default().name(name).build().unwrap())
+                .build()
+                .unwrap()
+        };
+        let attempts = AtomicUsize::new(0);
+        let res: Value = api::read_modify_write(3, || async {
+            let current: Value = get::Request::builder()
+                .id(net_id)
+                .build()
+                .unwrap()
+                .query_async(&session)
+                .await?;
+            if attempts.fetch_add(1, Ordering::SeqCst) == 0 {
+                // Concurrent modification
+                let _: Value = set_ep("other").query_async(&session).await?;
+            }
+            api::conditional(set_ep("mine"), Precondition::from_value(&current).unwrap())
+                .query_async(&session)
+                .await
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+        assert_eq!(res["name"], "mine");
+        assert_eq!(res["revision_number"], 3);
+    }
+}
@@ -14,10 +14,11 @@
 
 use std::any;
 use std::error::Error;
+use std::fmt;
 
 use thiserror::Error;
 
-use crate::api::PaginationError;
+use crate::api::{FieldError, PaginationError};
 use crate::types::ServiceType;
 
 /// Errors which may occur when creating form data.
@@ -37,6 +38,225 @@ pub enum BodyError {
         #[from]
         source: serde_json::Error,
     },
+    /// Request data does not satisfy the constraints of the API.
+    #[error(
+        "request validation failed: {}",
+        errors.iter().map(ToString::to_string).collect::<Vec<_>>().join("; ")
+    )]
+    Validation {
+        /// Every invalid field of the request.
+        errors: Vec<FieldError>,
+    },
+}
+
+/// Structured error information returned by the OpenStack service.
+///
+/// Every service reports errors in its own way (Nova `{"itemNotFound":
+/// {...}}`, Neutron `{"NeutronError": {...}}`, Octavia `faultstring`, Swift
+/// plain text, etc). The information is normalized into this structure.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServiceError {
+    /// The status code of the response.
+    pub status: http::StatusCode,
+    /// Machine readable error type or code (i.e. `itemNotFound`,
+    /// `NetworkNotFound`, `OverQuota`).
+    pub error_type: Option<String>,
+    /// The error message.
+    pub message: String,
+    /// Additional error details.
+    pub detail: Option<String>,
+}
+
+impl fmt::Display for ServiceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(error_type) = &self.error_type {
+            write!(f, "{}: ", error_type)?;
+        }
+        write!(f, "{}", self.message)?;
+        if let Some(detail) = &self.detail {
+            write!(f, " ({})", detail)?;
+        }
+        Ok(())
+    }
+}
+
+impl ServiceError {
+    /// Decode error information from the JSON response of the service.
+    ///
+    /// Returns `None` when the content is not in the format known for the
+    /// service.
+    pub fn from_json(
+        service_type: &ServiceType,
+        status: http::StatusCode,
+        value: &serde_json::Value,
+    ) -> Option<Self> {
+        let decoded = match service_type {
+            ServiceType::Network => Self::decode_neutron(status, value),
+            ServiceType::LoadBalancer => Self::decode_octavia(status, value),
+            ServiceType::Identity => Self::decode_keystone(status, value),
+            // Nova, Cinder and others using the same framework wrap the
+            // error into the object with the single key being the error type
+            _ => Self::decode_fault_wrapper(status, value),
+        };
+        decoded.or_else(|| Self::decode_generic(status, value))
+    }
+
+    /// Decode error information from the non JSON response of the service.
+    ///
+    /// Object store and Image services may return errors as plain text or
+    /// HTML.
+    pub fn from_text(
+        service_type: &ServiceType,
+        status: http::StatusCode,
+        body: &[u8],
+    ) -> Option<Self> {
+        match service_type {
+            ServiceType::ObjectStore | ServiceType::Image => {
+                let message = strip_html(&String::from_utf8_lossy(body));
+                if message.is_empty() {
+                    return None;
+                }
+                Some(Self {
+                    status,
+                    error_type: None,
+                    message,
+                    detail: None,
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether the error reports exceeded quota.
+    pub fn is_quota_exceeded(&self) -> bool {
+        if let Some(error_type) = &self.error_type {
+            let error_type = error_type.to_lowercase();
+            if error_type.contains("quota") || error_type == "overlimit" {
+                return true;
+            }
+        }
+        matches!(
+            self.status,
+            http::StatusCode::FORBIDDEN
+                | http::StatusCode::CONFLICT
+                | http::StatusCode::PAYLOAD_TOO_LARGE
+        ) && self.message.to_lowercase().contains("quota")
+    }
+
+    /// Neutron: `{"NeutronError": {"type": "", "message": "", "detail": ""}}`
+    fn decode_neutron(status: http::StatusCode, value: &serde_json::Value) -> Option<Self> {
+        let err = value.get("NeutronError")?;
+        if let Some(message) = err.as_str() {
+            return Some(Self {
+                status,
+                error_type: None,
+                message: message.into(),
+                detail: None,
+            });
+        }
+        Some(Self {
+            status,
+            error_type: json_str(err, "type"),
+            message: json_str(err, "message")?,
+            detail: json_str(err, "detail"),
+        })
+    }
+
+    /// Octavia: `{"faultcode": "", "faultstring": "", "debuginfo": ""}`
+    fn decode_octavia(status: http::StatusCode, value: &serde_json::Value) -> Option<Self> {
+        Some(Self {
+            status,
+            error_type: json_str(value, "faultcode"),
+            message: json_str(value, "faultstring")?,
+            detail: json_str(value, "debuginfo"),
+        })
+    }
+
+    /// Keystone: `{"error": {"code": 404, "message": "", "title": ""}}`
+    fn decode_keystone(status: http::StatusCode, value: &serde_json::Value) -> Option<Self> {
+        let err = value.get("error")?;
+        if !err.is_object() {
+            return None;
+        }
+        Some(Self {
+            status,
+            error_type: json_str(err, "title"),
+            message: json_str(err, "message")?,
+            detail: None,
+        })
+    }
+
+    /// Nova/Cinder: `{"<errorType>": {"code": 404, "message": ""}}`
+    fn decode_fault_wrapper(status: http::StatusCode, value: &serde_json::Value) -> Option<Self> {
+        let obj = value.as_object()?;
+        if obj.len() != 1 {
+            return None;
+        }
+        let (error_type, err) = obj.iter().next()?;
+        if !err.is_object() {
+            return None;
+        }
+        Some(Self {
+            status,
+            error_type: Some(error_type.clone()),
+            message: json_str(err, "message")?,
+            detail: json_str(err, "details"),
+        })
+    }
+
+    /// Generic `{"message": ""}` or `{"error": ""}` forms.
+    fn decode_generic(status: http::StatusCode, value: &serde_json::Value) -> Option<Self> {
+        let message = value
+            .pointer("/message")
+            .or_else(|| value.pointer("/error"))?
+            .as_str()?;
+        Some(Self {
+            status,
+            error_type: json_str(value, "type"),
+            message: message.into(),
+            detail: json_str(value, "detail"),
+        })
+    }
+}
+
+/// Get non empty string attribute of the JSON object.
+fn json_str(value: &serde_json::Value, key: &str) -> Option<String> {
+    value
+        .get(key)
+        .and_then(|x| x.as_str())
+        .filter(|x| !x.is_empty())
+        .map(String::from)
+}
+
+/// Convert HTML error page into plain text keeping only the content.
+fn strip_html(data: &str) -> String {
+    let mut res = String::with_capacity(data.len());
+    let mut in_tag = false;
+    let mut in_title = false;
+    let mut tag = String::new();
+    for c in data.chars() {
+        match c {
+            '<' => {
+                in_tag = true;
+                tag.clear();
+            }
+            '>' if in_tag => {
+                in_tag = false;
+                let name = tag.to_lowercase();
+                // Title duplicates the heading.
+                if name == "title" {
+                    in_title = true;
+                } else if name == "/title" {
+                    in_title = false;
+                }
+                res.push(' ');
+            }
+            _ if in_tag => tag.push(c),
+            _ if in_title => {}
+            _ => res.push(c),
+        }
+    }
+    res.split_whitespace().collect::<Vec<_>>().join(" ")
 }
 
 /// Errors which may occur when using API endpoints.
@@ -85,13 +305,35 @@ where
         /// The error message from OpenStack.
         msg: String,
     },
+    /// Server returned 403.
+    #[error("forbidden: {}", error)]
+    Forbidden {
+        /// The error information from OpenStack.
+        error: ServiceError,
+    },
+    /// Server returned 409.
+    #[error("conflict: {}", error)]
+    Conflict {
+        /// The error information from OpenStack.
+        error: ServiceError,
+    },
+    /// Server returned 412 (resource was modified since it has been read).
+    #[error("precondition failed: {}", error)]
+    PreconditionFailed {
+        /// The error information from OpenStack.
+        error: ServiceError,
+    },
+    /// Server reported that the quota is exceeded.
+    #[error("quota exceeded: {}", error)]
+    QuotaExceeded {
+        /// The error information from OpenStack.
+        error: ServiceError,
+    },
     /// OpenStack returned understandable error message
-    #[error("openstack server error: {}", msg)]
+    #[error("openstack server error: {}", error)]
     OpenStack {
-        /// The status code for the return.
-        status: http::StatusCode,
-        /// The error message from OpenStack.
-        msg: String,
+        /// The error information from OpenStack.
+        error: ServiceError,
     },
     /// OpenStack returned an error without JSON information.
     #[error("openstack internal server error {}", status)]
@@ -109,6 +351,21 @@ where
         /// The full object from OpenStack.
         obj: serde_json::Value,
     },
+    /// Resource reached one of the failure states while waiting for it.
+    #[error("resource reached failure status `{}`", status)]
+    ResourceFailed {
+        /// The status of the resource.
+        status: String,
+    },
+    /// Resource did not reach the desired state in time.
+    #[error(
+        "timeout waiting for the resource (last status: `{}`)",
+        status.as_deref().unwrap_or("unknown")
+    )]
+    WaitTimeout {
+        /// The last known status of the resource.
+        status: Option<String>,
+    },
     /// Failed to parse an expected data type from JSON.
     #[error("could not parse {} data from JSON: {}", typename, source)]
     DataType {
@@ -152,7 +409,11 @@ where
             Self::UrlParse { source } => ApiError::UrlParse { source },
             Self::Body { source } => ApiError::Body { source },
             Self::Json { source } => ApiError::Json { source },
-            Self::OpenStack { status, msg } => ApiError::OpenStack { status, msg },
+            Self::Forbidden { error } => ApiError::Forbidden { error },
+            Self::Conflict { error } => ApiError::Conflict { error },
+            Self::PreconditionFailed { error } => ApiError::PreconditionFailed { error },
+            Self::QuotaExceeded { error } => ApiError::QuotaExceeded { error },
+            Self::OpenStack { error } => ApiError::OpenStack { error },
             Self::Session { msg } => ApiError::Session { msg },
             Self::OpenStackService { status, data } => ApiError::OpenStackService { status, data },
             Self::ResourceNotFound => ApiError::ResourceNotFound,
@@ -160,17 +421,43 @@ where
             Self::OpenStackUnrecognized { status, obj } => {
                 ApiError::OpenStackUnrecognized { status, obj }
             }
+            Self::ResourceFailed { status } => ApiError::ResourceFailed { status },
+            Self::WaitTimeout { status } => ApiError::WaitTimeout { status },
             Self::DataType { source, typename } => ApiError::DataType { source, typename },
             Self::Pagination { source } => ApiError::Pagination { source },
         }
     }
 
+    /// Return structured error information reported by the service (when
+    /// available).
+    pub fn service_error(&self) -> Option<&ServiceError> {
+        match self {
+            Self::Forbidden { error }
+            | Self::Conflict { error }
+            | Self::PreconditionFailed { error }
+            | Self::QuotaExceeded { error }
+            | Self::OpenStack { error } => Some(error),
+            _ => None,
+        }
+    }
+
     /// Process server response with no Json body
     pub(crate) fn server_error(status: http::StatusCode, body: &bytes::Bytes) -> Self {
         // Non Json body response ends in this function
         if http::StatusCode::NOT_FOUND.as_u16() == status {
             return ApiError::ResourceNotFound;
         };
+        if http::StatusCode::PRECONDITION_FAILED == status {
+            // Swift and Glance report failed precondition with empty body
+            return ApiError::PreconditionFailed {
+                error: ServiceError {
+                    status,
+                    error_type: None,
+                    message: String::from_utf8_lossy(body).trim().to_string(),
+                    detail: None,
+                },
+            };
+        }
 
         Self::OpenStackService {
             status,
@@ -178,35 +465,64 @@ where
         }
     }
 
+    /// Process server error response of the service
+    pub(crate) fn from_response(
+        service_type: &ServiceType,
+        status: http::StatusCode,
+        body: &bytes::Bytes,
+    ) -> Self {
+        if let Ok(v) = serde_json::from_slice(body) {
+            Self::from_openstack(service_type, status, v)
+        } else if let Some(error) = ServiceError::from_text(service_type, status, body) {
+            Self::from_service_error(error)
+        } else {
+            Self::server_error(status, body)
+        }
+    }
+
     /// Process server error response with Json body
-    pub(crate) fn from_openstack(status: http::StatusCode, value: serde_json::Value) -> Self {
+    pub(crate) fn from_openstack(
+        service_type: &ServiceType,
+        status: http::StatusCode,
+        value: serde_json::Value,
+    ) -> Self {
         if http::StatusCode::NOT_FOUND.as_u16() == status {
             return ApiError::ResourceNotFound;
         };
 
-        let error_value = value
-            .pointer("/message")
-            .or_else(|| value.pointer("/error"));
+        if let Some(error) = ServiceError::from_json(service_type, status, &value) {
+            // Error we know how to parse
+            return Self::from_service_error(error);
+        }
 
-        if let Some(error_value) = error_value {
-            if let Some(msg) = error_value.as_str() {
-                // Error we know how to parse
-                ApiError::OpenStack {
-                    status,
-                    msg: msg.into(),
-                }
-            } else {
-                // Error we do not know how to parse
-                ApiError::OpenStackUnrecognized {
-                    status,
-                    obj: error_value.clone(),
-                }
+        // Error we do not know how to parse
+        if let Some(error_value) = value
+            .pointer("/message")
+            .or_else(|| value.pointer("/error"))
+        {
+            ApiError::OpenStackUnrecognized {
+                status,
+                obj: error_value.clone(),
             }
         } else {
             ApiError::OpenStackUnrecognized { status, obj: value }
         }
     }
 
+    /// Classify structured service error
+    fn from_service_error(error: ServiceError) -> Self {
+        if error.is_quota_exceeded() {
+            return ApiError::QuotaExceeded { error };
+        }
+        match error.status {
+            http::StatusCode::NOT_FOUND => ApiError::ResourceNotFound,
+            http::StatusCode::FORBIDDEN => ApiError::Forbidden { error },
+            http::StatusCode::CONFLICT => ApiError::Conflict { error },
+            http::StatusCode::PRECONDITION_FAILED => ApiError::PreconditionFailed { error },
+            _ => ApiError::OpenStack { error },
+        }
+    }
+
     pub(crate) fn data_type<T>(source: serde_json::Error) -> Self {
         ApiError::DataType {
             source,
@@ -227,11 +543,16 @@ mod tests {
     use thiserror::Error;
 
     use crate::api::ApiError;
+    use crate::types::ServiceType;
 
     #[derive(Debug, Error)]
     #[error("my error")]
     enum MyError {}
 
+    fn other() -> ServiceType {
+        ServiceType::Other("dummy".into())
+    }
+
     #[test]
     fn openstack_error_error() {
         let obj = json!({
@@ -239,10 +560,10 @@ mod tests {
         });
 
         let err: ApiError<MyError> =
-            ApiError::from_openstack(http::StatusCode::CONFLICT, obj.clone());
-        if let ApiError::OpenStack { status, msg } = err {
-            assert_eq!(msg, "error contents");
-            assert_eq!(status, http::StatusCode::CONFLICT);
+            ApiError::from_openstack(&other(), http::StatusCode::BAD_REQUEST, obj.clone());
+        if let ApiError::OpenStack { error } = err {
+            assert_eq!(error.message, "error contents");
+            assert_eq!(error.status, http::StatusCode::BAD_REQUEST);
         } else {
             panic!("unexpected error: {}", err);
         }
@@ -255,10 +576,10 @@ mod tests {
         });
 
         let err: ApiError<MyError> =
-            ApiError::from_openstack(http::StatusCode::CONFLICT, obj.clone());
-        if let ApiError::OpenStack { status, msg } = err {
-            assert_eq!(msg, "error contents");
-            assert_eq!(status, http::StatusCode::CONFLICT);
+            ApiError::from_openstack(&other(), http::StatusCode::BAD_REQUEST, obj.clone());
+        if let ApiError::OpenStack { error } = err {
+            assert_eq!(error.message, "error contents");
+            assert_eq!(error.status, http::StatusCode::BAD_REQUEST);
         } else {
             panic!("unexpected error: {}", err);
         }
@@ -274,7 +595,7 @@ mod tests {
         });
 
         let err: ApiError<MyError> =
-            ApiError::from_openstack(http::StatusCode::CONFLICT, obj.clone());
+            ApiError::from_openstack(&other(), http::StatusCode::CONFLICT, obj.clone());
         if let ApiError::OpenStackUnrecognized { status, obj } = err {
             assert_eq!(obj, err_obj);
             assert_eq!(status, http::StatusCode::CONFLICT);
@@ -290,7 +611,7 @@ mod tests {
         });
 
         let err: ApiError<MyError> =
-            ApiError::from_openstack(http::StatusCode::CONFLICT, err_obj.clone());
+            ApiError::from_openstack(&other(), http::StatusCode::CONFLICT, err_obj.clone());
         if let ApiError::OpenStackUnrecognized { status, obj } = err {
             assert_eq!(obj, err_obj);
             assert_eq!(status, http::StatusCode::CONFLICT);
@@ -306,9 +627,224 @@ mod tests {
         });
 
         let err: ApiError<MyError> =
-            ApiError::from_openstack(http::StatusCode::NOT_FOUND, err_obj.clone());
+            ApiError::from_openstack(&other(), http::StatusCode::NOT_FOUND, err_obj.clone());
         if !matches!(err, ApiError::ResourceNotFound) {
             panic!("unexpected error: {}", err);
         }
     }
+
+    #[test]
+    fn openstack_error_conflict() {
+        let obj = json!({
+            "message": "error contents",
+        });
+
+        let err: ApiError<MyError> =
+            ApiError::from_openstack(&other(), http::StatusCode::CONFLICT, obj.clone());
+        if let ApiError::Conflict { error } = err {
+            assert_eq!(error.message, "error contents");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn network_error_precondition_failed() {
+        let obj = json!({
+            "NeutronError": {
+                "type": "RevisionNumberConstraintFailed",
+                "message": "Constrained to 3, but current revision is 4",
+                "detail": ""
+            }
+        });
+
+        let err: ApiError<MyError> = ApiError::from_openstack(
+            &ServiceType::Network,
+            http::StatusCode::PRECONDITION_FAILED,
+            obj,
+        );
+        if let ApiError::PreconditionFailed { error } = err {
+            assert_eq!(
+                error.error_type,
+                Some("RevisionNumberConstraintFailed".into())
+            );
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+
+        let err: ApiError<MyError> = ApiError::from_response(
+            &ServiceType::Other("dummy".into()),
+            http::StatusCode::PRECONDITION_FAILED,
+            &bytes::Bytes::new(),
+        );
+        assert!(matches!(err, ApiError::PreconditionFailed { .. }));
+    }
+
+    #[test]
+    fn compute_error() {
+        let obj = json!({
+            "forbidden": {
+                "code": 403,
+                "message": "Policy doesn't allow os_compute_api:servers:create to be performed."
+            }
+        });
+
+        let err: ApiError<MyError> =
+            ApiError::from_openstack(&ServiceType::Compute, http::StatusCode::FORBIDDEN, obj);
+        if let ApiError::Forbidden { error } = err {
+            assert_eq!(error.error_type, Some("forbidden".into()));
+            assert_eq!(
+                error.message,
+                "Policy doesn't allow os_compute_api:servers:create to be performed."
+            );
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn compute_quota_error() {
+        let obj = json!({
+            "overLimit": {
+                "code": 413,
+                "message": "Quota exceeded for instances: Requested 1, but already used 10 of 10 instances"
+            }
+        });
+
+        let err: ApiError<MyError> = ApiError::from_openstack(
+            &ServiceType::Compute,
+            http::StatusCode::PAYLOAD_TOO_LARGE,
+            obj,
+        );
+        if let ApiError::QuotaExceeded { error } = err {
+            assert_eq!(error.error_type, Some("overLimit".into()));
+            assert_eq!(error.status, http::StatusCode::PAYLOAD_TOO_LARGE);
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn network_error() {
+        let obj = json!({
+            "NeutronError": {
+                "type": "IpAddressGenerationFailure",
+                "message": "No more IP addresses available on network foo.",
+                "detail": ""
+            }
+        });
+
+        let err: ApiError<MyError> =
+            ApiError::from_openstack(&ServiceType::Network, http::StatusCode::CONFLICT, obj);
+        if let ApiError::Conflict { error } = err {
+            assert_eq!(error.error_type, Some("IpAddressGenerationFailure".into()));
+            assert_eq!(
+                error.message,
+                "No more IP addresses available on network foo."
+            );
+            assert_eq!(error.detail, None);
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn network_quota_error() {
+        let obj = json!({
+            "NeutronError": {
+                "type": "OverQuota",
+                "message": "Quota exceeded for resources: ['port'].",
+                "detail": ""
+            }
+        });
+
+        let err: ApiError<MyError> =
+            ApiError::from_openstack(&ServiceType::Network, http::StatusCode::CONFLICT, obj);
+        if let ApiError::QuotaExceeded { error } = err {
+            assert_eq!(error.error_type, Some("OverQuota".into()));
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn load_balancer_error() {
+        let obj = json!({
+            "faultcode": "Client",
+            "faultstring": "Invalid input for field/attribute vip_subnet_id.",
+            "debuginfo": null
+        });
+
+        let err: ApiError<MyError> = ApiError::from_openstack(
+            &ServiceType::LoadBalancer,
+            http::StatusCode::BAD_REQUEST,
+            obj,
+        );
+        if let ApiError::OpenStack { error } = err {
+            assert_eq!(error.error_type, Some("Client".into()));
+            assert_eq!(
+                error.message,
+                "Invalid input for field/attribute vip_subnet_id."
+            );
+            assert_eq!(error.detail, None);
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn identity_error() {
+        let obj = json!({
+            "error": {
+                "code": 401,
+                "message": "The request you have made requires authentication.",
+                "title": "Unauthorized"
+            }
+        });
+
+        let err: ApiError<MyError> =
+            ApiError::from_openstack(&ServiceType::Identity, http::StatusCode::UNAUTHORIZED, obj);
+        if let ApiError::OpenStack { error } = err {
+            assert_eq!(error.status, http::StatusCode::UNAUTHORIZED);
+            assert_eq!(error.error_type, Some("Unauthorized".into()));
+            assert_eq!(
+                error.message,
+                "The request you have made requires authentication."
+            );
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn object_store_html_error() {
+        let body = bytes::Bytes::from(
+            "<html><head><title>Conflict</title></head><body><h1>Conflict</h1><p>There was a conflict when trying to complete your request.</p></body></html>",
+        );
+
+        let err: ApiError<MyError> =
+            ApiError::from_response(&ServiceType::ObjectStore, http::StatusCode::CONFLICT, &body);
+        if let ApiError::Conflict { error } = err {
+            assert_eq!(
+                error.message,
+                "Conflict There was a conflict when trying to complete your request."
+            );
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn text_error_other_service() {
+        let body = bytes::Bytes::from("some text");
+
+        let err: ApiError<MyError> =
+            ApiError::from_response(&ServiceType::Compute, http::StatusCode::CONFLICT, &body);
+        if let ApiError::OpenStackService { status, data } = err {
+            assert_eq!(status, http::StatusCode::CONFLICT);
+            assert_eq!(data, "some text");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
 }
@@ -50,6 +50,7 @@ where
     fn query(&self, client: &C) -> Result<(), ApiError<C::Error>> {
         let ep = client.get_service_endpoint(&self.endpoint.service_type())?;
         let (req, data) = prepare_request::<C, E>(
+            client,
             &ep,
             client.rest_endpoint(&self.endpoint.service_type(), &self.endpoint.endpoint())?,
             &self.endpoint,
@@ -59,12 +60,11 @@ where
 
         let status = rsp.status();
         if !status.is_success() {
-            let v = if let Ok(v) = serde_json::from_slice(rsp.body()) {
-                v
-            } else {
-                return Err(ApiError::server_error(rsp.status(), rsp.body()));
-            };
-            return Err(ApiError::from_openstack(status, v));
+            return Err(ApiError::from_response(
+                &self.endpoint.service_type(),
+                status,
+                rsp.body(),
+            ));
         }
 
         Ok(())
@@ -81,6 +81,7 @@ where
     async fn query_async(&self, client: &C) -> Result<(), ApiError<C::Error>> {
         let ep = client.get_service_endpoint(&self.endpoint.service_type())?;
         let (req, data) = prepare_request::<C, E>(
+            client,
             &ep,
             client.rest_endpoint(&self.endpoint.service_type(), &self.endpoint.endpoint())?,
             &self.endpoint,
@@ -90,12 +91,11 @@ where
 
         let status = rsp.status();
         if !status.is_success() {
-            let v = if let Ok(v) = serde_json::from_slice(rsp.body()) {
-                v
-            } else {
-                return Err(ApiError::server_error(rsp.status(), rsp.body()));
-            };
-            return Err(ApiError::from_openstack(status, v));
+            return Err(ApiError::from_response(
+                &self.endpoint.service_type(),
+                status,
+                rsp.body(),
+            ));
         }
 
         Ok(())
@@ -197,8 +197,8 @@ mod tests {
         });
 
         let err = api::ignore(Dummy).query(&client).unwrap_err();
-        if let ApiError::OpenStack { status: _, msg } = err {
-            assert_eq!(msg, "dummy error message");
+        if let ApiError::Conflict { error } = err {
+            assert_eq!(error.message, "dummy error message");
         } else {
             panic!("unexpected error: {}", err);
         }
@@ -15,10 +15,25 @@
 //! `Network` Service bindings
 pub mod address_group;
 pub mod address_scope;
+pub mod agent;
 pub mod availability_zone;
+pub mod bgpvpn;
 pub mod extension;
 pub mod floatingip;
+pub mod fwaas;
 pub mod network;
+pub mod network_ip_availability;
 pub mod port;
+pub mod qos;
+pub mod quota;
+pub mod rbac_policy;
 pub mod router;
+pub mod security_group;
+pub mod security_group_rule;
+pub mod segment;
+pub mod service_provider;
 pub mod subnet;
+pub mod subnetpool;
+pub mod tag;
+pub mod trunk;
+pub mod vpn;
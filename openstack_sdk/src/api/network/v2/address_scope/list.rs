@@ -37,6 +37,8 @@ use crate::api::rest_endpoint_prelude::*;
 
 use std::borrow::Cow;
 
+use crate::api::Pageable;
+
 #[derive(Builder, Debug, Clone)]
 #[builder(setter(strip_option))]
 pub struct Request<'a> {
@@ -50,6 +52,22 @@ pub struct Request<'a> {
     #[builder(default)]
     ip_version: Option<i32>,
 
+    /// Requests a page size of items. Returns a number of items up to a limit
+    /// value. Use the limit parameter to make an initial limited request and
+    /// use the ID of the last-seen item from the response as the marker
+    /// parameter value in a subsequent limited request.
+    ///
+    #[builder(default)]
+    limit: Option<i32>,
+
+    /// The ID of the last-seen item. Use the limit parameter to make an
+    /// initial limited request and use the ID of the last-seen item from the
+    /// response as the marker parameter value in a subsequent limited
+    /// request.
+    ///
+    #[builder(default, setter(into))]
+    marker: Option<Cow<'a, str>>,
+
     /// name query parameter for /v2.0/address-scopes API
     ///
     #[builder(default, setter(into))]
@@ -111,6 +129,8 @@ impl<'a> RestEndpoint for Request<'a> {
 
     fn parameters(&self) -> QueryParams {
         let mut params = QueryParams::default();
+        params.push_opt("limit", self.limit);
+        params.push_opt("marker", self.marker.as_ref());
         params.push_opt("id", self.id.as_ref());
         params.push_opt("name", self.name.as_ref());
         params.push_opt("tenant_id", self.tenant_id.as_ref());
@@ -133,6 +153,7 @@ impl<'a> RestEndpoint for Request<'a> {
         self._headers.as_ref()
     }
 }
+impl<'a> Pageable for Request<'a> {}
 
 #[cfg(test)]
 mod tests {
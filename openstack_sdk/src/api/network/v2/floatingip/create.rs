@@ -131,6 +131,12 @@ pub struct Floatingip<'a> {
     #[builder(default, setter(into))]
     pub(crate) subnet_id: Option<Option<Cow<'a, str>>>,
 
+    /// The list of tags on the resource.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into))]
+    pub(crate) tags: Option<Vec<Cow<'a, str>>>,
+
     /// The ID of the project.
     ///
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -39,6 +39,8 @@ use crate::api::rest_endpoint_prelude::*;
 use crate::api::common::CommaSeparatedList;
 use std::borrow::Cow;
 
+use crate::api::Pageable;
+
 #[derive(Builder, Debug, Clone)]
 #[builder(setter(strip_option))]
 pub struct Request<'a> {
@@ -67,6 +69,22 @@ pub struct Request<'a> {
     #[builder(default, setter(into))]
     id: Option<Cow<'a, str>>,
 
+    /// Requests a page size of items. Returns a number of items up to a limit
+    /// value. Use the limit parameter to make an initial limited request and
+    /// use the ID of the last-seen item from the response as the marker
+    /// parameter value in a subsequent limited request.
+    ///
+    #[builder(default)]
+    limit: Option<i32>,
+
+    /// The ID of the last-seen item. Use the limit parameter to make an
+    /// initial limited request and use the ID of the last-seen item from the
+    /// response as the marker parameter value in a subsequent limited
+    /// request.
+    ///
+    #[builder(default, setter(into))]
+    marker: Option<Cow<'a, str>>,
+
     /// not-tags query parameter for /v2.0/floatingips API
     ///
     #[builder(default, private, setter(name = "_not_tags"))]
@@ -214,6 +232,8 @@ impl<'a> RestEndpoint for Request<'a> {
 
     fn parameters(&self) -> QueryParams {
         let mut params = QueryParams::default();
+        params.push_opt("limit", self.limit);
+        params.push_opt("marker", self.marker.as_ref());
         params.push_opt("id", self.id.as_ref());
         params.push_opt("floating_ip_address", self.floating_ip_address.as_ref());
         params.push_opt("floating_network_id", self.floating_network_id.as_ref());
@@ -245,6 +265,7 @@ impl<'a> RestEndpoint for Request<'a> {
         self._headers.as_ref()
     }
 }
+impl<'a> Pageable for Request<'a> {}
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,258 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// WARNING: This file is automatically generated from OpenAPI schema using
+// `openstack-codegenerator`.
+
+//! Inserts a firewall rule in a firewall policy relative to the position of
+//! other rules.
+//!
+//! When neither `insert_after` nor `insert_before` is given the rule is
+//! inserted at the top of the policy.
+//!
+//! Normal response codes: 200
+//!
+//! Error response codes: 400, 401, 404, 409
+//!
+use derive_builder::Builder;
+use http::{HeaderMap, HeaderName, HeaderValue};
+
+use crate::api::rest_endpoint_prelude::*;
+
+use std::borrow::Cow;
+
+#[derive(Builder, Debug, Clone)]
+#[builder(setter(strip_option))]
+pub struct Request<'a> {
+    /// The ID of the firewall rule to insert.
+    ///
+    #[builder(setter(into))]
+    pub(crate) firewall_rule_id: Cow<'a, str>,
+
+    /// The ID of the firewall rule after which the rule is inserted. Mutually exclusive with `insert_before`.
+    ///
+    #[builder(default, setter(into))]
+    pub(crate) insert_after: Option<Cow<'a, str>>,
+
+    /// The ID of the firewall rule before which the rule is inserted. Mutually exclusive with `insert_after`.
+    ///
+    #[builder(default, setter(into))]
+    pub(crate) insert_before: Option<Cow<'a, str>>,
+
+    /// id parameter for /v2.0/fwaas/firewall_policies/{id} API
+    ///
+    #[builder(default, setter(into))]
+    id: Cow<'a, str>,
+
+    #[builder(setter(name = "_headers"), default, private)]
+    _headers: Option<HeaderMap>,
+}
+impl<'a> Request<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> RequestBuilder<'a> {
+        RequestBuilder::default()
+    }
+}
+
+impl<'a> RequestBuilder<'a> {
+    /// Add a single header to the Firewall_Policy.
+    pub fn header(&mut self, header_name: &'static str, header_value: &'static str) -> &mut Self
+where {
+        self._headers
+            .get_or_insert(None)
+            .get_or_insert_with(HeaderMap::new)
+            .insert(header_name, HeaderValue::from_static(header_value));
+        self
+    }
+
+    /// Add multiple headers.
+    pub fn headers<I, T>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = T>,
+        T: Into<(Option<HeaderName>, HeaderValue)>,
+    {
+        self._headers
+            .get_or_insert(None)
+            .get_or_insert_with(HeaderMap::new)
+            .extend(iter.map(Into::into));
+        self
+    }
+}
+
+impl<'a> RestEndpoint for Request<'a> {
+    fn method(&self) -> http::Method {
+        http::Method::PUT
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!(
+            "v2.0/fwaas/firewall_policies/{id}/insert_rule",
+            id = self.id.as_ref(),
+        )
+        .into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        QueryParams::default()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = JsonBodyParams::default();
+
+        params.push(
+            "firewall_rule_id",
+            serde_json::to_value(&self.firewall_rule_id)?,
+        );
+        if let Some(val) = &self.insert_after {
+            params.push("insert_after", serde_json::to_value(val)?);
+        }
+        if let Some(val) = &self.insert_before {
+            params.push("insert_before", serde_json::to_value(val)?);
+        }
+
+        params.into_body()
+    }
+
+    fn service_type(&self) -> ServiceType {
+        ServiceType::Network
+    }
+
+    fn response_key(&self) -> Option<Cow<'static, str>> {
+        None
+    }
+
+    /// Returns headers to be set into the request
+    fn request_headers(&self) -> Option<&HeaderMap> {
+        self._headers.as_ref()
+    }
+
+    fn validate(&self) -> Result<(), BodyError> {
+        Validator::new()
+            .format(
+                "firewall_rule_id",
+                Some(&self.firewall_rule_id),
+                Format::Uuid,
+            )
+            .exclusive(&[
+                ("insert_after", self.insert_after.is_some()),
+                ("insert_before", self.insert_before.is_some()),
+            ])
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(unused_imports)]
+    use super::*;
+    use crate::api::Query;
+    use crate::test::client::MockServerClient;
+    use crate::types::ServiceType;
+    use http::{HeaderName, HeaderValue};
+    use serde_json::json;
+
+    #[test]
+    fn test_service_type() {
+        assert_eq!(
+            Request::builder()
+                .firewall_rule_id("9b1c2d3e-4f5a-4b6c-8d7e-0f1a2b3c4d5e")
+                .build()
+                .unwrap()
+                .service_type(),
+            ServiceType::Network
+        );
+    }
+
+    #[test]
+    fn test_response_key() {
+        assert!(Request::builder()
+            .firewall_rule_id("9b1c2d3e-4f5a-4b6c-8d7e-0f1a2b3c4d5e")
+            .build()
+            .unwrap()
+            .response_key()
+            .is_none())
+    }
+
+    #[test]
+    fn endpoint() {
+        let client = MockServerClient::new();
+        let mock = client.server.mock(|when, then| {
+            when.method(httpmock::Method::PUT).path(format!(
+                "/v2.0/fwaas/firewall_policies/{id}/insert_rule",
+                id = "id",
+            ));
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(json!({ "dummy": {} }));
+        });
+
+        let endpoint = Request::builder()
+            .id("id")
+            .firewall_rule_id("9b1c2d3e-4f5a-4b6c-8d7e-0f1a2b3c4d5e")
+            .build()
+            .unwrap();
+        let _: serde_json::Value = endpoint.query(&client).unwrap();
+        mock.assert();
+    }
+
+    #[test]
+    fn endpoint_headers() {
+        let client = MockServerClient::new();
+        let mock = client.server.mock(|when, then| {
+            when.method(httpmock::Method::PUT)
+                .path(format!(
+                    "/v2.0/fwaas/firewall_policies/{id}/insert_rule",
+                    id = "id",
+                ))
+                .header("foo", "bar")
+                .header("not_foo", "not_bar");
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(json!({ "dummy": {} }));
+        });
+
+        let endpoint = Request::builder()
+            .id("id")
+            .firewall_rule_id("9b1c2d3e-4f5a-4b6c-8d7e-0f1a2b3c4d5e")
+            .headers(
+                [(
+                    Some(HeaderName::from_static("foo")),
+                    HeaderValue::from_static("bar"),
+                )]
+                .into_iter(),
+            )
+            .header("not_foo", "not_bar")
+            .build()
+            .unwrap();
+        let _: serde_json::Value = endpoint.query(&client).unwrap();
+        mock.assert();
+    }
+
+    #[test]
+    fn test_validate() {
+        let endpoint = Request::builder()
+            .id("id")
+            .firewall_rule_id("9b1c2d3e-4f5a-4b6c-8d7e-0f1a2b3c4d5e")
+            .insert_after("9b1c2d3e-4f5a-4b6c-8d7e-0f1a2b3c4d5e")
+            .insert_before("9b1c2d3e-4f5a-4b6c-8d7e-0f1a2b3c4d5e")
+            .build()
+            .unwrap();
+        let Err(BodyError::Validation { errors }) = endpoint.validate() else {
+            panic!("request is expected to be invalid");
+        };
+        let fields: Vec<&str> = errors.iter().map(|x| x.field.as_str()).collect();
+        assert_eq!(fields, ["insert_after"]);
+    }
+}
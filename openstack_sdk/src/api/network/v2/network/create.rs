@@ -183,6 +183,12 @@ pub struct Network<'a> {
     #[builder(default)]
     pub(crate) shared: Option<bool>,
 
+    /// The list of tags on the resource.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into))]
+    pub(crate) tags: Option<Vec<Cow<'a, str>>>,
+
     /// The ID of the project that owns the resource. Only administrative and
     /// users with advsvc role can specify a project ID other than their own.
     /// You cannot change this value through authorization policies.
@@ -0,0 +1,28 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// WARNING: This file is automatically generated from OpenAPI schema using
+// `openstack-codegenerator`.
+
+//! `/v2.0/qos/policies` REST operations of network
+pub mod bandwidth_limit_rule;
+pub mod create;
+pub mod delete;
+pub mod dscp_marking_rule;
+pub mod find;
+pub mod get;
+pub mod list;
+pub mod minimum_bandwidth_rule;
+pub mod minimum_packet_rate_rule;
+pub mod set;
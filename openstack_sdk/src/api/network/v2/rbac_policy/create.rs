@@ -0,0 +1,338 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// WARNING: This file is automatically generated from OpenAPI schema using
+// `openstack-codegenerator`.
+
+//! Create RBAC policy for given tenant.
+//!
+//! Normal response codes: 201
+//!
+//! Error response codes: 400, 401
+//!
+use derive_builder::Builder;
+use http::{HeaderMap, HeaderName, HeaderValue};
+
+use crate::api::rest_endpoint_prelude::*;
+
+use serde::Deserialize;
+use serde::Serialize;
+use std::borrow::Cow;
+
+#[derive(Debug, Deserialize, Clone, Serialize)]
+pub enum Action {
+    #[serde(rename = "access_as_external")]
+    AccessAsExternal,
+    #[serde(rename = "access_as_shared")]
+    AccessAsShared,
+}
+
+#[derive(Debug, Deserialize, Clone, Serialize)]
+pub enum ObjectType {
+    #[serde(rename = "address_group")]
+    AddressGroup,
+    #[serde(rename = "address_scope")]
+    AddressScope,
+    #[serde(rename = "network")]
+    Network,
+    #[serde(rename = "qos_policy")]
+    QosPolicy,
+    #[serde(rename = "security_group")]
+    SecurityGroup,
+    #[serde(rename = "subnetpool")]
+    Subnetpool,
+}
+
+/// A `rbac_policy` object.
+///
+#[derive(Builder, Debug, Deserialize, Clone, Serialize)]
+#[builder(setter(strip_option))]
+pub struct RbacPolicy<'a> {
+    /// Action for the RBAC policy which is `access_as_external` or
+    /// `access_as_shared`.
+    ///
+    #[serde()]
+    #[builder()]
+    pub(crate) action: Action,
+
+    /// The ID of the `object_type` resource. An `object_type` of `network`
+    /// returns a network ID, an `object_type` of `qos-policy` returns a QoS
+    /// policy ID, an `object_type` of `security-group` returns a security
+    /// group ID, an `object_type` of `address-scope` returns a address scope
+    /// ID, an `object_type` of `subnetpool` returns a subnetpool ID and an
+    /// `object_type` of `address-group` returns an address group ID.
+    ///
+    #[serde()]
+    #[builder(setter(into))]
+    pub(crate) object_id: Cow<'a, str>,
+
+    /// The type of the object that the RBAC policy affects. Types include
+    /// `qos-policy`, `network`, `security-group`, `address-scope`,
+    /// `subnetpool` or `address-group`.
+    ///
+    #[serde()]
+    #[builder()]
+    pub(crate) object_type: ObjectType,
+
+    /// The ID of the tenant to which the RBAC policy will be enforced. Use `*`
+    /// to grant access to all projects.
+    ///
+    #[serde()]
+    #[builder(setter(into))]
+    pub(crate) target_tenant: Cow<'a, str>,
+
+    /// The ID of the project that owns the resource. Only administrative and
+    /// users with advsvc role can specify a project ID other than their own.
+    /// You cannot change this value through authorization policies.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into))]
+    pub(crate) tenant_id: Option<Cow<'a, str>>,
+}
+
+#[derive(Builder, Debug, Clone)]
+#[builder(setter(strip_option))]
+pub struct Request<'a> {
+    /// A `rbac_policy` object.
+    ///
+    #[builder(setter(into))]
+    pub(crate) rbac_policy: RbacPolicy<'a>,
+
+    #[builder(setter(name = "_headers"), default, private)]
+    _headers: Option<HeaderMap>,
+}
+impl<'a> Request<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> RequestBuilder<'a> {
+        RequestBuilder::default()
+    }
+}
+
+impl<'a> RequestBuilder<'a> {
+    /// Add a single header to the Rbac_Policy.
+    pub fn header(&mut self, header_name: &'static str, header_value: &'static str) -> &mut Self
+where {
+        self._headers
+            .get_or_insert(None)
+            .get_or_insert_with(HeaderMap::new)
+            .insert(header_name, HeaderValue::from_static(header_value));
+        self
+    }
+
+    /// Add multiple headers.
+    pub fn headers<I, T>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = T>,
+        T: Into<(Option<HeaderName>, HeaderValue)>,
+    {
+        self._headers
+            .get_or_insert(None)
+            .get_or_insert_with(HeaderMap::new)
+            .extend(iter.map(Into::into));
+        self
+    }
+}
+
+impl<'a> RestEndpoint for Request<'a> {
+    fn method(&self) -> http::Method {
+        http::Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "v2.0/rbac-policies".to_string().into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        QueryParams::default()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = JsonBodyParams::default();
+
+        params.push("rbac_policy", serde_json::to_value(&self.rbac_policy)?);
+
+        params.into_body()
+    }
+
+    fn service_type(&self) -> ServiceType {
+        ServiceType::Network
+    }
+
+    fn response_key(&self) -> Option<Cow<'static, str>> {
+        Some("rbac_policy".into())
+    }
+
+    /// Returns headers to be set into the request
+    fn request_headers(&self) -> Option<&HeaderMap> {
+        self._headers.as_ref()
+    }
+
+    fn validate(&self) -> Result<(), BodyError> {
+        let rbac_policy = &self.rbac_policy;
+        Validator::new()
+            .format(
+                "rbac_policy.object_id",
+                Some(&rbac_policy.object_id),
+                Format::Uuid,
+            )
+            .length(
+                "rbac_policy.target_tenant",
+                Some(&rbac_policy.target_tenant),
+                Some(1),
+                Some(255),
+            )
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(unused_imports)]
+    use super::*;
+    use crate::api::Query;
+    use crate::test::client::MockServerClient;
+    use crate::types::ServiceType;
+    use http::{HeaderName, HeaderValue};
+    use serde_json::json;
+
+    #[test]
+    fn test_service_type() {
+        assert_eq!(
+            Request::builder()
+                .rbac_policy(
+                    RbacPolicyBuilder::default()
+                        .action(Action::AccessAsExternal)
+                        .object_id("9b1c2d3e-4f5a-4b6c-8d7e-0f1a2b3c4d5e")
+                        .object_type(ObjectType::AddressGroup)
+                        .target_tenant("*")
+                        .build()
+                        .unwrap()
+                )
+                .build()
+                .unwrap()
+                .service_type(),
+            ServiceType::Network
+        );
+    }
+
+    #[test]
+    fn test_response_key() {
+        assert_eq!(
+            Request::builder()
+                .rbac_policy(
+                    RbacPolicyBuilder::default()
+                        .action(Action::AccessAsExternal)
+                        .object_id("9b1c2d3e-4f5a-4b6c-8d7e-0f1a2b3c4d5e")
+                        .object_type(ObjectType::AddressGroup)
+                        .target_tenant("*")
+                        .build()
+                        .unwrap()
+                )
+                .build()
+                .unwrap()
+                .response_key()
+                .unwrap(),
+            "rbac_policy"
+        );
+    }
+
+    #[test]
+    fn endpoint() {
+        let client = MockServerClient::new();
+        let mock = client.server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/v2.0/rbac-policies".to_string());
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(json!({ "rbac_policy": {} }));
+        });
+
+        let endpoint = Request::builder()
+            .rbac_policy(
+                RbacPolicyBuilder::default()
+                    .action(Action::AccessAsExternal)
+                    .object_id("9b1c2d3e-4f5a-4b6c-8d7e-0f1a2b3c4d5e")
+                    .object_type(ObjectType::AddressGroup)
+                    .target_tenant("*")
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        let _: serde_json::Value = endpoint.query(&client).unwrap();
+        mock.assert();
+    }
+
+    #[test]
+    fn endpoint_headers() {
+        let client = MockServerClient::new();
+        let mock = client.server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/v2.0/rbac-policies".to_string())
+                .header("foo", "bar")
+                .header("not_foo", "not_bar");
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(json!({ "rbac_policy": {} }));
+        });
+
+        let endpoint = Request::builder()
+            .rbac_policy(
+                RbacPolicyBuilder::default()
+                    .action(Action::AccessAsExternal)
+                    .object_id("9b1c2d3e-4f5a-4b6c-8d7e-0f1a2b3c4d5e")
+                    .object_type(ObjectType::AddressGroup)
+                    .target_tenant("*")
+                    .build()
+                    .unwrap(),
+            )
+            .headers(
+                [(
+                    Some(HeaderName::from_static("foo")),
+                    HeaderValue::from_static("bar"),
+                )]
+                .into_iter(),
+            )
+            .header("not_foo", "not_bar")
+            .build()
+            .unwrap();
+        let _: serde_json::Value = endpoint.query(&client).unwrap();
+        mock.assert();
+    }
+
+    #[test]
+    fn test_validate() {
+        let endpoint = Request::builder()
+            .rbac_policy(
+                RbacPolicyBuilder::default()
+                    .action(Action::AccessAsShared)
+                    .object_id("foo")
+                    .object_type(ObjectType::Network)
+                    .target_tenant("")
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        let Err(BodyError::Validation { errors }) = endpoint.validate() else {
+            panic!("request is expected to be invalid");
+        };
+        let fields: Vec<&str> = errors.iter().map(|x| x.field.as_str()).collect();
+        assert_eq!(
+            fields,
+            ["rbac_policy.object_id", "rbac_policy.target_tenant"]
+        );
+    }
+}
@@ -0,0 +1,460 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// WARNING: This file is automatically generated from OpenAPI schema using
+// `openstack-codegenerator`.
+
+//! Creates an OpenStack Networking security group rule.
+//!
+//! Normal response codes: 201
+//!
+//! Error response codes: 400, 401, 404, 409
+//!
+use derive_builder::Builder;
+use http::{HeaderMap, HeaderName, HeaderValue};
+
+use crate::api::rest_endpoint_prelude::*;
+
+use serde::Deserialize;
+use serde::Serialize;
+use std::borrow::Cow;
+
+#[derive(Debug, Deserialize, Clone, Serialize)]
+pub enum Direction {
+    #[serde(rename = "egress")]
+    Egress,
+    #[serde(rename = "ingress")]
+    Ingress,
+}
+
+#[derive(Debug, Deserialize, Clone, Serialize)]
+pub enum Ethertype {
+    #[serde(rename = "IPv4")]
+    Ipv4,
+    #[serde(rename = "IPv6")]
+    Ipv6,
+}
+
+/// A `security_group_rule` object.
+///
+#[derive(Builder, Debug, Deserialize, Clone, Serialize)]
+#[builder(setter(strip_option))]
+pub struct SecurityGroupRule<'a> {
+    /// A human-readable description for the resource. Default is an empty
+    /// string.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into))]
+    pub(crate) description: Option<Cow<'a, str>>,
+
+    /// Ingress or egress, which is the direction in which the security group
+    /// rule is applied.
+    ///
+    #[serde()]
+    #[builder()]
+    pub(crate) direction: Direction,
+
+    /// Must be IPv4 or IPv6, and addresses represented in CIDR must match the
+    /// ingress or egress rules.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub(crate) ethertype: Option<Ethertype>,
+
+    /// The maximum port number in the range that is matched by the security
+    /// group rule. If the protocol is TCP, UDP, DCCP, SCTP or UDP-Lite this
+    /// value must be greater than or equal to the `port_range_min` attribute
+    /// value. If the protocol is ICMP, this value must be an ICMP code.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub(crate) port_range_max: Option<i32>,
+
+    /// The minimum port number in the range that is matched by the security
+    /// group rule. If the protocol is TCP, UDP, DCCP, SCTP or UDP-Lite this
+    /// value must be less than or equal to the `port_range_max` attribute
+    /// value. If the protocol is ICMP, this value must be an ICMP type.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub(crate) port_range_min: Option<i32>,
+
+    /// The IP protocol can be represented by a string, an integer, or `null`.
+    /// Valid string or integer values are `any` or `0`, `ah` or `51`, `dccp`
+    /// or `33`, `egp` or `8`, `esp` or `50`, `gre` or `47`, `icmp` or `1`,
+    /// `icmpv6` or `58`, `igmp` or `2`, `ipip` or `4`, `ipv6-encap` or `41`,
+    /// `ipv6-frag` or `44`, `ipv6-icmp` or `58`, `ipv6-nonxt` or `59`,
+    /// `ipv6-opts` or `60`, `ipv6-route` or `43`, `ospf` or `89`, `pgm` or
+    /// `113`, `rsvp` or `46`, `sctp` or `132`, `tcp` or `6`, `udp` or `17`,
+    /// `udplite` or `136`, `vrrp` or `112`. Additionally, any integer value
+    /// between [0-255] is also valid. The string `any` (or integer `0`) means
+    /// `all` IP protocols. See the constants in `neutron_lib.constants` for
+    /// the most up-to-date list of supported strings.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into))]
+    pub(crate) protocol: Option<Cow<'a, str>>,
+
+    /// The remote address group UUID that is associated with this security
+    /// group rule.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into))]
+    pub(crate) remote_address_group_id: Option<Cow<'a, str>>,
+
+    /// The remote group UUID to associate with this security group rule. You
+    /// can specify either the `remote_group_id` or `remote_ip_prefix`
+    /// attribute in the request body.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into))]
+    pub(crate) remote_group_id: Option<Cow<'a, str>>,
+
+    /// The remote IP prefix that is matched by this security group rule.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into))]
+    pub(crate) remote_ip_prefix: Option<Cow<'a, str>>,
+
+    /// The security group ID to associate with this security group rule.
+    ///
+    #[serde()]
+    #[builder(setter(into))]
+    pub(crate) security_group_id: Cow<'a, str>,
+
+    /// The ID of the project that owns the resource. Only administrative and
+    /// users with advsvc role can specify a project ID other than their own.
+    /// You cannot change this value through authorization policies.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into))]
+    pub(crate) tenant_id: Option<Cow<'a, str>>,
+}
+
+#[derive(Builder, Debug, Clone)]
+#[builder(setter(strip_option))]
+pub struct Request<'a> {
+    /// A `security_group_rule` object.
+    ///
+    #[builder(setter(into))]
+    pub(crate) security_group_rule: SecurityGroupRule<'a>,
+
+    #[builder(setter(name = "_headers"), default, private)]
+    _headers: Option<HeaderMap>,
+}
+impl<'a> Request<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> RequestBuilder<'a> {
+        RequestBuilder::default()
+    }
+}
+
+impl<'a> RequestBuilder<'a> {
+    /// Add a single header to the Security_Group_Rule.
+    pub fn header(&mut self, header_name: &'static str, header_value: &'static str) -> &mut Self
+where {
+        self._headers
+            .get_or_insert(None)
+            .get_or_insert_with(HeaderMap::new)
+            .insert(header_name, HeaderValue::from_static(header_value));
+        self
+    }
+
+    /// Add multiple headers.
+    pub fn headers<I, T>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = T>,
+        T: Into<(Option<HeaderName>, HeaderValue)>,
+    {
+        self._headers
+            .get_or_insert(None)
+            .get_or_insert_with(HeaderMap::new)
+            .extend(iter.map(Into::into));
+        self
+    }
+}
+
+impl<'a> RestEndpoint for Request<'a> {
+    fn method(&self) -> http::Method {
+        http::Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "v2.0/security-group-rules".to_string().into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        QueryParams::default()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = JsonBodyParams::default();
+
+        params.push(
+            "security_group_rule",
+            serde_json::to_value(&self.security_group_rule)?,
+        );
+
+        params.into_body()
+    }
+
+    fn service_type(&self) -> ServiceType {
+        ServiceType::Network
+    }
+
+    fn response_key(&self) -> Option<Cow<'static, str>> {
+        Some("security_group_rule".into())
+    }
+
+    /// Returns headers to be set into the request
+    fn request_headers(&self) -> Option<&HeaderMap> {
+        self._headers.as_ref()
+    }
+
+    fn validate(&self) -> Result<(), BodyError> {
+        let rule = &self.security_group_rule;
+        // For ICMP the port range holds the ICMP type and code instead
+        let icmp = matches!(
+            rule.protocol.as_deref(),
+            Some("icmp" | "ipv6-icmp" | "icmpv6" | "1" | "58")
+        );
+        let mut validator = Validator::new();
+        validator
+            .length(
+                "security_group_rule.description",
+                rule.description.as_deref(),
+                None,
+                Some(255),
+            )
+            .format(
+                "security_group_rule.security_group_id",
+                Some(&rule.security_group_id),
+                Format::Uuid,
+            )
+            .format(
+                "security_group_rule.remote_group_id",
+                rule.remote_group_id.as_deref(),
+                Format::Uuid,
+            )
+            .format(
+                "security_group_rule.remote_address_group_id",
+                rule.remote_address_group_id.as_deref(),
+                Format::Uuid,
+            )
+            .format(
+                "security_group_rule.remote_ip_prefix",
+                rule.remote_ip_prefix.as_deref(),
+                Format::Cidr,
+            )
+            .range(
+                "security_group_rule.port_range_min",
+                rule.port_range_min,
+                Some(0),
+                Some(if icmp { 255 } else { 65535 }),
+            )
+            .range(
+                "security_group_rule.port_range_max",
+                rule.port_range_max,
+                Some(0),
+                Some(if icmp { 255 } else { 65535 }),
+            )
+            .requires(
+                "security_group_rule.port_range_min",
+                rule.port_range_min.is_some(),
+                &[("security_group_rule.protocol", rule.protocol.is_some())],
+            )
+            .requires(
+                "security_group_rule.port_range_max",
+                rule.port_range_max.is_some(),
+                &[("security_group_rule.protocol", rule.protocol.is_some())],
+            )
+            .exclusive(&[
+                (
+                    "security_group_rule.remote_ip_prefix",
+                    rule.remote_ip_prefix.is_some(),
+                ),
+                (
+                    "security_group_rule.remote_group_id",
+                    rule.remote_group_id.is_some(),
+                ),
+                (
+                    "security_group_rule.remote_address_group_id",
+                    rule.remote_address_group_id.is_some(),
+                ),
+            ]);
+        if let (Some(min), Some(max), false) = (rule.port_range_min, rule.port_range_max, icmp) {
+            validator.check(
+                "security_group_rule.port_range_min",
+                min <= max,
+                "must be less than or equal to `security_group_rule.port_range_max`",
+            );
+        }
+        validator.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(unused_imports)]
+    use super::*;
+    use crate::api::Query;
+    use crate::test::client::MockServerClient;
+    use crate::types::ServiceType;
+    use http::{HeaderName, HeaderValue};
+    use serde_json::json;
+
+    #[test]
+    fn test_service_type() {
+        assert_eq!(
+            Request::builder()
+                .security_group_rule(
+                    SecurityGroupRuleBuilder::default()
+                        .direction(Direction::Egress)
+                        .security_group_id("5c8e0b2a-9d6b-4b52-8a4f-0d1e2f3a4b5c")
+                        .build()
+                        .unwrap()
+                )
+                .build()
+                .unwrap()
+                .service_type(),
+            ServiceType::Network
+        );
+    }
+
+    #[test]
+    fn test_response_key() {
+        assert_eq!(
+            Request::builder()
+                .security_group_rule(
+                    SecurityGroupRuleBuilder::default()
+                        .direction(Direction::Egress)
+                        .security_group_id("5c8e0b2a-9d6b-4b52-8a4f-0d1e2f3a4b5c")
+                        .build()
+                        .unwrap()
+                )
+                .build()
+                .unwrap()
+                .response_key()
+                .unwrap(),
+            "security_group_rule"
+        );
+    }
+
+    #[test]
+    fn endpoint() {
+        let client = MockServerClient::new();
+        let mock = client.server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/v2.0/security-group-rules".to_string());
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(json!({ "security_group_rule": {} }));
+        });
+
+        let endpoint = Request::builder()
+            .security_group_rule(
+                SecurityGroupRuleBuilder::default()
+                    .direction(Direction::Egress)
+                    .security_group_id("5c8e0b2a-9d6b-4b52-8a4f-0d1e2f3a4b5c")
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        let _: serde_json::Value = endpoint.query(&client).unwrap();
+        mock.assert();
+    }
+
+    #[test]
+    fn endpoint_headers() {
+        let client = MockServerClient::new();
+        let mock = client.server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/v2.0/security-group-rules".to_string())
+                .header("foo", "bar")
+                .header("not_foo", "not_bar");
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(json!({ "security_group_rule": {} }));
+        });
+
+        let endpoint = Request::builder()
+            .security_group_rule(
+                SecurityGroupRuleBuilder::default()
+                    .direction(Direction::Egress)
+                    .security_group_id("5c8e0b2a-9d6b-4b52-8a4f-0d1e2f3a4b5c")
+                    .build()
+                    .unwrap(),
+            )
+            .headers(
+                [(
+                    Some(HeaderName::from_static("foo")),
+                    HeaderValue::from_static("bar"),
+                )]
+                .into_iter(),
+            )
+            .header("not_foo", "not_bar")
+            .build()
+            .unwrap();
+        let _: serde_json::Value = endpoint.query(&client).unwrap();
+        mock.assert();
+    }
+
+    #[test]
+    fn test_validate() {
+        let endpoint = Request::builder()
+            .security_group_rule(
+                SecurityGroupRuleBuilder::default()
+                    .direction(Direction::Ingress)
+                    .security_group_id("5c8e0b2a-9d6b-4b52-8a4f-0d1e2f3a4b5c")
+                    .protocol("tcp")
+                    .port_range_min(443)
+                    .port_range_max(80)
+                    .remote_ip_prefix("10.0.0.0/33")
+                    .remote_group_id("9d6b3c2a-5c8e-4b52-8a4f-0d1e2f3a4b5c")
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        let Err(BodyError::Validation { errors }) = endpoint.validate() else {
+            panic!("request is expected to be invalid");
+        };
+        let fields: Vec<&str> = errors.iter().map(|x| x.field.as_str()).collect();
+        assert_eq!(
+            fields,
+            [
+                "security_group_rule.remote_ip_prefix",
+                "security_group_rule.remote_ip_prefix",
+                "security_group_rule.port_range_min"
+            ]
+        );
+
+        let endpoint = Request::builder()
+            .security_group_rule(
+                SecurityGroupRuleBuilder::default()
+                    .direction(Direction::Egress)
+                    .security_group_id("5c8e0b2a-9d6b-4b52-8a4f-0d1e2f3a4b5c")
+                    .protocol("icmp")
+                    .port_range_min(8)
+                    .port_range_max(0)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        assert!(endpoint.validate().is_ok());
+    }
+}
@@ -230,6 +230,12 @@ pub struct Subnet<'a> {
     #[builder(default, setter(into))]
     pub(crate) subnetpool_id: Option<Option<Cow<'a, str>>>,
 
+    /// The list of tags on the resource.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into))]
+    pub(crate) tags: Option<Vec<Cow<'a, str>>>,
+
     /// The ID of the project that owns the resource. Only administrative and
     /// users with advsvc role can specify a project ID other than their own.
     /// You cannot change this value through authorization policies.
@@ -339,7 +345,7 @@ mod tests {
             Request::builder()
                 .subnet(
                     SubnetBuilder::default()
-                        .ip_version(123)
+                        .ip_version(4)
                         .network_id("foo")
                         .build()
                         .unwrap()
@@ -357,7 +363,7 @@ mod tests {
             Request::builder()
                 .subnet(
                     SubnetBuilder::default()
-                        .ip_version(123)
+                        .ip_version(4)
                         .network_id("foo")
                         .build()
                         .unwrap()
@@ -385,7 +391,7 @@ mod tests {
         let endpoint = Request::builder()
             .subnet(
                 SubnetBuilder::default()
-                    .ip_version(123)
+                    .ip_version(4)
                     .network_id("foo")
                     .build()
                     .unwrap(),
@@ -412,7 +418,7 @@ mod tests {
         let endpoint = Request::builder()
             .subnet(
                 SubnetBuilder::default()
-                    .ip_version(123)
+                    .ip_version(4)
                     .network_id("foo")
                     .build()
                     .unwrap(),
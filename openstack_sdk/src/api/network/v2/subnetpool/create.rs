@@ -0,0 +1,392 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// WARNING: This file is automatically generated from OpenAPI schema using
+// `openstack-codegenerator`.
+
+//! Creates a subnet pool.
+//!
+//! Normal response codes: 201
+//!
+//! Error response codes: 400, 401, 403, 404
+//!
+use derive_builder::Builder;
+use http::{HeaderMap, HeaderName, HeaderValue};
+
+use crate::api::rest_endpoint_prelude::*;
+
+use serde::Deserialize;
+use serde::Serialize;
+use std::borrow::Cow;
+
+/// A `subnetpool` object.
+///
+#[derive(Builder, Debug, Deserialize, Clone, Serialize)]
+#[builder(setter(strip_option))]
+pub struct Subnetpool<'a> {
+    /// An address scope to assign to the subnet pool.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into))]
+    pub(crate) address_scope_id: Option<Cow<'a, str>>,
+
+    /// The size of the prefix to allocate when the `cidr` or `prefixlen`
+    /// attributes are omitted when you create the subnet. Default is
+    /// `min_prefixlen`.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub(crate) default_prefixlen: Option<i32>,
+
+    /// A per-project quota on the prefix space that can be allocated from the
+    /// subnet pool for project subnets. Default is no quota is enforced on
+    /// allocations from the subnet pool. For IPv4 subnet pools,
+    /// `default_quota` is measured in units of /32. For IPv6 subnet pools,
+    /// `default_quota` is measured units of /64. All projects that use the
+    /// subnet pool have the same prefix quota applied.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub(crate) default_quota: Option<i32>,
+
+    /// A human-readable description for the resource. Default is an empty
+    /// string.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into))]
+    pub(crate) description: Option<Cow<'a, str>>,
+
+    /// The subnetpool is default pool or not.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub(crate) is_default: Option<bool>,
+
+    /// The maximum prefix size that can be allocated from the subnet pool. For
+    /// IPv4 subnet pools, default is `32`. For IPv6 subnet pools, default is
+    /// `128`.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub(crate) max_prefixlen: Option<i32>,
+
+    /// The smallest prefix that can be allocated from a subnet pool. For IPv4
+    /// subnet pools, default is `8`. For IPv6 subnet pools, default is `64`.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub(crate) min_prefixlen: Option<i32>,
+
+    /// Human-readable name of the resource.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into))]
+    pub(crate) name: Option<Cow<'a, str>>,
+
+    /// A list of subnet prefixes to assign to the subnet pool. The API merges
+    /// adjacent prefixes and treats them as a single prefix. Each subnet
+    /// prefix must be unique among all subnet prefixes in all subnet pools
+    /// that are associated with the address scope.
+    ///
+    #[serde()]
+    #[builder(setter(into))]
+    pub(crate) prefixes: Vec<Cow<'a, str>>,
+
+    /// Indicates whether this resource is shared across all projects. By
+    /// default, only administrative users can change this value.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub(crate) shared: Option<bool>,
+
+    /// The list of tags on the resource.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into))]
+    pub(crate) tags: Option<Vec<Cow<'a, str>>>,
+
+    /// The ID of the project that owns the resource. Only administrative and
+    /// users with advsvc role can specify a project ID other than their own.
+    /// You cannot change this value through authorization policies.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into))]
+    pub(crate) tenant_id: Option<Cow<'a, str>>,
+}
+
+#[derive(Builder, Debug, Clone)]
+#[builder(setter(strip_option))]
+pub struct Request<'a> {
+    /// A `subnetpool` object.
+    ///
+    #[builder(setter(into))]
+    pub(crate) subnetpool: Subnetpool<'a>,
+
+    #[builder(setter(name = "_headers"), default, private)]
+    _headers: Option<HeaderMap>,
+}
+impl<'a> Request<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> RequestBuilder<'a> {
+        RequestBuilder::default()
+    }
+}
+
+impl<'a> RequestBuilder<'a> {
+    /// Add a single header to the Subnetpool.
+    pub fn header(&mut self, header_name: &'static str, header_value: &'static str) -> &mut Self
+where {
+        self._headers
+            .get_or_insert(None)
+            .get_or_insert_with(HeaderMap::new)
+            .insert(header_name, HeaderValue::from_static(header_value));
+        self
+    }
+
+    /// Add multiple headers.
+    pub fn headers<I, T>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = T>,
+        T: Into<(Option<HeaderName>, HeaderValue)>,
+    {
+        self._headers
+            .get_or_insert(None)
+            .get_or_insert_with(HeaderMap::new)
+            .extend(iter.map(Into::into));
+        self
+    }
+}
+
+impl<'a> RestEndpoint for Request<'a> {
+    fn method(&self) -> http::Method {
+        http::Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "v2.0/subnetpools".to_string().into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        QueryParams::default()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = JsonBodyParams::default();
+
+        params.push("subnetpool", serde_json::to_value(&self.subnetpool)?);
+
+        params.into_body()
+    }
+
+    fn service_type(&self) -> ServiceType {
+        ServiceType::Network
+    }
+
+    fn response_key(&self) -> Option<Cow<'static, str>> {
+        Some("subnetpool".into())
+    }
+
+    /// Returns headers to be set into the request
+    fn request_headers(&self) -> Option<&HeaderMap> {
+        self._headers.as_ref()
+    }
+
+    fn validate(&self) -> Result<(), BodyError> {
+        let subnetpool = &self.subnetpool;
+        let mut validator = Validator::new();
+        validator
+            .length(
+                "subnetpool.name",
+                subnetpool.name.as_deref(),
+                None,
+                Some(255),
+            )
+            .length(
+                "subnetpool.description",
+                subnetpool.description.as_deref(),
+                None,
+                Some(255),
+            )
+            .format(
+                "subnetpool.address_scope_id",
+                subnetpool.address_scope_id.as_deref(),
+                Format::Uuid,
+            )
+            .range(
+                "subnetpool.default_prefixlen",
+                subnetpool.default_prefixlen,
+                Some(0),
+                Some(128),
+            )
+            .range(
+                "subnetpool.min_prefixlen",
+                subnetpool.min_prefixlen,
+                Some(0),
+                Some(128),
+            )
+            .range(
+                "subnetpool.max_prefixlen",
+                subnetpool.max_prefixlen,
+                Some(0),
+                Some(128),
+            )
+            .range(
+                "subnetpool.default_quota",
+                subnetpool.default_quota,
+                Some(0),
+                None,
+            );
+        for (idx, prefix) in subnetpool.prefixes.iter().enumerate() {
+            validator.format(
+                &format!("subnetpool.prefixes[{}]", idx),
+                Some(prefix),
+                Format::Cidr,
+            );
+        }
+        validator.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(unused_imports)]
+    use super::*;
+    use crate::api::Query;
+    use crate::test::client::MockServerClient;
+    use crate::types::ServiceType;
+    use http::{HeaderName, HeaderValue};
+    use serde_json::json;
+
+    #[test]
+    fn test_service_type() {
+        assert_eq!(
+            Request::builder()
+                .subnetpool(
+                    SubnetpoolBuilder::default()
+                        .prefixes(Vec::from(["10.0.0.0/8".into()]))
+                        .build()
+                        .unwrap()
+                )
+                .build()
+                .unwrap()
+                .service_type(),
+            ServiceType::Network
+        );
+    }
+
+    #[test]
+    fn test_response_key() {
+        assert_eq!(
+            Request::builder()
+                .subnetpool(
+                    SubnetpoolBuilder::default()
+                        .prefixes(Vec::from(["10.0.0.0/8".into()]))
+                        .build()
+                        .unwrap()
+                )
+                .build()
+                .unwrap()
+                .response_key()
+                .unwrap(),
+            "subnetpool"
+        );
+    }
+
+    #[test]
+    fn endpoint() {
+        let client = MockServerClient::new();
+        let mock = client.server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/v2.0/subnetpools".to_string());
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(json!({ "subnetpool": {} }));
+        });
+
+        let endpoint = Request::builder()
+            .subnetpool(
+                SubnetpoolBuilder::default()
+                    .prefixes(Vec::from(["10.0.0.0/8".into()]))
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        let _: serde_json::Value = endpoint.query(&client).unwrap();
+        mock.assert();
+    }
+
+    #[test]
+    fn endpoint_headers() {
+        let client = MockServerClient::new();
+        let mock = client.server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/v2.0/subnetpools".to_string())
+                .header("foo", "bar")
+                .header("not_foo", "not_bar");
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(json!({ "subnetpool": {} }));
+        });
+
+        let endpoint = Request::builder()
+            .subnetpool(
+                SubnetpoolBuilder::default()
+                    .prefixes(Vec::from(["10.0.0.0/8".into()]))
+                    .build()
+                    .unwrap(),
+            )
+            .headers(
+                [(
+                    Some(HeaderName::from_static("foo")),
+                    HeaderValue::from_static("bar"),
+                )]
+                .into_iter(),
+            )
+            .header("not_foo", "not_bar")
+            .build()
+            .unwrap();
+        let _: serde_json::Value = endpoint.query(&client).unwrap();
+        mock.assert();
+    }
+
+    #[test]
+    fn test_validate() {
+        let endpoint = Request::builder()
+            .subnetpool(
+                SubnetpoolBuilder::default()
+                    .prefixes(Vec::from(["10.0.0.0/8".into(), "10.0.0.0/33".into()]))
+                    .address_scope_id("foo")
+                    .min_prefixlen(8)
+                    .max_prefixlen(129)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        let Err(BodyError::Validation { errors }) = endpoint.validate() else {
+            panic!("request is expected to be invalid");
+        };
+        let fields: Vec<&str> = errors.iter().map(|x| x.field.as_str()).collect();
+        assert_eq!(
+            fields,
+            [
+                "subnetpool.address_scope_id",
+                "subnetpool.max_prefixlen",
+                "subnetpool.prefixes[1]"
+            ]
+        );
+    }
+}
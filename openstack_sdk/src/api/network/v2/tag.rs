@@ -0,0 +1,24 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! `/v2.0/{collection}/{resource_id}/tags` REST operations of network
+//!
+//! Tags of every taggable resource are managed through the same API, so
+//! the endpoints are parameterised by the collection path of the resource.
+pub mod delete;
+pub mod delete_all;
+pub mod get;
+pub mod list;
+pub mod replace;
+pub mod set;
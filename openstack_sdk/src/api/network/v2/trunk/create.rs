@@ -0,0 +1,364 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// WARNING: This file is automatically generated from OpenAPI schema using
+// `openstack-codegenerator`.
+
+//! Create a trunk.
+//!
+//! Normal response codes: 201
+//!
+//! Error response codes: 400, 401, 403, 404, 409, 503
+//!
+use derive_builder::Builder;
+use http::{HeaderMap, HeaderName, HeaderValue};
+
+use crate::api::rest_endpoint_prelude::*;
+
+use serde::Deserialize;
+use serde::Serialize;
+use std::borrow::Cow;
+
+#[derive(Debug, Deserialize, Clone, Serialize)]
+pub enum SegmentationType {
+    #[serde(rename = "inherit")]
+    Inherit,
+    #[serde(rename = "vlan")]
+    Vlan,
+}
+
+#[derive(Builder, Debug, Deserialize, Clone, Serialize)]
+#[builder(setter(strip_option))]
+pub struct SubPorts<'a> {
+    /// The ID of the port to be added as a subport of the trunk.
+    ///
+    #[serde()]
+    #[builder(setter(into))]
+    pub(crate) port_id: Cow<'a, str>,
+
+    /// The segmentation ID on which the subport traffic is carried. Not used
+    /// with the `inherit` segmentation type.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub(crate) segmentation_id: Option<i32>,
+
+    /// The segmentation type of the subport.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub(crate) segmentation_type: Option<SegmentationType>,
+}
+
+/// A `trunk` object.
+///
+#[derive(Builder, Debug, Deserialize, Clone, Serialize)]
+#[builder(setter(strip_option))]
+pub struct Trunk<'a> {
+    /// The administrative state of the trunk, which is up (`true`) or down
+    /// (`false`). Default is `true`.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default)]
+    pub(crate) admin_state_up: Option<bool>,
+
+    /// A human-readable description for the resource. Default is an empty
+    /// string.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into))]
+    pub(crate) description: Option<Cow<'a, str>>,
+
+    /// Human-readable name of the resource. Default is an empty string.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into))]
+    pub(crate) name: Option<Cow<'a, str>>,
+
+    /// The ID of the parent port.
+    ///
+    #[serde()]
+    #[builder(setter(into))]
+    pub(crate) port_id: Cow<'a, str>,
+
+    /// A list of ports associated with the trunk.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into))]
+    pub(crate) sub_ports: Option<Vec<SubPorts<'a>>>,
+
+    /// The list of tags on the resource.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into))]
+    pub(crate) tags: Option<Vec<Cow<'a, str>>>,
+
+    /// The ID of the project that owns the resource. Only administrative and
+    /// users with advsvc role can specify a project ID other than their own.
+    /// You cannot change this value through authorization policies.
+    ///
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default, setter(into))]
+    pub(crate) tenant_id: Option<Cow<'a, str>>,
+}
+
+#[derive(Builder, Debug, Clone)]
+#[builder(setter(strip_option))]
+pub struct Request<'a> {
+    /// A `trunk` object.
+    ///
+    #[builder(setter(into))]
+    pub(crate) trunk: Trunk<'a>,
+
+    #[builder(setter(name = "_headers"), default, private)]
+    _headers: Option<HeaderMap>,
+}
+impl<'a> Request<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> RequestBuilder<'a> {
+        RequestBuilder::default()
+    }
+}
+
+impl<'a> RequestBuilder<'a> {
+    /// Add a single header to the Trunk.
+    pub fn header(&mut self, header_name: &'static str, header_value: &'static str) -> &mut Self
+where {
+        self._headers
+            .get_or_insert(None)
+            .get_or_insert_with(HeaderMap::new)
+            .insert(header_name, HeaderValue::from_static(header_value));
+        self
+    }
+
+    /// Add multiple headers.
+    pub fn headers<I, T>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = T>,
+        T: Into<(Option<HeaderName>, HeaderValue)>,
+    {
+        self._headers
+            .get_or_insert(None)
+            .get_or_insert_with(HeaderMap::new)
+            .extend(iter.map(Into::into));
+        self
+    }
+}
+
+impl<'a> RestEndpoint for Request<'a> {
+    fn method(&self) -> http::Method {
+        http::Method::POST
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "v2.0/trunks".to_string().into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        QueryParams::default()
+    }
+
+    fn body(&self) -> Result<Option<(&'static str, Vec<u8>)>, BodyError> {
+        let mut params = JsonBodyParams::default();
+
+        params.push("trunk", serde_json::to_value(&self.trunk)?);
+
+        params.into_body()
+    }
+
+    fn service_type(&self) -> ServiceType {
+        ServiceType::Network
+    }
+
+    fn response_key(&self) -> Option<Cow<'static, str>> {
+        Some("trunk".into())
+    }
+
+    /// Returns headers to be set into the request
+    fn request_headers(&self) -> Option<&HeaderMap> {
+        self._headers.as_ref()
+    }
+
+    fn validate(&self) -> Result<(), BodyError> {
+        let trunk = &self.trunk;
+        let mut validator = Validator::new();
+        validator
+            .length("trunk.name", trunk.name.as_deref(), None, Some(255))
+            .length(
+                "trunk.description",
+                trunk.description.as_deref(),
+                None,
+                Some(255),
+            )
+            .format("trunk.port_id", Some(&trunk.port_id), Format::Uuid);
+        for (idx, sub_port) in trunk.sub_ports.iter().flatten().enumerate() {
+            validator
+                .format(
+                    &format!("trunk.sub_ports[{}].port_id", idx),
+                    Some(&sub_port.port_id),
+                    Format::Uuid,
+                )
+                .range(
+                    &format!("trunk.sub_ports[{}].segmentation_id", idx),
+                    sub_port.segmentation_id,
+                    Some(1),
+                    Some(4094),
+                );
+        }
+        validator.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(unused_imports)]
+    use super::*;
+    use crate::api::Query;
+    use crate::test::client::MockServerClient;
+    use crate::types::ServiceType;
+    use http::{HeaderName, HeaderValue};
+    use serde_json::json;
+
+    #[test]
+    fn test_service_type() {
+        assert_eq!(
+            Request::builder()
+                .trunk(
+                    TrunkBuilder::default()
+                        .port_id("6a3a4b0e-7c8d-4f7b-9d8e-2a1b3c4d5e6f")
+                        .build()
+                        .unwrap()
+                )
+                .build()
+                .unwrap()
+                .service_type(),
+            ServiceType::Network
+        );
+    }
+
+    #[test]
+    fn test_response_key() {
+        assert_eq!(
+            Request::builder()
+                .trunk(
+                    TrunkBuilder::default()
+                        .port_id("6a3a4b0e-7c8d-4f7b-9d8e-2a1b3c4d5e6f")
+                        .build()
+                        .unwrap()
+                )
+                .build()
+                .unwrap()
+                .response_key()
+                .unwrap(),
+            "trunk"
+        );
+    }
+
+    #[test]
+    fn endpoint() {
+        let client = MockServerClient::new();
+        let mock = client.server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/v2.0/trunks".to_string());
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(json!({ "trunk": {} }));
+        });
+
+        let endpoint = Request::builder()
+            .trunk(
+                TrunkBuilder::default()
+                    .port_id("6a3a4b0e-7c8d-4f7b-9d8e-2a1b3c4d5e6f")
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        let _: serde_json::Value = endpoint.query(&client).unwrap();
+        mock.assert();
+    }
+
+    #[test]
+    fn endpoint_headers() {
+        let client = MockServerClient::new();
+        let mock = client.server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/v2.0/trunks".to_string())
+                .header("foo", "bar")
+                .header("not_foo", "not_bar");
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(json!({ "trunk": {} }));
+        });
+
+        let endpoint = Request::builder()
+            .trunk(
+                TrunkBuilder::default()
+                    .port_id("6a3a4b0e-7c8d-4f7b-9d8e-2a1b3c4d5e6f")
+                    .build()
+                    .unwrap(),
+            )
+            .headers(
+                [(
+                    Some(HeaderName::from_static("foo")),
+                    HeaderValue::from_static("bar"),
+                )]
+                .into_iter(),
+            )
+            .header("not_foo", "not_bar")
+            .build()
+            .unwrap();
+        let _: serde_json::Value = endpoint.query(&client).unwrap();
+        mock.assert();
+    }
+
+    #[test]
+    fn test_validate() {
+        let endpoint = Request::builder()
+            .trunk(
+                TrunkBuilder::default()
+                    .port_id("6a3a4b0e-7c8d-4f7b-9d8e-2a1b3c4d5e6f")
+                    .sub_ports(Vec::from([
+                        SubPortsBuilder::default()
+                            .port_id("9b1c2d3e-4f5a-4b6c-8d7e-0f1a2b3c4d5e")
+                            .segmentation_type(SegmentationType::Vlan)
+                            .segmentation_id(100)
+                            .build()
+                            .unwrap(),
+                        SubPortsBuilder::default()
+                            .port_id("foo")
+                            .segmentation_type(SegmentationType::Vlan)
+                            .segmentation_id(4095)
+                            .build()
+                            .unwrap(),
+                    ]))
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        let Err(BodyError::Validation { errors }) = endpoint.validate() else {
+            panic!("request is expected to be invalid");
+        };
+        let fields: Vec<&str> = errors.iter().map(|x| x.field.as_str()).collect();
+        assert_eq!(
+            fields,
+            [
+                "trunk.sub_ports[1].port_id",
+                "trunk.sub_ports[1].segmentation_id"
+            ]
+        );
+    }
+}
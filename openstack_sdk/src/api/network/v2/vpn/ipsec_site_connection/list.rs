@@ -0,0 +1,311 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+//
+// WARNING: This file is automatically generated from OpenAPI schema using
+// `openstack-codegenerator`.
+
+//! Lists IPsec site-to-site connections that belong to a project.
+//!
+//! Additionally, you can filter results by using query string parameters. For
+//! information, see
+//! [Filtering and Column Selection](https://wiki.openstack.org/wiki/Neutron/APIv2-specification#Filtering_and_Column_Selection).
+//!
+//! Use the `fields` query parameter to control which fields are returned in
+//! the response body. For information, see
+//! [Filtering and Column Selection](https://wiki.openstack.org/wiki/Neutron/APIv2-specification#Filtering_and_Column_Selection).
+//!
+//! Normal response codes: 200
+//!
+//! Error response codes: 401
+//!
+use derive_builder::Builder;
+use http::{HeaderMap, HeaderName, HeaderValue};
+
+use crate::api::rest_endpoint_prelude::*;
+
+use std::borrow::Cow;
+
+use crate::api::Pageable;
+
+#[derive(Builder, Debug, Clone)]
+#[builder(setter(strip_option))]
+pub struct Request<'a> {
+    /// admin_state_up query parameter for /v2.0/vpn/ipsec-site-connections API
+    ///
+    #[builder(default)]
+    admin_state_up: Option<bool>,
+
+    /// auth_mode query parameter for /v2.0/vpn/ipsec-site-connections API
+    ///
+    #[builder(default, setter(into))]
+    auth_mode: Option<Cow<'a, str>>,
+
+    /// description query parameter for /v2.0/vpn/ipsec-site-connections API
+    ///
+    #[builder(default, setter(into))]
+    description: Option<Cow<'a, str>>,
+
+    /// id query parameter for /v2.0/vpn/ipsec-site-connections API
+    ///
+    #[builder(default, setter(into))]
+    id: Option<Cow<'a, str>>,
+
+    /// ikepolicy_id query parameter for /v2.0/vpn/ipsec-site-connections API
+    ///
+    #[builder(default, setter(into))]
+    ikepolicy_id: Option<Cow<'a, str>>,
+
+    /// initiator query parameter for /v2.0/vpn/ipsec-site-connections API
+    ///
+    #[builder(default, setter(into))]
+    initiator: Option<Cow<'a, str>>,
+
+    /// ipsecpolicy_id query parameter for /v2.0/vpn/ipsec-site-connections API
+    ///
+    #[builder(default, setter(into))]
+    ipsecpolicy_id: Option<Cow<'a, str>>,
+
+    /// Requests a page size of items. Returns a number of items up to a limit
+    /// value. Use the limit parameter to make an initial limited request and
+    /// use the ID of the last-seen item from the response as the marker
+    /// parameter value in a subsequent limited request.
+    ///
+    #[builder(default)]
+    limit: Option<i32>,
+
+    /// local_ep_group_id query parameter for /v2.0/vpn/ipsec-site-connections API
+    ///
+    #[builder(default, setter(into))]
+    local_ep_group_id: Option<Cow<'a, str>>,
+
+    /// local_id query parameter for /v2.0/vpn/ipsec-site-connections API
+    ///
+    #[builder(default, setter(into))]
+    local_id: Option<Cow<'a, str>>,
+
+    /// The ID of the last-seen item. Use the limit parameter to make an
+    /// initial limited request and use the ID of the last-seen item from the
+    /// response as the marker parameter value in a subsequent limited
+    /// request.
+    ///
+    #[builder(default, setter(into))]
+    marker: Option<Cow<'a, str>>,
+
+    /// mtu query parameter for /v2.0/vpn/ipsec-site-connections API
+    ///
+    #[builder(default)]
+    mtu: Option<i32>,
+
+    /// name query parameter for /v2.0/vpn/ipsec-site-connections API
+    ///
+    #[builder(default, setter(into))]
+    name: Option<Cow<'a, str>>,
+
+    /// peer_address query parameter for /v2.0/vpn/ipsec-site-connections API
+    ///
+    #[builder(default, setter(into))]
+    peer_address: Option<Cow<'a, str>>,
+
+    /// peer_ep_group_id query parameter for /v2.0/vpn/ipsec-site-connections API
+    ///
+    #[builder(default, setter(into))]
+    peer_ep_group_id: Option<Cow<'a, str>>,
+
+    /// peer_id query parameter for /v2.0/vpn/ipsec-site-connections API
+    ///
+    #[builder(default, setter(into))]
+    peer_id: Option<Cow<'a, str>>,
+
+    /// project_id query parameter for /v2.0/vpn/ipsec-site-connections API
+    ///
+    #[builder(default, setter(into))]
+    project_id: Option<Cow<'a, str>>,
+
+    /// route_mode query parameter for /v2.0/vpn/ipsec-site-connections API
+    ///
+    #[builder(default, setter(into))]
+    route_mode: Option<Cow<'a, str>>,
+
+    /// status query parameter for /v2.0/vpn/ipsec-site-connections API
+    ///
+    #[builder(default, setter(into))]
+    status: Option<Cow<'a, str>>,
+
+    /// tenant_id query parameter for /v2.0/vpn/ipsec-site-connections API
+    ///
+    #[builder(default, setter(into))]
+    tenant_id: Option<Cow<'a, str>>,
+
+    /// vpnservice_id query parameter for /v2.0/vpn/ipsec-site-connections API
+    ///
+    #[builder(default, setter(into))]
+    vpnservice_id: Option<Cow<'a, str>>,
+
+    #[builder(setter(name = "_headers"), default, private)]
+    _headers: Option<HeaderMap>,
+}
+impl<'a> Request<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> RequestBuilder<'a> {
+        RequestBuilder::default()
+    }
+}
+
+impl<'a> RequestBuilder<'a> {
+    /// Add a single header to the Ipsec_Site_Connection.
+    pub fn header(&mut self, header_name: &'static str, header_value: &'static str) -> &mut Self
+where {
+        self._headers
+            .get_or_insert(None)
+            .get_or_insert_with(HeaderMap::new)
+            .insert(header_name, HeaderValue::from_static(header_value));
+        self
+    }
+
+    /// Add multiple headers.
+    pub fn headers<I, T>(&mut self, iter: I) -> &mut Self
+    where
+        I: Iterator<Item = T>,
+        T: Into<(Option<HeaderName>, HeaderValue)>,
+    {
+        self._headers
+            .get_or_insert(None)
+            .get_or_insert_with(HeaderMap::new)
+            .extend(iter.map(Into::into));
+        self
+    }
+}
+
+impl<'a> RestEndpoint for Request<'a> {
+    fn method(&self) -> http::Method {
+        http::Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "v2.0/vpn/ipsec-site-connections".to_string().into()
+    }
+
+    fn parameters(&self) -> QueryParams {
+        let mut params = QueryParams::default();
+        params.push_opt("limit", self.limit);
+        params.push_opt("marker", self.marker.as_ref());
+        params.push_opt("admin_state_up", self.admin_state_up);
+        params.push_opt("auth_mode", self.auth_mode.as_ref());
+        params.push_opt("description", self.description.as_ref());
+        params.push_opt("id", self.id.as_ref());
+        params.push_opt("ikepolicy_id", self.ikepolicy_id.as_ref());
+        params.push_opt("initiator", self.initiator.as_ref());
+        params.push_opt("ipsecpolicy_id", self.ipsecpolicy_id.as_ref());
+        params.push_opt("local_ep_group_id", self.local_ep_group_id.as_ref());
+        params.push_opt("local_id", self.local_id.as_ref());
+        params.push_opt("mtu", self.mtu);
+        params.push_opt("name", self.name.as_ref());
+        params.push_opt("peer_address", self.peer_address.as_ref());
+        params.push_opt("peer_ep_group_id", self.peer_ep_group_id.as_ref());
+        params.push_opt("peer_id", self.peer_id.as_ref());
+        params.push_opt("project_id", self.project_id.as_ref());
+        params.push_opt("route_mode", self.route_mode.as_ref());
+        params.push_opt("status", self.status.as_ref());
+        params.push_opt("tenant_id", self.tenant_id.as_ref());
+        params.push_opt("vpnservice_id", self.vpnservice_id.as_ref());
+
+        params
+    }
+
+    fn service_type(&self) -> ServiceType {
+        ServiceType::Network
+    }
+
+    fn response_key(&self) -> Option<Cow<'static, str>> {
+        Some("ipsec_site_connections".into())
+    }
+
+    /// Returns headers to be set into the request
+    fn request_headers(&self) -> Option<&HeaderMap> {
+        self._headers.as_ref()
+    }
+}
+impl<'a> Pageable for Request<'a> {}
+
+#[cfg(test)]
+mod tests {
+    #![allow(unused_imports)]
+    use super::*;
+    use crate::api::Query;
+    use crate::test::client::MockServerClient;
+    use crate::types::ServiceType;
+    use http::{HeaderName, HeaderValue};
+    use serde_json::json;
+
+    #[test]
+    fn test_service_type() {
+        assert_eq!(
+            Request::builder().build().unwrap().service_type(),
+            ServiceType::Network
+        );
+    }
+
+    #[test]
+    fn test_response_key() {
+        assert_eq!(
+            Request::builder().build().unwrap().response_key().unwrap(),
+            "ipsec_site_connections"
+        );
+    }
+
+    #[test]
+    fn endpoint() {
+        let client = MockServerClient::new();
+        let mock = client.server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/v2.0/vpn/ipsec-site-connections".to_string());
+
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(json!({ "ipsec_site_connections": {} }));
+        });
+
+        let endpoint = Request::builder().build().unwrap();
+        let _: serde_json::Value = endpoint.query(&client).unwrap();
+        mock.assert();
+    }
+
+    #[test]
+    fn endpoint_headers() {
+        let client = MockServerClient::new();
+        let mock = client.server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/v2.0/vpn/ipsec-site-connections".to_string())
+                .header("foo", "bar")
+                .header("not_foo", "not_bar");
+            then.status(200)
+                .header("content-type", "application/json")
+                .json_body(json!({ "ipsec_site_connections": {} }));
+        });
+
+        let endpoint = Request::builder()
+            .headers(
+                [(
+                    Some(HeaderName::from_static("foo")),
+                    HeaderValue::from_static("bar"),
+                )]
+                .into_iter(),
+            )
+            .header("not_foo", "not_bar")
+            .build()
+            .unwrap();
+        let _: serde_json::Value = endpoint.query(&client).unwrap();
+        mock.assert();
+    }
+}
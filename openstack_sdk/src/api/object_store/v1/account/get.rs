@@ -138,7 +138,11 @@ impl<'a> RestEndpoint for Account<'a> {
         self._headers.as_ref()
     }
 }
-impl<'a> Pageable for Account<'a> {}
+impl<'a> Pageable for Account<'a> {
+    fn pagination_marker_key(&self) -> Option<Cow<'static, str>> {
+        Some("name".into())
+    }
+}
 
 #[cfg(test)]
 mod tests {
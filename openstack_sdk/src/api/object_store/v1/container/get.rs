@@ -153,7 +153,11 @@ impl<'a> RestEndpoint for Container<'a> {
         self._headers.as_ref()
     }
 }
-impl<'a> Pageable for Container<'a> {}
+impl<'a> Pageable for Container<'a> {
+    fn pagination_marker_key(&self) -> Option<Cow<'static, str>> {
+        Some("name".into())
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -16,19 +16,23 @@ mod iter;
 mod next_page;
 mod pagination;
 
-use http::{header, HeaderValue, Request};
 use tracing::{debug, trace};
 
 #[cfg(feature = "async")]
 use async_trait::async_trait;
-use std::sync::{Arc, Mutex};
+#[cfg(feature = "async")]
+use futures_util::TryStreamExt;
 
 use serde::de::DeserializeOwned;
+use std::borrow::Cow;
 
+#[cfg(feature = "sync")]
+pub use self::iter::PagedIter;
+#[cfg(feature = "async")]
+pub use self::iter::PagedStream;
 pub use self::pagination::{Pagination, PaginationError};
 
-use crate::api::rest_endpoint::set_latest_microversion;
-use crate::api::{query, ApiError, RestEndpoint};
+use crate::api::{ApiError, RestEndpoint};
 
 #[cfg(feature = "async")]
 use crate::api::{AsyncClient, QueryAsync};
@@ -42,6 +46,13 @@ pub trait Pageable {
     fn use_keyset_pagination(&self) -> bool {
         true
     }
+
+    /// Attribute of the last item on the page used as a `marker` to request
+    /// the next page when the response contains no link to it (i.e. Swift
+    /// uses `name`).
+    fn pagination_marker_key(&self) -> Option<Cow<'static, str>> {
+        None
+    }
 }
 
 /// A query modifier that paginates an endpoint.
@@ -49,6 +60,7 @@ pub trait Pageable {
 pub struct Paged<E> {
     pub(in crate::api::paged) endpoint: E,
     pub(in crate::api::paged) pagination: Pagination,
+    pub(in crate::api::paged) prefetch: bool,
 }
 
 /// Collect data from a paged endpoint.
@@ -57,6 +69,16 @@ pub fn paged<E>(endpoint: E, pagination: Pagination) -> Paged<E> {
     Paged {
         endpoint,
         pagination,
+        prefetch: false,
+    }
+}
+
+impl<E> Paged<E> {
+    /// Request the following page while the current one is consumed from
+    /// the stream returned by [`Paged::iter_async`].
+    pub fn prefetch(mut self, prefetch: bool) -> Self {
+        self.prefetch = prefetch;
+        self
     }
 }
 
@@ -86,160 +108,14 @@ where
 {
     async fn query_async(&self, client: &C) -> Result<Vec<T>, ApiError<C::Error>> {
         debug!("Async Query for paginated resource");
-        // Consume iterator and fetch all requested data.
-
-        let ep = client.get_service_endpoint(&self.endpoint.service_type())?;
-        let url = {
-            let mut url =
-                client.rest_endpoint(&self.endpoint.service_type(), &self.endpoint.endpoint())?;
-            self.endpoint.parameters().add_to_url(&mut url);
-            url
-        };
-
-        let mut page_num = 1;
-        let per_page = self.pagination.page_limit();
-        let per_page_str = per_page.to_string();
-
-        let results = Arc::new(Mutex::new(Vec::new()));
-        let mut next_url = None;
-        let use_keyset_pagination = self.endpoint.use_keyset_pagination();
-        let mut marker: Option<String> = None;
-
-        let body = self.endpoint.body()?;
-
-        loop {
-            let page_url = if let Some(url) = next_url.take() {
-                url
-            } else {
-                let _page_str = page_num.to_string();
-                let mut page_url = url.clone();
-
-                {
-                    let mut pairs = page_url.query_pairs_mut();
-                    if per_page < usize::MAX {
-                        pairs.append_pair("limit", &per_page_str);
-                    }
-                    if let Some(ref m) = marker {
-                        pairs.append_pair("marker", m.as_str());
-                    }
-                }
-
-                page_url
-            };
-
-            let mut req = Request::builder()
-                .method(self.endpoint.method())
-                .uri(query::url_to_http_uri(page_url.clone()))
-                .header(header::ACCEPT, HeaderValue::from_static("application/json"));
-            set_latest_microversion(&mut req, &ep, &self.endpoint);
-            // Set endpoint headers
-            if let Some(request_headers) = self.endpoint.request_headers() {
-                let headers = req.headers_mut().unwrap();
-                for (k, v) in request_headers.iter() {
-                    headers.insert(k, v.clone());
-                }
-            }
-
-            let (req, data) = if let Some((mime, data)) = body.as_ref() {
-                let req = req.header(header::CONTENT_TYPE, *mime);
-                (req, data.clone())
-            } else {
-                (req, Vec::new())
-            };
-            let rsp = client.rest_async(req, data).await?;
-            let status = rsp.status();
-
-            let mut v = if let Ok(v) = serde_json::from_slice(rsp.body()) {
-                v
-            } else {
-                return Err(ApiError::server_error(status, rsp.body()));
-            };
-            if !status.is_success() {
-                return Err(ApiError::from_openstack(status, v));
-            }
-
-            if use_keyset_pagination {
-                next_url = next_page::next_page_from_body(
-                    &v,
-                    &self.endpoint.response_key(),
-                    page_url.clone(),
-                )?;
-                debug!("data = {:?}", v.clone());
-            } else {
-                next_url = next_page::next_page_from_headers(rsp.headers())?;
-            }
-
-            debug!("raw data = {:?}", v.clone());
-            if let Some(root_key) = self.endpoint.response_key() {
-                v = v[root_key.to_string()].take();
-            }
-
-            if next_url.is_none() {
-                // In swift we do not have next_page coming from anywhere.
-                // There is a header with total amount of records, but before
-                // even checking that we should also calculate the marker for
-                // the next page
-                if let Some(data) = v.as_array() {
-                    if let Some(last_page_element) = data.last() {
-                        if let Some(id) = last_page_element.get("id") {
-                            if let Some(val) = id.as_str() {
-                                marker = Some(String::from(val));
-                            }
-                        } else if let Some(id) = last_page_element.get("name") {
-                            if let Some(val) = id.as_str() {
-                                marker = Some(String::from(val));
-                            }
-                        }
-                    }
-                }
-            }
-
-            if let (Some(item_key), Some(array)) =
-                (self.endpoint.response_list_item_key(), v.as_array_mut())
-            {
-                for elem in array {
-                    *elem = elem[item_key.to_string()].take();
-                }
-            }
-            trace!("items data = {:?}", v.clone());
-
-            let mut page =
-                serde_json::from_value::<Vec<T>>(v).map_err(ApiError::data_type::<Vec<T>>)?;
-            let page_len = page.len();
-            let is_last_page = {
-                let mut locked_results = results.lock().expect("poisoned results");
-                if let Pagination::Limit(limit) = self.pagination {
-                    // with total limit need to check whether the page contains more data then necessary
-                    let total_read_till_now = locked_results.len();
-                    if total_read_till_now + page.len() > limit {
-                        // Discard unnecessary data
-                        page.truncate(limit - total_read_till_now);
-                    }
-                }
-
-                locked_results.extend(page);
-                self.pagination.is_last_page(page_len, locked_results.len())
-            };
-            if is_last_page {
-                break;
-            }
-
-            if use_keyset_pagination {
-                if next_url.is_none() && marker.is_none() {
-                    break;
-                }
-            } else {
-                page_num += 1;
-            }
-        }
-
-        let mut locked_results = results.lock().expect("poisoned results");
-        Ok(std::mem::take(&mut locked_results))
+        // Consume stream and fetch all requested data.
+        self.iter_async(client).try_collect().await
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use futures_util::{StreamExt, TryStreamExt};
     use http::StatusCode;
     use http::{HeaderMap, HeaderName, HeaderValue};
     use serde::{Deserialize, Serialize};
@@ -294,6 +170,47 @@ mod tests {
         value: u8,
     }
 
+    struct DummyInvalid;
+
+    impl RestEndpoint for DummyInvalid {
+        fn method(&self) -> http::Method {
+            http::Method::GET
+        }
+
+        fn endpoint(&self) -> Cow<'static, str> {
+            "paged_dummy".into()
+        }
+        fn service_type(&self) -> ServiceType {
+            ServiceType::Compute
+        }
+        fn validate(&self) -> Result<(), BodyError> {
+            Validator::new()
+                .range("limit", Some(0), Some(1), None)
+                .finish()
+        }
+    }
+
+    impl Pageable for DummyInvalid {}
+
+    #[test]
+    fn test_validation() {
+        let client = MockServerClient::new();
+        let mock = client.server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/paged_dummy");
+            then.status(200).json_body(json!([]));
+        });
+
+        let res: Result<Vec<DummyResult>, _> =
+            api::paged(DummyInvalid, Pagination::All).query(&client);
+        assert!(matches!(
+            res,
+            Err(ApiError::Body {
+                source: BodyError::Validation { .. }
+            })
+        ));
+        mock.assert_hits(0);
+    }
+
     #[test]
     fn test_non_json_response() {
         let client = MockServerClient::new();
@@ -344,8 +261,8 @@ mod tests {
 
         let res: Result<Vec<DummyResult>, _> = api::paged(endpoint, Pagination::All).query(&client);
         let err = res.unwrap_err();
-        if let ApiError::OpenStack { status: _, msg } = err {
-            assert_eq!(msg, "dummy error message");
+        if let ApiError::Conflict { error } = err {
+            assert_eq!(error.message, "dummy error message");
         } else {
             panic!("unexpected error: {}", err);
         }
@@ -530,4 +447,242 @@ mod tests {
             .unwrap();
         assert_eq!(res.len(), 256);
     }
+
+    struct DummyMarker;
+
+    impl RestEndpoint for DummyMarker {
+        fn method(&self) -> http::Method {
+            http::Method::GET
+        }
+
+        fn endpoint(&self) -> Cow<'static, str> {
+            "marker_dummy".into()
+        }
+        fn service_type(&self) -> ServiceType {
+            ServiceType::ObjectStore
+        }
+    }
+
+    impl Pageable for DummyMarker {
+        fn pagination_marker_key(&self) -> Option<Cow<'static, str>> {
+            Some("name".into())
+        }
+    }
+
+    struct DummyNextLink;
+
+    impl RestEndpoint for DummyNextLink {
+        fn method(&self) -> http::Method {
+            http::Method::GET
+        }
+
+        fn endpoint(&self) -> Cow<'static, str> {
+            "v2/images".into()
+        }
+        fn service_type(&self) -> ServiceType {
+            ServiceType::Image
+        }
+        fn response_key(&self) -> Option<Cow<'static, str>> {
+            Some("images".into())
+        }
+    }
+
+    impl Pageable for DummyNextLink {}
+
+    #[derive(Debug, Deserialize, Serialize)]
+    struct DummyNamedResult {
+        name: String,
+    }
+
+    fn no_marker(req: &httpmock::prelude::HttpMockRequest) -> bool {
+        !req.query_params
+            .as_ref()
+            .is_some_and(|params| params.iter().any(|(k, _)| k == "marker"))
+    }
+
+    fn mock_marker_pages(
+        server: &httpmock::MockServer,
+        with_count: bool,
+    ) -> Vec<httpmock::Mock<'_>> {
+        let pages = [
+            (None, json!([{"name": "a"}, {"name": "b"}])),
+            (Some("b"), json!([{"name": "c"}, {"name": "d"}])),
+            (Some("d"), json!([])),
+        ];
+        let mut mocks = Vec::new();
+        for (marker, data) in pages {
+            if with_count && marker == Some("d") {
+                // Total count is known, no request for the empty page expected
+                continue;
+            }
+            mocks.push(server.mock(|when, then| {
+                let when = when.method(httpmock::Method::GET).path("/marker_dummy");
+                if let Some(marker) = marker {
+                    when.query_param("marker", marker);
+                } else {
+                    when.matches(no_marker);
+                }
+                let then = then.status(200).json_body(data);
+                if with_count {
+                    then.header("x-container-object-count", "4");
+                }
+            }));
+        }
+        mocks
+    }
+
+    #[test]
+    fn test_keyset_pagination_marker() {
+        let client = MockServerClient::new();
+        let mocks = mock_marker_pages(&client.server, true);
+
+        let res: Vec<DummyNamedResult> = api::paged(DummyMarker, Pagination::All)
+            .query(&client)
+            .unwrap();
+        assert_eq!(
+            res.iter().map(|x| x.name.as_str()).collect::<Vec<_>>(),
+            ["a", "b", "c", "d"]
+        );
+        for mock in mocks {
+            mock.assert();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_keyset_pagination_marker_async() {
+        let client = MockAsyncServerClient::new().await;
+        let mocks = mock_marker_pages(&client.server, true);
+
+        let res: Vec<DummyNamedResult> = api::paged(DummyMarker, Pagination::All)
+            .query_async(&client)
+            .await
+            .unwrap();
+        assert_eq!(
+            res.iter().map(|x| x.name.as_str()).collect::<Vec<_>>(),
+            ["a", "b", "c", "d"]
+        );
+        for mock in mocks {
+            mock.assert();
+        }
+    }
+
+    #[test]
+    fn test_keyset_pagination_marker_no_count() {
+        let client = MockServerClient::new();
+        let mocks = mock_marker_pages(&client.server, false);
+
+        let res: Vec<DummyNamedResult> = api::paged(DummyMarker, Pagination::All)
+            .query(&client)
+            .unwrap();
+        assert_eq!(res.len(), 4);
+        for mock in mocks {
+            mock.assert();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_keyset_pagination_marker_no_count_async() {
+        let client = MockAsyncServerClient::new().await;
+        let mocks = mock_marker_pages(&client.server, false);
+
+        let res: Vec<DummyNamedResult> = api::paged(DummyMarker, Pagination::All)
+            .query_async(&client)
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 4);
+        for mock in mocks {
+            mock.assert();
+        }
+    }
+
+    fn mock_next_link_pages(server: &httpmock::MockServer) -> Vec<httpmock::Mock<'_>> {
+        vec![
+            server.mock(|when, then| {
+                when.method(httpmock::Method::GET)
+                    .path("/v2/images")
+                    .matches(no_marker);
+                then.status(200)
+                    .json_body(json!({"images": [{"name": "a"}], "next": "/v2/images?marker=a"}));
+            }),
+            server.mock(|when, then| {
+                when.method(httpmock::Method::GET)
+                    .path("/v2/images")
+                    .query_param("marker", "a");
+                then.status(200)
+                    .json_body(json!({"images": [{"name": "b"}]}));
+            }),
+        ]
+    }
+
+    #[test]
+    fn test_keyset_pagination_next_link() {
+        let client = MockServerClient::new();
+        let mocks = mock_next_link_pages(&client.server);
+
+        let res: Vec<DummyNamedResult> = api::paged(DummyNextLink, Pagination::All)
+            .query(&client)
+            .unwrap();
+        assert_eq!(
+            res.iter().map(|x| x.name.as_str()).collect::<Vec<_>>(),
+            ["a", "b"]
+        );
+        for mock in mocks {
+            mock.assert();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_keyset_pagination_next_link_async() {
+        let client = MockAsyncServerClient::new().await;
+        let mocks = mock_next_link_pages(&client.server);
+
+        let res: Vec<DummyNamedResult> = api::paged(DummyNextLink, Pagination::All)
+            .query_async(&client)
+            .await
+            .unwrap();
+        assert_eq!(
+            res.iter().map(|x| x.name.as_str()).collect::<Vec<_>>(),
+            ["a", "b"]
+        );
+        for mock in mocks {
+            mock.assert();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_pagination_stream_prefetch_async() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("paged_dummy")
+            .paginated(true)
+            .build()
+            .unwrap();
+        let client =
+            PagedTestClient::new_raw(endpoint, (0..=255).map(|value| DummyResult { value }));
+        let query = api::paged(Dummy::default(), Pagination::All).prefetch(true);
+
+        let res: Vec<DummyResult> = query.iter_async(&client).try_collect().await.unwrap();
+        assert_eq!(res.len(), 256);
+        for (i, value) in res.iter().enumerate() {
+            assert_eq!(value.value, i as u8);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_pagination_stream_lazy_async() {
+        let client = MockAsyncServerClient::new().await;
+        let mocks = mock_marker_pages(&client.server, true);
+        let query = api::paged(DummyMarker, Pagination::All);
+
+        let mut stream = query.iter_async::<_, DummyNamedResult>(&client);
+        assert_eq!(stream.next().await.unwrap().unwrap().name, "a");
+        assert_eq!(stream.next().await.unwrap().unwrap().name, "b");
+        // Next page is only requested when the first one is consumed
+        assert_eq!(mocks[1].hits(), 0);
+        assert_eq!(stream.next().await.unwrap().unwrap().name, "c");
+        assert_eq!(stream.next().await.unwrap().unwrap().name, "d");
+        assert!(stream.next().await.is_none());
+        for mock in mocks {
+            mock.assert();
+        }
+    }
 }
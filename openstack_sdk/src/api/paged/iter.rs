@@ -14,8 +14,14 @@
 
 //! Paged endpoint iterator implements an iterator interface to lazily fetch pages when required.
 //! This is similar to the python generator.
+//!
+//! For the async clients the same is exposed as a [`Stream`] optionally fetching the next page
+//! while the current one is being consumed.
 
-use std::sync::RwLock;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, RwLock};
+use std::task::{Context, Poll};
 
 use async_trait::async_trait;
 use bytes::Bytes;
@@ -26,7 +32,7 @@ use serde::de::DeserializeOwned;
 use url::Url;
 
 use crate::api::paged::{next_page, Pageable, Paged, Pagination};
-use crate::api::rest_endpoint::set_latest_microversion;
+use crate::api::rest_endpoint::{set_latest_microversion, validate_request};
 use crate::api::{query, ApiError, RestClient, RestEndpoint};
 #[cfg(feature = "async")]
 use crate::api::{AsyncClient, QueryAsync};
@@ -51,18 +57,16 @@ where
     E: RestEndpoint + Pageable + Sync,
 {
     /// Create a stream over the results of paginated results for with a client.
-    pub fn iter_async<'a, C, T>(
-        &'a self,
-        client: &'a C,
-    ) -> impl Stream<Item = Result<T, ApiError<C::Error>>> + 'a
+    ///
+    /// Pages are fetched lazily when the items of the previous page are consumed. With
+    /// [`Paged::prefetch`] enabled the following page is requested as soon as the current one
+    /// arrives.
+    pub fn iter_async<'a, C, T>(&'a self, client: &'a C) -> PagedStream<'a, E, C, T>
     where
         T: DeserializeOwned + 'static,
         C: AsyncClient + Sync,
     {
-        let iter = PagedIter::new(self, client);
-        futures_util::stream::unfold(iter, |mut iter| async move {
-            iter.next_async().await.map(|item| (item, iter))
-        })
+        PagedStream::new(self, client)
     }
 }
 
@@ -207,10 +211,15 @@ where
         Some(url)
     }
 
-    fn build_request<C>(&self, url: Url) -> Result<(RequestBuilder, Vec<u8>), ApiError<C::Error>>
+    fn build_request<C>(
+        &self,
+        client: &C,
+        url: Url,
+    ) -> Result<(RequestBuilder, Vec<u8>), ApiError<C::Error>>
     where
         C: RestClient,
     {
+        validate_request(client, &self.paged.endpoint)?;
         let body = self.paged.endpoint.body()?;
 
         let mut req = Request::builder()
@@ -245,17 +254,21 @@ where
         C: RestClient,
     {
         let status = rsp.status();
+        if !status.is_success() {
+            return Err(ApiError::from_response(
+                &self.paged.endpoint.service_type(),
+                status,
+                rsp.body(),
+            ));
+        }
         let mut v = if let Ok(v) = serde_json::from_slice(rsp.body()) {
             v
         } else {
             return Err(ApiError::server_error(status, rsp.body()));
         };
-        if !status.is_success() {
-            return Err(ApiError::from_openstack(status, v));
-        }
 
-        let next_url = if self.paged.endpoint.use_keyset_pagination() {
-            next_page::next_page_from_body(&v, &self.paged.endpoint.response_key(), base)?
+        let mut next_url = if self.paged.endpoint.use_keyset_pagination() {
+            next_page::next_page_from_body(&v, &self.paged.endpoint.response_key(), base.clone())?
         } else {
             None
         };
@@ -264,6 +277,8 @@ where
             v = v[root_key.to_string()].take();
         }
 
+        let marker = next_page::marker_from_page(&v, &self.paged.endpoint.pagination_marker_key());
+
         // List of items and every item is in additional container
         if let (Some(item_key), Some(array)) = (
             self.paged.endpoint.response_list_item_key(),
@@ -289,6 +304,22 @@ where
                 page.truncate(limit - total_read_till_now);
             }
         }
+        if next_url.is_none() {
+            // Swift has no link to the next page. The marker of the last
+            // element and the total count in the headers are used instead.
+            let total_results = self
+                .page_state
+                .read()
+                .expect("poisoned state")
+                .total_results
+                + page.len();
+            next_url = next_page::next_page_from_headers(
+                rsp.headers(),
+                total_results,
+                marker.as_deref(),
+                &base,
+            )?;
+        }
         self.next_page(page.len(), next_url);
 
         Ok(page)
@@ -306,16 +337,17 @@ where
 {
     fn query(&self, client: &C) -> Result<Vec<T>, ApiError<C::Error>> {
         let ep = client.get_service_endpoint(&self.paged.endpoint.service_type())?;
-        let url = if let Some(url) =
-            self.page_url(ep.url.clone().join(&self.paged.endpoint.endpoint())?)
-        {
+        let url = if let Some(url) = self.page_url(client.rest_endpoint(
+            &self.paged.endpoint.service_type(),
+            &self.paged.endpoint.endpoint(),
+        )?) {
             url
         } else {
             // Just return empty data.
             // XXX: Return a new kind of PaginationError here?
             return Ok(Vec::new());
         };
-        let (mut req, data) = self.build_request::<C>(url.clone())?;
+        let (mut req, data) = self.build_request(client, url.clone())?;
         set_latest_microversion(&mut req, &ep, &self.paged.endpoint);
         let rsp = client.rest(req, data)?;
         self.process_response::<C, _>(rsp, url.clone())
@@ -332,16 +364,17 @@ where
 {
     async fn query_async(&self, client: &C) -> Result<Vec<T>, ApiError<C::Error>> {
         let ep = client.get_service_endpoint(&self.paged.endpoint.service_type())?;
-        let url = if let Some(url) =
-            self.page_url(ep.url.clone().join(&self.paged.endpoint.endpoint())?)
-        {
+        let url = if let Some(url) = self.page_url(client.rest_endpoint(
+            &self.paged.endpoint.service_type(),
+            &self.paged.endpoint.endpoint(),
+        )?) {
             url
         } else {
             // Just return empty data.
             // XXX: Return a new kind of PaginationError here?
             return Ok(Vec::new());
         };
-        let (mut req, data) = self.build_request::<C>(url.clone())?;
+        let (mut req, data) = self.build_request(client, url.clone())?;
         set_latest_microversion(&mut req, &ep, &self.paged.endpoint);
         let rsp = client.rest_async(req, data).await?;
         self.process_response::<C, _>(rsp, url.clone())
@@ -399,26 +432,123 @@ where
     }
 }
 
-// Instead of implementing Stream directly, we implement this "async" next method and use it with
-// `stream::unfold` to return an anonymous Stream impl.
 #[cfg(feature = "async")]
-impl<'a, E, C, T> PagedIter<'a, E, C, T>
+type PageFuture<'a, T, E> = Pin<Box<dyn Future<Output = Result<Vec<T>, ApiError<E>>> + Send + 'a>>;
+
+/// A stream which yields items from a paginated result.
+///
+/// The same as for [`PagedIter`] pages are fetched lazily. When prefetching is enabled the
+/// request for the following page is driven while the items of the current page are consumed.
+#[cfg(feature = "async")]
+pub struct PagedStream<'a, E, C, T>
+where
+    C: RestClient,
+{
+    client: &'a C,
+    state: Arc<PagedState<'a, E>>,
+    prefetch: bool,
+    current_page: Vec<T>,
+    next_page: Option<PageFuture<'a, T, C::Error>>,
+    prefetched: Option<Result<Vec<T>, ApiError<C::Error>>>,
+    done: bool,
+}
+
+// No field is structurally pinned: the in-flight request is boxed.
+#[cfg(feature = "async")]
+impl<'a, E, C, T> Unpin for PagedStream<'a, E, C, T> where C: RestClient {}
+
+#[cfg(feature = "async")]
+impl<'a, E, C, T> PagedStream<'a, E, C, T>
 where
     E: RestEndpoint + Pageable + Sync,
     T: DeserializeOwned + 'static,
     C: AsyncClient + Sync,
 {
-    async fn next_async(&mut self) -> Option<Result<T, ApiError<C::Error>>> {
-        if self.current_page.is_empty() {
-            self.current_page = match self.state.query_async(self.client).await {
-                Ok(data) => data,
-                Err(err) => return Some(Err(err)),
-            };
+    fn new(paged: &'a Paged<E>, client: &'a C) -> Self {
+        Self {
+            client,
+            state: Arc::new(PagedState::new(paged)),
+            prefetch: paged.prefetch,
+            current_page: Vec::new(),
+            next_page: None,
+            prefetched: None,
+            done: false,
+        }
+    }
 
-            // Reverse the page order so that `.pop()` works.
-            self.current_page.reverse();
+    /// Start the request for the next page.
+    fn fetch(&self) -> PageFuture<'a, T, C::Error> {
+        let state = self.state.clone();
+        let client = self.client;
+        Box::pin(async move { state.query_async(client).await })
+    }
+
+    /// Drive the prefetch request without waiting for it.
+    fn poll_prefetch(&mut self, cx: &mut Context<'_>) {
+        if let Some(fut) = self.next_page.as_mut() {
+            if let Poll::Ready(page) = fut.as_mut().poll(cx) {
+                self.next_page = None;
+                self.prefetched = Some(page);
+            }
         }
+    }
+}
 
-        self.current_page.pop().map(Ok)
+#[cfg(feature = "async")]
+impl<'a, E, C, T> Stream for PagedStream<'a, E, C, T>
+where
+    E: RestEndpoint + Pageable + Sync,
+    T: DeserializeOwned + 'static,
+    C: AsyncClient + Sync,
+{
+    type Item = Result<T, ApiError<C::Error>>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            if let Some(item) = this.current_page.pop() {
+                if this.prefetch {
+                    this.poll_prefetch(cx);
+                }
+                return Poll::Ready(Some(Ok(item)));
+            }
+            if this.done {
+                return Poll::Ready(None);
+            }
+
+            let page = if let Some(page) = this.prefetched.take() {
+                page
+            } else {
+                if this.next_page.is_none() {
+                    this.next_page = Some(this.fetch());
+                }
+                let fut = this.next_page.as_mut().expect("request is present");
+                match fut.as_mut().poll(cx) {
+                    Poll::Ready(page) => {
+                        this.next_page = None;
+                        page
+                    }
+                    Poll::Pending => return Poll::Pending,
+                }
+            };
+
+            match page {
+                Ok(mut data) if !data.is_empty() => {
+                    // Reverse the page order so that `.pop()` works.
+                    data.reverse();
+                    this.current_page = data;
+                    if this.prefetch {
+                        this.next_page = Some(this.fetch());
+                    }
+                }
+                Ok(_) => {
+                    this.done = true;
+                }
+                Err(err) => {
+                    this.done = true;
+                    return Poll::Ready(Some(Err(err)));
+                }
+            }
+        }
     }
 }
@@ -19,13 +19,103 @@ use std::borrow::Cow;
 
 use url::Url;
 
-use http::HeaderMap;
+use http::{header, HeaderMap};
 
 use crate::api::PaginationError;
 
-pub(crate) fn next_page_from_headers(_headers: &HeaderMap) -> Result<Option<Url>, PaginationError> {
-    Err(PaginationError::Body {
-        msg: "error".into(),
+/// Headers with the total amount of entries in the listing (Swift).
+const TOTAL_COUNT_HEADERS: [&str; 2] = ["x-container-object-count", "x-account-container-count"];
+
+/// Detect link to the next page from the response headers.
+///
+/// Link to the next page is taken from the `Link` header when present.
+/// Otherwise when `marker` (the key of the last item on the page) is given
+/// the next page is requested with it unless the total count of entries
+/// reported by the service (Swift) is already read.
+pub(crate) fn next_page_from_headers(
+    headers: &HeaderMap,
+    total_results: usize,
+    marker: Option<&str>,
+    page_url: &Url,
+) -> Result<Option<Url>, PaginationError> {
+    for link in headers.get_all(header::LINK) {
+        let link = link.to_str().map_err(|_| PaginationError::Body {
+            msg: "`Link` header is not a valid string".into(),
+        })?;
+        for part in link.split(',') {
+            let mut elements = part.split(';').map(str::trim);
+            let target = elements.next().unwrap_or_default();
+            if elements.any(|x| x == "rel=\"next\"" || x == "rel=next") {
+                let target = target.trim_start_matches('<').trim_end_matches('>');
+                return Some(join_url(page_url, target)).transpose();
+            }
+        }
+    }
+
+    let marker = if let Some(marker) = marker {
+        marker
+    } else {
+        return Ok(None);
+    };
+
+    for name in TOTAL_COUNT_HEADERS {
+        if let Some(count) = headers
+            .get(name)
+            .and_then(|x| x.to_str().ok())
+            .and_then(|x| x.parse::<usize>().ok())
+        {
+            if total_results >= count {
+                return Ok(None);
+            }
+        }
+    }
+
+    let mut next = page_url.clone();
+    next.query_pairs_mut()
+        .clear()
+        .extend_pairs(page_url.query_pairs().filter(|(key, _)| key != "marker"))
+        .append_pair("marker", marker);
+    Ok(Some(next))
+}
+
+/// Get the marker for the next page from the last item of the page.
+pub(crate) fn marker_from_page(data: &Value, marker_key: &Option<Cow<'_, str>>) -> Option<String> {
+    let marker_key = marker_key.as_ref()?;
+    data.as_array()?
+        .last()?
+        .get(marker_key.as_ref())?
+        .as_str()
+        .map(String::from)
+}
+
+/// Construct absolute URL of the next page.
+///
+/// Some services (i.e. Glance) return a relative link starting with the API
+/// version (`/v2/images?marker=...`). When the service is deployed under
+/// a path prefix (`https://example.com/image/v2`) the prefix is preserved.
+fn join_url(page_url: &Url, next: &str) -> Result<Url, PaginationError> {
+    let next_url = if next.starts_with("http") {
+        next.to_string()
+    } else {
+        let path = page_url.path();
+        let prefix = next
+            .trim_start_matches('/')
+            .split(['/', '?'])
+            .next()
+            .filter(|x| !x.is_empty())
+            .and_then(|segment| path.find(&format!("/{}/", segment)))
+            .map(|pos| &path[..pos])
+            .unwrap_or_default();
+        format!(
+            "{}{}/{}",
+            &page_url[..url::Position::BeforePath],
+            prefix,
+            next.trim_start_matches('/')
+        )
+    };
+    Url::parse(&next_url).map_err(|x| PaginationError::InvalidUrl {
+        source: x,
+        url: next_url,
     })
 }
 
@@ -87,26 +177,7 @@ pub(crate) fn next_page_from_body(
         if let Some(n) = next {
             // We expect that the link contains all initial query parameters and we do NOT read them.
             if let Some(next_url) = n.as_str() {
-                let next: String = if !next_url.starts_with("http") {
-                    // Some services (i.e. Glance) has a relative link without
-                    // domain. So we need to construct it back.
-                    String::from(base_endpoint.scheme())
-                        + "://"
-                        + base_endpoint.domain().expect("Domain is present")
-                        + ":"
-                        + &base_endpoint
-                            .port_or_known_default()
-                            .expect("Port is unknown")
-                            .to_string()
-                        + next_url
-                } else {
-                    next_url.to_string()
-                };
-                return Some(Url::parse(&next).map_err(|x| PaginationError::InvalidUrl {
-                    source: x,
-                    url: next,
-                }))
-                .transpose();
+                return Some(join_url(&base_endpoint, next_url)).transpose();
             }
         }
     }
@@ -120,7 +191,9 @@ mod tests {
     use std::borrow::Cow;
     use url::Url;
 
-    use crate::api::paged::next_page::next_page_from_body;
+    use http::{HeaderMap, HeaderValue};
+
+    use crate::api::paged::next_page::{next_page_from_body, next_page_from_headers};
 
     #[test]
     fn test_body_links() {
@@ -161,4 +234,69 @@ mod tests {
             next_page_from_body(&data, &None, Url::parse("http://dummy:15").unwrap()).unwrap();
         assert_eq!(res.unwrap(), Url::parse("http://dummy:15/foo/bar").unwrap());
     }
+
+    #[test]
+    fn test_body_links_relative_prefix() {
+        let data = json!({"next": "/v2/images?marker=foo"});
+        let res = next_page_from_body(
+            &data,
+            &None,
+            Url::parse("https://dummy/image/v2/images").unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            res.unwrap(),
+            Url::parse("https://dummy/image/v2/images?marker=foo").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_headers_link() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "link",
+            HeaderValue::from_static(
+                "<http://foo.bar/prev>; rel=\"prev\", <http://foo.bar/next>; rel=\"next\"",
+            ),
+        );
+        let res = next_page_from_headers(&headers, 0, None, &Url::parse("http://dummy").unwrap());
+        assert_eq!(
+            res.unwrap().unwrap(),
+            Url::parse("http://foo.bar/next").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_headers_marker() {
+        let headers = HeaderMap::new();
+        let res = next_page_from_headers(
+            &headers,
+            2,
+            Some("b"),
+            &Url::parse("http://dummy/cont?marker=a&prefix=x").unwrap(),
+        );
+        assert_eq!(
+            res.unwrap().unwrap(),
+            Url::parse("http://dummy/cont?prefix=x&marker=b").unwrap()
+        );
+        let res = next_page_from_headers(&headers, 0, None, &Url::parse("http://dummy").unwrap());
+        assert_eq!(res.unwrap(), None);
+    }
+
+    #[test]
+    fn test_headers_marker_count() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-container-object-count", HeaderValue::from_static("2"));
+        let url = Url::parse("http://dummy/cont").unwrap();
+        assert_eq!(
+            next_page_from_headers(&headers, 2, Some("b"), &url).unwrap(),
+            None
+        );
+        assert_eq!(
+            next_page_from_headers(&headers, 1, Some("a"), &url)
+                .unwrap()
+                .unwrap(),
+            Url::parse("http://dummy/cont?marker=a").unwrap()
+        );
+    }
 }
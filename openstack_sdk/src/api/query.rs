@@ -27,6 +27,8 @@ use crate::api::AsyncClient;
 #[cfg(feature = "sync")]
 use crate::api::Client;
 use crate::types::BoxedAsyncRead;
+#[cfg(feature = "sync")]
+use crate::types::BoxedRead;
 use http::{HeaderMap, Response};
 
 pub fn url_to_http_uri(url: Url) -> Uri {
@@ -72,6 +74,16 @@ where
 {
     /// Perform the query against the client.
     fn raw_query(&self, client: &C) -> Result<Response<Bytes>, ApiError<C::Error>>;
+
+    /// Perform the query against the client with the body read from `Read`.
+    fn raw_query_read_body(
+        &self,
+        client: &C,
+        data: BoxedRead,
+    ) -> Result<Response<Bytes>, ApiError<C::Error>>;
+
+    /// Perform the call and return response headers with `Read` of the body
+    fn download(&self, client: &C) -> Result<(HeaderMap, BoxedRead), ApiError<C::Error>>;
 }
 
 /// A trait which represents an asynchronous query which may be made to a
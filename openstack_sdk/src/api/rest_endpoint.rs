@@ -29,13 +29,15 @@ use serde::de::DeserializeOwned;
 
 use serde_json::json;
 
-use crate::api::{query, ApiError, BodyError, QueryParams, RestClient};
+use crate::api::{query, validation, ApiError, BodyError, QueryParams, RestClient};
 #[cfg(feature = "async")]
 use crate::api::{AsyncClient, QueryAsync, RawQueryAsync};
 #[cfg(feature = "sync")]
 use crate::api::{Client, Query, RawQuery};
 use crate::catalog::ServiceEndpoint;
 use crate::types::BoxedAsyncRead;
+#[cfg(feature = "sync")]
+use crate::types::BoxedRead;
 use crate::types::ServiceType;
 
 /// A trait for providing the necessary information for a single REST API endpoint.
@@ -78,6 +80,13 @@ pub trait RestEndpoint {
     fn request_headers(&self) -> Option<&HeaderMap> {
         None
     }
+
+    /// Validate the request data against the constraints of the API.
+    ///
+    /// Returns [`BodyError::Validation`] listing every invalid field.
+    fn validate(&self) -> Result<(), BodyError> {
+        Ok(())
+    }
 }
 
 /// Set latest microversion information into the request
@@ -105,7 +114,20 @@ pub(crate) fn set_latest_microversion<E>(
     }
 }
 
+/// Validate the request data unless it is disabled for the client
+pub(crate) fn validate_request<C, E>(client: &C, endpoint: &E) -> Result<(), BodyError>
+where
+    E: RestEndpoint,
+    C: RestClient,
+{
+    if client.validate_requests() {
+        validation::validate_endpoint(endpoint)?;
+    }
+    Ok(())
+}
+
 pub(crate) fn prepare_request<C, E>(
+    client: &C,
     service_endpoint: &ServiceEndpoint,
     mut url: Url,
     endpoint: &E,
@@ -114,6 +136,7 @@ where
     E: RestEndpoint,
     C: RestClient,
 {
+    validate_request(client, endpoint)?;
     endpoint.parameters().add_to_url(&mut url);
     let mut req = Request::builder()
         .method(endpoint.method())
@@ -135,35 +158,35 @@ where
 }
 
 /// Cast response to Json Value
-pub(super) fn get_json<C>(rsp: &Response<Bytes>) -> Result<serde_json::Value, ApiError<C::Error>>
+pub(super) fn get_json<C>(
+    rsp: &Response<Bytes>,
+    service_type: &ServiceType,
+) -> Result<serde_json::Value, ApiError<C::Error>>
 where
     C: RestClient,
 {
     let status = rsp.status();
-    let v = if let Ok(v) = serde_json::from_slice(rsp.body()) {
-        v
-    } else {
-        return Err(ApiError::server_error(status, rsp.body()));
-    };
     if !status.is_success() {
-        return Err(ApiError::from_openstack(status, v));
+        return Err(ApiError::from_response(service_type, status, rsp.body()));
+    }
+    if let Ok(v) = serde_json::from_slice(rsp.body()) {
+        Ok(v)
+    } else {
+        Err(ApiError::server_error(status, rsp.body()))
     }
-    Ok(v)
 }
 
 /// Check for possible error in the response
-pub fn check_response_error<C>(rsp: &Response<Bytes>) -> Result<(), ApiError<C::Error>>
+pub fn check_response_error<C>(
+    rsp: &Response<Bytes>,
+    service_type: &ServiceType,
+) -> Result<(), ApiError<C::Error>>
 where
     C: RestClient,
 {
     let status = rsp.status();
     if !status.is_success() {
-        let v = if let Ok(v) = serde_json::from_slice(rsp.body()) {
-            v
-        } else {
-            return Err(ApiError::server_error(status, rsp.body()));
-        };
-        return Err(ApiError::from_openstack(status, v));
+        return Err(ApiError::from_response(service_type, status, rsp.body()));
     }
     Ok(())
 }
@@ -181,6 +204,7 @@ where
 
         let ep = client.get_service_endpoint(&self.service_type())?;
         let (req, data) = prepare_request::<C, E>(
+            client,
             &ep,
             client.rest_endpoint(&self.service_type(), &self.endpoint())?,
             self,
@@ -188,7 +212,7 @@ where
 
         let rsp = client.rest(req, data)?;
 
-        let mut v = get_json::<C>(&rsp)?;
+        let mut v = get_json::<C>(&rsp, &self.service_type())?;
 
         if let Some(root_key) = self.response_key() {
             v = v[root_key.to_string()].take();
@@ -223,13 +247,14 @@ where
 
         let ep = client.get_service_endpoint(&self.service_type())?;
         let (req, data) = prepare_request::<C, E>(
+            client,
             &ep,
             client.rest_endpoint(&self.service_type(), &self.endpoint())?,
             self,
         )?;
 
         let rsp = client.rest_async(req, data).await?;
-        let mut v = get_json::<C>(&rsp)?;
+        let mut v = get_json::<C>(&rsp, &self.service_type())?;
 
         if let Some(root_key) = self.response_key() {
             v = v[root_key.to_string()].take();
@@ -263,6 +288,7 @@ where
 
         let ep = client.get_service_endpoint(&self.service_type())?;
         let (req, data) = prepare_request::<C, E>(
+            client,
             &ep,
             client.rest_endpoint(&self.service_type(), &self.endpoint())?,
             self,
@@ -272,6 +298,54 @@ where
 
         Ok(rsp)
     }
+
+    fn raw_query_read_body(
+        &self,
+        client: &C,
+        data: BoxedRead,
+    ) -> Result<Response<Bytes>, ApiError<C::Error>> {
+        let span = span!(Level::DEBUG, "Query span");
+        let _enter = span.enter();
+
+        validate_request(client, self)?;
+        let ep = client.get_service_endpoint(&self.service_type())?;
+        let mut url = client.rest_endpoint(&self.service_type(), &self.endpoint())?;
+        self.parameters().add_to_url(&mut url);
+        let mut req = Request::builder()
+            .method(self.method())
+            .uri(query::url_to_http_uri(url));
+        set_latest_microversion(&mut req, &ep, self);
+        if let Some(request_headers) = self.request_headers() {
+            let headers = req.headers_mut().unwrap();
+            for (k, v) in request_headers.iter() {
+                headers.insert(k, v.clone());
+            }
+        }
+
+        let rsp = client.rest_read_body(req, data)?;
+
+        check_response_error::<C>(&rsp, &self.service_type())?;
+
+        Ok(rsp)
+    }
+
+    /// Perform a download API call (returning Read of the body)
+    fn download(&self, client: &C) -> Result<(HeaderMap, BoxedRead), ApiError<C::Error>> {
+        let span = span!(Level::DEBUG, "Query span");
+        let _enter = span.enter();
+
+        let ep = client.get_service_endpoint(&self.service_type())?;
+        let (req, data) = prepare_request::<C, E>(
+            client,
+            &ep,
+            client.rest_endpoint(&self.service_type(), &self.endpoint())?,
+            self,
+        )?;
+
+        let rsp = client.download(req, data)?;
+
+        Ok(rsp)
+    }
 }
 
 #[cfg(feature = "async")]
@@ -292,6 +366,7 @@ where
 
         let ep = client.get_service_endpoint(&self.service_type())?;
         let (req, data) = prepare_request::<C, E>(
+            client,
             &ep,
             client.rest_endpoint(&self.service_type(), &self.endpoint())?,
             self,
@@ -300,7 +375,7 @@ where
         let rsp = client.rest_async(req, data).await?;
 
         if inspect_error.unwrap_or(true) {
-            check_response_error::<C>(&rsp)?;
+            check_response_error::<C>(&rsp, &self.service_type())?;
         }
         Ok(rsp)
     }
@@ -317,6 +392,7 @@ where
         let span = span!(Level::DEBUG, "Query span");
         let _enter = span.enter();
 
+        validate_request(client, self)?;
         let ep = client.get_service_endpoint(&self.service_type())?;
         let mut url = client.rest_endpoint(&self.service_type(), &self.endpoint())?;
         self.parameters().add_to_url(&mut url);
@@ -333,7 +409,7 @@ where
 
         let rsp = client.rest_read_body_async(req, data).await?;
 
-        check_response_error::<C>(&rsp)?;
+        check_response_error::<C>(&rsp, &self.service_type())?;
 
         Ok(rsp)
     }
@@ -348,6 +424,7 @@ where
 
         let ep = client.get_service_endpoint(&self.service_type())?;
         let (req, data) = prepare_request::<C, E>(
+            client,
             &ep,
             client.rest_endpoint(&self.service_type(), &self.endpoint())?,
             self,
@@ -368,9 +445,9 @@ mod tests {
     use serde_json::json;
 
     use crate::api::rest_endpoint_prelude::*;
-    use crate::api::{ApiError, Query};
+    use crate::api::{ApiError, Query, RawQuery};
     use crate::test::client::MockServerClient;
-    use crate::types::ServiceType;
+    use crate::types::{BoxedRead, ServiceType};
 
     struct Dummy;
 
@@ -388,6 +465,22 @@ mod tests {
         }
     }
 
+    struct DummyUpload;
+
+    impl RestEndpoint for DummyUpload {
+        fn method(&self) -> http::Method {
+            http::Method::PUT
+        }
+
+        fn endpoint(&self) -> Cow<'static, str> {
+            "dummy".into()
+        }
+
+        fn service_type(&self) -> ServiceType {
+            ServiceType::Other("dummy".to_string())
+        }
+    }
+
     #[derive(Debug, Deserialize)]
     struct DummyResult {
         value: u8,
@@ -475,8 +568,8 @@ mod tests {
 
         let res: Result<DummyResult, _> = Dummy.query(&client);
         let err = res.unwrap_err();
-        if let ApiError::OpenStack { status: _, msg } = err {
-            assert_eq!(msg, "dummy error message");
+        if let ApiError::Conflict { error } = err {
+            assert_eq!(error.message, "dummy error message");
         } else {
             panic!("unexpected error: {}", err);
         }
@@ -537,4 +630,38 @@ mod tests {
         assert_eq!(res.unwrap().value, 0);
         mock.assert();
     }
+
+    #[test]
+    fn test_raw_query_read_body() {
+        let client = MockServerClient::new();
+        let mock = client.server.mock(|when, then| {
+            when.method(httpmock::Method::PUT)
+                .path("/dummy")
+                .body("streamed content");
+            then.status(204);
+        });
+
+        let data = BoxedRead::new(std::io::Cursor::new(b"streamed content".to_vec()));
+        let rsp = DummyUpload.raw_query_read_body(&client, data).unwrap();
+        assert_eq!(rsp.status(), StatusCode::NO_CONTENT);
+        mock.assert();
+    }
+
+    #[test]
+    fn test_download() {
+        let client = MockServerClient::new();
+        let mock = client.server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/dummy");
+            then.status(200)
+                .header("content-length", "16")
+                .body("streamed content");
+        });
+
+        let (headers, mut data) = Dummy.download(&client).unwrap();
+        let mut content = String::new();
+        std::io::Read::read_to_string(&mut data, &mut content).unwrap();
+        assert_eq!(headers["content-length"], "16");
+        assert_eq!(content, "streamed content");
+        mock.assert();
+    }
 }
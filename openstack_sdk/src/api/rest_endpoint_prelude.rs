@@ -20,9 +20,11 @@
 pub use std::borrow::Cow;
 
 pub use crate::api::BodyError;
+pub use crate::api::Format;
 //pub use crate::api::Client;
 pub use crate::api::JsonBodyParams;
 pub use crate::api::Pageable;
 pub use crate::api::QueryParams;
 pub use crate::api::RestEndpoint;
+pub use crate::api::Validator;
 pub use crate::types::ServiceType;
@@ -0,0 +1,411 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Client side validation of the request data
+//!
+//! Endpoints know the constraints of the API (allowed values, length and
+//! range limits, formats of the values, mutually exclusive attributes).
+//! Checking them before sending the request gives a clear error listing
+//! every invalid field instead of a vague `400` response of the service.
+//!
+//! Constraints of the schema are checked by [`RestEndpoint::validate`] of
+//! the generated endpoints. Rules of the API which the schema does not
+//! express (formats of the values, dependencies between attributes) are
+//! kept in the submodules of this module and are checked on the request
+//! body of the matching endpoint.
+//!
+//! ```rust,ignore
+//! fn create_network(body: &Value, validator: &mut Validator) {
+//!     let network = &body["network"];
+//!     validator
+//!         .length("network.name", network["name"].as_str(), None, Some(255))
+//!         .format("network.qos_policy_id", network["qos_policy_id"].as_str(), Format::Uuid);
+//! }
+//! ```
+
+mod compute;
+mod network;
+
+use std::fmt::{self, Display};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use http::Method;
+use serde_json::Value;
+
+use crate::api::{BodyError, RestEndpoint};
+use crate::types::ServiceType;
+
+/// Invalid field of the request
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldError {
+    /// Path of the field in the request body (i.e. `server.networks[0].uuid`).
+    pub field: String,
+    /// Reason why the value is invalid.
+    pub message: String,
+}
+
+impl Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "`{}` {}", self.field, self.message)
+    }
+}
+
+/// Format of the string value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Format {
+    /// UUID (with or without dashes)
+    Uuid,
+    /// IPv4 address
+    Ipv4,
+    /// IPv6 address
+    Ipv6,
+    /// IPv4 or IPv6 address
+    IpAddress,
+    /// IPv4 or IPv6 network in the CIDR notation
+    Cidr,
+    /// MAC address
+    MacAddress,
+}
+
+impl Format {
+    /// Whether the value matches the format
+    pub fn matches(&self, value: &str) -> bool {
+        match self {
+            Self::Uuid => is_uuid(value),
+            Self::Ipv4 => value.parse::<Ipv4Addr>().is_ok(),
+            Self::Ipv6 => value.parse::<Ipv6Addr>().is_ok(),
+            Self::IpAddress => value.parse::<IpAddr>().is_ok(),
+            Self::Cidr => is_cidr(value),
+            Self::MacAddress => is_mac_address(value),
+        }
+    }
+}
+
+impl Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Self::Uuid => "UUID",
+            Self::Ipv4 => "IPv4 address",
+            Self::Ipv6 => "IPv6 address",
+            Self::IpAddress => "IP address",
+            Self::Cidr => "CIDR",
+            Self::MacAddress => "MAC address",
+        })
+    }
+}
+
+/// Collector of the validation errors
+#[derive(Debug, Default)]
+pub struct Validator {
+    errors: Vec<FieldError>,
+}
+
+impl Validator {
+    /// Create new validator
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the error of the field
+    pub fn error<F: Into<String>, M: Into<String>>(&mut self, field: F, message: M) -> &mut Self {
+        self.errors.push(FieldError {
+            field: field.into(),
+            message: message.into(),
+        });
+        self
+    }
+
+    /// Check the custom condition of the field
+    pub fn check(&mut self, field: &str, valid: bool, message: &str) -> &mut Self {
+        if !valid {
+            self.error(field, message);
+        }
+        self
+    }
+
+    /// Check the length (in characters) of the string value
+    pub fn length(
+        &mut self,
+        field: &str,
+        value: Option<&str>,
+        min: Option<usize>,
+        max: Option<usize>,
+    ) -> &mut Self {
+        if let Some(value) = value {
+            let len = value.chars().count();
+            if let Some(min) = min.filter(|min| len < *min) {
+                self.error(field, format!("must be at least {} characters long", min));
+            }
+            if let Some(max) = max.filter(|max| len > *max) {
+                self.error(field, format!("must be at most {} characters long", max));
+            }
+        }
+        self
+    }
+
+    /// Check the number of items of the list
+    pub fn items<T>(
+        &mut self,
+        field: &str,
+        value: Option<&[T]>,
+        min: Option<usize>,
+        max: Option<usize>,
+    ) -> &mut Self {
+        if let Some(value) = value {
+            if let Some(min) = min.filter(|min| value.len() < *min) {
+                self.error(field, format!("must have at least {} items", min));
+            }
+            if let Some(max) = max.filter(|max| value.len() > *max) {
+                self.error(field, format!("must have at most {} items", max));
+            }
+        }
+        self
+    }
+
+    /// Check that the numeric value is in the range
+    pub fn range<N>(
+        &mut self,
+        field: &str,
+        value: Option<N>,
+        min: Option<N>,
+        max: Option<N>,
+    ) -> &mut Self
+    where
+        N: PartialOrd + Display + Copy,
+    {
+        if let Some(value) = value {
+            if let Some(min) = min.filter(|min| value < *min) {
+                self.error(field, format!("must be greater than or equal to {}", min));
+            }
+            if let Some(max) = max.filter(|max| value > *max) {
+                self.error(field, format!("must be less than or equal to {}", max));
+            }
+        }
+        self
+    }
+
+    /// Check that the value is one of the allowed values
+    pub fn one_of(&mut self, field: &str, value: Option<&str>, allowed: &[&str]) -> &mut Self {
+        if let Some(value) = value.filter(|x| !allowed.contains(x)) {
+            self.error(
+                field,
+                format!("`{}` is not one of [{}]", value, allowed.join(", ")),
+            );
+        }
+        self
+    }
+
+    /// Check that the value has the expected format
+    pub fn format(&mut self, field: &str, value: Option<&str>, format: Format) -> &mut Self {
+        if let Some(value) = value.filter(|x| !format.matches(x)) {
+            self.error(field, format!("`{}` is not a valid {}", value, format));
+        }
+        self
+    }
+
+    /// Check that at most one of the fields is present
+    pub fn exclusive(&mut self, fields: &[(&str, bool)]) -> &mut Self {
+        let present: Vec<&str> = fields
+            .iter()
+            .filter(|(_, present)| *present)
+            .map(|(name, _)| *name)
+            .collect();
+        if present.len() > 1 {
+            self.error(
+                present[0],
+                format!("cannot be combined with `{}`", present[1..].join("`, `")),
+            );
+        }
+        self
+    }
+
+    /// Check that the fields required by the present field are also present
+    pub fn requires(&mut self, field: &str, present: bool, required: &[(&str, bool)]) -> &mut Self {
+        if present {
+            for (name, _) in required.iter().filter(|(_, present)| !present) {
+                self.error(field, format!("requires `{}` to be set", name));
+            }
+        }
+        self
+    }
+
+    /// Return [`BodyError::Validation`] when there were invalid fields
+    pub fn finish(&mut self) -> Result<(), BodyError> {
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(BodyError::Validation {
+                errors: std::mem::take(&mut self.errors),
+            })
+        }
+    }
+}
+
+/// Rules of the request body
+type Rules = fn(&Value, &mut Validator);
+
+/// Rules of the endpoint which are not part of the endpoint schema
+fn endpoint_rules<E: RestEndpoint + ?Sized>(endpoint: &E) -> Option<Rules> {
+    if endpoint.method() != Method::POST {
+        return None;
+    }
+    match (endpoint.service_type(), endpoint.endpoint().as_ref()) {
+        (ServiceType::Compute, "v2.1/servers") => Some(compute::create_server),
+        (ServiceType::Network, "v2.0/networks") => Some(network::create_network),
+        (ServiceType::Network, "v2.0/ports") => Some(network::create_port),
+        (ServiceType::Network, "v2.0/subnets") => Some(network::create_subnet),
+        _ => None,
+    }
+}
+
+/// Validate the request of the endpoint.
+///
+/// Checks the constraints of the endpoint schema and the rules of the API
+/// for the request body.
+pub(crate) fn validate_endpoint<E: RestEndpoint + ?Sized>(endpoint: &E) -> Result<(), BodyError> {
+    endpoint.validate()?;
+    if let Some(rules) = endpoint_rules(endpoint) {
+        if let Some(("application/json", data)) = endpoint.body()? {
+            let body: Value = serde_json::from_slice(&data)?;
+            let mut validator = Validator::new();
+            rules(&body, &mut validator);
+            return validator.finish();
+        }
+    }
+    Ok(())
+}
+
+/// Enumerated items of the list attribute
+fn items(value: &Value) -> impl Iterator<Item = (usize, &Value)> {
+    value.as_array().into_iter().flatten().enumerate()
+}
+
+/// Whether the value is UUID (with or without dashes)
+fn is_uuid(value: &str) -> bool {
+    let hex = value.replace('-', "");
+    let dashed = value.len() == 36
+        && value
+            .char_indices()
+            .all(|(pos, c)| matches!(pos, 8 | 13 | 18 | 23) == (c == '-'));
+    hex.len() == 32 && hex.chars().all(|x| x.is_ascii_hexdigit()) && (dashed || value.len() == 32)
+}
+
+/// Whether the value is the network in CIDR notation
+fn is_cidr(value: &str) -> bool {
+    let Some((addr, prefix)) = value.split_once('/') else {
+        return false;
+    };
+    let Ok(prefix) = prefix.parse::<u8>() else {
+        return false;
+    };
+    match addr.parse::<IpAddr>() {
+        Ok(IpAddr::V4(_)) => prefix <= 32,
+        Ok(IpAddr::V6(_)) => prefix <= 128,
+        Err(_) => false,
+    }
+}
+
+/// Whether the value is the MAC address (`:` or `-` separated)
+fn is_mac_address(value: &str) -> bool {
+    let parts: Vec<&str> = value.split([':', '-']).collect();
+    parts.len() == 6
+        && parts
+            .iter()
+            .all(|x| x.len() == 2 && x.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_formats() {
+        assert!(Format::Uuid.matches("9d6b3c2a-5c8e-4b52-8a4f-0d1e2f3a4b5c"));
+        assert!(Format::Uuid.matches("9d6b3c2a5c8e4b528a4f0d1e2f3a4b5c"));
+        assert!(!Format::Uuid.matches("9d6b3c2a-5c8e4b52-8a4f-0d1e-2f3a4b5c"));
+        assert!(!Format::Uuid.matches("foo"));
+        assert!(Format::Ipv4.matches("10.0.0.1"));
+        assert!(!Format::Ipv4.matches("fe80::1"));
+        assert!(Format::Ipv6.matches("fe80::1"));
+        assert!(Format::IpAddress.matches("fe80::1"));
+        assert!(Format::Cidr.matches("10.0.0.0/24"));
+        assert!(Format::Cidr.matches("fd00::/64"));
+        assert!(!Format::Cidr.matches("10.0.0.0/33"));
+        assert!(!Format::Cidr.matches("10.0.0.0"));
+        assert!(Format::MacAddress.matches("fa:16:3e:00:11:22"));
+        assert!(!Format::MacAddress.matches("fa:16:3e:00:11"));
+    }
+
+    #[test]
+    fn test_validator() {
+        let res = Validator::new()
+            .length("name", Some("foo"), Some(1), Some(255))
+            .length("description", Some("foo"), None, Some(2))
+            .range("mtu", Some(10), Some(68), None)
+            .one_of("ip_version", Some("5"), &["4", "6"])
+            .format("cidr", Some("10.0.0.0/24"), Format::Cidr)
+            .exclusive(&[("a", true), ("b", false), ("c", true)])
+            .requires("d", true, &[("e", false)])
+            .finish();
+        let Err(BodyError::Validation { errors }) = res else {
+            panic!("unexpected result: {:?}", res);
+        };
+        let fields: Vec<&str> = errors.iter().map(|x| x.field.as_str()).collect();
+        assert_eq!(fields, ["description", "mtu", "ip_version", "a", "d"]);
+        assert_eq!(
+            errors[2].to_string(),
+            "`ip_version` `5` is not one of [4, 6]"
+        );
+        assert!(Validator::new()
+            .length("name", None, Some(1), None)
+            .finish()
+            .is_ok());
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_validation_switch() {
+        use serde_json::Value;
+
+        use crate::api::network::v2::network::create;
+        use crate::api::{ApiError, QueryAsync};
+        use crate::test_utils::FakeCloud;
+        use crate::types::ServiceType;
+        use crate::AsyncOpenStack;
+
+        let cloud = FakeCloud::new();
+        let mut session = AsyncOpenStack::new(&cloud.cloud_config()).await.unwrap();
+        session
+            .discover_service_endpoint(&ServiceType::Network)
+            .await
+            .unwrap();
+        let ep = create::Request::builder()
+            .network(create::NetworkBuilder::default().mtu(10).build().unwrap())
+            .build()
+            .unwrap();
+
+        let res: Result<Value, _> = ep.query_async(&session).await;
+        assert!(matches!(
+            res,
+            Err(ApiError::Body {
+                source: BodyError::Validation { .. }
+            })
+        ));
+
+        session.set_validate_requests(false);
+        let net: Value = ep.query_async(&session).await.unwrap();
+        assert_eq!(net["mtu"], 10);
+    }
+}
@@ -0,0 +1,195 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Validation rules of the Compute API requests
+
+use serde_json::Value;
+
+use super::items;
+use crate::api::{Format, Validator};
+
+/// Rules of the `POST /servers` request (all microversions)
+pub(super) fn create_server(body: &Value, validator: &mut Validator) {
+    let server = &body["server"];
+    let min_count = server["min_count"].as_i64();
+    let max_count = server["max_count"].as_i64();
+    let tags = server["tags"].as_array().map(Vec::as_slice);
+    if let Some(name) = server["name"].as_str() {
+        validator
+            .length("server.name", Some(name), Some(1), Some(255))
+            .check(
+                "server.name",
+                name.trim() == name,
+                "must not have leading or trailing whitespace",
+            );
+    }
+    validator
+        .length(
+            "server.flavorRef",
+            server["flavorRef"].as_str(),
+            Some(1),
+            None,
+        )
+        .length(
+            "server.description",
+            server["description"].as_str(),
+            None,
+            Some(255),
+        )
+        .length(
+            "server.hostname",
+            server["hostname"].as_str(),
+            Some(1),
+            Some(255),
+        )
+        .length(
+            "server.key_name",
+            server["key_name"].as_str(),
+            Some(1),
+            Some(255),
+        )
+        .length(
+            "server.availability_zone",
+            server["availability_zone"].as_str(),
+            Some(1),
+            Some(255),
+        )
+        .length("server.host", server["host"].as_str(), Some(1), Some(255))
+        .length(
+            "server.hypervisor_hostname",
+            server["hypervisor_hostname"].as_str(),
+            Some(1),
+            Some(255),
+        )
+        .format(
+            "server.accessIPv4",
+            server["accessIPv4"].as_str(),
+            Format::Ipv4,
+        )
+        .format(
+            "server.accessIPv6",
+            server["accessIPv6"].as_str(),
+            Format::Ipv6,
+        )
+        .format(
+            "server.imageRef",
+            server["imageRef"].as_str().filter(|x| !x.is_empty()),
+            Format::Uuid,
+        )
+        .range("server.min_count", min_count, Some(1), None)
+        .range("server.max_count", max_count, Some(1), None)
+        .items("server.tags", tags, None, Some(50))
+        .items(
+            "server.trusted_image_certificates",
+            server["trusted_image_certificates"]
+                .as_array()
+                .map(Vec::as_slice),
+            Some(1),
+            Some(50),
+        );
+    if let (Some(min), Some(max)) = (min_count, max_count) {
+        validator.check(
+            "server.max_count",
+            max >= min,
+            "must be greater than or equal to `server.min_count`",
+        );
+    }
+    for (idx, tag) in items(&server["tags"]) {
+        let field = format!("server.tags[{}]", idx);
+        if let Some(tag) = tag.as_str() {
+            validator
+                .length(&field, Some(tag), Some(1), Some(60))
+                .check(
+                    &field,
+                    !tag.contains(['/', ',']),
+                    "must not contain `/` or `,`",
+                );
+        }
+    }
+    for (key, val) in server["metadata"].as_object().into_iter().flatten() {
+        let field = format!("server.metadata.{}", key);
+        validator
+            .length(&field, Some(key), Some(1), Some(255))
+            .length(&field, val.as_str(), None, Some(255));
+    }
+    // `networks` is either the list of networks or one of `auto`/`none`
+    for (idx, network) in items(&server["networks"]) {
+        validator
+            .format(
+                &format!("server.networks[{}].uuid", idx),
+                network["uuid"].as_str(),
+                Format::Uuid,
+            )
+            .format(
+                &format!("server.networks[{}].port", idx),
+                network["port"].as_str(),
+                Format::Uuid,
+            )
+            .format(
+                &format!("server.networks[{}].fixed_ip", idx),
+                network["fixed_ip"].as_str(),
+                Format::IpAddress,
+            )
+            .length(
+                &format!("server.networks[{}].tag", idx),
+                network["tag"].as_str(),
+                Some(1),
+                Some(60),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::compute::v2::server::create_294::{
+        NetworksBuilder, Request, ServerBuilder, ServerNetworks,
+    };
+    use crate::api::validation::validate_endpoint;
+    use crate::api::BodyError;
+
+    #[test]
+    fn test_create_server() {
+        let endpoint = Request::builder()
+            .server(
+                ServerBuilder::default()
+                    .flavor_ref("foo")
+                    .name(" foo")
+                    .access_ipv4("fe80::1")
+                    .min_count(2)
+                    .max_count(1)
+                    .networks(ServerNetworks::F1(Vec::from([NetworksBuilder::default()
+                        .uuid("9d6b3c2a-5c8e-4b52-8a4f-0d1e2f3a4b5c")
+                        .fixed_ip("10.0.0.300")
+                        .build()
+                        .unwrap()])))
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        let Err(BodyError::Validation { errors }) = validate_endpoint(&endpoint) else {
+            panic!("request is expected to be invalid");
+        };
+        let fields: Vec<&str> = errors.iter().map(|x| x.field.as_str()).collect();
+        assert_eq!(
+            fields,
+            [
+                "server.name",
+                "server.accessIPv4",
+                "server.max_count",
+                "server.networks[0].fixed_ip"
+            ]
+        );
+    }
+}
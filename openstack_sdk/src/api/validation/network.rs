@@ -0,0 +1,255 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Validation rules of the Network API requests
+
+use serde_json::Value;
+
+use super::items;
+use crate::api::{Format, Validator};
+
+/// Rules of the `POST /v2.0/networks` request
+pub(super) fn create_network(body: &Value, validator: &mut Validator) {
+    let network = &body["network"];
+    validator
+        .length("network.name", network["name"].as_str(), None, Some(255))
+        .length(
+            "network.description",
+            network["description"].as_str(),
+            None,
+            Some(255),
+        )
+        .length(
+            "network.dns_domain",
+            network["dns_domain"].as_str(),
+            None,
+            Some(255),
+        )
+        .range("network.mtu", network["mtu"].as_i64(), Some(68), None)
+        .format(
+            "network.qos_policy_id",
+            network["qos_policy_id"].as_str(),
+            Format::Uuid,
+        )
+        .exclusive(&[
+            ("network.segments", !network["segments"].is_null()),
+            (
+                "network.provider:network_type",
+                !network["provider:network_type"].is_null(),
+            ),
+        ]);
+}
+
+/// Rules of the `POST /v2.0/ports` request
+pub(super) fn create_port(body: &Value, validator: &mut Validator) {
+    let port = &body["port"];
+    validator
+        .length("port.name", port["name"].as_str(), None, Some(255))
+        .length(
+            "port.description",
+            port["description"].as_str(),
+            None,
+            Some(255),
+        )
+        .length(
+            "port.device_id",
+            port["device_id"].as_str(),
+            None,
+            Some(255),
+        )
+        .length(
+            "port.device_owner",
+            port["device_owner"].as_str(),
+            None,
+            Some(255),
+        )
+        .format(
+            "port.mac_address",
+            port["mac_address"].as_str(),
+            Format::MacAddress,
+        )
+        .format(
+            "port.qos_policy_id",
+            port["qos_policy_id"].as_str(),
+            Format::Uuid,
+        );
+    for (idx, fixed_ip) in items(&port["fixed_ips"]) {
+        validator
+            .format(
+                &format!("port.fixed_ips[{}].ip_address", idx),
+                fixed_ip["ip_address"].as_str(),
+                Format::IpAddress,
+            )
+            .format(
+                &format!("port.fixed_ips[{}].subnet_id", idx),
+                fixed_ip["subnet_id"].as_str(),
+                Format::Uuid,
+            );
+    }
+    for (idx, pair) in items(&port["allowed_address_pairs"]) {
+        // Address pair may be the single address or the network
+        if let Some(ip) = pair["ip_address"].as_str() {
+            validator.check(
+                &format!("port.allowed_address_pairs[{}].ip_address", idx),
+                Format::IpAddress.matches(ip) || Format::Cidr.matches(ip),
+                "is not a valid IP address or CIDR",
+            );
+        }
+        validator.format(
+            &format!("port.allowed_address_pairs[{}].max_address", idx),
+            pair["max_address"].as_str(),
+            Format::MacAddress,
+        );
+    }
+    for (idx, sg) in items(&port["security_groups"]) {
+        validator.format(
+            &format!("port.security_groups[{}]", idx),
+            sg.as_str(),
+            Format::Uuid,
+        );
+    }
+}
+
+/// Rules of the `POST /v2.0/subnets` request
+pub(super) fn create_subnet(body: &Value, validator: &mut Validator) {
+    let subnet = &body["subnet"];
+    let ip_version = subnet["ip_version"].as_i64();
+    validator
+        .check(
+            "subnet.ip_version",
+            matches!(ip_version, Some(4 | 6)),
+            "must be 4 or 6",
+        )
+        .length("subnet.name", subnet["name"].as_str(), None, Some(255))
+        .length(
+            "subnet.description",
+            subnet["description"].as_str(),
+            None,
+            Some(255),
+        )
+        .format("subnet.cidr", subnet["cidr"].as_str(), Format::Cidr)
+        .format(
+            "subnet.gateway_ip",
+            subnet["gateway_ip"].as_str(),
+            Format::IpAddress,
+        )
+        .range(
+            "subnet.prefixlen",
+            subnet["prefixlen"].as_i64(),
+            Some(0),
+            Some(128),
+        )
+        .format(
+            "subnet.subnetpool_id",
+            subnet["subnetpool_id"].as_str(),
+            Format::Uuid,
+        )
+        .format(
+            "subnet.segment_id",
+            subnet["segment_id"].as_str(),
+            Format::Uuid,
+        )
+        .exclusive(&[
+            (
+                "subnet.use_default_subnetpool",
+                subnet["use_default_subnetpool"].as_bool() == Some(true),
+            ),
+            ("subnet.subnetpool_id", !subnet["subnetpool_id"].is_null()),
+        ])
+        .check(
+            "subnet.ipv6_address_mode",
+            subnet["ipv6_address_mode"].is_null() || ip_version == Some(6),
+            "requires `subnet.ip_version` to be 6",
+        )
+        .check(
+            "subnet.ipv6_ra_mode",
+            subnet["ipv6_ra_mode"].is_null() || ip_version == Some(6),
+            "requires `subnet.ip_version` to be 6",
+        );
+    for (idx, pool) in items(&subnet["allocation_pools"]) {
+        validator
+            .format(
+                &format!("subnet.allocation_pools[{}].start", idx),
+                pool["start"].as_str(),
+                Format::IpAddress,
+            )
+            .format(
+                &format!("subnet.allocation_pools[{}].end", idx),
+                pool["end"].as_str(),
+                Format::IpAddress,
+            );
+    }
+    for (idx, route) in items(&subnet["host_routes"]) {
+        validator
+            .format(
+                &format!("subnet.host_routes[{}].destination", idx),
+                route["destination"].as_str(),
+                Format::Cidr,
+            )
+            .format(
+                &format!("subnet.host_routes[{}].nexthop", idx),
+                route["nexthop"].as_str(),
+                Format::IpAddress,
+            );
+    }
+    for (idx, server) in items(&subnet["dns_nameservers"]) {
+        validator.format(
+            &format!("subnet.dns_nameservers[{}]", idx),
+            server.as_str(),
+            Format::IpAddress,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::network::v2::subnet::create::{
+        AllocationPoolsBuilder, Ipv6RaMode, Request, SubnetBuilder,
+    };
+    use crate::api::validation::validate_endpoint;
+    use crate::api::BodyError;
+
+    #[test]
+    fn test_create_subnet() {
+        let endpoint = Request::builder()
+            .subnet(
+                SubnetBuilder::default()
+                    .ip_version(4)
+                    .network_id("foo")
+                    .cidr(Some("10.0.0.0/33".into()))
+                    .ipv6_ra_mode(Ipv6RaMode::Slaac)
+                    .allocation_pools(Vec::from([AllocationPoolsBuilder::default()
+                        .start("10.0.0.2")
+                        .end("10.0.0")
+                        .build()
+                        .unwrap()]))
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        let Err(BodyError::Validation { errors }) = validate_endpoint(&endpoint) else {
+            panic!("request is expected to be invalid");
+        };
+        let fields: Vec<&str> = errors.iter().map(|x| x.field.as_str()).collect();
+        assert_eq!(
+            fields,
+            [
+                "subnet.cidr",
+                "subnet.ipv6_ra_mode",
+                "subnet.allocation_pools[0].end"
+            ]
+        );
+    }
+}
@@ -0,0 +1,506 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Waiting for the resource to reach the desired state
+//!
+//! Most of the OpenStack resources are being processed asynchronously after
+//! the API call returns (servers, volumes, load balancers, etc). Query
+//! modifiers in this module repeatedly fetch the resource with the given
+//! GET (or [`find`](crate::api::find)) endpoint until the status attribute
+//! reaches one of the target states, one of the failure states or the
+//! resource disappears.
+//!
+//! ```rust,ignore
+//! let server: serde_json::Value = wait_for_status(get_ep, "status", ["ACTIVE"])
+//!     .failures(["ERROR"])
+//!     .timeout(Duration::from_secs(600))
+//!     .query_async(&client)
+//!     .await?;
+//! ```
+
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use tracing::debug;
+
+use crate::api::ApiError;
+
+#[cfg(feature = "async")]
+use crate::api::{AsyncClient, QueryAsync};
+#[cfg(feature = "sync")]
+use crate::api::{Client, Query};
+
+/// Default total time to wait for the resource.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(1800);
+/// Default delay between first polls.
+const DEFAULT_INTERVAL: Duration = Duration::from_secs(2);
+/// Default maximal delay between polls.
+const DEFAULT_MAX_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Wait parameters common for all the waiters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct WaitOptions {
+    /// Path of the status attribute in the resource (`status`,
+    /// `provisioning_status`, `/server/status`, `a.b`).
+    field: String,
+    /// Failure states.
+    failures: Vec<String>,
+    /// Total time to wait.
+    timeout: Duration,
+    /// Delay before the first poll is repeated.
+    interval: Duration,
+    /// Maximal delay between polls. The delay is doubled after every poll.
+    max_interval: Duration,
+}
+
+impl WaitOptions {
+    fn new(field: &str) -> Self {
+        Self {
+            field: field.into(),
+            failures: Vec::new(),
+            timeout: DEFAULT_TIMEOUT,
+            interval: DEFAULT_INTERVAL,
+            max_interval: DEFAULT_MAX_INTERVAL,
+        }
+    }
+
+    /// Get status of the resource.
+    fn status(&self, data: &Value) -> Option<String> {
+        let pointer = if self.field.starts_with('/') {
+            self.field.clone()
+        } else {
+            format!("/{}", self.field.replace('.', "/"))
+        };
+        data.pointer(&pointer)
+            .and_then(|x| x.as_str())
+            .map(String::from)
+    }
+
+    /// Check whether the status is one of the failure states.
+    fn check_failure<E>(&self, status: &Option<String>) -> Result<(), ApiError<E>>
+    where
+        E: std::error::Error + Send + Sync + 'static,
+    {
+        if let Some(status) = status {
+            if contains(&self.failures, status) {
+                return Err(ApiError::ResourceFailed {
+                    status: status.clone(),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Case insensitive check whether status is present in the list.
+fn contains(states: &[String], status: &str) -> bool {
+    states.iter().any(|x| x.eq_ignore_ascii_case(status))
+}
+
+/// Poll timing with the exponential backoff.
+struct Poller {
+    deadline: Instant,
+    interval: Duration,
+    max_interval: Duration,
+}
+
+impl Poller {
+    fn new(options: &WaitOptions) -> Self {
+        Self {
+            deadline: Instant::now() + options.timeout,
+            interval: options.interval,
+            max_interval: options.max_interval,
+        }
+    }
+
+    /// Return delay before the next poll or an error when the time is over.
+    fn next_delay<E>(&mut self, status: Option<String>) -> Result<Duration, ApiError<E>>
+    where
+        E: std::error::Error + Send + Sync + 'static,
+    {
+        let now = Instant::now();
+        if now >= self.deadline {
+            return Err(ApiError::WaitTimeout { status });
+        }
+        let delay = self.interval.min(self.deadline - now);
+        self.interval = (self.interval * 2).min(self.max_interval);
+        debug!(
+            "Resource status is {:?}, checking again in {:?}",
+            status, delay
+        );
+        Ok(delay)
+    }
+}
+
+/// A query modifier that waits for the resource to reach one of the target
+/// states.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WaitForStatus<E> {
+    endpoint: E,
+    targets: Vec<String>,
+    options: WaitOptions,
+}
+
+/// Wait for the resource fetched with `endpoint` to have `field` set to one
+/// of the `targets` values (case insensitive).
+pub fn wait_for_status<E, I, S>(endpoint: E, field: &str, targets: I) -> WaitForStatus<E>
+where
+    I: IntoIterator<Item = S>,
+    S: Into<String>,
+{
+    WaitForStatus {
+        endpoint,
+        targets: targets.into_iter().map(Into::into).collect(),
+        options: WaitOptions::new(field),
+    }
+}
+
+/// A query modifier that waits for the resource to be deleted (endpoint
+/// returns 404).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WaitForDelete<E> {
+    endpoint: E,
+    options: WaitOptions,
+}
+
+/// Wait for the resource fetched with `endpoint` to disappear.
+pub fn wait_for_delete<E>(endpoint: E) -> WaitForDelete<E> {
+    WaitForDelete {
+        endpoint,
+        options: WaitOptions::new("status"),
+    }
+}
+
+macro_rules! wait_options_setters {
+    ($t:ident) => {
+        impl<E> $t<E> {
+            /// Status attribute path (`status`, `provisioning_status`,
+            /// `a.b` or a JSON pointer).
+            pub fn field(mut self, field: &str) -> Self {
+                self.options.field = field.into();
+                self
+            }
+
+            /// States meaning that the operation failed.
+            pub fn failures<I, S>(mut self, failures: I) -> Self
+            where
+                I: IntoIterator<Item = S>,
+                S: Into<String>,
+            {
+                self.options.failures = failures.into_iter().map(Into::into).collect();
+                self
+            }
+
+            /// Total time to wait.
+            pub fn timeout(mut self, timeout: Duration) -> Self {
+                self.options.timeout = timeout;
+                self
+            }
+
+            /// Delay between first polls. It is doubled after every poll up
+            /// to the `max_interval`.
+            pub fn interval(mut self, interval: Duration) -> Self {
+                self.options.interval = interval;
+                self
+            }
+
+            /// Maximal delay between polls.
+            pub fn max_interval(mut self, max_interval: Duration) -> Self {
+                self.options.max_interval = max_interval;
+                self
+            }
+        }
+    };
+}
+
+wait_options_setters!(WaitForStatus);
+wait_options_setters!(WaitForDelete);
+
+impl<E> WaitForStatus<E> {
+    /// Process the fetched resource. Returns the resource when the target
+    /// state is reached.
+    fn process<T, CE>(&self, data: Value) -> Result<Result<T, Option<String>>, ApiError<CE>>
+    where
+        T: DeserializeOwned,
+        CE: std::error::Error + Send + Sync + 'static,
+    {
+        let status = self.options.status(&data);
+        if let Some(current) = &status {
+            if contains(&self.targets, current) {
+                return serde_json::from_value::<T>(data)
+                    .map(Ok)
+                    .map_err(ApiError::data_type::<T>);
+            }
+        }
+        self.options.check_failure(&status)?;
+        Ok(Err(status))
+    }
+}
+
+impl<E> WaitForDelete<E> {
+    /// Process the fetch result. Returns `true` when the resource is gone.
+    fn process<CE>(
+        &self,
+        res: Result<Value, ApiError<CE>>,
+    ) -> Result<Result<(), Option<String>>, ApiError<CE>>
+    where
+        CE: std::error::Error + Send + Sync + 'static,
+    {
+        match res {
+            Err(ApiError::ResourceNotFound) => Ok(Ok(())),
+            Err(err) => Err(err),
+            Ok(data) => {
+                let status = self.options.status(&data);
+                self.options.check_failure(&status)?;
+                Ok(Err(status))
+            }
+        }
+    }
+}
+
+#[cfg(feature = "sync")]
+impl<E, T, C> Query<T, C> for WaitForStatus<E>
+where
+    E: Query<Value, C>,
+    T: DeserializeOwned,
+    C: Client,
+{
+    fn query(&self, client: &C) -> Result<T, ApiError<C::Error>> {
+        let mut poller = Poller::new(&self.options);
+        loop {
+            let data = self.endpoint.query(client)?;
+            match self.process(data)? {
+                Ok(res) => return Ok(res),
+                Err(status) => std::thread::sleep(poller.next_delay(status)?),
+            }
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait]
+impl<E, T, C> QueryAsync<T, C> for WaitForStatus<E>
+where
+    E: QueryAsync<Value, C> + Sync,
+    T: DeserializeOwned + Send + 'static,
+    C: AsyncClient + Sync,
+{
+    async fn query_async(&self, client: &C) -> Result<T, ApiError<C::Error>> {
+        let mut poller = Poller::new(&self.options);
+        loop {
+            let data = self.endpoint.query_async(client).await?;
+            match self.process(data)? {
+                Ok(res) => return Ok(res),
+                Err(status) => tokio::time::sleep(poller.next_delay(status)?).await,
+            }
+        }
+    }
+}
+
+#[cfg(feature = "sync")]
+impl<E, C> Query<(), C> for WaitForDelete<E>
+where
+    E: Query<Value, C>,
+    C: Client,
+{
+    fn query(&self, client: &C) -> Result<(), ApiError<C::Error>> {
+        let mut poller = Poller::new(&self.options);
+        loop {
+            match self.process(self.endpoint.query(client))? {
+                Ok(res) => return Ok(res),
+                Err(status) => std::thread::sleep(poller.next_delay(status)?),
+            }
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait]
+impl<E, C> QueryAsync<(), C> for WaitForDelete<E>
+where
+    E: QueryAsync<Value, C> + Sync,
+    C: AsyncClient + Sync,
+{
+    async fn query_async(&self, client: &C) -> Result<(), ApiError<C::Error>> {
+        let mut poller = Poller::new(&self.options);
+        loop {
+            match self.process(self.endpoint.query_async(client).await)? {
+                Ok(res) => return Ok(res),
+                Err(status) => tokio::time::sleep(poller.next_delay(status)?).await,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use http::StatusCode;
+    use serde_json::{json, Value};
+
+    use crate::api::rest_endpoint_prelude::*;
+    #[cfg(feature = "sync")]
+    use crate::api::Query;
+    #[cfg(feature = "async")]
+    use crate::api::QueryAsync;
+    use crate::api::{self, ApiError};
+    #[cfg(feature = "async")]
+    use crate::test::client::MockAsyncServerClient;
+    #[cfg(feature = "sync")]
+    use crate::test::client::MockServerClient;
+    use crate::types::ServiceType;
+
+    struct Dummy;
+
+    impl RestEndpoint for Dummy {
+        fn method(&self) -> http::Method {
+            http::Method::GET
+        }
+
+        fn endpoint(&self) -> Cow<'static, str> {
+            "dummy".into()
+        }
+
+        fn service_type(&self) -> ServiceType {
+            ServiceType::Other("dummy".to_string())
+        }
+
+        fn response_key(&self) -> Option<Cow<'static, str>> {
+            Some("resource".into())
+        }
+    }
+
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_wait_status() {
+        let client = MockServerClient::new();
+        let mock = client.server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/dummy");
+            then.status(200)
+                .json_body(json!({"resource": {"id": "foo", "status": "active"}}));
+        });
+
+        let res: Value = api::wait_for_status(Dummy, "status", ["ACTIVE"])
+            .query(&client)
+            .unwrap();
+        assert_eq!(res["id"], "foo");
+        mock.assert();
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_wait_status_async() {
+        let client = MockAsyncServerClient::new().await;
+        let mock = client.server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/dummy");
+            then.status(200).json_body(
+                json!({"resource": {"id": "foo", "provisioning": {"status": "ACTIVE"}}}),
+            );
+        });
+
+        let res: Value = api::wait_for_status(Dummy, "provisioning.status", ["ACTIVE"])
+            .query_async(&client)
+            .await
+            .unwrap();
+        assert_eq!(res["id"], "foo");
+        mock.assert();
+    }
+
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_wait_status_failure() {
+        let client = MockServerClient::new();
+        let mock = client.server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/dummy");
+            then.status(200)
+                .json_body(json!({"resource": {"status": "ERROR"}}));
+        });
+
+        let err = api::wait_for_status(Dummy, "status", ["ACTIVE"])
+            .failures(["error"])
+            .query(&client)
+            .map(|_: Value| ())
+            .unwrap_err();
+        if let ApiError::ResourceFailed { status } = err {
+            assert_eq!(status, "ERROR");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+        mock.assert();
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_wait_status_timeout_async() {
+        let client = MockAsyncServerClient::new().await;
+        let mock = client.server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/dummy");
+            then.status(200)
+                .json_body(json!({"resource": {"status": "BUILD"}}));
+        });
+
+        let err = api::wait_for_status(Dummy, "status", ["ACTIVE"])
+            .timeout(Duration::from_millis(50))
+            .interval(Duration::from_millis(10))
+            .query_async(&client)
+            .await
+            .map(|_: Value| ())
+            .unwrap_err();
+        if let ApiError::WaitTimeout { status } = err {
+            assert_eq!(status, Some("BUILD".into()));
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+        assert!(mock.hits() > 1);
+    }
+
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_wait_delete() {
+        let client = MockServerClient::new();
+        let mock = client.server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/dummy");
+            then.status(StatusCode::NOT_FOUND.into());
+        });
+
+        api::wait_for_delete(Dummy).query(&client).unwrap();
+        mock.assert();
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_wait_delete_failure_async() {
+        let client = MockAsyncServerClient::new().await;
+        let mock = client.server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/dummy");
+            then.status(200)
+                .json_body(json!({"resource": {"status": "error_deleting"}}));
+        });
+
+        let err = api::wait_for_delete(Dummy)
+            .failures(["error_deleting"])
+            .query_async(&client)
+            .await
+            .unwrap_err();
+        if let ApiError::ResourceFailed { status } = err {
+            assert_eq!(status, "error_deleting");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+        mock.assert();
+    }
+}
@@ -138,6 +138,19 @@ pub async fn get_token_auth(url: &mut Url) -> Result<AuthToken, AuthTokenError>
     })
 }
 
+/// Return [`AuthToken`] obtained using the WebSSO for the blocking client
+///
+/// Callback server is asynchronous, therefore a dedicated runtime is started for the duration of
+/// the authentication.
+#[cfg(feature = "sync")]
+pub fn get_token_auth_blocking(url: &mut Url) -> Result<AuthToken, AuthTokenError> {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map_err(WebSsoError::from)?;
+    runtime.block_on(get_token_auth(url))
+}
+
 // Perform WebSSO by opening a browser window with tiny webserver started to capture the callback
 ///
 /// - start callback server
@@ -0,0 +1,576 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Recording and replaying of the HTTP interactions
+//!
+//! A cassette is a JSON file with the HTTP requests and responses of the
+//! session. In the `record` mode every interaction of the
+//! [`AsyncOpenStack`](crate::AsyncOpenStack) with the cloud is appended to
+//! the cassette with tokens, passwords and secrets being redacted. In the
+//! `replay` mode no requests are sent to the cloud and responses are served
+//! from the cassette instead. Recorded interaction is matched by the method,
+//! path template (IDs in the path are ignored) and body of the request.
+//! Interactions are replayed in the order they were recorded, so that
+//! repeated requests (i.e. pagination) get different responses.
+//!
+//! The cassette is enabled with the `OS_CASSETTE` (path to the file) and
+//! `OS_CASSETTE_MODE` (`record` or `replay`, default) environment variables
+//! or with [`AsyncOpenStack::new_with_cassette`](crate::AsyncOpenStack::new_with_cassette).
+//! Authentication caching is disabled while using the cassette.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Mutex;
+
+use bytes::Bytes;
+use http::{HeaderMap, Response as HttpResponse, StatusCode};
+use reqwest::{Client, Request, Response};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use thiserror::Error;
+use tracing::{debug, trace};
+use url::Url;
+
+use crate::error::RestError;
+
+/// Environment variable with the path to the cassette file.
+const CASSETTE_ENV: &str = "OS_CASSETTE";
+
+/// Environment variable with the cassette mode.
+const CASSETTE_MODE_ENV: &str = "OS_CASSETTE_MODE";
+
+/// Replacement of the sensitive data.
+const REDACTED: &str = "REDACTED";
+
+/// Headers carrying credentials.
+const SENSITIVE_HEADERS: &[&str] = &[
+    "authorization",
+    "openstack-auth-receipt",
+    "x-auth-token",
+    "x-service-token",
+    "x-subject-token",
+];
+
+/// Body attributes carrying credentials.
+const SENSITIVE_KEYS: &[&str] = &[
+    "adminPass",
+    "admin_pass",
+    "application_credential_secret",
+    "passcode",
+    "password",
+    "secret",
+];
+
+/// Cassette errors
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum CassetteError {
+    /// Cassette file cannot be read or written
+    #[error("cannot access cassette `{}`: {}", path.display(), source)]
+    IO {
+        /// Path of the cassette
+        path: PathBuf,
+        /// The source of the error.
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// Cassette file content is invalid
+    #[error("cannot parse cassette `{}`: {}", path.display(), source)]
+    Parse {
+        /// Path of the cassette
+        path: PathBuf,
+        /// The source of the error.
+        #[source]
+        source: serde_json::Error,
+    },
+
+    /// Unknown cassette mode
+    #[error("unknown cassette mode `{0}`, expected `record` or `replay`")]
+    UnknownMode(String),
+
+    /// Request has no matching recorded interaction
+    #[error("no recorded interaction for `{} {}`", method, path)]
+    NoInteraction {
+        /// HTTP method
+        method: String,
+        /// Path template of the request
+        path: String,
+    },
+}
+
+/// Cassette mode
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CassetteMode {
+    /// Send requests to the cloud and record interactions
+    Record,
+    /// Serve responses from the recorded interactions
+    Replay,
+}
+
+impl FromStr for CassetteMode {
+    type Err = CassetteError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.to_lowercase().as_str() {
+            "record" => Ok(Self::Record),
+            "replay" => Ok(Self::Replay),
+            _ => Err(CassetteError::UnknownMode(input.into())),
+        }
+    }
+}
+
+/// Recorded body
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(tag = "encoding", content = "data", rename_all = "lowercase")]
+enum RecordedBody {
+    Json(Value),
+    Text(String),
+    Binary(Vec<u8>),
+}
+
+impl RecordedBody {
+    /// Record non empty body redacting credentials
+    fn from_bytes(data: &[u8]) -> Option<Self> {
+        if data.is_empty() {
+            return None;
+        }
+        Some(if let Ok(mut val) = serde_json::from_slice::<Value>(data) {
+            redact_json(&mut val, None);
+            Self::Json(val)
+        } else if let Ok(text) = std::str::from_utf8(data) {
+            Self::Text(text.into())
+        } else {
+            Self::Binary(data.into())
+        })
+    }
+
+    /// Raw body data
+    fn to_bytes(&self) -> Bytes {
+        match self {
+            Self::Json(val) => Bytes::from(val.to_string()),
+            Self::Text(text) => Bytes::from(text.clone()),
+            Self::Binary(data) => Bytes::from(data.clone()),
+        }
+    }
+}
+
+/// Recorded request
+#[derive(Clone, Debug, Deserialize, Serialize)]
+struct RecordedRequest {
+    method: String,
+    uri: String,
+    headers: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body: Option<RecordedBody>,
+}
+
+impl RecordedRequest {
+    fn new(request: &Request) -> Self {
+        Self {
+            method: request.method().to_string(),
+            uri: request.url().to_string(),
+            headers: record_headers(request.headers()),
+            // Streamed bodies are not recorded
+            body: request
+                .body()
+                .and_then(|x| x.as_bytes())
+                .and_then(RecordedBody::from_bytes),
+        }
+    }
+
+    /// Path of the request with IDs replaced by a placeholder
+    fn path_template(&self) -> String {
+        let path = Url::parse(&self.uri)
+            .map(|x| x.path().to_string())
+            .unwrap_or_else(|_| self.uri.clone());
+        path.split('/')
+            .map(|segment| if is_id(segment) { "{id}" } else { segment })
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+
+    /// Whether the recorded request matches the other one
+    fn matches(&self, other: &Self) -> bool {
+        self.method == other.method
+            && self.path_template() == other.path_template()
+            && self.body == other.body
+    }
+}
+
+/// Recorded response
+#[derive(Clone, Debug, Deserialize, Serialize)]
+struct RecordedResponse {
+    status: u16,
+    headers: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body: Option<RecordedBody>,
+}
+
+/// Single request with its response
+#[derive(Clone, Debug, Deserialize, Serialize)]
+struct Interaction {
+    request: RecordedRequest,
+    response: RecordedResponse,
+}
+
+/// Content of the cassette file
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+struct CassetteData {
+    interactions: Vec<Interaction>,
+}
+
+/// Cassette state
+#[derive(Debug, Default)]
+struct CassetteState {
+    data: CassetteData,
+    /// Whether the interaction was already replayed
+    replayed: Vec<bool>,
+}
+
+/// Cassette with the recorded HTTP interactions
+#[derive(Debug)]
+pub struct Cassette {
+    path: PathBuf,
+    mode: CassetteMode,
+    state: Mutex<CassetteState>,
+}
+
+impl Cassette {
+    /// Open the cassette
+    ///
+    /// In the `record` mode new interactions are appended to the existing
+    /// file. Missing file is created.
+    pub fn new<P: Into<PathBuf>>(path: P, mode: CassetteMode) -> Result<Self, CassetteError> {
+        let path = path.into();
+        let data: CassetteData = match fs::read(&path) {
+            Ok(content) => {
+                serde_json::from_slice(&content).map_err(|source| CassetteError::Parse {
+                    path: path.clone(),
+                    source,
+                })?
+            }
+            Err(err) if err.kind() == ErrorKind::NotFound && mode == CassetteMode::Record => {
+                CassetteData::default()
+            }
+            Err(source) => return Err(CassetteError::IO { path, source }),
+        };
+        debug!(
+            "Using cassette {:?} with {} interactions in {:?} mode",
+            path,
+            data.interactions.len(),
+            mode
+        );
+        Ok(Self {
+            path,
+            mode,
+            state: Mutex::new(CassetteState {
+                replayed: vec![false; data.interactions.len()],
+                data,
+            }),
+        })
+    }
+
+    /// Open the cassette configured with the `OS_CASSETTE` and
+    /// `OS_CASSETTE_MODE` environment variables
+    pub fn from_env() -> Result<Option<Self>, CassetteError> {
+        let Some(path) = env::var_os(CASSETTE_ENV) else {
+            return Ok(None);
+        };
+        let mode = match env::var(CASSETTE_MODE_ENV) {
+            Ok(mode) => mode.parse()?,
+            Err(_) => CassetteMode::Replay,
+        };
+        Self::new(path, mode).map(Some)
+    }
+
+    /// Cassette mode
+    pub fn mode(&self) -> CassetteMode {
+        self.mode
+    }
+
+    /// Send the request recording the interaction or replay it
+    pub(crate) async fn execute(
+        &self,
+        client: &Client,
+        request: Request,
+    ) -> Result<Response, RestError> {
+        let recorded_request = RecordedRequest::new(&request);
+        if let CassetteMode::Replay = self.mode {
+            return Ok(self.replay(&recorded_request)?.into());
+        }
+
+        let rsp = client.execute(request).await?;
+        let mut http_rsp = HttpResponse::builder()
+            .status(rsp.status())
+            .version(rsp.version());
+        if let Some(headers) = http_rsp.headers_mut() {
+            headers.extend(rsp.headers().clone());
+        }
+        let body = rsp.bytes().await?;
+        let http_rsp = http_rsp.body(body)?;
+        self.record(Interaction {
+            request: recorded_request,
+            response: RecordedResponse {
+                status: http_rsp.status().as_u16(),
+                headers: record_headers(http_rsp.headers()),
+                body: RecordedBody::from_bytes(http_rsp.body()),
+            },
+        })?;
+        Ok(http_rsp.into())
+    }
+
+    /// Append interaction to the cassette file
+    fn record(&self, interaction: Interaction) -> Result<(), CassetteError> {
+        let mut state = self.state.lock().expect("cassette state is not poisoned");
+        trace!("Recording interaction {:?}", interaction);
+        state.data.interactions.push(interaction);
+        state.replayed.push(true);
+        let content =
+            serde_json::to_vec_pretty(&state.data).map_err(|source| CassetteError::Parse {
+                path: self.path.clone(),
+                source,
+            })?;
+        fs::write(&self.path, content).map_err(|source| CassetteError::IO {
+            path: self.path.clone(),
+            source,
+        })
+    }
+
+    /// Find the recorded response for the request
+    ///
+    /// The first not yet replayed matching interaction is used. When all of
+    /// them were already replayed the last one is repeated.
+    fn replay(&self, request: &RecordedRequest) -> Result<HttpResponse<Bytes>, CassetteError> {
+        let mut state = self.state.lock().expect("cassette state is not poisoned");
+        let state = &mut *state;
+        let interactions = &state.data.interactions;
+        let pos = interactions
+            .iter()
+            .zip(state.replayed.iter())
+            .position(|(x, replayed)| !replayed && x.request.matches(request))
+            .or_else(|| {
+                interactions
+                    .iter()
+                    .rposition(|x| x.request.matches(request))
+            })
+            .ok_or_else(|| CassetteError::NoInteraction {
+                method: request.method.clone(),
+                path: request.path_template(),
+            })?;
+        state.replayed[pos] = true;
+
+        let recorded = &interactions[pos].response;
+        trace!("Replaying response {:?}", recorded);
+        let mut rsp = HttpResponse::builder()
+            .status(StatusCode::from_u16(recorded.status).unwrap_or(StatusCode::OK));
+        for (name, val) in recorded.headers.iter() {
+            rsp = rsp.header(name, val);
+        }
+        Ok(rsp
+            .body(
+                recorded
+                    .body
+                    .as_ref()
+                    .map(RecordedBody::to_bytes)
+                    .unwrap_or_default(),
+            )
+            .expect("recorded response is valid"))
+    }
+}
+
+/// Convert headers into the map redacting credentials
+fn record_headers(headers: &HeaderMap) -> BTreeMap<String, String> {
+    let mut res: BTreeMap<String, String> = BTreeMap::new();
+    for (name, val) in headers.iter() {
+        let val = if SENSITIVE_HEADERS.contains(&name.as_str()) {
+            REDACTED
+        } else {
+            val.to_str().unwrap_or_default()
+        };
+        res.entry(name.to_string())
+            .and_modify(|x| {
+                x.push_str(", ");
+                x.push_str(val);
+            })
+            .or_insert_with(|| val.to_string());
+    }
+    res
+}
+
+/// Redact credentials in the JSON document
+///
+/// Besides the well known attributes the `id` of the `token` used for the
+/// authentication is redacted.
+fn redact_json(val: &mut Value, parent: Option<&str>) {
+    match val {
+        Value::Object(attrs) => {
+            for (key, attr) in attrs.iter_mut() {
+                if attr.is_string()
+                    && (SENSITIVE_KEYS.contains(&key.as_str())
+                        || (parent == Some("token") && key == "id"))
+                {
+                    *attr = REDACTED.into();
+                } else {
+                    redact_json(attr, Some(key));
+                }
+            }
+        }
+        Value::Array(items) => {
+            for item in items.iter_mut() {
+                redact_json(item, parent);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Whether the path segment is a resource ID
+fn is_id(segment: &str) -> bool {
+    let segment = segment.strip_prefix("AUTH_").unwrap_or(segment);
+    let hex = segment.replace('-', "");
+    (segment.len() >= 16 && !hex.is_empty() && hex.chars().all(|x| x.is_ascii_hexdigit()))
+        || (!segment.is_empty() && segment.chars().all(|x| x.is_ascii_digit()))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::api::network::v2::network;
+    use crate::api::QueryAsync;
+    use crate::test_utils::FakeCloud;
+    use crate::types::ServiceType;
+    use crate::AsyncOpenStack;
+
+    #[test]
+    fn test_redact_json() {
+        let mut val = json!({
+            "auth": {
+                "identity": {
+                    "password": {"user": {"name": "admin", "password": "secret"}},
+                    "token": {"id": "foo"}
+                }
+            },
+            "server": {"name": "srv", "adminPass": "bar"}
+        });
+        redact_json(&mut val, None);
+        assert_eq!(
+            val,
+            json!({
+                "auth": {
+                    "identity": {
+                        "password": {"user": {"name": "admin", "password": "REDACTED"}},
+                        "token": {"id": "REDACTED"}
+                    }
+                },
+                "server": {"name": "srv", "adminPass": "REDACTED"}
+            })
+        );
+    }
+
+    #[test]
+    fn test_path_template() {
+        let req = RecordedRequest {
+            method: "GET".into(),
+            uri: "http://localhost:8080/v1/AUTH_0f1e2d3c4b5a69788796a5b4c3d2e1f0/cont?limit=1"
+                .into(),
+            headers: BTreeMap::new(),
+            body: None,
+        };
+        assert_eq!(req.path_template(), "/v1/{id}/cont");
+        let req = RecordedRequest {
+            uri: "http://localhost/v2.0/ports/9d6b3c2a-5c8e-4b52-8a4f-0d1e2f3a4b5c".into(),
+            ..req
+        };
+        assert_eq!(req.path_template(), "/v2.0/ports/{id}");
+    }
+
+    #[test]
+    fn test_mode() {
+        assert_eq!(
+            "Record".parse::<CassetteMode>().unwrap(),
+            CassetteMode::Record
+        );
+        assert!(matches!(
+            "foo".parse::<CassetteMode>(),
+            Err(CassetteError::UnknownMode(_))
+        ));
+        assert!(matches!(
+            Cassette::new("/nonexisting/cassette.json", CassetteMode::Replay),
+            Err(CassetteError::IO { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_record_replay() {
+        let path = env::temp_dir().join(format!("cassette-{}.json", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let cloud = FakeCloud::new();
+        let config = cloud.cloud_config();
+
+        let ep = network::create::Request::builder()
+            .network(
+                network::create::NetworkBuilder::default()
+                    .name("net")
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        let mut session = AsyncOpenStack::new_with_cassette(
+            &config,
+            Cassette::new(&path, CassetteMode::Record).unwrap(),
+        )
+        .await
+        .unwrap();
+        session
+            .discover_service_endpoint(&ServiceType::Network)
+            .await
+            .unwrap();
+        let recorded: Value = ep.query_async(&session).await.unwrap();
+        drop(cloud);
+
+        let content = fs::read_to_string(&path).unwrap();
+        assert!(content.contains(r#""password": "REDACTED""#));
+        assert!(!content.contains("fake-"));
+
+        let mut session = AsyncOpenStack::new_with_cassette(
+            &config,
+            Cassette::new(&path, CassetteMode::Replay).unwrap(),
+        )
+        .await
+        .unwrap();
+        session
+            .discover_service_endpoint(&ServiceType::Network)
+            .await
+            .unwrap();
+        let replayed: Value = ep.query_async(&session).await.unwrap();
+        assert_eq!(recorded, replayed);
+
+        let res: Result<Value, _> = network::get::Request::builder()
+            .id("foo")
+            .build()
+            .unwrap()
+            .query_async(&session)
+            .await;
+        assert!(res.is_err());
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -17,7 +17,7 @@
 use bytes::Bytes;
 use serde::de::Deserializer;
 use serde::Deserialize;
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 
 use anyhow::Context;
 use thiserror::Error;
@@ -42,6 +42,18 @@ pub enum CatalogError {
         #[from]
         source: url::ParseError,
     },
+    #[error("API version `{requested}` is not advertised by the `{url}` endpoint")]
+    VersionNotAvailable {
+        /// Requested API version
+        requested: String,
+        /// Service endpoint URL
+        url: String,
+    },
+    #[error("region `{region}` is not present in the service catalog")]
+    RegionNotAvailable {
+        /// Requested region
+        region: String,
+    },
     #[error(transparent)]
     Other(#[from] anyhow::Error),
 }
@@ -49,10 +61,16 @@ pub enum CatalogError {
 /// ServiceEndpoint data
 #[derive(Debug, Clone)]
 pub struct ServiceEndpoint {
+    /// Base URL of the service API
     pub url: Url,
+    /// Whether the version discovery has been performed
     pub discovered: bool,
+    /// All versions advertised by the version discovery document
     pub versions: Vec<EndpointVersion>,
+    /// Version used as the base of the endpoint
     pub current_version: Option<EndpointVersion>,
+    /// Aliases of the API extensions enabled for the service (once fetched)
+    pub extensions: Option<BTreeSet<String>>,
 }
 
 impl ServiceEndpoint {
@@ -64,6 +82,7 @@ impl ServiceEndpoint {
             discovered: false,
             versions: Vec::new(),
             current_version: None,
+            extensions: None,
         })
     }
 
@@ -117,6 +136,84 @@ impl ServiceEndpoint {
         }
         Ok(())
     }
+
+    /// Find the advertised version satisfying the requested one
+    ///
+    /// Requested version is given as `<major>[.<minor>]` (optionally prefixed
+    /// with `v`, i.e. `2`, `v3` or `2.16`). A version with the same major and
+    /// the same or higher minor (in the version id or in the supported
+    /// microversion range) matches. Current version is preferred, otherwise
+    /// the highest matching version is returned.
+    pub fn find_version(&self, requested: &str) -> Option<&EndpointVersion> {
+        let (major, minor) = parse_version(requested)?;
+        self.versions
+            .iter()
+            .filter(|ver| ver.satisfies(major, minor))
+            .max_by_key(|ver| {
+                (
+                    ver.status == EndpointVersionStatus::Current,
+                    parse_version(&ver.id).map(|(major, minor)| (major, minor.unwrap_or(0))),
+                )
+            })
+    }
+
+    /// Use the advertised version satisfying the requested one as the base
+    /// of the endpoint
+    pub fn select_version(&mut self, requested: &str) -> Result<(), CatalogError> {
+        let ver = self.find_version(requested).cloned().ok_or_else(|| {
+            CatalogError::VersionNotAvailable {
+                requested: requested.to_string(),
+                url: self.url.to_string(),
+            }
+        })?;
+        if let Some(link) = ver.links.iter().find(|link| link.rel == "self") {
+            self.url = self.rebase_url(&link.href)?;
+            info!(
+                "Using URL `{}` of the requested version `{}` as the base",
+                self.url, requested
+            );
+        }
+        self.current_version = Some(ver);
+        Ok(())
+    }
+
+    /// Build the endpoint URL for the version link
+    ///
+    /// Scheme and host of the catalog URL are kept (the version document
+    /// may be behind the proxy), while anything following the version in the
+    /// catalog URL (i.e. `/v3/<project_id>`) is appended to the link path.
+    fn rebase_url(&self, href: &str) -> Result<Url, CatalogError> {
+        let link = Url::parse(href).with_context(|| format!("Wrong endpoint URL: `{}`", href))?;
+        let segments: Vec<&str> = self
+            .url
+            .path_segments()
+            .map(|x| x.filter(|x| !x.is_empty()).collect())
+            .unwrap_or_default();
+        let suffix = segments
+            .iter()
+            .position(|x| x.starts_with('v') && parse_version(x).is_some())
+            .map_or(&segments[..0], |pos| &segments[pos + 1..]);
+
+        let mut path: String = link.path().trim_end_matches('/').into();
+        for segment in suffix {
+            path.push('/');
+            path.push_str(segment);
+        }
+        // Path MUST end with "/"
+        path.push('/');
+        let mut url = self.url.clone();
+        url.set_path(&path);
+        Ok(url)
+    }
+}
+
+/// Parse version string (`v2.1`, `3`) into the major and optional minor
+fn parse_version(ver: &str) -> Option<(u32, Option<u32>)> {
+    let ver = ver.trim().trim_start_matches(['v', 'V']);
+    match ver.split_once('.') {
+        Some((major, minor)) => Some((major.parse().ok()?, Some(minor.parse().ok()?))),
+        None => Some((ver.parse().ok()?, None)),
+    }
 }
 
 /// Endpoint version status
@@ -173,6 +270,34 @@ pub struct EndpointVersion {
     pub links: Vec<Link>,
 }
 
+impl EndpointVersion {
+    /// Whether the version satisfies the requested major and minor version
+    fn satisfies(&self, major: u32, minor: Option<u32>) -> bool {
+        let Some((id_major, id_minor)) = parse_version(&self.id) else {
+            return false;
+        };
+        if id_major != major {
+            return false;
+        }
+        let Some(minor) = minor else {
+            return true;
+        };
+        if id_minor.unwrap_or(0) >= minor {
+            return true;
+        }
+        // Microversions range of the version (i.e. compute `2.1` - `2.96`)
+        let range = |ver: &Option<String>| {
+            ver.as_deref()
+                .and_then(parse_version)
+                .map(|(major, minor)| (major, minor.unwrap_or(0)))
+        };
+        match (range(&self.min_version), range(&self.version)) {
+            (Some(min), Some(max)) => min <= (major, minor) && (major, minor) <= max,
+            _ => false,
+        }
+    }
+}
+
 /// `Versions` array of Endpoint Versions
 #[derive(Debug, Deserialize, Clone)]
 pub(crate) struct EndpointVersions {
@@ -199,6 +324,10 @@ pub(crate) struct Catalog {
     token_catalog: Option<Vec<ServiceEndpoints>>,
     /// Configured endpoint overrides
     endpoint_overrides: HashMap<String, ServiceEndpoint>,
+    /// Configured API versions (`<service>_api_version`)
+    api_versions: HashMap<String, String>,
+    /// Region the endpoints are selected for (all regions when unset)
+    region: Option<String>,
 }
 
 impl Catalog {
@@ -221,7 +350,10 @@ impl Catalog {
         //// Set the URL from catalog/input respecting known overrides
         self.service_endpoints.insert(
             service_type.to_string(),
-            match self.endpoint_overrides.get(&service_type.to_string()) {
+            match self
+                .endpoint_overrides
+                .get(&ServiceType::from(service_type).to_string())
+            {
                 Some(ep) => ep.clone(),
                 None => self.build_service_endpoint(url)?,
             },
@@ -236,6 +368,17 @@ impl Catalog {
         interface: Option<&str>,
     ) -> Result<(), CatalogError> {
         trace!("Start processing ServiceCatalog response");
+        if let Some(region) = &self.region {
+            if !srv_endpoints.is_empty()
+                && !srv_endpoints
+                    .iter()
+                    .any(|srv| srv.endpoints.iter().any(|ep| &ep.region == region))
+            {
+                return Err(CatalogError::RegionNotAvailable {
+                    region: region.clone(),
+                });
+            }
+        }
         let mut token_catalog = Vec::new();
         let intf = interface.unwrap_or("public");
         for srv in srv_endpoints {
@@ -243,7 +386,12 @@ impl Catalog {
             token_catalog.push(srv.clone());
             for ep in &srv.endpoints {
                 trace!("Processing endpoint {:?}", ep);
-                if ep.interface == intf {
+                if ep.interface == intf
+                    && self
+                        .region
+                        .as_ref()
+                        .map_or(true, |region| &ep.region == region)
+                {
                     self.add_service_endpoint(&srv.service_type, &ep.url)
                         .with_context(|| {
                             format!(
@@ -288,19 +436,82 @@ impl Catalog {
         data: &Bytes,
     ) -> Result<(), CatalogError> {
         for cat_type in service_type.get_supported_catalog_types() {
-            if let Some(sep) = self.service_endpoints.get_mut(&cat_type.to_string()) {
-                return sep.process_discovery(data);
+            if let Some(sep) = self.service_endpoints.get(&cat_type.to_string()) {
+                // Endpoint is only updated when the requested version is
+                // available so that the discovery can proceed with other
+                // version documents otherwise.
+                let mut sep = sep.clone();
+                sep.process_discovery(data)?;
+                if let (Some(requested), false) =
+                    (self.get_api_version(service_type), sep.versions.is_empty())
+                {
+                    sep.select_version(requested)?;
+                }
+                self.service_endpoints.insert(cat_type.to_string(), sep);
+                return Ok(());
             }
         }
 
         Ok(())
     }
 
+    /// Save aliases of the API extensions enabled for the service type
+    pub(crate) fn set_service_extensions(
+        &mut self,
+        service_type: &ServiceType,
+        extensions: BTreeSet<String>,
+    ) {
+        for cat_type in service_type.get_supported_catalog_types() {
+            if let Some(sep) = self.service_endpoints.get_mut(cat_type) {
+                sep.extensions = Some(extensions);
+                return;
+            }
+        }
+        if let Some(sep) = self.endpoint_overrides.get_mut(&service_type.to_string()) {
+            sep.extensions = Some(extensions);
+        }
+    }
+
+    /// Get API version configured for the service type
+    pub(crate) fn get_api_version(&self, service_type: &ServiceType) -> Option<&String> {
+        self.api_versions.get(&service_type.to_string())
+    }
+
+    /// Set API version to be used for the service type
+    pub(crate) fn set_api_version<S: Into<String>>(
+        &mut self,
+        service_type: &ServiceType,
+        version: S,
+    ) -> &mut Self {
+        self.api_versions
+            .insert(service_type.to_string(), version.into());
+        self
+    }
+
     /// Return catalog endpoints as returned in the authorization response
     pub fn get_token_catalog(&self) -> Option<Vec<ServiceEndpoints>> {
         self.token_catalog.clone()
     }
 
+    /// Return sorted names of the regions present in the token catalog
+    pub(crate) fn get_regions(&self) -> Vec<String> {
+        let mut regions: Vec<String> = self
+            .token_catalog
+            .iter()
+            .flatten()
+            .flat_map(|srv| srv.endpoints.iter().map(|ep| ep.region.clone()))
+            .collect();
+        regions.sort();
+        regions.dedup();
+        regions
+    }
+
+    /// Set region the service endpoints are selected for
+    pub(crate) fn set_region(&mut self, region: Option<String>) -> &mut Self {
+        self.region = region;
+        self
+    }
+
     // Save endpoint overrides given in the config
     pub fn set_endpoint_overrides(
         &mut self,
@@ -310,7 +521,8 @@ impl Catalog {
             if name.ends_with("_endpoint_override") {
                 let len = name.len();
                 let srv_type = &name[..(len - 18)];
-                let service_type = &srv_type.replace('_', "-");
+                // Overrides may be given for any alias of the service type
+                let service_type = ServiceType::from(srv_type.replace('_', "-").as_str());
 
                 self.endpoint_overrides.insert(
                     service_type.to_string(),
@@ -320,4 +532,188 @@ impl Catalog {
         }
         Ok(self)
     }
+
+    // Save API versions given in the config
+    pub fn set_api_versions(&mut self, config: &CloudConfig) -> &mut Self {
+        for (name, val) in config.options.iter() {
+            if let Some(srv_type) = name.strip_suffix("_api_version") {
+                let service_type = ServiceType::from(srv_type.replace('_', "-").as_str());
+                self.api_versions
+                    .insert(service_type.to_string(), val.to_string());
+            }
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+    use serde_json::json;
+    use std::collections::BTreeSet;
+
+    use super::{Catalog, CatalogError, ServiceEndpoint};
+    use crate::config::CloudConfig;
+    use crate::types::identity::v3::ServiceEndpoints;
+    use crate::types::ServiceType;
+
+    fn volume_versions() -> Bytes {
+        Bytes::from(
+            json!({"versions": [
+                {
+                    "id": "v2.0",
+                    "status": "DEPRECATED",
+                    "links": [{"href": "http://proxy/volume/v2/", "rel": "self"}],
+                },
+                {
+                    "id": "v3.0",
+                    "status": "CURRENT",
+                    "version": "3.71",
+                    "min_version": "3.0",
+                    "links": [{"href": "http://localhost/volume/v3/", "rel": "self"}],
+                },
+            ]})
+            .to_string(),
+        )
+    }
+
+    #[test]
+    fn test_find_version() {
+        let mut ep = ServiceEndpoint::from_url_string("http://localhost/volume/v3/p1/").unwrap();
+        ep.process_discovery(&volume_versions()).unwrap();
+        assert_eq!(ep.versions.len(), 2);
+        assert_eq!(ep.find_version("2").unwrap().id, "v2.0");
+        assert_eq!(ep.find_version("v3").unwrap().id, "v3.0");
+        assert_eq!(ep.find_version("3.50").unwrap().id, "v3.0");
+        assert!(ep.find_version("3.72").is_none());
+        assert!(ep.find_version("1").is_none());
+        assert!(ep.find_version("foo").is_none());
+    }
+
+    #[test]
+    fn test_select_version() {
+        let mut ep = ServiceEndpoint::from_url_string("http://localhost/volume/v3/p1/").unwrap();
+        ep.process_discovery(&volume_versions()).unwrap();
+        ep.select_version("2").unwrap();
+        assert_eq!(ep.url.as_str(), "http://localhost/volume/v2/p1/");
+        assert_eq!(ep.current_version.as_ref().unwrap().id, "v2.0");
+        assert!(matches!(
+            ep.select_version("4"),
+            Err(CatalogError::VersionNotAvailable { .. })
+        ));
+    }
+
+    #[test]
+    fn test_catalog_aliases() {
+        let mut config = CloudConfig::default();
+        config.options.insert(
+            "volume_endpoint_override".into(),
+            config::Value::from("http://override/volume/v3"),
+        );
+        config
+            .options
+            .insert("block_store_api_version".into(), config::Value::from("3"));
+        let mut catalog = Catalog::default();
+        catalog
+            .set_endpoint_overrides(&config)
+            .unwrap()
+            .set_api_versions(&config);
+        catalog
+            .add_service_endpoint("volumev3", "http://localhost/volume/v3")
+            .unwrap();
+        catalog
+            .add_service_endpoint("sharev2", "http://localhost/share/v2")
+            .unwrap();
+
+        for name in ["block-storage", "volumev2", "volume"] {
+            let ep = catalog
+                .get_service_endpoint(&ServiceType::from(name))
+                .unwrap();
+            assert_eq!(ep.url.as_str(), "http://override/volume/v3/");
+        }
+        assert_eq!(
+            catalog.get_api_version(&ServiceType::Other("volumev3".into())),
+            Some(&"3".to_string())
+        );
+        let ep = catalog
+            .get_service_endpoint(&ServiceType::from("shared-file-system"))
+            .unwrap();
+        assert_eq!(ep.url.as_str(), "http://localhost/share/v2/");
+    }
+
+    #[test]
+    fn test_catalog_api_version() {
+        let mut catalog = Catalog::default();
+        catalog
+            .add_service_endpoint("volumev3", "http://localhost/volume/v3/p1")
+            .unwrap();
+        catalog.set_api_version(&ServiceType::BlockStorage, "2");
+        catalog
+            .process_endpoint_discovery(&ServiceType::BlockStorage, &volume_versions())
+            .unwrap();
+        let ep = catalog
+            .get_service_endpoint(&ServiceType::BlockStorage)
+            .unwrap();
+        assert_eq!(ep.url.as_str(), "http://localhost/volume/v2/p1/");
+
+        catalog.set_api_version(&ServiceType::BlockStorage, "4");
+        assert!(catalog
+            .process_endpoint_discovery(&ServiceType::BlockStorage, &volume_versions())
+            .is_err());
+    }
+
+    #[test]
+    fn test_catalog_extensions() {
+        let mut catalog = Catalog::default();
+        catalog
+            .add_service_endpoint("network", "http://localhost/network")
+            .unwrap();
+        assert!(catalog
+            .get_service_endpoint(&ServiceType::Network)
+            .unwrap()
+            .extensions
+            .is_none());
+        catalog.set_service_extensions(
+            &ServiceType::Network,
+            BTreeSet::from(["vpnaas".to_string()]),
+        );
+        let ep = catalog.get_service_endpoint(&ServiceType::Network).unwrap();
+        assert!(ep.extensions.unwrap().contains("vpnaas"));
+    }
+
+    #[test]
+    fn test_catalog_region() {
+        let srv_endpoints: Vec<ServiceEndpoints> = serde_json::from_value(json!([{
+            "type": "compute",
+            "name": "nova",
+            "endpoints": [
+                {"id": "1", "interface": "public", "region": "R1", "url": "http://r1/compute"},
+                {"id": "2", "interface": "public", "region": "R2", "url": "http://r2/compute"},
+                {"id": "3", "interface": "internal", "region": "R1", "url": "http://int/compute"},
+            ],
+        }]))
+        .unwrap();
+
+        let mut catalog = Catalog::default();
+        catalog.set_region(Some("R1".into()));
+        catalog
+            .process_catalog_endpoints(&srv_endpoints, Some("public"))
+            .unwrap();
+        assert_eq!(catalog.get_regions(), vec!["R1", "R2"]);
+        let ep = catalog.get_service_endpoint(&ServiceType::Compute).unwrap();
+        assert_eq!(ep.url.as_str(), "http://r1/compute/");
+
+        catalog.set_region(Some("R2".into()));
+        catalog
+            .process_catalog_endpoints(&srv_endpoints, Some("public"))
+            .unwrap();
+        let ep = catalog.get_service_endpoint(&ServiceType::Compute).unwrap();
+        assert_eq!(ep.url.as_str(), "http://r2/compute/");
+
+        catalog.set_region(Some("R3".into()));
+        assert!(matches!(
+            catalog.process_catalog_endpoints(&srv_endpoints, Some("public")),
+            Err(CatalogError::RegionNotAvailable { .. })
+        ));
+    }
 }
@@ -0,0 +1,321 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Connection manager for multiple clouds and regions
+//!
+//! A single token is valid for all regions of the cloud. [`CloudPool`]
+//! authenticates only once for every identity (authentication URL, user and
+//! scope) and derives region bound sessions from it sharing the token and
+//! the service catalog.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+
+use futures::future::join_all;
+use tokio::sync::OnceCell;
+use tracing::debug;
+
+use crate::auth::authtoken::AuthTokenScope;
+use crate::config::{get_config_identity_hash, CloudConfig, ConfigError, ConfigFile};
+use crate::error::{OpenStackError, OpenStackResult};
+use crate::AsyncOpenStack;
+
+/// Key of the shared session: identity hash and the authorization scope
+type SessionKey = (u64, AuthTokenScope);
+
+/// Data tagged with the cloud and region it belongs to
+#[derive(Clone, Debug)]
+pub struct Tagged<T> {
+    /// Cloud name
+    pub cloud: String,
+    /// Region name (`None` when the session is not bound to a region)
+    pub region: Option<String>,
+    /// The data
+    pub data: T,
+}
+
+/// Pool of connections to all clouds of the configuration
+///
+/// ```rust,no_run
+/// use openstack_sdk::api::network::v2::network::list;
+/// use openstack_sdk::api::QueryAsync;
+/// use openstack_sdk::types::ServiceType;
+/// use openstack_sdk::{config::ConfigFile, CloudPool, OpenStackError};
+///
+/// async fn list_networks() -> Result<(), OpenStackError> {
+///     let pool = CloudPool::new(ConfigFile::new()?);
+///     let results = pool
+///         .fan_out(|mut session| async move {
+///             session
+///                 .discover_service_endpoint(&ServiceType::Network)
+///                 .await?;
+///             let ep = list::Request::builder().build().unwrap();
+///             let data: Vec<serde_json::Value> = ep.query_async(&session).await?;
+///             Ok::<_, OpenStackError>(data)
+///         })
+///         .await;
+///     for res in results {
+///         println!("{}/{:?}: {:?}", res.cloud, res.region, res.data);
+///     }
+///     Ok(())
+/// }
+/// ```
+pub struct CloudPool {
+    /// Configuration of the clouds
+    config: ConfigFile,
+    /// Authenticated sessions by the identity hash and the scope
+    sessions: Mutex<HashMap<SessionKey, Arc<OnceCell<AsyncOpenStack>>>>,
+}
+
+impl CloudPool {
+    /// Create new pool for the clouds of the configuration
+    ///
+    /// Connections are established lazily on the first use.
+    pub fn new(config: ConfigFile) -> Self {
+        Self {
+            config,
+            sessions: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Return sorted names of the configured clouds
+    pub fn clouds(&self) -> Vec<String> {
+        let mut clouds: Vec<String> = self
+            .config
+            .clouds
+            .iter()
+            .flat_map(|x| x.keys().cloned())
+            .collect();
+        clouds.sort();
+        clouds
+    }
+
+    /// Return session for the cloud bound to the region
+    ///
+    /// When no region is given the `region_name` of the cloud configuration is
+    /// used.
+    pub async fn session(
+        &self,
+        cloud: &str,
+        region: Option<&str>,
+    ) -> OpenStackResult<AsyncOpenStack> {
+        let mut config = self.cloud_config(cloud)?;
+        if let Some(region) = region {
+            config.region_name = Some(region.to_string());
+        }
+        self.identity_session(&config).await?.with_config(&config)
+    }
+
+    /// Return regions of the cloud
+    ///
+    /// This is the configured `region_name` of the cloud or all regions of
+    /// the service catalog otherwise.
+    pub async fn regions(&self, cloud: &str) -> OpenStackResult<Vec<String>> {
+        let config = self.cloud_config(cloud)?;
+        match &config.region_name {
+            Some(region) => Ok(vec![region.clone()]),
+            None => Ok(self.identity_session(&config).await?.get_regions()),
+        }
+    }
+
+    /// Execute the query in every region of every cloud
+    ///
+    /// Queries are executed concurrently and the results are returned tagged
+    /// with the cloud and the region ordered by the cloud name. Failure to
+    /// connect to the cloud is reported as a single result of the cloud.
+    pub async fn fan_out<F, Fut, T, E>(&self, f: F) -> Vec<Tagged<Result<T, E>>>
+    where
+        F: Fn(AsyncOpenStack) -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+        E: From<OpenStackError>,
+    {
+        let clouds = self.clouds();
+        let targets = join_all(clouds.iter().map(|cloud| self.region_sessions(cloud))).await;
+
+        let mut failed = Vec::new();
+        let mut queries = Vec::new();
+        for (cloud, sessions) in clouds.into_iter().zip(targets) {
+            match sessions {
+                Ok(sessions) => {
+                    for session in sessions {
+                        let tag = (cloud.clone(), session.get_region().map(String::from));
+                        queries.push(async { (tag, f(session).await) });
+                    }
+                }
+                Err(err) => {
+                    debug!("Connection to the cloud {} failed: {}", cloud, err);
+                    let region = self.cloud_config(&cloud).ok().and_then(|x| x.region_name);
+                    failed.push(Tagged {
+                        cloud,
+                        region,
+                        data: Err(err.into()),
+                    });
+                }
+            }
+        }
+
+        let mut results: Vec<Tagged<Result<T, E>>> = join_all(queries)
+            .await
+            .into_iter()
+            .map(|((cloud, region), data)| Tagged {
+                cloud,
+                region,
+                data,
+            })
+            .collect();
+        results.extend(failed);
+        results.sort_by(|a, b| (&a.cloud, &a.region).cmp(&(&b.cloud, &b.region)));
+        results
+    }
+
+    /// Get connection configuration of the cloud
+    fn cloud_config(&self, cloud: &str) -> OpenStackResult<CloudConfig> {
+        Ok(self
+            .config
+            .get_cloud_config(cloud.to_string())?
+            .ok_or_else(|| ConfigError::CloudNotFound(cloud.to_string()))?)
+    }
+
+    /// Get authenticated session shared by all clouds of the same identity
+    async fn identity_session(&self, config: &CloudConfig) -> OpenStackResult<AsyncOpenStack> {
+        let key = (
+            get_config_identity_hash(config),
+            AuthTokenScope::try_from(config)?,
+        );
+        let cell = self
+            .sessions
+            .lock()
+            .expect("cloud pool sessions are not poisoned")
+            .entry(key)
+            .or_default()
+            .clone();
+        let session = cell.get_or_try_init(|| AsyncOpenStack::new(config)).await?;
+        Ok(session.clone())
+    }
+
+    /// Get sessions for all regions of the cloud
+    async fn region_sessions(&self, cloud: &str) -> OpenStackResult<Vec<AsyncOpenStack>> {
+        let config = self.cloud_config(cloud)?;
+        let session = self.identity_session(&config).await?;
+        if config.region_name.is_some() {
+            return Ok(vec![session.with_config(&config)?]);
+        }
+        let regions = session.get_regions();
+        if regions.is_empty() {
+            return Ok(vec![session.with_config(&config)?]);
+        }
+        regions
+            .iter()
+            .map(|region| {
+                let mut config = config.clone();
+                config.region_name = Some(region.clone());
+                session.with_config(&config)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use serde_json::{json, Value};
+
+    use super::CloudPool;
+    use crate::api::network::v2::network::list;
+    use crate::api::QueryAsync;
+    use crate::config::{ConfigError, ConfigFile};
+    use crate::error::OpenStackError;
+    use crate::test_utils::{FakeCloud, FakeResource};
+    use crate::types::ServiceType;
+
+    fn pool(cloud: &FakeCloud, other: &FakeCloud) -> CloudPool {
+        let mut unbound = cloud.cloud_config();
+        unbound.region_name = None;
+        let mut denied = other.cloud_config();
+        if let Some(auth) = denied.auth.as_mut() {
+            auth.password = Some("wrong".into());
+        }
+        CloudPool::new(ConfigFile {
+            cache: None,
+            clouds: Some(HashMap::from([
+                ("a".to_string(), cloud.cloud_config()),
+                ("b".to_string(), unbound),
+                ("c".to_string(), denied),
+            ])),
+            public_clouds: None,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_fan_out() {
+        let cloud = FakeCloud::new();
+        let other = FakeCloud::new();
+        cloud.add_resource(FakeResource::Network, json!({"name": "net"}));
+        let pool = pool(&cloud, &other);
+        assert_eq!(pool.clouds(), vec!["a", "b", "c"]);
+
+        let results = pool
+            .fan_out(|mut session| async move {
+                session
+                    .discover_service_endpoint(&ServiceType::Network)
+                    .await?;
+                let data: Vec<Value> = list::Request::builder()
+                    .build()
+                    .unwrap()
+                    .query_async(&session)
+                    .await?;
+                Ok::<_, OpenStackError>((session.get_auth_token(), data.len()))
+            })
+            .await;
+        assert_eq!(results.len(), 3);
+        let tags: Vec<(&str, Option<&str>)> = results
+            .iter()
+            .map(|x| (x.cloud.as_str(), x.region.as_deref()))
+            .collect();
+        assert_eq!(
+            tags,
+            vec![
+                ("a", Some("RegionOne")),
+                ("b", Some("RegionOne")),
+                ("c", Some("RegionOne"))
+            ]
+        );
+        let (token_a, count_a) = results[0].data.as_ref().unwrap();
+        let (token_b, count_b) = results[1].data.as_ref().unwrap();
+        assert_eq!((count_a, count_b), (&1, &1));
+        // Both clouds share the same identity and thus the token
+        assert_eq!(token_a, token_b);
+        assert!(results[2].data.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_session() {
+        let cloud = FakeCloud::new();
+        let other = FakeCloud::new();
+        let pool = pool(&cloud, &other);
+
+        assert_eq!(pool.regions("b").await.unwrap(), vec!["RegionOne"]);
+        let session = pool.session("b", Some("RegionOne")).await.unwrap();
+        assert_eq!(session.get_region(), Some("RegionOne"));
+        assert!(pool.session("a", Some("RegionTwo")).await.is_err());
+        assert!(matches!(
+            pool.session("missing", None).await,
+            Err(OpenStackError::ConfigError {
+                source: ConfigError::CloudNotFound(_)
+            })
+        ));
+    }
+}
@@ -70,6 +70,8 @@ impl ConfigError {
 #[derive(Deserialize, Debug, Clone)]
 pub struct CacheConfig {
     pub auth: Option<bool>,
+    /// Time in seconds the service version discovery documents are cached for
+    pub discovery_ttl: Option<u64>,
 }
 
 /// ConfigFile structure
@@ -225,6 +227,18 @@ pub fn get_config_identity_hash(config: &CloudConfig) -> u64 {
 
 /// CloudConfig struct implementation
 impl CloudConfig {
+    /// Whether the request data should be validated client side before
+    /// sending (`validate_requests` option, enabled by default).
+    ///
+    /// Clouds with vendor extensions may accept values not allowed by the
+    /// upstream API and require the validation to be disabled.
+    pub fn validate_requests(&self) -> bool {
+        self.options
+            .get("validate_requests")
+            .and_then(|x| x.clone().into_bool().ok())
+            .unwrap_or(true)
+    }
+
     /// Update unset CloudConfig with values from the `update` var
     pub fn update(&mut self, update: &CloudConfig) {
         if let Some(update_auth) = &update.auth {
@@ -401,6 +415,15 @@ impl ConfigFile {
     pub fn is_auth_cache_enabled(&self) -> bool {
         self.cache.as_ref().and_then(|c| c.auth).unwrap_or(true)
     }
+
+    /// Return time in seconds the version discovery documents are cached for
+    /// (1 day by default, `0` disables the caching)
+    pub fn discovery_cache_ttl(&self) -> u64 {
+        self.cache
+            .as_ref()
+            .and_then(|c| c.discovery_ttl)
+            .unwrap_or(86400)
+    }
 }
 
 #[cfg(test)]
@@ -22,6 +22,8 @@ use crate::auth::{
     authtoken::AuthTokenError, authtoken_scope::AuthTokenScopeError, v3websso::WebSsoError,
     AuthError,
 };
+#[cfg(feature = "async")]
+use crate::cassette::CassetteError;
 use crate::catalog::CatalogError;
 use crate::config::ConfigError;
 
@@ -52,6 +54,15 @@ pub enum RestError {
         #[from]
         source: http::Error,
     },
+
+    /// Cassette error
+    #[cfg(feature = "async")]
+    #[error("cassette error: {}", source)]
+    Cassette {
+        /// The source of the error.
+        #[from]
+        source: CassetteError,
+    },
 }
 
 /// OpenStack Client error
@@ -128,6 +139,15 @@ pub enum OpenStackError {
     #[error("Endpoint version discovery error: {}", msg)]
     Discovery { msg: String },
 
+    /// API extension is not enabled in the cloud
+    #[error("extension `{}` is not available for the `{}` service", alias, service)]
+    ExtensionNotAvailable {
+        /// Service type
+        service: String,
+        /// Extension alias
+        alias: String,
+    },
+
     /// Interactive mode required
     #[error(
         "Interactive mode is required but not available (running `echo foo | osc`?). {}",
@@ -143,6 +163,15 @@ pub enum OpenStackError {
         source: serde_json::Error,
     },
 
+    /// Cassette error
+    #[cfg(feature = "async")]
+    #[error("cassette error: {}", source)]
+    Cassette {
+        /// The source of the error.
+        #[from]
+        source: CassetteError,
+    },
+
     /// Any other error
     #[error(transparent)]
     Other(#[from] anyhow::Error),
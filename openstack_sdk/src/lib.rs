@@ -16,7 +16,11 @@
 
 pub mod api;
 mod auth;
+#[cfg(feature = "async")]
+mod cassette;
 mod catalog;
+#[cfg(feature = "async")]
+mod cloud_pool;
 pub mod config;
 mod error;
 #[cfg(feature = "sync")]
@@ -28,7 +32,14 @@ mod utils;
 
 pub mod types;
 
+#[cfg(any(test, feature = "test-utils"))]
+pub mod test_utils;
+
 pub use crate::auth::AuthError;
+#[cfg(feature = "async")]
+pub use crate::cassette::{Cassette, CassetteError, CassetteMode};
+#[cfg(feature = "async")]
+pub use crate::cloud_pool::{CloudPool, Tagged};
 pub use crate::error::{OpenStackError, RestError};
 #[cfg(feature = "sync")]
 pub use crate::openstack::OpenStack;
@@ -23,9 +23,9 @@ use tracing::{debug, error, info, span, trace, Level};
 
 use anyhow::anyhow;
 use bytes::Bytes;
-use http::{Response as HttpResponse, StatusCode};
+use http::{HeaderMap, Response as HttpResponse, StatusCode};
 
-use reqwest::blocking::Client;
+use reqwest::blocking::{Body, Client, Request, Response};
 use url::Url;
 
 use crate::config::CloudConfig;
@@ -33,6 +33,7 @@ use crate::config::CloudConfig;
 use crate::api;
 use crate::api::query;
 use crate::api::query::RawQuery;
+use crate::api::RestClient;
 use crate::auth::{
     self, authtoken,
     authtoken::{AuthTokenError, AuthType},
@@ -40,10 +41,10 @@ use crate::auth::{
 };
 use crate::config::{get_config_identity_hash, ConfigFile};
 use crate::state;
-use crate::types::identity::v3::{AuthReceiptResponse, AuthResponse, Project};
-use crate::types::ServiceType;
+use crate::types::identity::v3::{AuthReceiptResponse, AuthResponse, Project, ServiceEndpoints};
+use crate::types::{BoxedRead, ServiceType};
 
-use crate::catalog::{Catalog, ServiceEndpoint};
+use crate::catalog::{Catalog, CatalogError, ServiceEndpoint};
 
 use crate::error::{OpenStackError, OpenStackResult, RestError};
 
@@ -111,6 +112,8 @@ pub struct OpenStack {
     /// information in the file (similar to how other cli tools are doing)
     /// and check auth expiration upon load.
     state: state::State,
+    /// Whether the request data is validated before sending.
+    validate_requests: bool,
 }
 
 impl Debug for OpenStack {
@@ -142,6 +145,7 @@ impl OpenStack {
             auth,
             catalog: Catalog::default(),
             state: state::State::new(),
+            validate_requests: config.validate_requests(),
         };
 
         let auth_data = session
@@ -159,12 +163,18 @@ impl OpenStack {
             .catalog
             .add_service_endpoint("identity", identity_service_url)?;
 
-        session.catalog.set_endpoint_overrides(config)?;
+        session
+            .catalog
+            .set_endpoint_overrides(config)?
+            .set_api_versions(config)
+            .set_region(config.region_name.clone());
 
+        let config_file = ConfigFile::new()?;
         session
             .state
             .set_auth_hash_key(get_config_identity_hash(config))
-            .enable_auth_cache(ConfigFile::new()?.is_auth_cache_enabled());
+            .enable_auth_cache(config_file.is_auth_cache_enabled())
+            .set_discovery_cache_ttl(config_file.discovery_cache_ttl());
 
         Ok(session)
     }
@@ -185,6 +195,40 @@ impl OpenStack {
         Ok(session)
     }
 
+    /// Create a new OpenStack API session from CloudConfig
+    pub fn new_interactive(config: &CloudConfig, renew_auth: bool) -> OpenStackResult<Self> {
+        let span = span!(Level::TRACE, "Session span");
+        let _enter = span.enter();
+        trace!("Building new session");
+        let mut session = Self::new_impl(config, Auth::None)?;
+
+        // Ensure we resolve identity endpoint using version discovery
+        session.discover_service_endpoint(&ServiceType::Identity)?;
+
+        session.authorize(None, true, renew_auth)?;
+
+        Ok(session)
+    }
+
+    /// Enable or disable client side validation of the request data
+    pub fn set_validate_requests(&mut self, validate: bool) -> &mut Self {
+        self.validate_requests = validate;
+        self
+    }
+
+    /// Set API version (`<major>[.<minor>]`) of the service to be used
+    ///
+    /// Overrides the `<service>_api_version` configuration option. Must be
+    /// invoked before the endpoint version discovery of the service.
+    pub fn set_service_api_version<S: Into<String>>(
+        &mut self,
+        service_type: &ServiceType,
+        version: S,
+    ) -> &mut Self {
+        self.catalog.set_api_version(service_type, version);
+        self
+    }
+
     /// Set the authorization to be used by the client
     fn set_auth(&mut self, auth: auth::Auth, skip_cache_update: bool) -> &mut Self {
         self.auth = auth;
@@ -258,7 +302,7 @@ impl OpenStack {
                 // No auth/authz information available. Proceed with new auth
                 trace!("No Auth already available. Proceeding with new login");
 
-                match AuthType::from_cloud_config(&self.config)? {
+                match auth_type {
                     AuthType::V3ApplicationCredential => {
                         let identity =
                             authtoken::build_identity_data_from_config(&self.config, interactive)?;
@@ -296,12 +340,29 @@ impl OpenStack {
                                 rsp = auth_endpoint.raw_query(self)?;
                             }
                         }
-                        api::check_response_error::<Self>(&rsp)?;
+                        api::check_response_error::<Self>(&rsp, &ServiceType::Identity)?;
                     }
-                    other => {
-                        return Err(AuthTokenError::IdentityMethodSync {
-                            auth_type: other.as_str().to_string(),
-                        })?
+                    AuthType::V3WebSso => {
+                        let auth_url = auth::v3websso::get_auth_url(&self.config)?;
+                        let mut url = self.rest_endpoint(&ServiceType::Identity, &auth_url)?;
+
+                        let mut token_auth = auth::v3websso::get_token_auth_blocking(&mut url)?;
+
+                        // Set retrieved token as current auth
+                        self.set_auth(auth::Auth::AuthToken(Box::new(token_auth.clone())), true);
+
+                        // Get the token info (for the expiration)
+                        let token_info = self.fetch_token_info(token_auth.token.clone())?;
+                        token_auth.auth_info = Some(token_info.clone());
+                        let scope = authtoken::AuthTokenScope::from(&token_info);
+
+                        // Save unscoped token in the cache
+                        self.state.set_scope_auth(&scope, &token_auth);
+
+                        // And now time to rescope the token
+                        let auth_ep =
+                            authtoken::build_reauth_request(&token_auth, &requested_scope)?;
+                        rsp = auth_ep.raw_query(self)?;
                     }
                 }
             };
@@ -344,6 +405,16 @@ impl OpenStack {
             if !ep.discovered {
                 info!("Performing `{}` endpoint version discovery", service_type);
 
+                if let Some(data) = self.state.get_service_discovery(ep.url.as_str()) {
+                    if self
+                        .catalog
+                        .process_endpoint_discovery(service_type, &data)
+                        .is_ok()
+                    {
+                        return Ok(());
+                    }
+                }
+
                 let mut try_url = ep.url.clone();
                 let mut max_depth = 10;
                 loop {
@@ -353,9 +424,21 @@ impl OpenStack {
 
                     let rsp = self.rest_with_auth(req, Vec::new(), &self.auth)?;
                     if rsp.status() != StatusCode::NOT_FOUND {
-                        return Ok(self
+                        match self
                             .catalog
-                            .process_endpoint_discovery(service_type, rsp.body())?);
+                            .process_endpoint_discovery(service_type, rsp.body())
+                        {
+                            Ok(()) => {
+                                self.state
+                                    .set_service_discovery(ep.url.as_str(), rsp.body());
+                                return Ok(());
+                            }
+                            // The document does not advertise the requested
+                            // version, look for it one level up
+                            Err(CatalogError::VersionNotAvailable { .. })
+                                if try_url.path() != "/" => {}
+                            Err(err) => return Err(err.into()),
+                        }
                     }
                     if try_url.path() != "/" {
                         // We are not at the root yet and have not found a
@@ -381,6 +464,19 @@ impl OpenStack {
         Ok(())
     }
 
+    /// Return catalog information given in the token
+    pub fn get_token_catalog(&self) -> Option<Vec<ServiceEndpoints>> {
+        self.catalog.get_token_catalog()
+    }
+
+    /// Return current authentication information
+    pub fn get_auth_info(&self) -> Option<AuthResponse> {
+        if let Auth::AuthToken(token) = &self.auth {
+            return token.auth_info.clone();
+        }
+        None
+    }
+
     /// Return current authentication token
     pub fn get_auth_token(&self) -> Option<String> {
         if let Auth::AuthToken(token) = &self.auth {
@@ -389,6 +485,31 @@ impl OpenStack {
         None
     }
 
+    /// Perform token introspection call
+    pub fn fetch_token_info<S: AsRef<str>>(
+        &self,
+        token: S,
+    ) -> Result<AuthResponse, OpenStackError> {
+        let auth_ep = auth::authtoken::build_token_info_endpoint(token)?;
+        let rsp = auth_ep.raw_query(self)?;
+        let data: AuthResponse = serde_json::from_slice(rsp.body())?;
+        Ok(data)
+    }
+
+    /// Perform HTTP request with given request and return raw response.
+    fn execute_request(&self, request: Request) -> Result<Response, reqwest::Error> {
+        info!("Sending request {:?}", request);
+        let start = SystemTime::now();
+        let rsp = self.client.execute(request)?;
+        let elapsed = SystemTime::now().duration_since(start);
+        info!(
+            "Request completed with status {} in {}ms",
+            rsp.status(),
+            elapsed.unwrap_or_default().as_millis()
+        );
+        Ok(rsp)
+    }
+
     /// Perform a REST query with a given auth.
     pub fn rest_with_auth(
         &self,
@@ -401,15 +522,7 @@ impl OpenStack {
             let http_request = request.body(body)?;
             let request = http_request.try_into()?;
 
-            info!("Sending request {:?}", request);
-            let start = SystemTime::now();
-            let rsp = self.client.execute(request)?;
-            let elapsed = SystemTime::now().duration_since(start);
-            info!(
-                "Request completed with status {} in {}ms",
-                rsp.status(),
-                elapsed.unwrap_or_default().as_millis()
-            );
+            let rsp = self.execute_request(request)?;
 
             let mut http_rsp = HttpResponse::builder()
                 .status(rsp.status())
@@ -422,6 +535,55 @@ impl OpenStack {
         };
         call().map_err(api::ApiError::client)
     }
+
+    /// Perform a REST query with a given auth and the body read from `Read`.
+    fn rest_with_auth_read_body(
+        &self,
+        mut request: http::request::Builder,
+        body_read: BoxedRead,
+        auth: &Auth,
+    ) -> Result<HttpResponse<Bytes>, api::ApiError<<Self as api::RestClient>::Error>> {
+        let call = || -> Result<_, RestError> {
+            auth.set_header(request.headers_mut().unwrap())?;
+            let http_request = request.body(Body::new(body_read))?;
+            let request = http_request.try_into()?;
+
+            let rsp = self.execute_request(request)?;
+
+            let mut http_rsp = HttpResponse::builder()
+                .status(rsp.status())
+                .version(rsp.version());
+            let headers = http_rsp.headers_mut().unwrap();
+            for (key, value) in rsp.headers() {
+                headers.insert(key, value.clone());
+            }
+            Ok(http_rsp.body(rsp.bytes()?)?)
+        };
+        call().map_err(api::ApiError::client)
+    }
+
+    /// Perform a REST query with a given auth and return `Read` of the body.
+    fn download_with_auth(
+        &self,
+        mut request: http::request::Builder,
+        body: Vec<u8>,
+        auth: &Auth,
+    ) -> Result<(HeaderMap, BoxedRead), api::ApiError<<Self as api::RestClient>::Error>> {
+        let call = || -> Result<_, RestError> {
+            auth.set_header(request.headers_mut().unwrap())?;
+            let http_request = request.body(body)?;
+            let request = http_request.try_into()?;
+
+            let rsp = self.execute_request(request)?;
+
+            let mut headers = HeaderMap::new();
+            for (key, value) in rsp.headers() {
+                headers.insert(key, value.clone());
+            }
+            Ok((headers, BoxedRead::new(rsp)))
+        };
+        call().map_err(api::ApiError::client)
+    }
 }
 
 impl api::RestClient for OpenStack {
@@ -469,6 +631,11 @@ impl api::RestClient for OpenStack {
         }
         None
     }
+
+    /// Whether the request data should be validated before sending
+    fn validate_requests(&self) -> bool {
+        self.validate_requests
+    }
 }
 
 impl api::Client for OpenStack {
@@ -480,4 +647,22 @@ impl api::Client for OpenStack {
     ) -> Result<HttpResponse<Bytes>, api::ApiError<Self::Error>> {
         self.rest_with_auth(request, body, &self.auth)
     }
+
+    /// Perform the query with the body read from `Read`
+    fn rest_read_body(
+        &self,
+        request: http::request::Builder,
+        body: BoxedRead,
+    ) -> Result<HttpResponse<Bytes>, api::ApiError<Self::Error>> {
+        self.rest_with_auth_read_body(request, body, &self.auth)
+    }
+
+    /// Download result of HTTP operation.
+    fn download(
+        &self,
+        request: http::request::Builder,
+        body: Vec<u8>,
+    ) -> Result<(HeaderMap, BoxedRead), api::ApiError<Self::Error>> {
+        self.download_with_auth(request, body, &self.auth)
+    }
 }
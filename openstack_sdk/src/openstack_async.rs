@@ -16,6 +16,7 @@
 
 use std::convert::TryInto;
 use std::fmt::{self, Debug};
+use std::sync::Arc;
 use std::time::SystemTime;
 use tracing::{debug, error, info, span, trace, Level};
 
@@ -48,7 +49,8 @@ use crate::state;
 use crate::types::identity::v3::{AuthReceiptResponse, AuthResponse, Project, ServiceEndpoints};
 use crate::types::{BoxedAsyncRead, ServiceType};
 
-use crate::catalog::{Catalog, ServiceEndpoint};
+use crate::cassette::Cassette;
+use crate::catalog::{Catalog, CatalogError, ServiceEndpoint};
 
 use crate::error::{OpenStackError, OpenStackResult, RestError};
 
@@ -102,6 +104,10 @@ pub struct AsyncOpenStack {
     /// information in the file (similar to how other cli tools are doing)
     /// and check auth expiration upon load.
     state: state::State,
+    /// Whether the request data is validated before sending.
+    validate_requests: bool,
+    /// Cassette to record or replay the HTTP interactions
+    cassette: Option<Arc<Cassette>>,
 }
 
 impl Debug for AsyncOpenStack {
@@ -159,6 +165,11 @@ impl api::RestClient for AsyncOpenStack {
         }
         None
     }
+
+    /// Whether the request data should be validated before sending
+    fn validate_requests(&self) -> bool {
+        self.validate_requests
+    }
 }
 
 #[async_trait]
@@ -205,6 +216,8 @@ impl AsyncOpenStack {
             auth,
             catalog: Catalog::default(),
             state: state::State::new(),
+            validate_requests: config.validate_requests(),
+            cassette: None,
         };
 
         let auth_data = session
@@ -222,16 +235,36 @@ impl AsyncOpenStack {
             .catalog
             .add_service_endpoint("identity", identity_service_url)?;
 
-        session.catalog.set_endpoint_overrides(config)?;
+        session
+            .catalog
+            .set_endpoint_overrides(config)?
+            .set_api_versions(config)
+            .set_region(config.region_name.clone());
 
+        let config_file = ConfigFile::new()?;
         session
             .state
             .set_auth_hash_key(get_config_identity_hash(config))
-            .enable_auth_cache(ConfigFile::new()?.is_auth_cache_enabled());
+            .enable_auth_cache(config_file.is_auth_cache_enabled())
+            .set_discovery_cache_ttl(config_file.discovery_cache_ttl());
+
+        if let Some(cassette) = Cassette::from_env()? {
+            session.use_cassette(cassette);
+        }
 
         Ok(session)
     }
 
+    /// Record or replay the HTTP interactions using the cassette
+    ///
+    /// Authentication caching is disabled so that the authentication requests
+    /// are also recorded.
+    fn use_cassette(&mut self, cassette: Cassette) -> &mut Self {
+        self.state.enable_auth_cache(false);
+        self.cassette = Some(Arc::new(cassette));
+        self
+    }
+
     /// Create a new OpenStack API session from CloudConfig
     pub async fn new(config: &CloudConfig) -> OpenStackResult<Self> {
         let span = span!(Level::DEBUG, "Session span");
@@ -249,6 +282,28 @@ impl AsyncOpenStack {
         Ok(session)
     }
 
+    /// Create a new OpenStack API session from CloudConfig recording or
+    /// replaying the HTTP interactions with the cassette
+    pub async fn new_with_cassette(
+        config: &CloudConfig,
+        cassette: Cassette,
+    ) -> OpenStackResult<Self> {
+        let span = span!(Level::DEBUG, "Session span");
+        let _enter = span.enter();
+        debug!("Building new session with cassette");
+        let mut session = Self::new_impl(config, Auth::None)?;
+        session.use_cassette(cassette);
+
+        // Ensure we resolve identity endpoint using version discovery
+        session
+            .discover_service_endpoint(&ServiceType::Identity)
+            .await?;
+
+        session.authorize(None, false, false).await?;
+
+        Ok(session)
+    }
+
     /// Create a new OpenStack API session from CloudConfig
     pub async fn new_interactive(config: &CloudConfig, renew_auth: bool) -> OpenStackResult<Self> {
         let span = span!(Level::DEBUG, "Session span");
@@ -266,6 +321,70 @@ impl AsyncOpenStack {
         Ok(session)
     }
 
+    /// Enable or disable client side validation of the request data
+    pub fn set_validate_requests(&mut self, validate: bool) -> &mut Self {
+        self.validate_requests = validate;
+        self
+    }
+
+    /// Set API version (`<major>[.<minor>]`) of the service to be used
+    ///
+    /// Overrides the `<service>_api_version` configuration option. Must be
+    /// invoked before the endpoint version discovery of the service.
+    pub fn set_service_api_version<S: Into<String>>(
+        &mut self,
+        service_type: &ServiceType,
+        version: S,
+    ) -> &mut Self {
+        self.catalog.set_api_version(service_type, version);
+        self
+    }
+
+    /// Return new session bound to the region
+    ///
+    /// The authorization and the catalog of the current session are reused,
+    /// so no further requests to the identity service are needed. Services
+    /// are discovered again for the region.
+    pub fn with_region<S: AsRef<str>>(&self, region: S) -> OpenStackResult<Self> {
+        let mut config = self.config.clone();
+        config.region_name = Some(region.as_ref().to_string());
+        self.with_config(&config)
+    }
+
+    /// Return new session for the cloud configuration of the same identity
+    ///
+    /// Endpoint overrides, API versions and the region are taken from the
+    /// `config`, while the authorization of the current session is reused.
+    pub(crate) fn with_config(&self, config: &CloudConfig) -> OpenStackResult<Self> {
+        let mut catalog = Catalog::default();
+        if let Some(auth_url) = config.auth.as_ref().and_then(|x| x.auth_url.as_ref()) {
+            catalog.add_service_endpoint("identity", auth_url)?;
+        }
+        catalog
+            .set_endpoint_overrides(config)?
+            .set_api_versions(config)
+            .set_region(config.region_name.clone());
+        if let Some(endpoints) = self.catalog.get_token_catalog() {
+            catalog.process_catalog_endpoints(&endpoints, Some("public"))?;
+        }
+
+        let mut session = self.clone();
+        session.config = config.clone();
+        session.catalog = catalog;
+        session.validate_requests = config.validate_requests();
+        Ok(session)
+    }
+
+    /// Return names of the regions present in the service catalog
+    pub fn get_regions(&self) -> Vec<String> {
+        self.catalog.get_regions()
+    }
+
+    /// Return the region the session is bound to
+    pub fn get_region(&self) -> Option<&str> {
+        self.config.region_name.as_deref()
+    }
+
     /// Set the authorization to be used by the client
     fn set_auth(&mut self, auth: auth::Auth, skip_cache_update: bool) -> &mut Self {
         self.auth = auth;
@@ -380,7 +499,7 @@ where {
                                 rsp = auth_endpoint.raw_query_async(self).await?;
                             }
                         }
-                        api::check_response_error::<Self>(&rsp)?;
+                        api::check_response_error::<Self>(&rsp, &ServiceType::Identity)?;
                     }
                     AuthType::V3WebSso => {
                         let auth_url = auth::v3websso::get_auth_url(&self.config)?;
@@ -446,6 +565,16 @@ where {
             if !ep.discovered {
                 info!("Performing `{}` endpoint version discovery", service_type);
 
+                if let Some(data) = self.state.get_service_discovery(ep.url.as_str()) {
+                    if self
+                        .catalog
+                        .process_endpoint_discovery(service_type, &data)
+                        .is_ok()
+                    {
+                        return Ok(());
+                    }
+                }
+
                 let mut try_url = ep.url.clone();
                 let mut max_depth = 10;
                 loop {
@@ -457,9 +586,21 @@ where {
                         .rest_with_auth_async(req, Vec::new(), &self.auth)
                         .await?;
                     if rsp.status() != StatusCode::NOT_FOUND {
-                        return Ok(self
+                        match self
                             .catalog
-                            .process_endpoint_discovery(service_type, rsp.body())?);
+                            .process_endpoint_discovery(service_type, rsp.body())
+                        {
+                            Ok(()) => {
+                                self.state
+                                    .set_service_discovery(ep.url.as_str(), rsp.body());
+                                return Ok(());
+                            }
+                            // The document does not advertise the requested
+                            // version, look for it one level up
+                            Err(CatalogError::VersionNotAvailable { .. })
+                                if try_url.path() != "/" => {}
+                            Err(err) => return Err(err.into()),
+                        }
                     }
                     if try_url.path() != "/" {
                         // We are not at the root yet and have not found a
@@ -485,6 +626,46 @@ where {
         Ok(())
     }
 
+    /// Ensure the API extension is enabled for the network service
+    ///
+    /// Resources of optional extensions (VPNaaS, FWaaS, BGP VPN) are only
+    /// served when the extension is loaded, otherwise every request ends with
+    /// a bare `404`.
+    /// Aliases of the enabled extensions are fetched once and cached with the
+    /// service endpoint, so that further checks do not need any requests.
+    #[cfg(feature = "network")]
+    pub async fn ensure_network_extension(&mut self, alias: &str) -> Result<(), OpenStackError> {
+        let service_type = ServiceType::Network;
+        let extensions = match self
+            .catalog
+            .get_service_endpoint(&service_type)
+            .and_then(|ep| ep.extensions)
+        {
+            Some(extensions) => extensions,
+            None => {
+                let ep = api::network::v2::extension::list::Request::builder()
+                    .build()
+                    .map_err(|x| anyhow!(x))?;
+                let data: Vec<serde_json::Value> = api::QueryAsync::query_async(&ep, self).await?;
+                let extensions: std::collections::BTreeSet<String> = data
+                    .iter()
+                    .filter_map(|x| x["alias"].as_str().map(String::from))
+                    .collect();
+                self.catalog
+                    .set_service_extensions(&service_type, extensions.clone());
+                extensions
+            }
+        };
+        if extensions.contains(alias) {
+            Ok(())
+        } else {
+            Err(OpenStackError::ExtensionNotAvailable {
+                service: service_type.to_string(),
+                alias: alias.into(),
+            })
+        }
+    }
+
     // TODO(gtema): rename to `get_catalog`)
     /// Return catalog information given in the token
     pub fn get_token_catalog(&self) -> Option<Vec<ServiceEndpoints>> {
@@ -519,7 +700,7 @@ where {
     }
 
     /// Perform HTTP request with given request and return raw response.
-    async fn execute_request(&self, request: Request) -> Result<Response, reqwest::Error> {
+    async fn execute_request(&self, request: Request) -> Result<Response, RestError> {
         info!("Sending request {:?}", request);
         // Body may contain sensitive info.
         if let Some(body) = request.body() {
@@ -529,7 +710,10 @@ where {
             );
         }
         let start = SystemTime::now();
-        let rsp = self.client.execute(request).await?;
+        let rsp = match &self.cassette {
+            Some(cassette) => cassette.execute(&self.client, request).await?,
+            None => self.client.execute(request).await?,
+        };
         let elapsed = SystemTime::now().duration_since(start);
         info!(
             "Request completed with status {} in {}ms",
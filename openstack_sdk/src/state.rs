@@ -16,8 +16,11 @@
 //!
 //! A session object keeps certain information that may need to be cached. This module implements
 //! caching of the authentication/authorization information with certain functionality to manage
-//! cache data.
+//! cache data. Service version discovery documents are cached next to the authentication data
+//! for the configured time.
 
+use bytes::Bytes;
+use chrono::Utc;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs::{DirBuilder, File};
@@ -85,6 +88,29 @@ impl ScopeAuths {
     }
 }
 
+/// Cached version discovery document
+#[derive(Clone, Deserialize, Serialize, Debug)]
+pub(crate) struct CachedDiscovery {
+    /// Unix timestamp of the document retrieval
+    fetched_at: i64,
+    /// Raw discovery document
+    data: Vec<u8>,
+}
+
+/// A HashMap of the service endpoint URL to the version discovery document
+#[derive(Clone, Default, Deserialize, Serialize, Debug)]
+pub(crate) struct ServiceDiscoveries(HashMap<String, CachedDiscovery>);
+
+impl ServiceDiscoveries {
+    /// Filter out documents older than `ttl` seconds
+    fn filter_expired(&mut self, ttl: u64) -> &mut Self {
+        let now = Utc::now().timestamp();
+        self.0
+            .retain(|_, v| now.saturating_sub(v.fetched_at) < ttl as i64);
+        self
+    }
+}
+
 /// OSC state
 #[derive(Clone, Default, Deserialize, Serialize)]
 pub(crate) struct State {
@@ -93,6 +119,8 @@ pub(crate) struct State {
     base_dir: PathBuf,
     auth_hash: u64,
     auth_cache_enabled: bool,
+    /// Time in seconds the version discovery documents are cached for
+    discovery_cache_ttl: u64,
 }
 
 impl State {
@@ -101,6 +129,7 @@ impl State {
             auth_hash: 0,
             auth_state: Default::default(),
             auth_cache_enabled: false,
+            discovery_cache_ttl: 0,
             base_dir: dirs::home_dir()
                 .expect("Cannot determine users XDG_HOME")
                 .join(".osc"),
@@ -123,6 +152,15 @@ impl State {
         self
     }
 
+    /// Set time in seconds the version discovery documents are cached for
+    ///
+    /// Documents are only cached when the authorization caching is also
+    /// enabled. `0` disables the caching.
+    pub fn set_discovery_cache_ttl(&mut self, ttl: u64) -> &mut Self {
+        self.discovery_cache_ttl = ttl;
+        self
+    }
+
     /// Set authz into the state
     pub fn set_scope_auth(&mut self, scope: &AuthTokenScope, authz: &AuthToken) {
         self.auth_state.filter_invalid_auths();
@@ -225,6 +263,81 @@ impl State {
         None
     }
 
+    /// Whether the version discovery caching is enabled
+    fn discovery_cache_enabled(&self) -> bool {
+        self.auth_cache_enabled && self.discovery_cache_ttl > 0
+    }
+
+    /// Get cached version discovery document of the service endpoint
+    pub fn get_service_discovery(&self, url: &str) -> Option<Bytes> {
+        if !self.discovery_cache_enabled() {
+            return None;
+        }
+        let mut state = self.load_discovery_state()?;
+        state
+            .filter_expired(self.discovery_cache_ttl)
+            .0
+            .remove(url)
+            .map(|x| {
+                trace!("Found version discovery document of `{}` in the cache", url);
+                Bytes::from(x.data)
+            })
+    }
+
+    /// Save version discovery document of the service endpoint to the FS
+    pub fn set_service_discovery(&self, url: &str, data: &Bytes) {
+        if !self.discovery_cache_enabled() {
+            return;
+        }
+        let mut state = self.load_discovery_state().unwrap_or_default();
+        state.filter_expired(self.discovery_cache_ttl).0.insert(
+            url.to_string(),
+            CachedDiscovery {
+                fetched_at: Utc::now().timestamp(),
+                data: data.to_vec(),
+            },
+        );
+
+        match bincode::serialize(&state) {
+            Ok(ser_data) => match File::create(self.get_discovery_state_filename().as_path()) {
+                Ok(mut file) => {
+                    let _ = file.write_all(&ser_data);
+                }
+                _ => {
+                    warn!("Error writing discovery state file");
+                }
+            },
+            Err(e) => {
+                warn!("Error serializing discovery state, {:?}", e);
+            }
+        }
+    }
+
+    /// Get filename of the version discovery state file
+    fn get_discovery_state_filename(&self) -> PathBuf {
+        let mut fname_buf = self.base_dir.clone();
+        fname_buf.push(format!("{}.discovery", self.auth_hash));
+        fname_buf
+    }
+
+    /// Load version discovery state from the FS
+    fn load_discovery_state(&self) -> Option<ServiceDiscoveries> {
+        let fname = self.get_discovery_state_filename();
+        let contents = std::fs::read(fname.as_path()).ok()?;
+        match bincode::deserialize::<ServiceDiscoveries>(&contents) {
+            Ok(state) => Some(state),
+            Err(x) => {
+                warn!(
+                    "Corrupted cache file {}: {:?}. Removing ",
+                    fname.display(),
+                    x
+                );
+                let _ = std::fs::remove_file(fname);
+                None
+            }
+        }
+    }
+
     /// Get filename of the auth state file
     fn get_auth_state_filename(&self, auth_hash: u64) -> PathBuf {
         let mut fname_buf = self.base_dir.clone();
@@ -292,3 +405,75 @@ impl State {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+    use chrono::Utc;
+
+    use super::{CachedDiscovery, ServiceDiscoveries, State};
+
+    fn state(ttl: u64) -> State {
+        let base_dir = std::env::temp_dir().join(format!(
+            "osc-state-{}-{}",
+            std::process::id(),
+            Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        ));
+        std::fs::create_dir_all(&base_dir).unwrap();
+        let mut state = State {
+            base_dir,
+            ..Default::default()
+        };
+        state
+            .set_auth_hash_key(1)
+            .enable_auth_cache(true)
+            .set_discovery_cache_ttl(ttl);
+        state
+    }
+
+    #[test]
+    fn test_service_discovery_cache() {
+        let state = state(60);
+        let data = Bytes::from_static(b"{\"versions\": []}");
+        assert!(state.get_service_discovery("http://foo/").is_none());
+        state.set_service_discovery("http://foo/", &data);
+        assert_eq!(state.get_service_discovery("http://foo/"), Some(data));
+        assert!(state.get_service_discovery("http://bar/").is_none());
+        let _ = std::fs::remove_dir_all(&state.base_dir);
+    }
+
+    #[test]
+    fn test_service_discovery_cache_disabled() {
+        let mut state = state(0);
+        let data = Bytes::from_static(b"{}");
+        state.set_service_discovery("http://foo/", &data);
+        assert!(state.get_service_discovery("http://foo/").is_none());
+
+        state.set_discovery_cache_ttl(60).enable_auth_cache(false);
+        state.set_service_discovery("http://foo/", &data);
+        assert!(state.get_service_discovery("http://foo/").is_none());
+        let _ = std::fs::remove_dir_all(&state.base_dir);
+    }
+
+    #[test]
+    fn test_service_discovery_expired() {
+        let mut discoveries = ServiceDiscoveries::default();
+        discoveries.0.insert(
+            "old".into(),
+            CachedDiscovery {
+                fetched_at: Utc::now().timestamp() - 120,
+                data: Vec::new(),
+            },
+        );
+        discoveries.0.insert(
+            "new".into(),
+            CachedDiscovery {
+                fetched_at: Utc::now().timestamp(),
+                data: Vec::new(),
+            },
+        );
+        discoveries.filter_expired(60);
+        assert!(discoveries.0.contains_key("new"));
+        assert!(!discoveries.0.contains_key("old"));
+    }
+}
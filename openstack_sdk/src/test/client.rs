@@ -43,7 +43,7 @@ use crate::api::{ApiError, RestClient};
 
 use crate::catalog::ServiceEndpoint;
 use crate::types::identity::v3::Project;
-use crate::types::{BoxedAsyncRead, ServiceType};
+use crate::types::{BoxedAsyncRead, BoxedRead, ServiceType};
 use crate::RestError;
 
 use httpmock::prelude::*;
@@ -197,6 +197,7 @@ impl<T> RestClient for PagedTestClient<T> {
             discovered: true,
             versions: Vec::new(),
             current_version: None,
+            extensions: None,
         })
     }
 
@@ -303,6 +304,22 @@ where
             .unwrap()
             .map(Into::into))
     }
+
+    fn rest_read_body(
+        &self,
+        _request: RequestBuilder,
+        _body: BoxedRead,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        todo!();
+    }
+
+    fn download(
+        &self,
+        _request: RequestBuilder,
+        _body: Vec<u8>,
+    ) -> Result<(HeaderMap, BoxedRead), ApiError<Self::Error>> {
+        todo!();
+    }
 }
 
 #[cfg(feature = "async")]
@@ -375,6 +392,7 @@ impl RestClient for MockServerClient {
             discovered: true,
             versions: Vec::new(),
             current_version: None,
+            extensions: None,
         })
     }
 
@@ -409,6 +427,51 @@ impl Client for MockServerClient {
         };
         call().map_err(ApiError::client)
     }
+
+    fn rest_read_body(
+        &self,
+        request: RequestBuilder,
+        body: BoxedRead,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        let call = || -> Result<_, Self::Error> {
+            let http_request = request.body(reqwest::blocking::Body::new(body))?;
+            let request = http_request.try_into()?;
+
+            let rsp = self.client.execute(request)?;
+
+            let mut http_rsp = HttpResponse::builder()
+                .status(rsp.status())
+                .version(rsp.version());
+
+            let headers = http_rsp.headers_mut().unwrap();
+            for (key, value) in rsp.headers() {
+                headers.insert(key, value.clone());
+            }
+
+            Ok(http_rsp.body(rsp.bytes()?)?)
+        };
+        call().map_err(ApiError::client)
+    }
+
+    fn download(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<(HeaderMap, BoxedRead), ApiError<Self::Error>> {
+        let call = || -> Result<_, Self::Error> {
+            let http_request = request.body(body)?;
+            let request = http_request.try_into()?;
+
+            let rsp = self.client.execute(request)?;
+
+            let mut headers = HeaderMap::new();
+            for (key, value) in rsp.headers() {
+                headers.insert(key, value.clone());
+            }
+            Ok((headers, BoxedRead::new(rsp)))
+        };
+        call().map_err(ApiError::client)
+    }
 }
 
 #[cfg(feature = "async")]
@@ -451,6 +514,7 @@ impl RestClient for MockAsyncServerClient {
             discovered: true,
             versions: Vec::new(),
             current_version: None,
+            extensions: None,
         })
     }
 
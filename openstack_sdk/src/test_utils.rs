@@ -0,0 +1,35 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Utilities for testing of the SDK consumers
+//!
+//! [`FakeCloud`] is an in-process OpenStack cloud. It implements the Keystone
+//! authentication with the service catalog, version discovery documents of
+//! the services and keeps resources created through the API in memory. It
+//! allows running tests against the real [`OpenStack`](crate::OpenStack)
+//! and [`AsyncOpenStack`](crate::AsyncOpenStack) clients without a
+//! deployment.
+//!
+//! ```rust,ignore
+//! use openstack_sdk::test_utils::FakeCloud;
+//!
+//! let cloud = FakeCloud::new();
+//! let mut session = AsyncOpenStack::new(&cloud.cloud_config()).await?;
+//! session.discover_service_endpoint(&ServiceType::Network).await?;
+//! ```
+
+mod fake_cloud;
+
+pub use self::fake_cloud::FakeCloud;
+pub use self::fake_cloud::FakeResource;
@@ -0,0 +1,642 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! In-process fake OpenStack cloud
+//!
+//! The cloud is an HTTP server running in a dedicated thread with its own
+//! runtime. This way it can be used with both the synchronous and
+//! asynchronous clients. Every service is exposed under its own path prefix
+//! of the server (`/identity`, `/compute`, `/network`, `/volume`, `/image`
+//! and `/object-store`).
+//!
+//! Tokens are issued only for the configured credentials, but on the service
+//! APIs they are not validated beyond being present. This keeps the fake
+//! cloud compatible with the authentication cache of the client.
+
+use std::collections::{BTreeMap, HashMap};
+use std::convert::Infallible;
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+
+use bytes::Bytes;
+use http::{header, HeaderMap, Method, Request, Response, StatusCode};
+use http_body_util::{BodyExt, Full};
+use hyper::body::Incoming;
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper_util::rt::TokioIo;
+use percent_encoding::percent_decode_str;
+use serde_json::{json, Value};
+use tokio::sync::oneshot;
+
+use crate::config::{Auth, CloudConfig};
+
+mod identity;
+mod object_store;
+mod resources;
+
+/// Region of the fake cloud endpoints.
+const REGION: &str = "RegionOne";
+
+/// Resource types kept by the [`FakeCloud`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum FakeResource {
+    /// Compute server
+    Server,
+    /// Network
+    Network,
+    /// Network port
+    Port,
+    /// Block storage volume
+    Volume,
+    /// Image
+    Image,
+}
+
+/// In-process fake OpenStack cloud
+///
+/// The server is started on a random local port and is stopped when the
+/// instance is dropped.
+///
+/// ```rust,no_run
+/// use openstack_sdk::test_utils::{FakeCloud, FakeResource};
+/// use openstack_sdk::OpenStack;
+///
+/// let cloud = FakeCloud::new();
+/// cloud.add_resource(FakeResource::Network, serde_json::json!({"name": "net"}));
+/// let session = OpenStack::new(&cloud.cloud_config()).unwrap();
+/// ```
+pub struct FakeCloud {
+    /// Base URL of the server
+    url: String,
+    /// Cloud data shared with the server
+    state: Arc<Mutex<CloudState>>,
+    /// Server shutdown trigger
+    shutdown: Option<oneshot::Sender<()>>,
+    /// Server thread
+    server: Option<JoinHandle<()>>,
+}
+
+impl FakeCloud {
+    /// Name of the user accepted by the fake cloud.
+    pub const USERNAME: &'static str = "admin";
+    /// Password of the user.
+    pub const PASSWORD: &'static str = "password";
+    /// ID of the user.
+    pub const USER_ID: &'static str = "a1b2c3d4e5f60718293a4b5c6d7e8f90";
+    /// Name of the project all the resources belong to.
+    pub const PROJECT_NAME: &'static str = "admin";
+    /// ID of the project.
+    pub const PROJECT_ID: &'static str = "0f1e2d3c4b5a69788796a5b4c3d2e1f0";
+    /// Name of the domain of the user and the project.
+    pub const DOMAIN_NAME: &'static str = "Default";
+
+    /// Start the fake cloud
+    pub fn new() -> Self {
+        let listener =
+            std::net::TcpListener::bind("127.0.0.1:0").expect("fake cloud listener is bound");
+        listener
+            .set_nonblocking(true)
+            .expect("fake cloud listener is non-blocking");
+        let url = format!(
+            "http://{}",
+            listener.local_addr().expect("fake cloud address is known")
+        );
+        let state = Arc::new(Mutex::new(CloudState::new(&url)));
+        let (shutdown, shutdown_rx) = oneshot::channel();
+        let server_state = state.clone();
+        let server = std::thread::spawn(move || {
+            tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .expect("fake cloud runtime is started")
+                .block_on(serve(listener, server_state, shutdown_rx));
+        });
+        Self {
+            url,
+            state,
+            shutdown: Some(shutdown),
+            server: Some(server),
+        }
+    }
+
+    /// Base URL of the fake cloud
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Connection configuration for the fake cloud
+    pub fn cloud_config(&self) -> CloudConfig {
+        CloudConfig {
+            auth: Some(Auth {
+                auth_url: Some(format!("{}/identity", self.url)),
+                username: Some(Self::USERNAME.into()),
+                password: Some(Self::PASSWORD.into()),
+                user_domain_name: Some(Self::DOMAIN_NAME.into()),
+                project_name: Some(Self::PROJECT_NAME.into()),
+                project_domain_name: Some(Self::DOMAIN_NAME.into()),
+                ..Default::default()
+            }),
+            auth_type: Some("v3password".into()),
+            region_name: Some(REGION.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Add resource to the cloud bypassing the API
+    ///
+    /// Attributes not present in `data` (i.e. `id` or `status`) are set the
+    /// same way as when the resource is created through the API. Returns the
+    /// resulting resource.
+    pub fn add_resource(&self, resource: FakeResource, data: Value) -> Value {
+        self.state().create_resource(resource, data)
+    }
+
+    /// Current resources of the given type
+    pub fn resources(&self, resource: FakeResource) -> Vec<Value> {
+        self.state()
+            .resources
+            .get(&resource)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Content of the object store object
+    pub fn object(&self, container: &str, object: &str) -> Option<Bytes> {
+        self.state().object_data(container, object)
+    }
+
+    /// Get exclusive access to the cloud data
+    fn state(&self) -> std::sync::MutexGuard<'_, CloudState> {
+        self.state.lock().expect("fake cloud state is not poisoned")
+    }
+}
+
+impl Default for FakeCloud {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for FakeCloud {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            // Server may already be gone
+            let _ = shutdown.send(());
+        }
+        if let Some(server) = self.server.take() {
+            let _ = server.join();
+        }
+    }
+}
+
+/// Accept connections until shutdown is requested
+async fn serve(
+    listener: std::net::TcpListener,
+    state: Arc<Mutex<CloudState>>,
+    mut shutdown: oneshot::Receiver<()>,
+) {
+    let listener =
+        tokio::net::TcpListener::from_std(listener).expect("fake cloud listener is registered");
+    loop {
+        tokio::select! {
+            _ = &mut shutdown => break,
+            conn = listener.accept() => {
+                if let Ok((stream, _)) = conn {
+                    let state = state.clone();
+                    tokio::spawn(async move {
+                        let service = service_fn(move |req| handle(state.clone(), req));
+                        // Errors of individual connections are not interesting
+                        let _ = http1::Builder::new()
+                            .serve_connection(TokioIo::new(stream), service)
+                            .await;
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Process single HTTP request
+async fn handle(
+    state: Arc<Mutex<CloudState>>,
+    request: Request<Incoming>,
+) -> Result<FakeResponse, Infallible> {
+    let (parts, body) = request.into_parts();
+    let body = body
+        .collect()
+        .await
+        .map(|x| x.to_bytes())
+        .unwrap_or_default();
+    let request = FakeRequest {
+        method: parts.method,
+        path: parts
+            .uri
+            .path()
+            .split('/')
+            .filter(|x| !x.is_empty())
+            .map(|x| percent_decode_str(x).decode_utf8_lossy().to_string())
+            .collect(),
+        query: parts
+            .uri
+            .query()
+            .map(|x| form_urlencoded::parse(x.as_bytes()).into_owned().collect())
+            .unwrap_or_default(),
+        headers: parts.headers,
+        body,
+    };
+    let mut state = state.lock().expect("fake cloud state is not poisoned");
+    Ok(state.handle(&request))
+}
+
+/// Response of the fake cloud
+type FakeResponse = Response<Full<Bytes>>;
+
+/// Request to the fake cloud
+struct FakeRequest {
+    method: Method,
+    /// Decoded path segments
+    path: Vec<String>,
+    /// Decoded query parameters
+    query: Vec<(String, String)>,
+    headers: HeaderMap,
+    body: Bytes,
+}
+
+impl FakeRequest {
+    /// Value of the query parameter
+    fn query_param(&self, name: &str) -> Option<&str> {
+        self.query
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, val)| val.as_str())
+    }
+
+    /// Value of the request header
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).and_then(|x| x.to_str().ok())
+    }
+
+    /// Request body as JSON
+    fn json(&self) -> Option<Value> {
+        serde_json::from_slice(&self.body).ok()
+    }
+}
+
+/// Data of the fake cloud
+struct CloudState {
+    /// Base URL of the server
+    url: String,
+    /// Counter for the generated IDs
+    last_id: u64,
+    /// Issued tokens
+    tokens: HashMap<String, Value>,
+    /// JSON resources by type in the order of creation
+    resources: HashMap<FakeResource, Vec<Value>>,
+    /// Uploaded image data
+    image_data: HashMap<String, Bytes>,
+    /// Object store account metadata
+    account_metadata: BTreeMap<String, String>,
+    /// Object store containers
+    containers: BTreeMap<String, object_store::FakeContainer>,
+}
+
+impl CloudState {
+    fn new(url: &str) -> Self {
+        Self {
+            url: url.to_string(),
+            last_id: 0,
+            tokens: HashMap::new(),
+            resources: HashMap::new(),
+            image_data: HashMap::new(),
+            account_metadata: BTreeMap::new(),
+            containers: BTreeMap::new(),
+        }
+    }
+
+    /// Generate new unique ID
+    fn new_id(&mut self) -> String {
+        self.last_id += 1;
+        format!("00000000-0000-4000-8000-{:012x}", self.last_id)
+    }
+
+    /// Route the request to the service
+    fn handle(&mut self, request: &FakeRequest) -> FakeResponse {
+        let Some((service, path)) = request.path.split_first() else {
+            return error_response(StatusCode::NOT_FOUND, "Not Found");
+        };
+        if service != "identity" && request.header("x-auth-token").is_none() {
+            return error_response(
+                StatusCode::UNAUTHORIZED,
+                "The request you have made requires authentication.",
+            );
+        }
+        match service.as_str() {
+            "identity" => self.handle_identity(request, path),
+            "compute" => self.handle_compute(request, path),
+            "network" => self.handle_network(request, path),
+            "volume" => self.handle_volume(request, path),
+            "image" => self.handle_image(request, path),
+            "object-store" => self.handle_object_store(request, path),
+            _ => error_response(StatusCode::NOT_FOUND, "Not Found"),
+        }
+    }
+}
+
+/// Current time in the format used by the services
+fn now() -> String {
+    chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+}
+
+/// Build JSON response
+fn json_response(status: StatusCode, data: &Value) -> FakeResponse {
+    Response::builder()
+        .status(status)
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(Full::new(Bytes::from(data.to_string())))
+        .expect("valid response")
+}
+
+/// Build response without body
+fn empty_response(status: StatusCode) -> FakeResponse {
+    Response::builder()
+        .status(status)
+        .body(Full::new(Bytes::new()))
+        .expect("valid response")
+}
+
+/// Build error response
+fn error_response(status: StatusCode, message: &str) -> FakeResponse {
+    json_response(
+        status,
+        &json!({"error": {
+            "code": status.as_u16(),
+            "title": status.canonical_reason(),
+            "message": message,
+        }}),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::{FakeCloud, FakeResource};
+    use crate::types::ServiceType;
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_network_crud_async() {
+        use crate::api::network::v2::{network, port};
+        use crate::api::{ignore, QueryAsync};
+        use crate::AsyncOpenStack;
+
+        let cloud = FakeCloud::new();
+        let mut session = AsyncOpenStack::new(&cloud.cloud_config()).await.unwrap();
+        session
+            .discover_service_endpoint(&ServiceType::Network)
+            .await
+            .unwrap();
+
+        let net: Value = network::create::Request::builder()
+            .network(
+                network::create::NetworkBuilder::default()
+                    .name("net")
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap()
+            .query_async(&session)
+            .await
+            .unwrap();
+        let net_id = net["id"].as_str().unwrap();
+        let port: Value = port::create::Request::builder()
+            .port(
+                port::create::PortBuilder::default()
+                    .network_id(net_id)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap()
+            .query_async(&session)
+            .await
+            .unwrap();
+        assert_eq!(port["network_id"], net_id);
+        assert_eq!(port["status"], "DOWN");
+
+        let ports: Vec<Value> = port::list::Request::builder()
+            .network_id(net_id)
+            .build()
+            .unwrap()
+            .query_async(&session)
+            .await
+            .unwrap();
+        assert_eq!(ports, std::slice::from_ref(&port));
+
+        // Network with ports is still in use
+        let delete_net = network::delete::Request::builder()
+            .id(net_id)
+            .build()
+            .unwrap();
+        assert!(ignore(delete_net.clone())
+            .query_async(&session)
+            .await
+            .is_err());
+        ignore(
+            port::delete::Request::builder()
+                .id(port["id"].as_str().unwrap())
+                .build()
+                .unwrap(),
+        )
+        .query_async(&session)
+        .await
+        .unwrap();
+        ignore(delete_net).query_async(&session).await.unwrap();
+        assert!(cloud.resources(FakeResource::Network).is_empty());
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_image_upload_async() {
+        use crate::api::image::v2::image::{file, get, patch};
+        use crate::api::{QueryAsync, RawQueryAsync};
+        use crate::types::BoxedAsyncRead;
+        use crate::AsyncOpenStack;
+
+        let cloud = FakeCloud::new();
+        let image = cloud.add_resource(FakeResource::Image, json!({"name": "cirros"}));
+        let image_id = image["id"].as_str().unwrap();
+        let mut session = AsyncOpenStack::new(&cloud.cloud_config()).await.unwrap();
+        session
+            .discover_service_endpoint(&ServiceType::Image)
+            .await
+            .unwrap();
+
+        file::upload::Request::builder()
+            .image_id(image_id)
+            .build()
+            .unwrap()
+            .raw_query_read_body_async(
+                &session,
+                BoxedAsyncRead::new(futures::io::Cursor::new(b"image data".to_vec())),
+            )
+            .await
+            .unwrap();
+        let _: Value = patch::Request::builder()
+            .id(image_id)
+            .patch(
+                serde_json::from_value(json!([
+                    {"op": "replace", "path": "/name", "value": "new"},
+                    {"op": "add", "path": "/os_distro", "value": "cirros"},
+                ]))
+                .unwrap(),
+            )
+            .build()
+            .unwrap()
+            .query_async(&session)
+            .await
+            .unwrap();
+
+        let image: Value = get::Request::builder()
+            .id(image_id)
+            .build()
+            .unwrap()
+            .query_async(&session)
+            .await
+            .unwrap();
+        assert_eq!(image["name"], "new");
+        assert_eq!(image["os_distro"], "cirros");
+        assert_eq!(image["status"], "active");
+        assert_eq!(image["size"], 10);
+    }
+
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_volume_pagination() {
+        use crate::api::block_storage::v3::volume::list_detailed;
+        use crate::api::{paged, Pagination, Query};
+        use crate::OpenStack;
+
+        let cloud = FakeCloud::new();
+        for name in ["a", "b", "c"] {
+            cloud.add_resource(FakeResource::Volume, json!({ "name": name }));
+        }
+        let mut session = OpenStack::new(&cloud.cloud_config()).unwrap();
+        session
+            .discover_service_endpoint(&ServiceType::BlockStorage)
+            .unwrap();
+
+        let ep = list_detailed::Request::builder().limit(1).build().unwrap();
+        let volumes: Vec<Value> = paged(ep, Pagination::All).query(&session).unwrap();
+        let names: Vec<&Value> = volumes.iter().map(|x| &x["name"]).collect();
+        assert_eq!(names, ["a", "b", "c"]);
+        assert!(volumes.iter().all(|x| x["status"] == "available"));
+    }
+
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_volume_api_version() {
+        use crate::api::RestClient;
+        use crate::OpenStack;
+
+        let cloud = FakeCloud::new();
+        let mut config = cloud.cloud_config();
+        config
+            .options
+            .insert("volume_api_version".into(), config::Value::from("2"));
+        let mut session = OpenStack::new(&config).unwrap();
+        session
+            .discover_service_endpoint(&ServiceType::BlockStorage)
+            .unwrap();
+
+        let ep = session
+            .get_service_endpoint(&ServiceType::BlockStorage)
+            .unwrap();
+        assert_eq!(ep.versions.len(), 2);
+        assert_eq!(ep.current_version.unwrap().id, "v2.0");
+        let url = session
+            .rest_endpoint(&ServiceType::BlockStorage, "types")
+            .unwrap();
+        assert_eq!(
+            url.path(),
+            format!("/volume/v2/{}/types", FakeCloud::PROJECT_ID)
+        );
+
+        let mut session = OpenStack::new(&cloud.cloud_config()).unwrap();
+        session.set_service_api_version(&ServiceType::BlockStorage, "4");
+        assert!(session
+            .discover_service_endpoint(&ServiceType::BlockStorage)
+            .is_err());
+    }
+
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_object_store() {
+        use std::io::Read;
+
+        use crate::api::object_store::v1::{container, object};
+        use crate::api::{ignore, Query, RawQuery};
+        use crate::types::BoxedRead;
+        use crate::OpenStack;
+
+        let cloud = FakeCloud::new();
+        let mut session = OpenStack::new(&cloud.cloud_config()).unwrap();
+        session
+            .discover_service_endpoint(&ServiceType::ObjectStore)
+            .unwrap();
+
+        ignore(
+            container::put::Container::builder()
+                .container("foo")
+                .build()
+                .unwrap(),
+        )
+        .query(&session)
+        .unwrap();
+        object::put::Object::builder()
+            .container("foo")
+            .object("dir/bar")
+            .build()
+            .unwrap()
+            .raw_query_read_body(
+                &session,
+                BoxedRead::new(std::io::Cursor::new(b"content".to_vec())),
+            )
+            .unwrap();
+        assert_eq!(cloud.object("foo", "dir/bar").unwrap(), "content");
+
+        let objects: Vec<Value> = container::get::Container::builder()
+            .container("foo")
+            .delimiter("/")
+            .build()
+            .unwrap()
+            .query(&session)
+            .unwrap();
+        assert_eq!(objects, [json!({"subdir": "dir/"})]);
+
+        let (_, mut data) = object::get::Object::builder()
+            .container("foo")
+            .object("dir/bar")
+            .build()
+            .unwrap()
+            .download(&session)
+            .unwrap();
+        let mut content = String::new();
+        data.read_to_string(&mut content).unwrap();
+        assert_eq!(content, "content");
+    }
+}
@@ -0,0 +1,235 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Identity service and version discovery documents of the fake cloud
+
+use http::{Method, StatusCode};
+use serde_json::{json, Value};
+
+use super::{
+    error_response, json_response, CloudState, FakeCloud, FakeRequest, FakeResponse, REGION,
+};
+
+impl CloudState {
+    /// Handle identity service request
+    pub(super) fn handle_identity(
+        &mut self,
+        request: &FakeRequest,
+        path: &[String],
+    ) -> FakeResponse {
+        let path: Vec<&str> = path.iter().map(String::as_str).collect();
+        match (&request.method, path.as_slice()) {
+            (&Method::GET, []) => json_response(
+                StatusCode::MULTIPLE_CHOICES,
+                &json!({"versions": {"values": [self.identity_version()]}}),
+            ),
+            (&Method::GET, ["v3"]) => {
+                json_response(StatusCode::OK, &json!({"version": self.identity_version()}))
+            }
+            (&Method::POST, ["v3", "auth", "tokens"]) => self.issue_token(request),
+            (&Method::GET, ["v3", "auth", "tokens"]) => {
+                match request
+                    .header("x-subject-token")
+                    .and_then(|x| self.tokens.get(x))
+                {
+                    Some(token) => json_response(StatusCode::OK, token),
+                    None => error_response(StatusCode::NOT_FOUND, "Could not find token."),
+                }
+            }
+            _ => error_response(StatusCode::NOT_FOUND, "Not Found"),
+        }
+    }
+
+    /// Handle version discovery of other services
+    ///
+    /// Returns `None` when the request is not for a discovery document.
+    pub(super) fn discovery(
+        &self,
+        request: &FakeRequest,
+        service: &str,
+        path: &[String],
+    ) -> Option<FakeResponse> {
+        if request.method != Method::GET {
+            return None;
+        }
+        let path: Vec<&str> = path.iter().map(String::as_str).collect();
+        // Deprecated block storage v2 is only advertised by the unversioned
+        // document
+        let volume_v2 = json!({
+            "id": "v2.0",
+            "status": "DEPRECATED",
+            "links": [self.link("self", "volume/v2/")],
+        });
+        let version = match (service, path.as_slice()) {
+            ("volume", ["v2"]) => volume_v2.clone(),
+            ("compute", [] | ["v2.1"]) => json!({
+                "id": "v2.1",
+                "status": "CURRENT",
+                "version": "2.96",
+                "min_version": "2.1",
+                "links": [self.link("self", "compute/v2.1/")],
+            }),
+            ("network", []) => json!({
+                "id": "v2.0",
+                "status": "CURRENT",
+                "links": [self.link("self", "network/v2.0/")],
+            }),
+            ("volume", [] | ["v3"]) => json!({
+                "id": "v3.0",
+                "status": "CURRENT",
+                "version": "3.71",
+                "min_version": "3.0",
+                "links": [self.link("self", "volume/v3/")],
+            }),
+            ("image", []) => json!({
+                "id": "v2.16",
+                "status": "CURRENT",
+                "links": [self.link("self", "image/v2/")],
+            }),
+            _ => return None,
+        };
+        Some(match path.as_slice() {
+            [] if service == "volume" => {
+                json_response(StatusCode::OK, &json!({"versions": [volume_v2, version]}))
+            }
+            [] => json_response(StatusCode::OK, &json!({"versions": [version]})),
+            _ => json_response(StatusCode::OK, &json!({"version": version})),
+        })
+    }
+
+    /// Identity v3 version information
+    fn identity_version(&self) -> Value {
+        json!({
+            "id": "v3.14",
+            "status": "stable",
+            "updated": "2020-04-07T00:00:00Z",
+            "links": [self.link("self", "identity/v3/")],
+        })
+    }
+
+    /// Build link object pointing to the server
+    fn link(&self, rel: &str, path: &str) -> Value {
+        json!({"rel": rel, "href": format!("{}/{}", self.url, path)})
+    }
+
+    /// Service catalog of the cloud
+    fn catalog(&self) -> Value {
+        let services = [
+            ("identity", "keystone", "identity".to_string()),
+            ("compute", "nova", "compute/v2.1".to_string()),
+            ("network", "neutron", "network".to_string()),
+            (
+                "block-storage",
+                "cinder",
+                format!("volume/v3/{}", FakeCloud::PROJECT_ID),
+            ),
+            ("image", "glance", "image".to_string()),
+            (
+                "object-store",
+                "swift",
+                format!("object-store/v1/AUTH_{}", FakeCloud::PROJECT_ID),
+            ),
+        ];
+        services
+            .iter()
+            .enumerate()
+            .map(|(idx, (service_type, name, path))| {
+                json!({
+                    "id": format!("{:032x}", idx + 1),
+                    "type": service_type,
+                    "name": name,
+                    "endpoints": [{
+                        "id": format!("{:032x}", idx + 101),
+                        "interface": "public",
+                        "region": REGION,
+                        "region_id": REGION,
+                        "url": format!("{}/{}", self.url, path),
+                    }],
+                })
+            })
+            .collect()
+    }
+
+    /// Authenticate the user and issue new token
+    fn issue_token(&mut self, request: &FakeRequest) -> FakeResponse {
+        let unauthorized = error_response(
+            StatusCode::UNAUTHORIZED,
+            "The request you have made requires authentication.",
+        );
+        let Some(auth) = request
+            .json()
+            .and_then(|mut x| x.get_mut("auth").map(Value::take))
+        else {
+            return error_response(StatusCode::BAD_REQUEST, "Auth data is missing");
+        };
+        let identity = &auth["identity"];
+        let methods = identity["methods"].clone();
+        let authenticated = methods.as_array().is_some_and(|methods| {
+            methods.iter().all(|method| match method.as_str() {
+                Some("password") => {
+                    let user = &identity["password"]["user"];
+                    (user["name"] == FakeCloud::USERNAME || user["id"] == FakeCloud::USER_ID)
+                        && user["password"] == FakeCloud::PASSWORD
+                }
+                // Tokens are not validated by the fake cloud
+                Some("token") => identity["token"]["id"].is_string(),
+                _ => false,
+            }) && !methods.is_empty()
+        });
+        if !authenticated {
+            return unauthorized;
+        }
+
+        let now = chrono::Utc::now();
+        let domain = json!({"id": "default", "name": FakeCloud::DOMAIN_NAME});
+        let mut token = json!({
+            "methods": methods,
+            "user": {
+                "id": FakeCloud::USER_ID,
+                "name": FakeCloud::USERNAME,
+                "domain": domain,
+                "password_expires_at": null,
+            },
+            "audit_ids": [],
+            "issued_at": now.to_rfc3339(),
+            "expires_at": (now + chrono::Duration::hours(1)).to_rfc3339(),
+        });
+        if let Some(project) = auth["scope"].get("project") {
+            if project["name"] != FakeCloud::PROJECT_NAME && project["id"] != FakeCloud::PROJECT_ID
+            {
+                return unauthorized;
+            }
+            token["project"] = json!({
+                "id": FakeCloud::PROJECT_ID,
+                "name": FakeCloud::PROJECT_NAME,
+                "domain": domain,
+            });
+            token["roles"] = json!([
+                {"id": format!("{:032x}", 1), "name": "admin"},
+                {"id": format!("{:032x}", 2), "name": "member"},
+            ]);
+            token["catalog"] = self.catalog();
+        }
+
+        let token_id = format!("fake-{}", self.new_id());
+        let data = json!({ "token": token });
+        self.tokens.insert(token_id.clone(), data.clone());
+        let mut response = json_response(StatusCode::CREATED, &data);
+        response.headers_mut().insert(
+            "x-subject-token",
+            token_id.parse().expect("valid token header"),
+        );
+        response
+    }
+}
@@ -0,0 +1,360 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Object store service of the fake cloud
+
+use std::collections::BTreeMap;
+
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use http::{header, HeaderName, HeaderValue, Method, Response, StatusCode};
+use http_body_util::Full;
+use serde_json::{json, Value};
+
+use super::{
+    empty_response, error_response, json_response, CloudState, FakeCloud, FakeRequest, FakeResponse,
+};
+
+/// Default maximal amount of entries in the listing.
+const LISTING_LIMIT: usize = 10000;
+
+/// Object store container
+pub(super) struct FakeContainer {
+    metadata: BTreeMap<String, String>,
+    objects: BTreeMap<String, FakeObject>,
+    last_modified: DateTime<Utc>,
+}
+
+/// Object store object
+struct FakeObject {
+    data: Bytes,
+    content_type: String,
+    metadata: BTreeMap<String, String>,
+    last_modified: DateTime<Utc>,
+}
+
+impl FakeContainer {
+    /// Total size of the objects
+    fn bytes(&self) -> usize {
+        self.objects.values().map(|x| x.data.len()).sum()
+    }
+
+    /// Response headers describing the container
+    fn headers(&self) -> Vec<(String, String)> {
+        let mut headers = Vec::from([
+            (
+                "x-container-object-count".to_string(),
+                self.objects.len().to_string(),
+            ),
+            (
+                "x-container-bytes-used".to_string(),
+                self.bytes().to_string(),
+            ),
+        ]);
+        headers.extend(self.metadata.clone());
+        headers
+    }
+}
+
+impl FakeObject {
+    /// Response headers describing the object
+    fn headers(&self) -> Vec<(String, String)> {
+        let mut headers = Vec::from([
+            (header::CONTENT_TYPE.to_string(), self.content_type.clone()),
+            (
+                header::CONTENT_LENGTH.to_string(),
+                self.data.len().to_string(),
+            ),
+            (
+                header::LAST_MODIFIED.to_string(),
+                self.last_modified
+                    .format("%a, %d %b %Y %H:%M:%S GMT")
+                    .to_string(),
+            ),
+        ]);
+        headers.extend(self.metadata.clone());
+        headers
+    }
+}
+
+impl CloudState {
+    /// Handle object store service request
+    pub(super) fn handle_object_store(
+        &mut self,
+        request: &FakeRequest,
+        path: &[String],
+    ) -> FakeResponse {
+        let account = format!("AUTH_{}", FakeCloud::PROJECT_ID);
+        match path {
+            [version, name, rest @ ..] if version == "v1" && *name == account => match rest {
+                [] => self.handle_account(request),
+                [container] => self.handle_container(request, container),
+                [container, object @ ..] => {
+                    self.handle_object(request, container, &object.join("/"))
+                }
+            },
+            _ => error_response(StatusCode::NOT_FOUND, "Not Found"),
+        }
+    }
+
+    /// Content of the object
+    pub(super) fn object_data(&self, container: &str, object: &str) -> Option<Bytes> {
+        self.containers
+            .get(container)?
+            .objects
+            .get(object)
+            .map(|x| x.data.clone())
+    }
+
+    /// Handle account request
+    fn handle_account(&mut self, request: &FakeRequest) -> FakeResponse {
+        let mut headers = Vec::from([
+            (
+                "x-account-container-count".to_string(),
+                self.containers.len().to_string(),
+            ),
+            (
+                "x-account-object-count".to_string(),
+                self.containers
+                    .values()
+                    .map(|x| x.objects.len())
+                    .sum::<usize>()
+                    .to_string(),
+            ),
+            (
+                "x-account-bytes-used".to_string(),
+                self.containers
+                    .values()
+                    .map(FakeContainer::bytes)
+                    .sum::<usize>()
+                    .to_string(),
+            ),
+        ]);
+        headers.extend(self.account_metadata.clone());
+        match request.method {
+            Method::GET => {
+                let data: Vec<Value> = filter_names(self.containers.keys(), request)
+                    .into_iter()
+                    .take(listing_limit(request))
+                    .filter_map(|name| {
+                        self.containers.get(name).map(|container| {
+                            json!({
+                                "name": name,
+                                "count": container.objects.len(),
+                                "bytes": container.bytes(),
+                                "last_modified": format_timestamp(&container.last_modified),
+                            })
+                        })
+                    })
+                    .collect();
+                with_headers(json_response(StatusCode::OK, &data.into()), headers)
+            }
+            Method::HEAD => with_headers(empty_response(StatusCode::NO_CONTENT), headers),
+            Method::POST => {
+                self.account_metadata
+                    .extend(request_metadata(request, "x-account-meta-"));
+                empty_response(StatusCode::NO_CONTENT)
+            }
+            _ => error_response(StatusCode::METHOD_NOT_ALLOWED, "Method Not Allowed"),
+        }
+    }
+
+    /// Handle container request
+    fn handle_container(&mut self, request: &FakeRequest, name: &str) -> FakeResponse {
+        if let Method::PUT = request.method {
+            let metadata = request_metadata(request, "x-container-meta-");
+            return match self.containers.get_mut(name) {
+                Some(container) => {
+                    container.metadata.extend(metadata);
+                    empty_response(StatusCode::ACCEPTED)
+                }
+                None => {
+                    self.containers.insert(
+                        name.to_string(),
+                        FakeContainer {
+                            metadata,
+                            objects: BTreeMap::new(),
+                            last_modified: Utc::now(),
+                        },
+                    );
+                    empty_response(StatusCode::CREATED)
+                }
+            };
+        }
+        let Some(container) = self.containers.get_mut(name) else {
+            return error_response(StatusCode::NOT_FOUND, "Not Found");
+        };
+        match request.method {
+            Method::GET => {
+                let prefix = request.query_param("prefix").unwrap_or_default();
+                let delimiter = request.query_param("delimiter").filter(|x| !x.is_empty());
+                let mut data: Vec<Value> = Vec::new();
+                for name in filter_names(container.objects.keys(), request) {
+                    if let Some(pos) = delimiter.and_then(|x| name[prefix.len()..].find(x)) {
+                        // Pseudo-directory
+                        let delimiter_len = delimiter.map(str::len).unwrap_or_default();
+                        let subdir = &name[..prefix.len() + pos + delimiter_len];
+                        if data.last().is_some_and(|x| x["subdir"] == subdir) {
+                            continue;
+                        }
+                        data.push(json!({ "subdir": subdir }));
+                    } else if let Some(object) = container.objects.get(name) {
+                        data.push(json!({
+                            "name": name,
+                            "bytes": object.data.len(),
+                            "content_type": object.content_type,
+                            "last_modified": format_timestamp(&object.last_modified),
+                        }));
+                    }
+                }
+                data.truncate(listing_limit(request));
+                with_headers(
+                    json_response(StatusCode::OK, &data.into()),
+                    container.headers(),
+                )
+            }
+            Method::HEAD => {
+                with_headers(empty_response(StatusCode::NO_CONTENT), container.headers())
+            }
+            Method::POST => {
+                container
+                    .metadata
+                    .extend(request_metadata(request, "x-container-meta-"));
+                empty_response(StatusCode::NO_CONTENT)
+            }
+            Method::DELETE => {
+                if !container.objects.is_empty() {
+                    return error_response(
+                        StatusCode::CONFLICT,
+                        "There was a conflict when trying to complete your request.",
+                    );
+                }
+                self.containers.remove(name);
+                empty_response(StatusCode::NO_CONTENT)
+            }
+            _ => error_response(StatusCode::METHOD_NOT_ALLOWED, "Method Not Allowed"),
+        }
+    }
+
+    /// Handle object request
+    fn handle_object(
+        &mut self,
+        request: &FakeRequest,
+        container: &str,
+        name: &str,
+    ) -> FakeResponse {
+        let Some(container) = self.containers.get_mut(container) else {
+            return error_response(StatusCode::NOT_FOUND, "Not Found");
+        };
+        if let Method::PUT = request.method {
+            let object = FakeObject {
+                data: request.body.clone(),
+                content_type: request
+                    .header(header::CONTENT_TYPE.as_str())
+                    .unwrap_or("application/octet-stream")
+                    .to_string(),
+                metadata: request_metadata(request, "x-object-meta-"),
+                last_modified: Utc::now(),
+            };
+            container.objects.insert(name.to_string(), object);
+            container.last_modified = Utc::now();
+            return empty_response(StatusCode::CREATED);
+        }
+        let Some(object) = container.objects.get_mut(name) else {
+            return error_response(StatusCode::NOT_FOUND, "Not Found");
+        };
+        match request.method {
+            Method::GET => {
+                let response = Response::builder()
+                    .status(StatusCode::OK)
+                    .body(Full::new(object.data.clone()))
+                    .expect("valid response");
+                with_headers(response, object.headers())
+            }
+            Method::HEAD => with_headers(empty_response(StatusCode::OK), object.headers()),
+            Method::POST => {
+                object.metadata = request_metadata(request, "x-object-meta-");
+                empty_response(StatusCode::ACCEPTED)
+            }
+            Method::DELETE => {
+                container.objects.remove(name);
+                container.last_modified = Utc::now();
+                empty_response(StatusCode::NO_CONTENT)
+            }
+            _ => error_response(StatusCode::METHOD_NOT_ALLOWED, "Method Not Allowed"),
+        }
+    }
+}
+
+/// Apply `prefix`, `marker`, `end_marker` and `reverse` listing parameters
+fn filter_names<'a, I>(names: I, request: &FakeRequest) -> Vec<&'a str>
+where
+    I: Iterator<Item = &'a String>,
+{
+    let prefix = request.query_param("prefix").unwrap_or_default();
+    let marker = request.query_param("marker");
+    let end_marker = request.query_param("end_marker");
+    let reverse = request
+        .query_param("reverse")
+        .is_some_and(|x| matches!(x.to_lowercase().as_str(), "1" | "true" | "yes" | "on"));
+    let mut names: Vec<&str> = names
+        .map(String::as_str)
+        .filter(|name| name.starts_with(prefix))
+        .collect();
+    if reverse {
+        names.reverse();
+    }
+    names.retain(|name| {
+        let after_marker = marker.map_or(true, |x| if reverse { *name < x } else { *name > x });
+        let before_end = end_marker.map_or(true, |x| if reverse { *name > x } else { *name < x });
+        after_marker && before_end
+    });
+    names
+}
+
+/// Maximal amount of entries to return in the listing
+fn listing_limit(request: &FakeRequest) -> usize {
+    request
+        .query_param("limit")
+        .and_then(|x| x.parse().ok())
+        .unwrap_or(LISTING_LIMIT)
+}
+
+/// Metadata headers with the given prefix
+fn request_metadata(request: &FakeRequest, prefix: &str) -> BTreeMap<String, String> {
+    request
+        .headers
+        .iter()
+        .filter(|(name, _)| name.as_str().starts_with(prefix))
+        .filter_map(|(name, val)| Some((name.to_string(), val.to_str().ok()?.to_string())))
+        .collect()
+}
+
+/// Timestamp in the format of the listings
+fn format_timestamp(timestamp: &DateTime<Utc>) -> String {
+    timestamp.format("%Y-%m-%dT%H:%M:%S%.6f").to_string()
+}
+
+/// Add headers to the response
+fn with_headers(mut response: FakeResponse, headers: Vec<(String, String)>) -> FakeResponse {
+    for (name, val) in headers {
+        if let (Ok(name), Ok(val)) = (
+            HeaderName::from_bytes(name.as_bytes()),
+            HeaderValue::from_str(&val),
+        ) {
+            response.headers_mut().insert(name, val);
+        }
+    }
+    response
+}
@@ -0,0 +1,493 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Compute, network, block storage and image resources of the fake cloud
+
+use http::{header, Method, Response, StatusCode};
+use http_body_util::Full;
+use serde_json::{json, Map, Value};
+
+use super::{
+    empty_response, error_response, json_response, now, CloudState, FakeCloud, FakeRequest,
+    FakeResource, FakeResponse,
+};
+
+/// Query parameters not used to filter the listings.
+const NON_FILTER_PARAMS: &[&str] = &[
+    "all_projects",
+    "all_tenants",
+    "fields",
+    "limit",
+    "marker",
+    "offset",
+    "sort",
+    "sort_dir",
+    "sort_key",
+];
+
+impl FakeResource {
+    /// Resource and collection keys used in the API
+    fn keys(&self) -> (&'static str, &'static str) {
+        match self {
+            FakeResource::Server => ("server", "servers"),
+            FakeResource::Network => ("network", "networks"),
+            FakeResource::Port => ("port", "ports"),
+            FakeResource::Volume => ("volume", "volumes"),
+            FakeResource::Image => ("image", "images"),
+        }
+    }
+
+    /// Whether the resource is wrapped into the resource key in the API
+    fn is_wrapped(&self) -> bool {
+        !matches!(self, FakeResource::Image)
+    }
+
+    /// Name of the attribute with the last modification time
+    fn updated_key(&self) -> &'static str {
+        match self {
+            FakeResource::Server => "updated",
+            _ => "updated_at",
+        }
+    }
+}
+
+impl CloudState {
+    /// Handle compute service request
+    pub(super) fn handle_compute(
+        &mut self,
+        request: &FakeRequest,
+        path: &[String],
+    ) -> FakeResponse {
+        if let Some(rsp) = self.discovery(request, "compute", path) {
+            return rsp;
+        }
+        let path: Vec<&str> = path.iter().map(String::as_str).collect();
+        match path.as_slice() {
+            ["v2.1", "servers", rest @ ..] => {
+                self.handle_collection(request, FakeResource::Server, rest)
+            }
+            _ => error_response(StatusCode::NOT_FOUND, "Not Found"),
+        }
+    }
+
+    /// Handle network service request
+    pub(super) fn handle_network(
+        &mut self,
+        request: &FakeRequest,
+        path: &[String],
+    ) -> FakeResponse {
+        if let Some(rsp) = self.discovery(request, "network", path) {
+            return rsp;
+        }
+        let path: Vec<&str> = path.iter().map(String::as_str).collect();
+        match path.as_slice() {
+            ["v2.0", "networks", rest @ ..] => {
+                self.handle_collection(request, FakeResource::Network, rest)
+            }
+            ["v2.0", "ports", rest @ ..] => {
+                self.handle_collection(request, FakeResource::Port, rest)
+            }
+            _ => error_response(StatusCode::NOT_FOUND, "Not Found"),
+        }
+    }
+
+    /// Handle block storage service request
+    pub(super) fn handle_volume(&mut self, request: &FakeRequest, path: &[String]) -> FakeResponse {
+        if let Some(rsp) = self.discovery(request, "volume", path) {
+            return rsp;
+        }
+        let path: Vec<&str> = path.iter().map(String::as_str).collect();
+        match path.as_slice() {
+            ["v2" | "v3", project_id, "volumes", rest @ ..]
+                if *project_id == FakeCloud::PROJECT_ID =>
+            {
+                self.handle_collection(request, FakeResource::Volume, rest)
+            }
+            _ => error_response(StatusCode::NOT_FOUND, "Not Found"),
+        }
+    }
+
+    /// Handle image service request
+    pub(super) fn handle_image(&mut self, request: &FakeRequest, path: &[String]) -> FakeResponse {
+        if let Some(rsp) = self.discovery(request, "image", path) {
+            return rsp;
+        }
+        let path: Vec<&str> = path.iter().map(String::as_str).collect();
+        match path.as_slice() {
+            ["v2", "images", id, "file"] => self.handle_image_file(request, id),
+            ["v2", "images", rest @ ..] => {
+                self.handle_collection(request, FakeResource::Image, rest)
+            }
+            _ => error_response(StatusCode::NOT_FOUND, "Not Found"),
+        }
+    }
+
+    /// Handle CRUD operations on the resource collection
+    fn handle_collection(
+        &mut self,
+        request: &FakeRequest,
+        resource: FakeResource,
+        path: &[&str],
+    ) -> FakeResponse {
+        match (&request.method, path) {
+            (&Method::GET, [] | ["detail"]) => self.list_resources(request, resource),
+            (&Method::POST, []) => {
+                let Some(data) = request_data(request, resource) else {
+                    return error_response(StatusCode::BAD_REQUEST, "Invalid request body");
+                };
+                if let FakeResource::Port = resource {
+                    let network_id = data.get("network_id").and_then(Value::as_str);
+                    if network_id
+                        .and_then(|id| self.find_resource(FakeResource::Network, id))
+                        .is_none()
+                    {
+                        return error_response(
+                            StatusCode::NOT_FOUND,
+                            "Network could not be found.",
+                        );
+                    }
+                }
+                let status = match resource {
+                    FakeResource::Server | FakeResource::Volume => StatusCode::ACCEPTED,
+                    _ => StatusCode::CREATED,
+                };
+                let item = self.create_resource(resource, data);
+                json_response(status, &wrap(resource, item))
+            }
+            (&Method::GET, [id]) => match self.find_resource(resource, id) {
+                Some(item) => json_response(StatusCode::OK, &wrap(resource, item.clone())),
+                None => resource_not_found(resource, id),
+            },
+            (&Method::PUT, [id]) if resource.is_wrapped() => {
+                let Some(Value::Object(data)) = request_data(request, resource) else {
+                    return error_response(StatusCode::BAD_REQUEST, "Invalid request body");
+                };
+                let Some(Value::Object(item)) = self.find_resource_mut(resource, id) else {
+                    return resource_not_found(resource, id);
+                };
+                // Neutron `If-Match: revision_number=N` constraint
+                let revision = item.get("revision_number").and_then(Value::as_u64);
+                if let (Some(current), Some(expected)) = (
+                    revision,
+                    request
+                        .header("if-match")
+                        .and_then(|x| x.strip_prefix("revision_number="))
+                        .and_then(|x| x.parse::<u64>().ok()),
+                ) {
+                    if current != expected {
+                        return json_response(
+                            StatusCode::PRECONDITION_FAILED,
+                            &json!({"NeutronError": {
+                                "type": "RevisionNumberConstraintFailed",
+                                "message": format!(
+                                    "Constrained to {}, but current revision is {}",
+                                    expected, current
+                                ),
+                                "detail": "",
+                            }}),
+                        );
+                    }
+                }
+                for (key, val) in data.into_iter().filter(|(key, _)| key != "id") {
+                    item.insert(key, val);
+                }
+                item.insert(resource.updated_key().into(), now().into());
+                if let Some(current) = revision {
+                    item.insert("revision_number".into(), (current + 1).into());
+                }
+                json_response(StatusCode::OK, &wrap(resource, Value::Object(item.clone())))
+            }
+            (&Method::PATCH, [id]) if !resource.is_wrapped() => {
+                let Ok(patch) = serde_json::from_slice::<json_patch::Patch>(&request.body) else {
+                    return error_response(StatusCode::BAD_REQUEST, "Invalid JSON patch");
+                };
+                let Some(item) = self.find_resource_mut(resource, id) else {
+                    return resource_not_found(resource, id);
+                };
+                let mut patched = item.clone();
+                if json_patch::patch(&mut patched, &patch).is_err() {
+                    return error_response(StatusCode::CONFLICT, "JSON patch cannot be applied");
+                }
+                patched[resource.updated_key()] = now().into();
+                *item = patched;
+                json_response(StatusCode::OK, item)
+            }
+            (&Method::DELETE, [id]) => self.delete_resource(resource, id),
+            _ => error_response(StatusCode::NOT_FOUND, "Not Found"),
+        }
+    }
+
+    /// List resources applying filters and pagination
+    fn list_resources(&self, request: &FakeRequest, resource: FakeResource) -> FakeResponse {
+        let (_, collection) = resource.keys();
+        let items: Vec<&Value> = self
+            .resources
+            .get(&resource)
+            .map(|items| {
+                items
+                    .iter()
+                    .filter(|item| matches_filters(item, &request.query))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let start = match request.query_param("marker") {
+            Some(marker) => match items.iter().position(|item| item["id"] == marker) {
+                Some(pos) => pos + 1,
+                None => {
+                    return error_response(
+                        StatusCode::BAD_REQUEST,
+                        &format!("Marker {} could not be found.", marker),
+                    )
+                }
+            },
+            None => 0,
+        };
+        let end = request
+            .query_param("limit")
+            .and_then(|x| x.parse::<usize>().ok())
+            .filter(|x| *x > 0)
+            .map_or(items.len(), |limit| items.len().min(start + limit));
+        let page: Vec<Value> = items[start..end].iter().map(|x| (*x).clone()).collect();
+
+        let mut data = json!({ collection: page });
+        if let (true, Some(last)) = (end < items.len(), page.last()) {
+            let query = form_urlencoded::Serializer::new(String::new())
+                .extend_pairs(request.query.iter().filter(|(key, _)| key != "marker"))
+                .append_pair("marker", last["id"].as_str().unwrap_or_default())
+                .finish();
+            if resource.is_wrapped() {
+                let href = format!("{}/{}?{}", self.url, request.path.join("/"), query);
+                data[format!("{}_links", collection)] = json!([{"rel": "next", "href": href}]);
+            } else {
+                // Glance returns link relative to the service endpoint
+                data["next"] = format!("/{}?{}", request.path[1..].join("/"), query).into();
+            }
+        }
+        json_response(StatusCode::OK, &data)
+    }
+
+    /// Store new resource setting default attributes
+    pub(super) fn create_resource(&mut self, resource: FakeResource, data: Value) -> Value {
+        let mut item = match data {
+            Value::Object(item) => item,
+            _ => Map::new(),
+        };
+        let id = match item.get("id").and_then(Value::as_str) {
+            Some(id) => id.to_string(),
+            None => self.new_id(),
+        };
+        item.insert("id".into(), id.clone().into());
+        let timestamp = now();
+        let defaults = match resource {
+            FakeResource::Server => json!({
+                "name": "",
+                "status": "ACTIVE",
+                "tenant_id": FakeCloud::PROJECT_ID,
+                "user_id": FakeCloud::USER_ID,
+                "addresses": {},
+                "metadata": {},
+                "created": timestamp,
+                "updated": timestamp,
+            }),
+            FakeResource::Network => json!({
+                "name": "",
+                "status": "ACTIVE",
+                "admin_state_up": true,
+                "shared": false,
+                "router:external": false,
+                "subnets": [],
+                "tags": [],
+                "revision_number": 1,
+                "project_id": FakeCloud::PROJECT_ID,
+                "tenant_id": FakeCloud::PROJECT_ID,
+                "created_at": timestamp,
+                "updated_at": timestamp,
+            }),
+            FakeResource::Port => json!({
+                "name": "",
+                "status": "DOWN",
+                "admin_state_up": true,
+                "mac_address": format!(
+                    "fa:16:3e:{:02x}:{:02x}:{:02x}",
+                    (self.last_id >> 16) & 0xff,
+                    (self.last_id >> 8) & 0xff,
+                    self.last_id & 0xff
+                ),
+                "fixed_ips": [],
+                "device_id": "",
+                "device_owner": "",
+                "tags": [],
+                "revision_number": 1,
+                "project_id": FakeCloud::PROJECT_ID,
+                "tenant_id": FakeCloud::PROJECT_ID,
+                "created_at": timestamp,
+                "updated_at": timestamp,
+            }),
+            FakeResource::Volume => json!({
+                "name": null,
+                "status": "available",
+                "size": 1,
+                "bootable": "false",
+                "multiattach": false,
+                "attachments": [],
+                "metadata": {},
+                "user_id": FakeCloud::USER_ID,
+                "os-vol-tenant-attr:tenant_id": FakeCloud::PROJECT_ID,
+                "created_at": timestamp,
+                "updated_at": timestamp,
+            }),
+            FakeResource::Image => json!({
+                "name": null,
+                "status": "queued",
+                "visibility": "shared",
+                "protected": false,
+                "tags": [],
+                "size": null,
+                "min_disk": 0,
+                "min_ram": 0,
+                "owner": FakeCloud::PROJECT_ID,
+                "file": format!("/v2/images/{}/file", id),
+                "self": format!("/v2/images/{}", id),
+                "schema": "/v2/schemas/image",
+                "created_at": timestamp,
+                "updated_at": timestamp,
+            }),
+        };
+        if let Value::Object(defaults) = defaults {
+            for (key, val) in defaults {
+                item.entry(key).or_insert(val);
+            }
+        }
+        let item = Value::Object(item);
+        self.resources
+            .entry(resource)
+            .or_default()
+            .push(item.clone());
+        item
+    }
+
+    /// Delete the resource
+    fn delete_resource(&mut self, resource: FakeResource, id: &str) -> FakeResponse {
+        if self.find_resource(resource, id).is_none() {
+            return resource_not_found(resource, id);
+        }
+        if let FakeResource::Network = resource {
+            let in_use = self
+                .resources
+                .get(&FakeResource::Port)
+                .is_some_and(|ports| ports.iter().any(|port| port["network_id"] == id));
+            if in_use {
+                return error_response(
+                    StatusCode::CONFLICT,
+                    &format!(
+                        "Unable to complete operation on network {}. There are one or more ports still in use on the network.",
+                        id
+                    ),
+                );
+            }
+        }
+        if let Some(items) = self.resources.get_mut(&resource) {
+            items.retain(|item| item["id"] != id);
+        }
+        if let FakeResource::Image = resource {
+            self.image_data.remove(id);
+        }
+        match resource {
+            FakeResource::Volume => empty_response(StatusCode::ACCEPTED),
+            _ => empty_response(StatusCode::NO_CONTENT),
+        }
+    }
+
+    /// Upload or download image data
+    fn handle_image_file(&mut self, request: &FakeRequest, id: &str) -> FakeResponse {
+        if self.find_resource(FakeResource::Image, id).is_none() {
+            return resource_not_found(FakeResource::Image, id);
+        }
+        match request.method {
+            Method::PUT => {
+                if let Some(image) = self.find_resource_mut(FakeResource::Image, id) {
+                    image["status"] = "active".into();
+                    image["size"] = request.body.len().into();
+                    image["updated_at"] = now().into();
+                }
+                self.image_data.insert(id.to_string(), request.body.clone());
+                empty_response(StatusCode::NO_CONTENT)
+            }
+            Method::GET => match self.image_data.get(id) {
+                Some(data) => Response::builder()
+                    .status(StatusCode::OK)
+                    .header(header::CONTENT_TYPE, "application/octet-stream")
+                    .body(Full::new(data.clone()))
+                    .expect("valid response"),
+                None => empty_response(StatusCode::NO_CONTENT),
+            },
+            _ => error_response(StatusCode::METHOD_NOT_ALLOWED, "Method Not Allowed"),
+        }
+    }
+
+    /// Find the resource by ID
+    fn find_resource(&self, resource: FakeResource, id: &str) -> Option<&Value> {
+        self.resources
+            .get(&resource)?
+            .iter()
+            .find(|item| item["id"] == id)
+    }
+
+    /// Find the resource by ID for modification
+    fn find_resource_mut(&mut self, resource: FakeResource, id: &str) -> Option<&mut Value> {
+        self.resources
+            .get_mut(&resource)?
+            .iter_mut()
+            .find(|item| item["id"] == id)
+    }
+}
+
+/// Resource data from the request body
+fn request_data(request: &FakeRequest, resource: FakeResource) -> Option<Value> {
+    let mut data = request.json()?;
+    if resource.is_wrapped() {
+        data = data.get_mut(resource.keys().0)?.take();
+    }
+    data.is_object().then_some(data)
+}
+
+/// Wrap resource into the response body
+fn wrap(resource: FakeResource, item: Value) -> Value {
+    if resource.is_wrapped() {
+        json!({ resource.keys().0: item })
+    } else {
+        item
+    }
+}
+
+/// Check whether the resource matches the query filters
+fn matches_filters(item: &Value, query: &[(String, String)]) -> bool {
+    query
+        .iter()
+        .filter(|(key, _)| !NON_FILTER_PARAMS.contains(&key.as_str()))
+        .all(|(key, val)| match item.get(key) {
+            Some(Value::String(attr)) => attr == val,
+            Some(Value::Bool(attr)) => attr.to_string() == val.to_lowercase(),
+            Some(Value::Number(attr)) => attr.to_string() == *val,
+            _ => false,
+        })
+}
+
+/// Resource not found response
+fn resource_not_found(resource: FakeResource, id: &str) -> FakeResponse {
+    error_response(
+        StatusCode::NOT_FOUND,
+        &format!("{} {} could not be found.", resource.keys().0, id),
+    )
+}
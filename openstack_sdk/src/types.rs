@@ -18,8 +18,16 @@
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
+pub mod block_storage;
 pub mod compute;
 pub mod identity;
+pub mod image;
+pub mod load_balancer;
+pub mod network;
+pub mod object_store;
+mod service_types;
+
+pub use service_types::{ServiceTypeInfo, SERVICE_TYPES};
 
 use futures::io::AsyncRead;
 use futures::io::Error;
@@ -33,20 +41,6 @@ struct IdentityService {}
 struct ImageService {}
 struct ObjectStoreService {}
 
-const BLOCK_STORAGE_SERVICE_TYPES: &[&str] = &[
-    "block-storage",
-    "volumev3",
-    "volumev2",
-    "volume",
-    "block-store",
-];
-const COMPUTE_SERVICE_TYPES: &[&str] = &["compute"];
-const IDENTITY_SERVICE_TYPES: &[&str] = &["identity"];
-const IMAGE_SERVICE_TYPES: &[&str] = &["image"];
-const OBJECT_STORE_SERVICE_TYPES: &[&str] = &["object-store"];
-const LOAD_BALANCER_SERVICE_TYPES: &[&str] = &["load-balancer"];
-const NETWORK_SERVICE_TYPES: &[&str] = &["network"];
-
 /// Supported Service Types
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum ServiceType {
@@ -60,21 +54,31 @@ pub enum ServiceType {
     Other(String),
 }
 
+impl ServiceType {
+    /// Service types authority information of the service (`None` for the
+    /// unknown services)
+    pub fn info(&self) -> Option<&'static ServiceTypeInfo> {
+        service_types::find(&self.to_string())
+    }
+
+    /// Historical aliases of the service type
+    pub fn aliases(&self) -> &'static [&'static str] {
+        self.info().map(|x| x.aliases).unwrap_or_default()
+    }
+}
+
 pub trait SupportedServiceTypes {
+    /// Service types the service may be registered with in the catalog
+    /// (official type followed by its aliases)
     fn get_supported_catalog_types(&self) -> Vec<&str>;
 }
 
 impl SupportedServiceTypes for ServiceType {
     fn get_supported_catalog_types(&self) -> Vec<&str> {
-        match self {
-            ServiceType::BlockStorage => BLOCK_STORAGE_SERVICE_TYPES.to_vec(),
-            ServiceType::Compute => COMPUTE_SERVICE_TYPES.to_vec(),
-            ServiceType::Image => IMAGE_SERVICE_TYPES.to_vec(),
-            ServiceType::Identity => IDENTITY_SERVICE_TYPES.to_vec(),
-            ServiceType::LoadBalancer => LOAD_BALANCER_SERVICE_TYPES.to_vec(),
-            ServiceType::Network => NETWORK_SERVICE_TYPES.to_vec(),
-            ServiceType::ObjectStore => OBJECT_STORE_SERVICE_TYPES.to_vec(),
-            ServiceType::Other(x) => Vec::from([x.as_str()]),
+        match (self.info(), self) {
+            (Some(info), _) => info.all_types().collect(),
+            (None, ServiceType::Other(x)) => Vec::from([x.as_str()]),
+            (None, _) => Vec::new(),
         }
     }
 }
@@ -89,14 +93,23 @@ impl fmt::Display for ServiceType {
             ServiceType::LoadBalancer => write!(f, "load-balancer"),
             ServiceType::Network => write!(f, "network"),
             ServiceType::ObjectStore => write!(f, "object-store"),
-            ServiceType::Other(x) => write!(f, "{}", x),
+            // Aliases are displayed as the official service type
+            ServiceType::Other(x) => write!(
+                f,
+                "{}",
+                service_types::find(x).map_or(x.as_str(), |x| x.service_type)
+            ),
         }
     }
 }
 
 impl From<&str> for ServiceType {
+    /// Build the service type from the official type or any of its aliases
+    /// (i.e. `volumev3` is `BlockStorage`, `sharev2` is
+    /// `Other("shared-file-system")`)
     fn from(val: &str) -> ServiceType {
-        match val {
+        let service_type = service_types::find(val).map_or(val, |x| x.service_type);
+        match service_type {
             "block-storage" => ServiceType::BlockStorage,
             "compute" => ServiceType::Compute,
             "identity" => ServiceType::Identity,
@@ -104,7 +117,7 @@ impl From<&str> for ServiceType {
             "load-balancer" => ServiceType::LoadBalancer,
             "network" => ServiceType::Network,
             "object-store" => ServiceType::ObjectStore,
-            _ => ServiceType::Other(val.to_string()),
+            other => ServiceType::Other(other.to_string()),
         }
     }
 }
@@ -143,6 +156,26 @@ impl AsyncRead for BoxedAsyncRead {
     }
 }
 
+/// A wrapper around `std::io::Read` used for streaming the request and
+/// response bodies with the blocking client.
+pub struct BoxedRead {
+    reader: Box<dyn std::io::Read + Send>,
+}
+
+impl BoxedRead {
+    pub fn new(reader: impl std::io::Read + Send + 'static) -> Self {
+        Self {
+            reader: Box::new(reader),
+        }
+    }
+}
+
+impl std::io::Read for BoxedRead {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.reader.read(buf)
+    }
+}
+
 /// A reference to a resource by its Name and ID.
 #[derive(Deserialize, Debug, Clone, Serialize)]
 pub struct IdAndName {
@@ -162,3 +195,73 @@ pub enum NameOrId {
     #[serde(rename = "name")]
     Name(String),
 }
+
+/// Deserialize optional number which may be also returned as a string.
+///
+/// Some services (i.e. Nova with flavor `swap`) return empty string instead of
+/// `null` or the number serialized as string.
+pub(crate) fn deser_num_or_str<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: std::str::FromStr + serde::de::DeserializeOwned,
+{
+    match Option::<serde_json::Value>::deserialize(deserializer)? {
+        None | Some(serde_json::Value::Null) => Ok(None),
+        Some(serde_json::Value::String(val)) if val.is_empty() => Ok(None),
+        Some(serde_json::Value::String(val)) => val
+            .parse::<T>()
+            .map(Some)
+            .map_err(|_| serde::de::Error::custom(format!("`{}` is not a number", val))),
+        Some(val) => serde_json::from_value(val)
+            .map(Some)
+            .map_err(serde::de::Error::custom),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ServiceType, SupportedServiceTypes};
+
+    #[test]
+    fn test_service_type_aliases() {
+        assert_eq!(ServiceType::from("volumev3"), ServiceType::BlockStorage);
+        assert_eq!(ServiceType::from("block-store"), ServiceType::BlockStorage);
+        assert_eq!(
+            ServiceType::from("sharev2"),
+            ServiceType::Other("shared-file-system".into())
+        );
+        assert_eq!(ServiceType::from("foo"), ServiceType::Other("foo".into()));
+        assert_eq!(
+            ServiceType::Other("volumev2".into()).to_string(),
+            "block-storage"
+        );
+        assert_eq!(ServiceType::Other("foo".into()).to_string(), "foo");
+    }
+
+    #[test]
+    fn test_supported_catalog_types() {
+        assert_eq!(
+            ServiceType::BlockStorage.get_supported_catalog_types(),
+            [
+                "block-storage",
+                "volumev3",
+                "volumev2",
+                "volume",
+                "block-store"
+            ]
+        );
+        assert_eq!(
+            ServiceType::from("share").get_supported_catalog_types(),
+            ["shared-file-system", "sharev2", "share"]
+        );
+        assert_eq!(
+            ServiceType::from("barbican").get_supported_catalog_types(),
+            ["key-manager"]
+        );
+        assert_eq!(ServiceType::Compute.aliases(), [] as [&str; 0]);
+        assert_eq!(
+            ServiceType::Other("foo".into()).get_supported_catalog_types(),
+            ["foo"]
+        );
+    }
+}
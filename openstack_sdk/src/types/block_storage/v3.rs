@@ -0,0 +1,120 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Block storage API v3 data types
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Volume
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Volume {
+    /// The UUID of the volume.
+    pub id: String,
+    /// The volume name.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// The volume status.
+    #[serde(default)]
+    pub status: Option<String>,
+    /// The size of the volume, in gibibytes (GiB).
+    #[serde(default)]
+    pub size: Option<u64>,
+    /// The volume description.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Instance attachment information.
+    #[serde(default)]
+    pub attachments: Option<Value>,
+    /// The name of the availability zone.
+    #[serde(default)]
+    pub availability_zone: Option<String>,
+    /// Enables or disables the bootable attribute.
+    #[serde(default)]
+    pub bootable: Option<String>,
+    /// If true, this volume is encrypted.
+    #[serde(default)]
+    pub encrypted: Option<bool>,
+    /// If true, this volume can attach to more than one instance.
+    #[serde(default)]
+    pub multiattach: Option<bool>,
+    /// A metadata object.
+    #[serde(default)]
+    pub metadata: Option<Value>,
+    /// The UUID of the source volume snapshot.
+    #[serde(default)]
+    pub snapshot_id: Option<String>,
+    /// The UUID of the source volume.
+    #[serde(default)]
+    pub source_volid: Option<String>,
+    /// The associated volume type name for the volume.
+    #[serde(default)]
+    pub volume_type: Option<String>,
+    /// The UUID of the user.
+    #[serde(default)]
+    pub user_id: Option<String>,
+    /// The project ID which the volume belongs to.
+    #[serde(rename = "os-vol-tenant-attr:tenant_id", default)]
+    pub project_id: Option<String>,
+    /// The date and time when the resource was created.
+    #[serde(default)]
+    pub created_at: Option<String>,
+    /// The date and time when the resource was updated.
+    #[serde(default)]
+    pub updated_at: Option<String>,
+    /// Other attributes of the resource returned by the API.
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+/// Volume snapshot
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Snapshot {
+    /// The UUID of the snapshot.
+    pub id: String,
+    /// The name of the snapshot.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// The status for the snapshot.
+    #[serde(default)]
+    pub status: Option<String>,
+    /// The size of the volume, in gibibytes (GiB).
+    #[serde(default)]
+    pub size: Option<u64>,
+    /// A description for the snapshot.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// The UUID of the volume.
+    #[serde(default)]
+    pub volume_id: Option<String>,
+    /// One or more metadata key and value pairs for the snapshot.
+    #[serde(default)]
+    pub metadata: Option<Value>,
+    /// A percentage value for the build progress.
+    #[serde(rename = "os-extended-snapshot-attributes:progress", default)]
+    pub progress: Option<String>,
+    /// The UUID of the owning project.
+    #[serde(rename = "os-extended-snapshot-attributes:project_id", default)]
+    pub project_id: Option<String>,
+    /// The date and time when the resource was created.
+    #[serde(default)]
+    pub created_at: Option<String>,
+    /// The date and time when the resource was updated.
+    #[serde(default)]
+    pub updated_at: Option<String>,
+    /// Other attributes of the resource returned by the API.
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
@@ -13,7 +13,12 @@
 // SPDX-License-Identifier: Apache-2.0
 
 //! Compute API v2 data types
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::types::deser_num_or_str;
 
 /// Flavors
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -22,6 +27,40 @@ pub struct Flavor {
     pub name: String,
     /// The UID for the Flavor.
     pub id: String,
+    /// The description of the flavor.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// The size of the root disk that will be created in GiB.
+    #[serde(default, deserialize_with = "deser_num_or_str")]
+    pub disk: Option<u64>,
+    /// The amount of RAM a flavor has, in MiB.
+    #[serde(default, deserialize_with = "deser_num_or_str")]
+    pub ram: Option<u64>,
+    /// The number of virtual CPUs that will be allocated to the server.
+    #[serde(default, deserialize_with = "deser_num_or_str")]
+    pub vcpus: Option<u64>,
+    /// The size of a dedicated swap disk that will be allocated, in MiB.
+    #[serde(default, deserialize_with = "deser_num_or_str")]
+    pub swap: Option<u64>,
+    /// The size of the ephemeral disk that will be created, in GiB.
+    #[serde(
+        rename = "OS-FLV-EXT-DATA:ephemeral",
+        default,
+        deserialize_with = "deser_num_or_str"
+    )]
+    pub ephemeral: Option<u64>,
+    /// The receive / transmit factor (as a float) that will be set on ports.
+    #[serde(default, deserialize_with = "deser_num_or_str")]
+    pub rxtx_factor: Option<f64>,
+    /// Whether the flavor is public (available to all projects).
+    #[serde(rename = "os-flavor-access:is_public", default)]
+    pub is_public: Option<bool>,
+    /// A dictionary of the flavor’s extra-specs key-and-value pairs.
+    #[serde(default)]
+    pub extra_specs: Option<Value>,
+    /// Other attributes of the resource returned by the API.
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
 }
 
 /// Servers
@@ -31,6 +70,143 @@ pub struct Server {
     pub name: String,
     /// The UID for the Server.
     pub id: String,
-    #[serde(skip_serializing_if = "Option::is_none", default)]
+    /// The server status.
+    #[serde(default)]
+    pub status: Option<String>,
+    /// The description of the server.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// IPv4 address that should be used to access this server.
+    #[serde(
+        rename = "accessIPv4",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
     pub access_ip_v4: Option<String>,
+    /// IPv6 address that should be used to access this server.
+    #[serde(rename = "accessIPv6", default)]
+    pub access_ip_v6: Option<String>,
+    /// The addresses for the server grouped by the network name.
+    #[serde(default)]
+    pub addresses: Option<Value>,
+    /// The availability zone name.
+    #[serde(rename = "OS-EXT-AZ:availability_zone", default)]
+    pub availability_zone: Option<String>,
+    /// Indicates whether or not a config drive was used for this server.
+    #[serde(default)]
+    pub config_drive: Option<String>,
+    /// The date and time when the resource was created.
+    #[serde(default)]
+    pub created: Option<String>,
+    /// The fault object with details of the server error.
+    #[serde(default)]
+    pub fault: Option<Value>,
+    /// The flavor information of the server.
+    #[serde(default)]
+    pub flavor: Option<Value>,
+    /// The ID of the host.
+    #[serde(rename = "hostId", default)]
+    pub host_id: Option<String>,
+    /// The name of the compute host the server is running on.
+    #[serde(rename = "OS-EXT-SRV-ATTR:host", default)]
+    pub host: Option<String>,
+    /// The hypervisor host name.
+    #[serde(rename = "OS-EXT-SRV-ATTR:hypervisor_hostname", default)]
+    pub hypervisor_hostname: Option<String>,
+    /// The image information of the server. It is an empty string for the
+    /// servers booted from volume.
+    #[serde(default)]
+    pub image: Option<Value>,
+    /// The name of associated key pair, if any.
+    #[serde(default)]
+    pub key_name: Option<String>,
+    /// True if the instance is locked otherwise False.
+    #[serde(default)]
+    pub locked: Option<bool>,
+    /// A dictionary of metadata key-and-value pairs.
+    #[serde(default)]
+    pub metadata: Option<Value>,
+    /// The power state of the instance.
+    #[serde(rename = "OS-EXT-STS:power_state", default)]
+    pub power_state: Option<i32>,
+    /// The task state of the instance.
+    #[serde(rename = "OS-EXT-STS:task_state", default)]
+    pub task_state: Option<String>,
+    /// The VM state.
+    #[serde(rename = "OS-EXT-STS:vm_state", default)]
+    pub vm_state: Option<String>,
+    /// A percentage value of the operation progress.
+    #[serde(default)]
+    pub progress: Option<i32>,
+    /// One or more security groups objects.
+    #[serde(default)]
+    pub security_groups: Option<Value>,
+    /// A list of tags.
+    #[serde(default)]
+    pub tags: Option<Vec<String>>,
+    /// The UUID of the tenant in a multi-tenancy cloud.
+    #[serde(default)]
+    pub tenant_id: Option<String>,
+    /// The date and time when the resource was updated.
+    #[serde(default)]
+    pub updated: Option<String>,
+    /// The user ID of the user who owns the server.
+    #[serde(default)]
+    pub user_id: Option<String>,
+    /// The attached volumes, if any.
+    #[serde(rename = "os-extended-volumes:volumes_attached", default)]
+    pub volumes_attached: Option<Value>,
+    /// Other attributes of the resource returned by the API.
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_flavor_deserialize() {
+        let flavor: Flavor = serde_json::from_value(json!({
+            "id": "1",
+            "name": "m1.tiny",
+            "disk": 1,
+            "ram": "512",
+            "vcpus": 1,
+            "swap": "",
+            "OS-FLV-EXT-DATA:ephemeral": 0,
+            "rxtx_factor": 1.0,
+            "os-flavor-access:is_public": true
+        }))
+        .unwrap();
+        assert_eq!(flavor.disk, Some(1));
+        assert_eq!(flavor.ram, Some(512));
+        assert_eq!(flavor.swap, None);
+        assert_eq!(flavor.ephemeral, Some(0));
+        assert_eq!(flavor.rxtx_factor, Some(1.0));
+        assert_eq!(flavor.is_public, Some(true));
+    }
+
+    #[test]
+    fn test_server_extra_attributes() {
+        let data = json!({
+            "id": "foo",
+            "name": "bar",
+            "status": "ACTIVE",
+            "OS-EXT-SRV-ATTR:host": "compute1",
+            "OS-EXT-SRV-ATTR:instance_name": "instance-00000001",
+            "host_status": "UP"
+        });
+        let server: Server = serde_json::from_value(data.clone()).unwrap();
+        assert_eq!(server.host.as_deref(), Some("compute1"));
+        assert_eq!(server.extra.get("host_status"), Some(&json!("UP")));
+        assert!(!server.extra.contains_key("OS-EXT-SRV-ATTR:host"));
+        let serialized = serde_json::to_value(&server).unwrap();
+        assert_eq!(
+            serialized.get("OS-EXT-SRV-ATTR:instance_name"),
+            Some(&json!("instance-00000001"))
+        );
+    }
 }
@@ -18,6 +18,9 @@ use serde::{Deserialize, Serialize};
 
 use crate::types::IdAndName;
 
+pub mod project;
+pub mod user;
+
 /// AuthResponse structure returned by token authentication calls
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct AuthResponse {
@@ -0,0 +1,52 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Identity v3 project data types
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Project
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Project {
+    /// The ID for the project.
+    pub id: String,
+    /// The name of the project.
+    pub name: String,
+    /// The description of the project.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// The ID of the domain for the project.
+    #[serde(default)]
+    pub domain_id: Option<String>,
+    /// If set to `true`, project is enabled.
+    #[serde(default)]
+    pub enabled: Option<bool>,
+    /// If set to `true`, project is acting as a domain.
+    #[serde(default)]
+    pub is_domain: Option<bool>,
+    /// The ID of the parent for the project.
+    #[serde(default)]
+    pub parent_id: Option<String>,
+    /// The resource options for the project.
+    #[serde(default)]
+    pub options: Option<Value>,
+    /// A list of simple strings assigned to a project.
+    #[serde(default)]
+    pub tags: Option<Vec<String>>,
+    /// Other attributes of the resource returned by the API.
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
@@ -0,0 +1,52 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Identity v3 user data types
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// User
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct User {
+    /// The user ID.
+    pub id: String,
+    /// The user name.
+    pub name: String,
+    /// The ID of the default project for the user.
+    #[serde(default)]
+    pub default_project_id: Option<String>,
+    /// The description of the user.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// The ID of the domain.
+    #[serde(default)]
+    pub domain_id: Option<String>,
+    /// If the user is enabled, this value is `true`.
+    #[serde(default)]
+    pub enabled: Option<bool>,
+    /// List of federated objects associated with a user.
+    #[serde(default)]
+    pub federated: Option<Value>,
+    /// The resource options for the user.
+    #[serde(default)]
+    pub options: Option<Value>,
+    /// The date and time when the password expires.
+    #[serde(default)]
+    pub password_expires_at: Option<String>,
+    /// Other attributes of the resource returned by the API.
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
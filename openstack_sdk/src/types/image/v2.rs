@@ -0,0 +1,107 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Image API v2 data types
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Image
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Image {
+    /// A unique, user-defined image UUID.
+    pub id: String,
+    /// The name of the image.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// The image status.
+    #[serde(default)]
+    pub status: Option<String>,
+    /// Format of the image container.
+    #[serde(default)]
+    pub container_format: Option<String>,
+    /// The format of the disk.
+    #[serde(default)]
+    pub disk_format: Option<String>,
+    /// Hash that is used over the image data.
+    #[serde(default)]
+    pub checksum: Option<String>,
+    /// The algorithm used to compute a secure hash of the image data.
+    #[serde(default)]
+    pub os_hash_algo: Option<String>,
+    /// The hexdigest of the secure hash of the image data.
+    #[serde(default)]
+    pub os_hash_value: Option<String>,
+    /// This field controls whether an image is displayed in the default
+    /// image-list response.
+    #[serde(default)]
+    pub os_hidden: Option<bool>,
+    /// Amount of disk space in GB that is required to boot the image.
+    #[serde(default)]
+    pub min_disk: Option<u64>,
+    /// Amount of RAM in MB that is required to boot the image.
+    #[serde(default)]
+    pub min_ram: Option<u64>,
+    /// An identifier for the owner of the image, usually the project ID.
+    #[serde(default)]
+    pub owner: Option<String>,
+    /// A boolean value that must be false or the image cannot be deleted.
+    #[serde(default)]
+    pub protected: Option<bool>,
+    /// The size of the image data, in bytes.
+    #[serde(default)]
+    pub size: Option<u64>,
+    /// The virtual size of the image.
+    #[serde(default)]
+    pub virtual_size: Option<u64>,
+    /// Image visibility.
+    #[serde(default)]
+    pub visibility: Option<String>,
+    /// List of tags for this image.
+    #[serde(default)]
+    pub tags: Option<Vec<String>>,
+    /// The date and time when the resource was created.
+    #[serde(default)]
+    pub created_at: Option<String>,
+    /// The date and time when the resource was updated.
+    #[serde(default)]
+    pub updated_at: Option<String>,
+    /// Additional image properties.
+    #[serde(flatten)]
+    pub properties: HashMap<String, Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_image_properties() {
+        let image: Image = serde_json::from_value(json!({
+            "id": "foo",
+            "name": "cirros",
+            "status": "active",
+            "hw_disk_bus": "scsi",
+            "os_distro": "cirros"
+        }))
+        .unwrap();
+        assert_eq!(image.name.as_deref(), Some("cirros"));
+        assert_eq!(image.properties.get("hw_disk_bus"), Some(&json!("scsi")));
+        assert_eq!(image.properties.get("os_distro"), Some(&json!("cirros")));
+        assert!(!image.properties.contains_key("status"));
+    }
+}
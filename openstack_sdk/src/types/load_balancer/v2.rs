@@ -0,0 +1,86 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Load balancer API v2 data types
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Load balancer
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LoadBalancer {
+    /// The ID of the load balancer.
+    pub id: String,
+    /// Human-readable name of the resource.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// A human-readable description for the resource.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// The administrative state of the resource.
+    #[serde(default)]
+    pub admin_state_up: Option<bool>,
+    /// An availability zone name.
+    #[serde(default)]
+    pub availability_zone: Option<String>,
+    /// The ID of the flavor.
+    #[serde(default)]
+    pub flavor_id: Option<String>,
+    /// The associated listener IDs, if any.
+    #[serde(default)]
+    pub listeners: Option<Value>,
+    /// The associated pool IDs, if any.
+    #[serde(default)]
+    pub pools: Option<Value>,
+    /// The operating status of the resource.
+    #[serde(default)]
+    pub operating_status: Option<String>,
+    /// The provisioning status of the resource.
+    #[serde(default)]
+    pub provisioning_status: Option<String>,
+    /// The ID of the project owning this resource.
+    #[serde(default)]
+    pub project_id: Option<String>,
+    /// Provider name for the load balancer.
+    #[serde(default)]
+    pub provider: Option<String>,
+    /// A list of simple strings assigned to the resource.
+    #[serde(default)]
+    pub tags: Option<Vec<String>>,
+    /// The IP address of the Virtual IP (VIP).
+    #[serde(default)]
+    pub vip_address: Option<String>,
+    /// The ID of the network for the Virtual IP (VIP).
+    #[serde(default)]
+    pub vip_network_id: Option<String>,
+    /// The ID of the Virtual IP (VIP) port.
+    #[serde(default)]
+    pub vip_port_id: Option<String>,
+    /// The ID of the subnet for the Virtual IP (VIP).
+    #[serde(default)]
+    pub vip_subnet_id: Option<String>,
+    /// Additional VIPs.
+    #[serde(default)]
+    pub additional_vips: Option<Value>,
+    /// The UTC date and timestamp when the resource was created.
+    #[serde(default)]
+    pub created_at: Option<String>,
+    /// The UTC date and timestamp when the resource was last updated.
+    #[serde(default)]
+    pub updated_at: Option<String>,
+    /// Other attributes of the resource returned by the API.
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
@@ -0,0 +1,293 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Network API v2 data types
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Network
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Network {
+    /// The ID of the network.
+    pub id: String,
+    /// Human-readable name of the network.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// The network status.
+    #[serde(default)]
+    pub status: Option<String>,
+    /// A human-readable description for the resource.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// The administrative state of the network.
+    #[serde(default)]
+    pub admin_state_up: Option<bool>,
+    /// The availability zones for the network.
+    #[serde(default)]
+    pub availability_zones: Option<Vec<String>>,
+    /// A valid DNS domain.
+    #[serde(default)]
+    pub dns_domain: Option<String>,
+    /// The maximum transmission unit (MTU) value.
+    #[serde(default)]
+    pub mtu: Option<u32>,
+    /// The port security status of the network.
+    #[serde(default)]
+    pub port_security_enabled: Option<bool>,
+    /// The type of physical network that this network is mapped to.
+    #[serde(rename = "provider:network_type", default)]
+    pub provider_network_type: Option<String>,
+    /// The physical network where this network is implemented.
+    #[serde(rename = "provider:physical_network", default)]
+    pub provider_physical_network: Option<String>,
+    /// The ID of the isolated segment on the physical network.
+    #[serde(rename = "provider:segmentation_id", default)]
+    pub provider_segmentation_id: Option<u32>,
+    /// The ID of the QoS policy associated with the network.
+    #[serde(default)]
+    pub qos_policy_id: Option<String>,
+    /// The revision number of the resource.
+    #[serde(default)]
+    pub revision_number: Option<u64>,
+    /// Indicates whether the network has an external routing facility.
+    #[serde(rename = "router:external", default)]
+    pub router_external: Option<bool>,
+    /// Indicates whether this network is shared across all tenants.
+    #[serde(default)]
+    pub shared: Option<bool>,
+    /// The associated subnets.
+    #[serde(default)]
+    pub subnets: Option<Vec<String>>,
+    /// The list of tags on the resource.
+    #[serde(default)]
+    pub tags: Option<Vec<String>>,
+    /// The ID of the project.
+    #[serde(default)]
+    pub project_id: Option<String>,
+    /// Time at which the resource has been created.
+    #[serde(default)]
+    pub created_at: Option<String>,
+    /// Time at which the resource has been updated.
+    #[serde(default)]
+    pub updated_at: Option<String>,
+    /// Other attributes of the resource returned by the API.
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+/// Subnet
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Subnet {
+    /// The ID of the subnet.
+    pub id: String,
+    /// Human-readable name of the resource.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// A human-readable description for the resource.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// The ID of the network to which the subnet belongs.
+    #[serde(default)]
+    pub network_id: Option<String>,
+    /// The CIDR of the subnet.
+    #[serde(default)]
+    pub cidr: Option<String>,
+    /// The IP protocol version. Value is `4` or `6`.
+    #[serde(default)]
+    pub ip_version: Option<u8>,
+    /// Gateway IP of this subnet.
+    #[serde(default)]
+    pub gateway_ip: Option<String>,
+    /// Allocation pools with `start` and `end` IP addresses.
+    #[serde(default)]
+    pub allocation_pools: Option<Value>,
+    /// List of dns name servers associated with the subnet.
+    #[serde(default)]
+    pub dns_nameservers: Option<Vec<String>>,
+    /// Indicates whether dhcp is enabled or disabled for the subnet.
+    #[serde(default)]
+    pub enable_dhcp: Option<bool>,
+    /// Additional routes for the subnet.
+    #[serde(default)]
+    pub host_routes: Option<Value>,
+    /// The IPv6 address modes.
+    #[serde(default)]
+    pub ipv6_address_mode: Option<String>,
+    /// The IPv6 router advertisement mode.
+    #[serde(default)]
+    pub ipv6_ra_mode: Option<String>,
+    /// The ID of a network segment the subnet is associated with.
+    #[serde(default)]
+    pub segment_id: Option<String>,
+    /// The ID of the subnet pool associated with the subnet.
+    #[serde(default)]
+    pub subnetpool_id: Option<String>,
+    /// The revision number of the resource.
+    #[serde(default)]
+    pub revision_number: Option<u64>,
+    /// The list of tags on the resource.
+    #[serde(default)]
+    pub tags: Option<Vec<String>>,
+    /// The ID of the project.
+    #[serde(default)]
+    pub project_id: Option<String>,
+    /// Time at which the resource has been created.
+    #[serde(default)]
+    pub created_at: Option<String>,
+    /// Time at which the resource has been updated.
+    #[serde(default)]
+    pub updated_at: Option<String>,
+    /// Other attributes of the resource returned by the API.
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+/// Port
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Port {
+    /// The ID of the resource.
+    pub id: String,
+    /// Human-readable name of the resource.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// The port status.
+    #[serde(default)]
+    pub status: Option<String>,
+    /// A human-readable description for the resource.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// The ID of the attached network.
+    #[serde(default)]
+    pub network_id: Option<String>,
+    /// The administrative state of the resource.
+    #[serde(default)]
+    pub admin_state_up: Option<bool>,
+    /// A set of zero or more allowed address pair objects.
+    #[serde(default)]
+    pub allowed_address_pairs: Option<Value>,
+    /// The ID of the host where the port resides.
+    #[serde(rename = "binding:host_id", default)]
+    pub binding_host_id: Option<String>,
+    /// Information specific to the networking back-end.
+    #[serde(rename = "binding:profile", default)]
+    pub binding_profile: Option<Value>,
+    /// Additional information on the port.
+    #[serde(rename = "binding:vif_details", default)]
+    pub binding_vif_details: Option<Value>,
+    /// The type of which mechanism is used for the port.
+    #[serde(rename = "binding:vif_type", default)]
+    pub binding_vif_type: Option<String>,
+    /// The type of vNIC which this port should be attached to.
+    #[serde(rename = "binding:vnic_type", default)]
+    pub binding_vnic_type: Option<String>,
+    /// The ID of the device that uses this port.
+    #[serde(default)]
+    pub device_id: Option<String>,
+    /// The entity type that uses this port.
+    #[serde(default)]
+    pub device_owner: Option<String>,
+    /// A valid DNS name.
+    #[serde(default)]
+    pub dns_name: Option<String>,
+    /// The IP addresses for the port.
+    #[serde(default)]
+    pub fixed_ips: Option<Value>,
+    /// The MAC address of the port.
+    #[serde(default)]
+    pub mac_address: Option<String>,
+    /// The port security status.
+    #[serde(default)]
+    pub port_security_enabled: Option<bool>,
+    /// The ID of the QoS policy associated with the port.
+    #[serde(default)]
+    pub qos_policy_id: Option<String>,
+    /// The IDs of security groups applied to the port.
+    #[serde(default)]
+    pub security_groups: Option<Vec<String>>,
+    /// The revision number of the resource.
+    #[serde(default)]
+    pub revision_number: Option<u64>,
+    /// The list of tags on the resource.
+    #[serde(default)]
+    pub tags: Option<Vec<String>>,
+    /// The ID of the project.
+    #[serde(default)]
+    pub project_id: Option<String>,
+    /// Time at which the resource has been created.
+    #[serde(default)]
+    pub created_at: Option<String>,
+    /// Time at which the resource has been updated.
+    #[serde(default)]
+    pub updated_at: Option<String>,
+    /// Other attributes of the resource returned by the API.
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+/// Router
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Router {
+    /// The ID of the router.
+    pub id: String,
+    /// Human-readable name of the resource.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// The router status.
+    #[serde(default)]
+    pub status: Option<String>,
+    /// A human-readable description for the resource.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// The administrative state of the resource.
+    #[serde(default)]
+    pub admin_state_up: Option<bool>,
+    /// The availability zones for the router.
+    #[serde(default)]
+    pub availability_zones: Option<Vec<String>>,
+    /// `true` indicates a distributed router.
+    #[serde(default)]
+    pub distributed: Option<bool>,
+    /// The external gateway information of the router.
+    #[serde(default)]
+    pub external_gateway_info: Option<Value>,
+    /// The ID of the flavor associated with the router.
+    #[serde(default)]
+    pub flavor_id: Option<String>,
+    /// `true` indicates a highly-available router.
+    #[serde(default)]
+    pub ha: Option<bool>,
+    /// The extra routes configuration for L3 router.
+    #[serde(default)]
+    pub routes: Option<Value>,
+    /// The revision number of the resource.
+    #[serde(default)]
+    pub revision_number: Option<u64>,
+    /// The list of tags on the resource.
+    #[serde(default)]
+    pub tags: Option<Vec<String>>,
+    /// The ID of the project.
+    #[serde(default)]
+    pub project_id: Option<String>,
+    /// Time at which the resource has been created.
+    #[serde(default)]
+    pub created_at: Option<String>,
+    /// Time at which the resource has been updated.
+    #[serde(default)]
+    pub updated_at: Option<String>,
+    /// Other attributes of the resource returned by the API.
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
@@ -0,0 +1,66 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Object store API v1 data types
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Container entry of the account listing
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Container {
+    /// The name of the container.
+    pub name: String,
+    /// The number of objects in the container.
+    #[serde(default)]
+    pub count: Option<u64>,
+    /// The total number of bytes that are stored in Object Storage for the
+    /// container.
+    #[serde(default)]
+    pub bytes: Option<u64>,
+    /// The date and time when the container was last modified.
+    #[serde(default)]
+    pub last_modified: Option<String>,
+    /// Other attributes of the resource returned by the API.
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+/// Object entry of the container listing
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Object {
+    /// The name of the object. For the pseudo-directories only `subdir` is
+    /// returned.
+    #[serde(default, alias = "subdir")]
+    pub name: Option<String>,
+    /// The content type of the object.
+    #[serde(default)]
+    pub content_type: Option<String>,
+    /// The total number of bytes that are stored for the object.
+    #[serde(default)]
+    pub bytes: Option<u64>,
+    /// The MD5 checksum value of the object content.
+    #[serde(default)]
+    pub hash: Option<String>,
+    /// The date and time when the object was last modified.
+    #[serde(default)]
+    pub last_modified: Option<String>,
+    /// Target path of the symlink object.
+    #[serde(default)]
+    pub symlink_path: Option<String>,
+    /// Other attributes of the resource returned by the API.
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
@@ -0,0 +1,135 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Official OpenStack service types
+//!
+//! Data of the [service-types-authority](https://service-types.openstack.org/)
+//! with the official service types and their historical aliases (in the
+//! order of preference).
+
+/// Official service type information
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ServiceTypeInfo {
+    /// Official service type
+    pub service_type: &'static str,
+    /// Project implementing the service
+    pub project: &'static str,
+    /// Historical aliases of the service type
+    pub aliases: &'static [&'static str],
+}
+
+macro_rules! service_types {
+    ($($service_type:literal => $project:literal [$($alias:literal),*]),* $(,)?) => {
+        &[$(ServiceTypeInfo {
+            service_type: $service_type,
+            project: $project,
+            aliases: &[$($alias),*],
+        }),*]
+    };
+}
+
+/// All known service types
+pub const SERVICE_TYPES: &[ServiceTypeInfo] = service_types![
+    "accelerator" => "cyborg" [],
+    "alarm" => "aodh" ["alarming"],
+    "application-catalog" => "murano" [],
+    "application-container" => "zun" ["container"],
+    "application-deployment" => "solum" [],
+    "backup" => "freezer" [],
+    "baremetal" => "ironic" [],
+    "baremetal-introspection" => "ironic-inspector" [],
+    "block-storage" => "cinder" ["volumev3", "volumev2", "volume", "block-store"],
+    "cloudformation" => "heat" [],
+    "clustering" => "senlin" ["cluster"],
+    "compute" => "nova" [],
+    "container-infrastructure-management" => "magnum" ["container-infrastructure", "container-infra"],
+    "data-processing" => "sahara" [],
+    "database" => "trove" [],
+    "dns" => "designate" [],
+    "ec2-api" => "ec2-api" [],
+    "event" => "panko" [],
+    "function-engine" => "qinling" [],
+    "identity" => "keystone" [],
+    "image" => "glance" [],
+    "instance-ha" => "masakari" ["ha"],
+    "key-manager" => "barbican" [],
+    "load-balancer" => "octavia" [],
+    "messaging" => "zaqar" [],
+    "meter" => "ceilometer" ["metering"],
+    "metric" => "gnocchi" [],
+    "monitoring" => "monasca" [],
+    "network" => "neutron" [],
+    "nfv-orchestration" => "tacker" [],
+    "object-store" => "swift" [],
+    "orchestration" => "heat" [],
+    "placement" => "placement" [],
+    "rating" => "cloudkitty" [],
+    "reservation" => "blazar" [],
+    "resource-optimization" => "watcher" ["infra-optim"],
+    "root-cause-analysis" => "vitrage" ["rca"],
+    "search" => "searchlight" [],
+    "shared-file-system" => "manila" ["sharev2", "share"],
+    "workflow" => "mistral" ["workflowv2"],
+];
+
+/// Find service type information by the official service type, its alias
+/// or the project name
+pub fn find(name: &str) -> Option<&'static ServiceTypeInfo> {
+    let name = name.trim().to_lowercase().replace('_', "-");
+    SERVICE_TYPES
+        .iter()
+        .find(|x| x.service_type == name || x.aliases.contains(&name.as_str()))
+        .or_else(|| {
+            // Project name (i.e. `barbican`) is only used for services with a
+            // single service type
+            let mut matching = SERVICE_TYPES.iter().filter(|x| x.project == name);
+            match (matching.next(), matching.next()) {
+                (Some(info), None) => Some(info),
+                _ => None,
+            }
+        })
+}
+
+impl ServiceTypeInfo {
+    /// Official service type followed by the aliases
+    pub fn all_types(&self) -> impl Iterator<Item = &'static str> {
+        std::iter::once(self.service_type).chain(self.aliases.iter().copied())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find() {
+        assert_eq!(find("volumev3").unwrap().service_type, "block-storage");
+        assert_eq!(find("block_storage").unwrap().service_type, "block-storage");
+        assert_eq!(find("sharev2").unwrap().service_type, "shared-file-system");
+        assert_eq!(find("barbican").unwrap().service_type, "key-manager");
+        assert_eq!(find("DNS").unwrap().service_type, "dns");
+        // heat implements 2 service types
+        assert!(find("heat").is_none());
+        assert!(find("foo").is_none());
+    }
+
+    #[test]
+    fn test_unique() {
+        let mut names: Vec<&str> = SERVICE_TYPES.iter().flat_map(|x| x.all_types()).collect();
+        let count = names.len();
+        names.sort();
+        names.dedup();
+        assert_eq!(names.len(), count);
+    }
+}
@@ -12,19 +12,14 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 
-use std::env;
-
 use openstack_sdk::types::ServiceType;
-use openstack_sdk::{config::ConfigFile, OpenStack};
+use openstack_sdk::OpenStack;
+
+use crate::get_cloud_config;
 
 #[test]
 fn sync_connection() -> Result<(), Box<dyn std::error::Error>> {
-    let cfg = ConfigFile::new().unwrap();
-    // Get connection config from clouds.yaml/secure.yaml
-    let profile = cfg
-        .get_cloud_config(env::var("OS_CLOUD").expect("OS_CLOUD variable set"))
-        .unwrap()
-        .unwrap();
+    let (profile, _cloud) = get_cloud_config();
     // Establish connection
     let mut session = OpenStack::new(&profile)?;
 
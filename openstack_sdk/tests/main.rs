@@ -13,4 +13,44 @@
 // SPDX-License-Identifier: Apache-2.0
 
 //! Integration tests of OpenStackSDK
+//!
+//! Tests are executed against the cloud named by the `OS_CLOUD` environment
+//! variable. With the `test-utils` feature enabled and `OS_CLOUD` not being
+//! set the fake cloud is used instead.
 mod connection;
+
+use std::env;
+
+use openstack_sdk::config::{CloudConfig, ConfigFile};
+#[cfg(feature = "test-utils")]
+use openstack_sdk::test_utils::FakeCloud;
+
+/// Get connection config from clouds.yaml/secure.yaml
+fn load_cloud_config(cloud: String) -> CloudConfig {
+    ConfigFile::new()
+        .unwrap()
+        .get_cloud_config(cloud)
+        .unwrap()
+        .unwrap()
+}
+
+/// Connection config of the cloud to test against
+///
+/// The fake cloud (if any) is stopped once the returned guard is dropped.
+#[cfg(feature = "test-utils")]
+pub fn get_cloud_config() -> (CloudConfig, Option<FakeCloud>) {
+    match env::var("OS_CLOUD") {
+        Ok(cloud) => (load_cloud_config(cloud), None),
+        Err(_) => {
+            let fake = FakeCloud::new();
+            (fake.cloud_config(), Some(fake))
+        }
+    }
+}
+
+/// Connection config of the cloud to test against
+#[cfg(not(feature = "test-utils"))]
+pub fn get_cloud_config() -> (CloudConfig, Option<()>) {
+    let cloud = env::var("OS_CLOUD").expect("OS_CLOUD variable set");
+    (load_cloud_config(cloud), None)
+}
@@ -6,6 +6,8 @@ use std::{
 
 use openstack_cli::Cli;
 
+mod openapi;
+
 type DynError = Box<dyn std::error::Error>;
 
 fn main() {
@@ -20,6 +22,12 @@ fn try_main() -> Result<(), DynError> {
     match task.as_deref() {
         Some("dist") => dist()?,
         Some("doc") => build_doc()?,
+        Some("openapi") => openapi::export(
+            &env::args()
+                .nth(2)
+                .map(PathBuf::from)
+                .unwrap_or_else(|| project_root().join("target/openapi")),
+        )?,
         _ => print_help(),
     }
     Ok(())
@@ -31,6 +39,7 @@ fn print_help() {
 
 dist            builds application and man pages
 doc             builds man pages and doc
+openapi [DIR]   exports OpenAPI specs of the SDK endpoints (default: target/openapi)
 "
     )
 }
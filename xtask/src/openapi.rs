@@ -0,0 +1,729 @@
+//! Export of the OpenAPI specs of the endpoints wrapped by the `openstack_sdk`
+//!
+//! Every endpoint module of the SDK (`openstack_sdk/src/api/<service>/<version>`)
+//! is parsed and converted into the OpenAPI 3.1 operation:
+//!
+//! - path and method come from the `RestEndpoint` implementation
+//! - path and query parameters come from the request structure fields
+//! - request body schema is built from the serde structures of the module
+//! - response codes and descriptions come from the module documentation
+//!
+//! Modules implementing different microversions of the same operation (i.e.
+//! `create_21`, `create_294`) or different actions on the same URL are
+//! combined into the single operation with `oneOf` body schemas annotated
+//! with the `x-openstack` extension (`min-ver`, `operation`).
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use quote::ToTokens;
+use regex::Regex;
+use serde_json::{json, Map, Value};
+use syn::{Attribute, Expr, Fields, GenericArgument, Item, Lit, PathArguments, Type};
+
+use crate::{project_root, DynError};
+
+/// Maximal depth of the nested structures expansion
+const MAX_DEPTH: usize = 16;
+
+/// Build OpenAPI specs of all services and write them into the `output`
+/// directory as `<service>/<version>.json`.
+pub(crate) fn export(output: &Path) -> Result<(), DynError> {
+    let api_root = project_root().join("openstack_sdk/src/api");
+    for service in sorted_dirs(&api_root)? {
+        for version in sorted_dirs(&service)? {
+            let mut operations: Vec<Operation> = Vec::new();
+            collect_operations(&version, &version, &mut operations)?;
+            if operations.is_empty() {
+                continue;
+            }
+            let service_name = file_name(&service);
+            let version_name = file_name(&version);
+            let spec = build_spec(&service_name, &version_name, operations);
+            let dst = output.join(&service_name);
+            fs::create_dir_all(&dst)?;
+            let dst = dst.join(format!("{}.json", version_name));
+            fs::write(&dst, serde_json::to_string_pretty(&spec)? + "\n")?;
+            println!("written `{}`", dst.display());
+        }
+    }
+    Ok(())
+}
+
+/// Single endpoint module
+#[derive(Debug)]
+struct Operation {
+    /// Module path relative to the service version (`network/set`)
+    module: String,
+    /// Microversion of the operation
+    microversion: Option<String>,
+    method: String,
+    path: String,
+    summary: Option<String>,
+    description: Option<String>,
+    parameters: Vec<Value>,
+    request_body: Option<(String, Value)>,
+    responses: BTreeMap<String, String>,
+}
+
+impl Operation {
+    /// Operation ID without the microversion suffix
+    fn base_id(&self) -> String {
+        let module = match &self.microversion {
+            Some(_) => self
+                .module
+                .rsplit_once('_')
+                .map_or(self.module.as_str(), |(base, _)| base),
+            None => self.module.as_str(),
+        };
+        module.replace('/', ".")
+    }
+
+    /// Sort key of the microversion
+    fn version_key(&self) -> (u32, u32) {
+        self.microversion
+            .as_deref()
+            .and_then(|x| x.split_once('.'))
+            .map_or((0, 0), |(major, minor)| {
+                (major.parse().unwrap_or(0), minor.parse().unwrap_or(0))
+            })
+    }
+}
+
+/// Structures and enums declared in the module
+#[derive(Default)]
+struct Items {
+    structs: HashMap<String, syn::ItemStruct>,
+    enums: HashMap<String, syn::ItemEnum>,
+}
+
+fn sorted_dirs(path: &Path) -> Result<Vec<PathBuf>, DynError> {
+    let mut res: Vec<PathBuf> = fs::read_dir(path)?
+        .filter_map(|x| x.ok().map(|x| x.path()))
+        .filter(|x| x.is_dir())
+        .collect();
+    res.sort();
+    Ok(res)
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_stem()
+        .map(|x| x.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+/// Recursively parse endpoint modules of the directory
+fn collect_operations(
+    root: &Path,
+    dir: &Path,
+    operations: &mut Vec<Operation>,
+) -> Result<(), DynError> {
+    let mut entries: Vec<PathBuf> = fs::read_dir(dir)?
+        .filter_map(|x| x.ok().map(|x| x.path()))
+        .collect();
+    entries.sort();
+    for entry in entries {
+        if entry.is_dir() {
+            collect_operations(root, &entry, operations)?;
+        } else if entry.extension().is_some_and(|x| x == "rs") {
+            let module = entry
+                .strip_prefix(root)?
+                .with_extension("")
+                .to_string_lossy()
+                .to_string();
+            let content = fs::read_to_string(&entry)?;
+            let file = syn::parse_file(&content)
+                .map_err(|err| format!("cannot parse `{}`: {}", entry.display(), err))?;
+            if let Some(operation) = parse_operation(&module, &file) {
+                operations.push(operation);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Convert the endpoint module into the operation
+fn parse_operation(module: &str, file: &syn::File) -> Option<Operation> {
+    let mut items = Items::default();
+    let mut endpoint_impl: Option<&syn::ItemImpl> = None;
+    for item in file.items.iter() {
+        match item {
+            Item::Struct(val) => {
+                items.structs.insert(val.ident.to_string(), val.clone());
+            }
+            Item::Enum(val) => {
+                items.enums.insert(val.ident.to_string(), val.clone());
+            }
+            Item::Impl(val) => {
+                let is_endpoint = val.trait_.as_ref().is_some_and(|(_, path, _)| {
+                    path.segments
+                        .last()
+                        .is_some_and(|x| x.ident == "RestEndpoint")
+                });
+                if is_endpoint {
+                    endpoint_impl = Some(val);
+                }
+            }
+            _ => {}
+        }
+    }
+    let endpoint_impl = endpoint_impl?;
+    let request = items.structs.get(&type_name(&endpoint_impl.self_ty)?)?;
+    let fields = struct_fields(request);
+
+    let mut method = None;
+    let mut path = None;
+    let mut parameters = Vec::new();
+    let mut request_body = None;
+    for item in endpoint_impl.items.iter() {
+        let syn::ImplItem::Fn(func) = item else {
+            continue;
+        };
+        let code: String = func
+            .block
+            .to_token_stream()
+            .to_string()
+            .split_whitespace()
+            .collect();
+        match func.sig.ident.to_string().as_str() {
+            "method" => {
+                method = code
+                    .rsplit("::")
+                    .next()
+                    .map(|x| x.trim_end_matches('}').to_string())
+            }
+            "endpoint" => {
+                let (template, params) = parse_path(&code, &fields, &items);
+                path = Some(template);
+                parameters.extend(params);
+            }
+            "parameters" => parameters.extend(parse_query(&code, &fields, &items)),
+            "body" => request_body = parse_body(&code, &fields, &items),
+            _ => {}
+        }
+    }
+
+    let docs = doc_string(&file.attrs);
+    let microversion = Regex::new(r"_(\d)(\d+)$")
+        .expect("valid regex")
+        .captures(module)
+        .map(|x| format!("{}.{}", &x[1], &x[2]));
+    Some(Operation {
+        module: module.to_string(),
+        microversion,
+        method: method?.to_lowercase(),
+        path: format!("/{}", path?.trim_start_matches('/')),
+        summary: docs
+            .as_deref()
+            .and_then(|x| x.split("\n\n").next())
+            .map(|x| x.replace('\n', " ")),
+        responses: parse_responses(docs.as_deref().unwrap_or_default()),
+        description: docs,
+        parameters,
+        request_body,
+    })
+}
+
+/// Name of the type (without generics)
+fn type_name(ty: &Type) -> Option<String> {
+    match ty {
+        Type::Path(path) => path.path.segments.last().map(|x| x.ident.to_string()),
+        _ => None,
+    }
+}
+
+/// Fields of the structure by name
+fn struct_fields(item: &syn::ItemStruct) -> HashMap<String, syn::Field> {
+    match &item.fields {
+        Fields::Named(fields) => fields
+            .named
+            .iter()
+            .filter_map(|x| x.ident.as_ref().map(|name| (name.to_string(), x.clone())))
+            .collect(),
+        _ => HashMap::new(),
+    }
+}
+
+/// Path template and path parameters from the `endpoint` function
+fn parse_path(
+    code: &str,
+    fields: &HashMap<String, syn::Field>,
+    items: &Items,
+) -> (String, Vec<Value>) {
+    let mut mapping: HashMap<String, String> = Regex::new(r"(\w+)=self\.(\w+)")
+        .expect("valid regex")
+        .captures_iter(code)
+        .map(|x| (x[1].to_string(), x[2].to_string()))
+        .collect();
+    let template = match Regex::new(r#""([^"]*)""#)
+        .expect("valid regex")
+        .captures(code)
+    {
+        Some(x) => x[1].to_string(),
+        // Endpoint is the field itself (i.e. object store container)
+        None => match Regex::new(r"^\{self\.(\w+)")
+            .expect("valid regex")
+            .captures(code)
+        {
+            Some(x) => {
+                mapping.insert(x[1].to_string(), x[1].to_string());
+                format!("{{{}}}", &x[1])
+            }
+            None => String::new(),
+        },
+    };
+    let params = Regex::new(r"\{(\w+)\}")
+        .expect("valid regex")
+        .captures_iter(&template)
+        .map(|x| {
+            let name = x[1].to_string();
+            let field = mapping.get(&name).and_then(|x| fields.get(x));
+            parameter(&name, "path", true, field, items)
+        })
+        .collect();
+    (template, params)
+}
+
+/// Query parameters from the `parameters` function
+fn parse_query(code: &str, fields: &HashMap<String, syn::Field>, items: &Items) -> Vec<Value> {
+    let push =
+        Regex::new(r#"push(?:_opt|_opt_key_only)?\("([^"]+)",self\.(\w+)"#).expect("valid regex");
+    let extend =
+        Regex::new(r#"extend\(self\.(\w+)\.iter\(\)\.map\(\|value\|\("([^"]+)",value\)\)"#)
+            .expect("valid regex");
+    let mut res: Vec<Value> = push
+        .captures_iter(code)
+        .map(|x| (x[1].to_string(), x[2].to_string()))
+        .chain(
+            extend
+                .captures_iter(code)
+                .map(|x| (x[2].to_string(), x[1].to_string())),
+        )
+        .map(|(name, field)| {
+            let field = fields.get(&field);
+            let required = field.is_some_and(|x| !is_option(&x.ty));
+            let mut param = parameter(&name, "query", required, field, items);
+            if param["schema"]["type"] == "array" {
+                param["explode"] = true.into();
+            }
+            param
+        })
+        .collect();
+    res.dedup_by(|a, b| a["name"] == b["name"]);
+    res
+}
+
+/// Parameter object
+fn parameter(
+    name: &str,
+    location: &str,
+    required: bool,
+    field: Option<&syn::Field>,
+    items: &Items,
+) -> Value {
+    let mut param = json!({
+        "name": name,
+        "in": location,
+        "required": required,
+        "schema": field.map_or(json!({"type": "string"}), |x| type_schema(&x.ty, items, 0)),
+    });
+    if let Some(description) = field.and_then(|x| doc_string(&x.attrs)) {
+        param["description"] = description.into();
+    }
+    param
+}
+
+/// Request body content type and schema from the `body` function
+fn parse_body(
+    code: &str,
+    fields: &HashMap<String, syn::Field>,
+    items: &Items,
+) -> Option<(String, Value)> {
+    let content_type = Regex::new(r#""(application/[^"]+)""#)
+        .expect("valid regex")
+        .captures(code)
+        .map_or("application/json".to_string(), |x| x[1].to_string());
+    if !code.contains("params.into_body") {
+        // Body is sent as is (i.e. JSON patch or binary data)
+        return code.contains("Ok(Some(").then(|| (content_type, json!({})));
+    }
+
+    let mut properties = Map::new();
+    let mut required = Vec::new();
+    let always =
+        Regex::new(r#"push\("([^"]+)",serde_json::to_value\(&self\.(\w+)\)"#).expect("valid regex");
+    for cap in always.captures_iter(code) {
+        if let Some(field) = fields.get(&cap[2]) {
+            properties.insert(cap[1].to_string(), field_schema(field, items, 0));
+            required.push(cap[1].to_string());
+        }
+    }
+    let optional = Regex::new(
+        r#"ifletSome\(val\)=&self\.(\w+)\{params\.push\("([^"]+)",serde_json::to_value\(val\)"#,
+    )
+    .expect("valid regex");
+    for cap in optional.captures_iter(code) {
+        if let Some(field) = fields.get(&cap[1]) {
+            properties.insert(cap[2].to_string(), field_schema(field, items, 0));
+        }
+    }
+    for cap in Regex::new(r#"push\("([^"]+)",Value::Null\)"#)
+        .expect("valid regex")
+        .captures_iter(code)
+    {
+        properties.insert(cap[1].to_string(), json!({"type": "null"}));
+        required.push(cap[1].to_string());
+    }
+    let mut schema = json!({"type": "object", "properties": properties});
+    if !required.is_empty() {
+        schema["required"] = required.into();
+    }
+    if code.contains("params.push(key.clone()") {
+        schema["additionalProperties"] = true.into();
+    }
+    Some((content_type, schema))
+}
+
+/// Response codes from the module documentation
+fn parse_responses(docs: &str) -> BTreeMap<String, String> {
+    let mut res = BTreeMap::new();
+    for (prefix, description) in [
+        ("Normal response codes:", "Successful response"),
+        ("Error response codes:", "Error response"),
+    ] {
+        if let Some(line) = docs.lines().find(|x| x.starts_with(prefix)) {
+            for code in line[prefix.len()..].split(',') {
+                let code = code.trim();
+                if code.len() == 3 && code.chars().all(|x| x.is_ascii_digit()) {
+                    res.insert(code.to_string(), description.to_string());
+                }
+            }
+        }
+    }
+    res
+}
+
+/// Documentation of the item from the `///` comments
+fn doc_string(attrs: &[Attribute]) -> Option<String> {
+    let lines: Vec<String> = attrs
+        .iter()
+        .filter(|x| x.path().is_ident("doc"))
+        .filter_map(|x| match &x.meta {
+            syn::Meta::NameValue(val) => match &val.value {
+                Expr::Lit(syn::ExprLit {
+                    lit: Lit::Str(doc), ..
+                }) => Some(doc.value()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .map(|x| x.strip_prefix(' ').unwrap_or(&x).trim_end().to_string())
+        .collect();
+    let doc = lines.join("\n").trim().to_string();
+    (!doc.is_empty()).then_some(doc)
+}
+
+/// Content of the attribute (i.e. `serde`) as a string
+fn attr_string(attrs: &[Attribute], name: &str) -> String {
+    attrs
+        .iter()
+        .filter(|x| x.path().is_ident(name))
+        .map(|x| x.meta.to_token_stream().to_string())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Value of the `rename = "..."` serde attribute
+fn serde_rename(attrs: &[Attribute]) -> Option<String> {
+    Regex::new(r#"\brename\s*=\s*"([^"]+)""#)
+        .expect("valid regex")
+        .captures(&attr_string(attrs, "serde"))
+        .map(|x| x[1].to_string())
+}
+
+fn has_flag(attrs: &[Attribute], name: &str, flag: &str) -> bool {
+    Regex::new(&format!(r"\b{}\b\s*(?:[,)]|$)", flag))
+        .expect("valid regex")
+        .is_match(&attr_string(attrs, name))
+}
+
+/// Whether the type is `Option<T>`
+fn is_option(ty: &Type) -> bool {
+    type_name(ty).is_some_and(|x| x == "Option")
+}
+
+/// Generic type arguments of the type
+fn type_args(ty: &Type) -> Vec<&Type> {
+    let Type::Path(path) = ty else {
+        return Vec::new();
+    };
+    match path.path.segments.last().map(|x| &x.arguments) {
+        Some(PathArguments::AngleBracketed(args)) => args
+            .args
+            .iter()
+            .filter_map(|x| match x {
+                GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Schema of the structure field with its description
+fn field_schema(field: &syn::Field, items: &Items, depth: usize) -> Value {
+    let mut schema = type_schema(&field.ty, items, depth);
+    if let (Some(description), Value::Object(obj)) = (doc_string(&field.attrs), &mut schema) {
+        obj.insert("description".into(), description.into());
+    }
+    schema
+}
+
+/// Schema of the rust type
+fn type_schema(ty: &Type, items: &Items, depth: usize) -> Value {
+    let ty = match ty {
+        Type::Reference(val) => &val.elem,
+        other => other,
+    };
+    let args = type_args(ty);
+    match ty {
+        Type::Slice(val) => {
+            return json!({"type": "array", "items": type_schema(&val.elem, items, depth)})
+        }
+        Type::Path(_) => {}
+        _ => return json!({}),
+    }
+    let name = type_name(ty).unwrap_or_default();
+    match name.as_str() {
+        "str" | "String" => json!({"type": "string"}),
+        "bool" => json!({"type": "boolean"}),
+        "i8" | "i16" | "i32" | "u8" | "u16" | "u32" => {
+            json!({"type": "integer", "format": "int32"})
+        }
+        "i64" | "u64" | "isize" | "usize" => json!({"type": "integer", "format": "int64"}),
+        "f32" | "f64" => json!({"type": "number"}),
+        "Value" => json!({}),
+        "Cow" | "Box" | "Arc" => args
+            .last()
+            .map_or(json!({}), |x| type_schema(x, items, depth)),
+        "Option" => match args.first() {
+            Some(inner) if is_option(inner) => nullable(type_schema(inner, items, depth)),
+            Some(inner) => type_schema(inner, items, depth),
+            None => json!({}),
+        },
+        "Vec" | "CommaSeparatedList" | "BTreeSet" | "HashSet" => json!({
+            "type": "array",
+            "items": args.first().map_or(json!({}), |x| type_schema(x, items, depth)),
+        }),
+        "BTreeMap" | "HashMap" => json!({
+            "type": "object",
+            "additionalProperties": args.get(1).map_or(json!({}), |x| type_schema(x, items, depth)),
+        }),
+        _ if depth >= MAX_DEPTH => json!({}),
+        _ => {
+            if let Some(item) = items.structs.get(&name) {
+                struct_schema(item, items, depth + 1)
+            } else if let Some(item) = items.enums.get(&name) {
+                enum_schema(item, items, depth + 1)
+            } else {
+                json!({"x-rust-type": name})
+            }
+        }
+    }
+}
+
+/// Allow `null` in addition to the schema
+fn nullable(mut schema: Value) -> Value {
+    match schema.get("type").cloned() {
+        Some(Value::String(kind)) => {
+            schema["type"] = json!([kind, "null"]);
+            schema
+        }
+        _ => json!({"anyOf": [schema, {"type": "null"}]}),
+    }
+}
+
+/// Schema of the serde structure
+fn struct_schema(item: &syn::ItemStruct, items: &Items, depth: usize) -> Value {
+    let mut properties = Map::new();
+    let mut required = Vec::new();
+    let mut additional = None;
+    if let Fields::Named(fields) = &item.fields {
+        for field in fields.named.iter() {
+            let Some(ident) = &field.ident else {
+                continue;
+            };
+            if has_flag(&field.attrs, "serde", "skip") {
+                continue;
+            }
+            if has_flag(&field.attrs, "serde", "flatten") {
+                let schema = type_schema(&field.ty, items, depth);
+                match schema.get("properties") {
+                    Some(Value::Object(props)) => properties.extend(props.clone()),
+                    _ => additional = schema.get("additionalProperties").cloned(),
+                }
+                continue;
+            }
+            let name = serde_rename(&field.attrs).unwrap_or_else(|| ident.to_string());
+            if !is_option(&field.ty) && !has_flag(&field.attrs, "builder", "default") {
+                required.push(name.clone());
+            }
+            properties.insert(name, field_schema(field, items, depth));
+        }
+    }
+    let mut schema = json!({"type": "object", "properties": properties});
+    if !required.is_empty() {
+        schema["required"] = required.into();
+    }
+    if let Some(additional) = additional {
+        schema["additionalProperties"] = additional;
+    }
+    if let Some(description) = doc_string(&item.attrs) {
+        schema["description"] = description.into();
+    }
+    schema
+}
+
+/// Schema of the serde enum
+fn enum_schema(item: &syn::ItemEnum, items: &Items, depth: usize) -> Value {
+    let mut values = Vec::new();
+    let mut variants = Vec::new();
+    for variant in item.variants.iter() {
+        match &variant.fields {
+            Fields::Unit => values.push(Value::from(
+                serde_rename(&variant.attrs).unwrap_or_else(|| variant.ident.to_string()),
+            )),
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                variants.push(type_schema(&fields.unnamed[0].ty, items, depth))
+            }
+            _ => variants.push(json!({})),
+        }
+    }
+    if !values.is_empty() {
+        variants.push(json!({"type": "string", "enum": values}));
+    }
+    if variants.len() == 1 {
+        variants.remove(0)
+    } else {
+        json!({ "oneOf": variants })
+    }
+}
+
+/// Build OpenAPI document of the service version
+fn build_spec(service: &str, version: &str, mut operations: Vec<Operation>) -> Value {
+    let mut grouped: BTreeMap<(String, String), Vec<Operation>> = BTreeMap::new();
+    operations.sort_by_key(|x| (x.module.clone(), x.version_key()));
+    for operation in operations {
+        grouped
+            .entry((operation.path.clone(), operation.method.clone()))
+            .or_default()
+            .push(operation);
+    }
+
+    let mut paths: BTreeMap<String, Map<String, Value>> = BTreeMap::new();
+    for ((path, method), mut variants) in grouped {
+        variants.sort_by_key(|x| (x.base_id(), x.version_key()));
+        let ids: BTreeSet<String> = variants.iter().map(|x| x.base_id()).collect();
+        let latest = variants.last().expect("group is not empty");
+        let mut operation = json!({
+            "tags": [latest.module.split('/').next().unwrap_or(service)],
+        });
+        if ids.len() == 1 {
+            operation["operationId"] = ids.into_iter().next().unwrap_or_default().into();
+            if let Some(summary) = &latest.summary {
+                operation["summary"] = summary.clone().into();
+            }
+            if let Some(description) = &latest.description {
+                operation["description"] = description.clone().into();
+            }
+        } else {
+            // Different operations (i.e. server actions) share the URL. Every
+            // one of them is described by the `oneOf` body schema.
+            let resource = latest
+                .module
+                .rsplit_once('/')
+                .map_or(latest.module.as_str(), |(parent, _)| parent);
+            let verb = path
+                .rsplit('/')
+                .find(|x| !x.starts_with('{'))
+                .unwrap_or(&method);
+            operation["operationId"] = format!("{}.{}", resource.replace('/', "."), verb).into();
+        }
+
+        let mut parameters: Vec<Value> = Vec::new();
+        for param in variants.iter().flat_map(|x| x.parameters.iter()) {
+            if !parameters
+                .iter()
+                .any(|x| x["name"] == param["name"] && x["in"] == param["in"])
+            {
+                parameters.push(param.clone());
+            }
+        }
+        if !parameters.is_empty() {
+            operation["parameters"] = parameters.into();
+        }
+
+        let bodies: Vec<(&Operation, &(String, Value))> = variants
+            .iter()
+            .filter_map(|x| x.request_body.as_ref().map(|body| (x, body)))
+            .collect();
+        if let [(variant, (content_type, schema))] = bodies.as_slice() {
+            let mut schema = schema.clone();
+            if let Some(ver) = &variant.microversion {
+                schema["x-openstack"] = json!({"min-ver": ver});
+            }
+            operation["requestBody"] = json!({"content": {content_type: {"schema": schema}}});
+        } else if !bodies.is_empty() {
+            let content_type = &bodies[0].1 .0;
+            let schemas: Vec<Value> = bodies
+                .iter()
+                .map(|(variant, (_, schema))| {
+                    let mut schema = schema.clone();
+                    let mut ext = json!({"operation": variant.module.replace('/', ".")});
+                    if let Some(ver) = &variant.microversion {
+                        ext["min-ver"] = ver.clone().into();
+                    }
+                    schema["x-openstack"] = ext;
+                    schema
+                })
+                .collect();
+            operation["requestBody"] =
+                json!({"content": {content_type: {"schema": {"oneOf": schemas}}}});
+        }
+
+        let versions: Vec<&String> = variants
+            .iter()
+            .filter_map(|x| x.microversion.as_ref())
+            .collect();
+        if let Some(min_ver) = versions.first() {
+            operation["x-openstack"] = json!({"min-ver": min_ver});
+        }
+
+        let mut responses: BTreeMap<String, String> = BTreeMap::new();
+        for variant in variants.iter() {
+            responses.extend(variant.responses.clone());
+        }
+        operation["responses"] = if responses.is_empty() {
+            json!({"default": {"description": "Response of the operation"}})
+        } else {
+            responses
+                .into_iter()
+                .map(|(code, description)| (code, json!({ "description": description })))
+                .collect::<Map<String, Value>>()
+                .into()
+        };
+
+        paths.entry(path).or_default().insert(method, operation);
+    }
+
+    json!({
+        "openapi": "3.1.0",
+        "info": {
+            "title": format!("OpenStack {} API", service.replace('_', " ")),
+            "description": "Operations wrapped by the `openstack_sdk` crate",
+            "version": version,
+        },
+        "paths": paths,
+    })
+}